@@ -3,10 +3,9 @@ alias=$(jq -r '.alias' collection.json)
 
 # Create both a collection and a dataset
 dvcli collection create --parent Root --body collection.json
-dvcli dataset create --collection $alias --body dataset.json >> dataset_output.json
+persistent_id=$(dvcli dataset create --collection $alias --body dataset.json --print-id)
 
-# Get the persistent ID of the dataset and upload a file
-persistent_id=$(jq -r '.persistentId' dataset_output.json)
+# Upload a file to the dataset
 dvcli dataset upload files/data.csv \
     --id $persistent_id \
     --body file.json
@@ -0,0 +1,172 @@
+use std::path::PathBuf;
+
+use crate::{
+    access::{datafile::download_file, zip::download_files_zip},
+    client::BaseClient,
+    identifier::Identifier,
+    native_api::dataset::get::get_dataset_meta,
+    native_api::info::zip_limit::get_zip_download_limit,
+};
+
+/// The outcome of a batched dataset download.
+#[derive(Debug, Default)]
+pub struct BatchedDownload {
+    /// ZIP archives containing multiple files.
+    pub archives: Vec<PathBuf>,
+    /// Files downloaded individually, either as the only file of a batch or because they exceed the limit.
+    pub files: Vec<PathBuf>,
+    /// File IDs of the batches that failed, along with the error message.
+    pub failed: Vec<(Vec<i64>, String)>,
+}
+
+/// Downloads all files of a dataset in ZIP batches that respect the download limit of the instance.
+///
+/// The instance omits files from a ZIP download once their total size exceeds its `zipDownloadLimit`. This
+/// asynchronous function queries the limit, groups the files of the latest version into batches below it and
+/// downloads each batch as a separate archive into the destination directory. Files larger than the limit are
+/// downloaded individually. A failing batch does not abort the remaining downloads.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `destination` - A `PathBuf` of the directory to download to, which is created if necessary.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `BatchedDownload` summary, or a `String` error message if the limit or the files of
+/// the dataset could not be retrieved.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let download = access::download_dataset_batched(&client, id, PathBuf::from("data")).await?;
+///
+/// println!("Downloaded {} archives", download.archives.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn download_dataset_batched(
+    client: &BaseClient,
+    id: Identifier,
+    destination: PathBuf,
+) -> Result<BatchedDownload, String> {
+    let limit = get_zip_download_limit(client)
+        .await?
+        .data
+        .and_then(|limit| limit.bytes())
+        .ok_or("Failed to retrieve the ZIP download limit of the instance")?;
+
+    let response = get_dataset_meta(client, id).await?;
+    if response.status.is_err() {
+        return Err(format!(
+            "Failed to retrieve the dataset: {}",
+            response.message.map(|m| m.to_string()).unwrap_or_default()
+        ));
+    }
+
+    let files = response
+        .data
+        .and_then(|dataset| dataset.latest_version)
+        .map(|version| version.files)
+        .unwrap_or_default()
+        .iter()
+        .filter_map(|file| {
+            let data_file = file.data_file.as_ref()?;
+            Some((data_file.id?, data_file.filesize.unwrap_or_default().max(0) as u64))
+        })
+        .collect::<Vec<_>>();
+
+    std::fs::create_dir_all(&destination).map_err(|e| e.to_string())?;
+
+    let (batches, oversized) = plan_batches(&files, limit);
+    let mut download = BatchedDownload::default();
+
+    for (index, batch) in batches.into_iter().enumerate() {
+        let result = match batch.as_slice() {
+            [id] => download_file(client, Identifier::Id(*id), destination.clone(), None).await,
+            ids => {
+                let path = destination.join(format!("dataverse_files_{}.zip", index + 1));
+                download_files_zip(client, ids, path, None).await
+            }
+        };
+
+        match result {
+            Ok(path) if batch.len() == 1 => download.files.push(path),
+            Ok(path) => download.archives.push(path),
+            Err(e) => download.failed.push((batch, e)),
+        }
+    }
+
+    for id in oversized {
+        match download_file(client, Identifier::Id(id), destination.clone(), None).await {
+            Ok(path) => download.files.push(path),
+            Err(e) => download.failed.push((vec![id], e)),
+        }
+    }
+
+    Ok(download)
+}
+
+// Groups files into batches whose total size stays within the limit, keeping the
+// original order. Returns the batches and the IDs of files exceeding the limit.
+fn plan_batches(files: &[(i64, u64)], limit: u64) -> (Vec<Vec<i64>>, Vec<i64>) {
+    let mut batches: Vec<Vec<i64>> = vec![];
+    let mut oversized = vec![];
+    let mut current_size = 0;
+
+    for &(id, size) in files {
+        if size > limit {
+            oversized.push(id);
+            continue;
+        }
+
+        match batches.last_mut() {
+            Some(batch) if current_size + size <= limit => {
+                batch.push(id);
+                current_size += size;
+            }
+            _ => {
+                batches.push(vec![id]);
+                current_size = size;
+            }
+        }
+    }
+
+    (batches, oversized)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::plan_batches;
+
+    /// Tests that files are grouped below the limit and oversized files are set aside.
+    #[test]
+    fn test_plan_batches() {
+        let files = [(1, 40), (2, 50), (3, 20), (4, 150), (5, 100), (6, 0)];
+        let (batches, oversized) = plan_batches(&files, 100);
+
+        assert_eq!(batches, vec![vec![1, 2], vec![3], vec![5, 6]]);
+        assert_eq!(oversized, vec![4]);
+    }
+
+    /// Tests that an empty dataset results in no batches.
+    #[test]
+    fn test_plan_batches_empty() {
+        let (batches, oversized) = plan_batches(&[], 100);
+
+        assert!(batches.is_empty());
+        assert!(oversized.is_empty());
+    }
+}
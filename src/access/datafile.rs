@@ -0,0 +1,399 @@
+use std::str::FromStr;
+#[cfg(feature = "fs")]
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+use crate::response::Response;
+#[cfg(feature = "fs")]
+use crate::{
+    callback::CallbackFun, client::BaseClient, filewrapper::write_response, identifier::Identifier,
+    request::RequestType,
+};
+
+/// Downloads a file and streams it to disk.
+///
+/// This asynchronous function sends a GET request to the data access API and writes the response chunk by
+/// chunk to the given destination, such that large files are never held in memory. The progress is rendered
+/// as a progress bar and reported to the optional callback with the number of bytes written per chunk.
+///
+/// If the destination is an existing directory, the file is stored within it using the file name provided
+/// by the server.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+/// * `destination` - A `PathBuf` of the file or directory to download to.
+/// * `callback` - An optional `CallbackFun` instance receiving the number of bytes written.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `PathBuf` the file has been written to, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let callback = CallbackFun::wrap(|bytes| println!("Received {} bytes", bytes));
+/// let path = access::download_file(&client, Identifier::Id(42), PathBuf::from("."), Some(callback)).await?;
+///
+/// println!("Downloaded to {}", path.display());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "fs")]
+pub async fn download_file(
+    client: &BaseClient,
+    id: Identifier,
+    destination: PathBuf,
+    callback: Option<CallbackFun>,
+) -> Result<PathBuf, String> {
+    // Endpoint metadata
+    let (url, parameters) = match &id {
+        Identifier::PersistentId(id) => (
+            "api/access/datafile/:persistentId".to_string(),
+            Some(HashMap::from([("persistentId".to_string(), id.clone())])),
+        ),
+        Identifier::Id(id) => (format!("api/access/datafile/{}", id), None),
+    };
+
+    let fallback = match &id {
+        Identifier::PersistentId(id) => id.rsplit('/').next().unwrap_or(id).to_string(),
+        Identifier::Id(id) => id.to_string(),
+    };
+
+    download(client, &url, parameters, destination, &fallback, callback).await
+}
+
+/// Formats in which tabular files ingested by Dataverse can be downloaded.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TabularFormat {
+    /// The file as it has been uploaded, e.g. an SPSS or Stata file
+    Original,
+    /// The tab-delimited archival format produced by the ingest, served by default
+    Tab,
+    /// An R data frame including the variable metadata
+    RData,
+    /// A pre-processed JSON summary of the variables
+    Prep,
+}
+
+impl TabularFormat {
+    // Value of the `format` query parameter, which is omitted for the default format
+    #[cfg(feature = "fs")]
+    fn parameter(&self) -> Option<&'static str> {
+        match self {
+            TabularFormat::Original => Some("original"),
+            TabularFormat::Tab => None,
+            TabularFormat::RData => Some("RData"),
+            TabularFormat::Prep => Some("prep"),
+        }
+    }
+}
+
+impl FromStr for TabularFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "original" => Ok(TabularFormat::Original),
+            "tab" => Ok(TabularFormat::Tab),
+            "RData" | "rdata" => Ok(TabularFormat::RData),
+            "prep" => Ok(TabularFormat::Prep),
+            _ => Err(format!("Invalid tabular format: {}", s)),
+        }
+    }
+}
+
+/// Downloads a tabular file in the given format and streams it to disk.
+///
+/// This asynchronous function behaves like `download_file`, but selects the format of an ingested tabular
+/// file via the `format` query parameter. Without it, Dataverse serves the tab-delimited archival version,
+/// such that the originally uploaded file can only be retrieved with `TabularFormat::Original`.
+///
+/// If the destination is an existing directory and the server does not provide a file name, the extension
+/// of the fallback name is derived from the content type of the response.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+/// * `format` - The `TabularFormat` to download the file in.
+/// * `destination` - A `PathBuf` of the file or directory to download to.
+/// * `callback` - An optional `CallbackFun` instance receiving the number of bytes written.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `PathBuf` the file has been written to, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+/// use dataverse::prelude::*;
+/// use dataverse::access::datafile::TabularFormat;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let path = access::download_tabular_file(
+///     &client,
+///     Identifier::Id(42),
+///     TabularFormat::Original,
+///     PathBuf::from("."),
+///     None,
+/// ).await?;
+///
+/// println!("Downloaded to {}", path.display());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "fs")]
+pub async fn download_tabular_file(
+    client: &BaseClient,
+    id: Identifier,
+    format: TabularFormat,
+    destination: PathBuf,
+    callback: Option<CallbackFun>,
+) -> Result<PathBuf, String> {
+    // Endpoint metadata
+    let (url, mut parameters) = match &id {
+        Identifier::PersistentId(id) => (
+            "api/access/datafile/:persistentId".to_string(),
+            HashMap::from([("persistentId".to_string(), id.clone())]),
+        ),
+        Identifier::Id(id) => (format!("api/access/datafile/{}", id), HashMap::new()),
+    };
+
+    if let Some(format) = format.parameter() {
+        parameters.insert("format".to_string(), format.to_string());
+    }
+
+    let fallback = match &id {
+        Identifier::PersistentId(id) => id.rsplit('/').next().unwrap_or(id).to_string(),
+        Identifier::Id(id) => id.to_string(),
+    };
+
+    let parameters = Some(parameters).filter(|parameters| !parameters.is_empty());
+    download(client, &url, parameters, destination, &fallback, callback).await
+}
+
+// Sends a GET request and streams the response to the destination. If the destination is a
+// directory, the file name provided by the server or the fallback name is used within it.
+#[cfg(feature = "fs")]
+pub(crate) async fn download(
+    client: &BaseClient,
+    url: &str,
+    parameters: Option<HashMap<String, String>>,
+    destination: PathBuf,
+    fallback: &str,
+    callback: Option<CallbackFun>,
+) -> Result<PathBuf, String> {
+    // Send request
+    let context = RequestType::Plain;
+    let response = client
+        .get(url, parameters, &context)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !response.status().is_success() {
+        return Err(error_message(response).await);
+    }
+
+    // Resolve the target path, using the name provided by the server for directories
+    let path = if destination.is_dir() {
+        let headers = response.headers();
+        let filename = headers
+            .get(reqwest::header::CONTENT_DISPOSITION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(filename_from_disposition)
+            .unwrap_or_else(|| {
+                let content_type = headers
+                    .get(reqwest::header::CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok());
+                fallback_filename(fallback, content_type)
+            });
+        destination.join(filename)
+    } else {
+        destination
+    };
+
+    write_response(response, &path, callback)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(path)
+}
+
+// Extracts the error message of a failed request, which is usually
+// wrapped in the JSON response structure of the native API
+pub(crate) async fn error_message(response: reqwest::Response) -> String {
+    let status = response.status();
+    let content = response.text().await.unwrap_or_default();
+
+    match serde_json::from_str::<Response<serde_json::Value>>(&content) {
+        Ok(Response { message: Some(message), .. }) => message.to_string(),
+        _ => format!("{} - {}", status, content),
+    }
+}
+
+// Extracts the file name of a Content-Disposition header value
+#[cfg(feature = "fs")]
+fn filename_from_disposition(value: &str) -> Option<String> {
+    value
+        .split(';')
+        .map(|part| part.trim())
+        .find_map(|part| part.strip_prefix("filename="))
+        .map(|name| name.trim_matches('"').to_string())
+        .filter(|name| !name.is_empty() && !name.contains(['/', '\\']))
+}
+
+// Appends the extension matching the content type to fallback names without one,
+// such that e.g. tab-delimited and RData versions of a file can be told apart
+#[cfg(feature = "fs")]
+fn fallback_filename(fallback: &str, content_type: Option<&str>) -> String {
+    if Path::new(fallback).extension().is_some() {
+        return fallback.to_string();
+    }
+
+    let mime = content_type
+        .and_then(|value| value.split(';').next())
+        .map(|mime| mime.trim().to_lowercase());
+
+    let extension = match mime.as_deref() {
+        Some("text/tab-separated-values") => "tab",
+        Some("text/csv") | Some("text/comma-separated-values") => "csv",
+        Some("application/x-rlang-transport") => "RData",
+        Some("application/x-stata") | Some("application/x-stata-13") | Some("application/x-stata-14") => "dta",
+        Some("application/x-spss-sav") => "sav",
+        Some("application/x-spss-por") => "por",
+        Some("application/json") => "json",
+        Some("application/zip") => "zip",
+        Some("application/pdf") => "pdf",
+        Some("text/plain") => "txt",
+        _ => return fallback.to_string(),
+    };
+
+    format!("{}.{}", fallback, extension)
+}
+
+#[cfg(all(test, feature = "fs"))]
+mod tests {
+    use std::sync::{Arc, Mutex};
+
+    use httpmock::prelude::*;
+
+    use crate::prelude::*;
+
+    use super::{fallback_filename, filename_from_disposition};
+    use super::TabularFormat;
+
+    /// Tests that a file is streamed to a directory and that the callback receives all bytes.
+    ///
+    /// This test serves a file from a mock server, downloads it into a temporary directory and
+    /// asserts that the name of the Content-Disposition header is used and that the callback
+    /// has been called with the full content length.
+    #[tokio::test]
+    async fn test_download_file() {
+        let content = "Hello, Dataverse!".repeat(1024);
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/api/access/datafile/42");
+            then.status(200)
+                .header("Content-Disposition", "attachment; filename=\"hello.txt\"")
+                .body(&content);
+        });
+
+        let dir = std::env::temp_dir().join(format!("dvcli_download_{}", rand::random::<u32>()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let received = Arc::new(Mutex::new(0));
+        let counter = Arc::clone(&received);
+        let callback = CallbackFun::wrap(move |bytes| *counter.lock().unwrap() += bytes);
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let path = access::download_file(&client, Identifier::Id(42), dir.clone(), Some(callback))
+            .await
+            .expect("Failed to download file");
+
+        mock.assert();
+        assert_eq!(path, dir.join("hello.txt"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), content);
+        assert_eq!(*received.lock().unwrap(), content.len() as u64);
+    }
+
+    /// Tests the extraction of file names from Content-Disposition headers.
+    #[test]
+    fn test_filename_from_disposition() {
+        assert_eq!(
+            filename_from_disposition("attachment; filename=\"data.csv\""),
+            Some("data.csv".to_string())
+        );
+        assert_eq!(filename_from_disposition("attachment; filename=data.csv"), Some("data.csv".to_string()));
+        assert_eq!(filename_from_disposition("attachment; filename=\"../etc/passwd\""), None);
+        assert_eq!(filename_from_disposition("attachment"), None);
+    }
+
+    /// Tests that the format query parameter is sent and that the original file name is used.
+    #[tokio::test]
+    async fn test_download_tabular_file_original() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/access/datafile/42")
+                .query_param("format", "original");
+            then.status(200)
+                .header("Content-Disposition", "attachment; filename=\"survey.sav\"")
+                .body("original");
+        });
+
+        let dir = std::env::temp_dir().join(format!("dvcli_tabular_{}", rand::random::<u32>()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let path = access::download_tabular_file(
+            &client,
+            Identifier::Id(42),
+            TabularFormat::Original,
+            dir.clone(),
+            None,
+        )
+            .await
+            .expect("Failed to download file");
+
+        mock.assert();
+        assert_eq!(path, dir.join("survey.sav"));
+    }
+
+    /// Tests the extension of fallback names by the content type of the response.
+    #[test]
+    fn test_fallback_filename() {
+        assert_eq!(fallback_filename("42", Some("text/tab-separated-values; charset=UTF-8")), "42.tab");
+        assert_eq!(fallback_filename("42", Some("application/x-rlang-transport")), "42.RData");
+        assert_eq!(fallback_filename("42", Some("application/octet-stream")), "42");
+        assert_eq!(fallback_filename("42", None), "42");
+        assert_eq!(fallback_filename("data.csv", Some("text/tab-separated-values")), "data.csv");
+    }
+
+    /// Tests the parsing of tabular formats.
+    #[test]
+    fn test_tabular_format_from_str() {
+        assert_eq!("original".parse::<TabularFormat>(), Ok(TabularFormat::Original));
+        assert_eq!("RData".parse::<TabularFormat>(), Ok(TabularFormat::RData));
+        assert!("xlsx".parse::<TabularFormat>().is_err());
+    }
+}
@@ -0,0 +1,256 @@
+use std::path::{Path, PathBuf};
+
+use futures::stream::{self, StreamExt};
+
+use crate::{
+    access::mirror::{download_to, relative_path},
+    checksum::file_checksum,
+    client::BaseClient,
+    identifier::Identifier,
+    native_api::dataset::get::{get_dataset_version, GetDatasetOptions},
+};
+
+/// The outcome of downloading a version of a dataset into a directory.
+#[derive(Debug, Default)]
+pub struct DatasetDownload {
+    /// The downloaded version, e.g. `1.2`, or `None` for drafts.
+    pub version: Option<String>,
+    /// Files that have been downloaded and match the checksum reported by the instance.
+    pub downloaded: Vec<PathBuf>,
+    /// Files that have been downloaded without a checksum to compare with, such as ingested tabular files
+    /// whose checksum refers to the original upload.
+    pub unverified: Vec<PathBuf>,
+    /// Files that could not be downloaded or did not match their checksum, along with the error message.
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+impl DatasetDownload {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Downloads the files of a dataset version into a directory, recreating their folder structure.
+///
+/// This asynchronous function lists the files of the given version and downloads up to `concurrency` of them
+/// at a time into the path given by their directory label within `target_dir`. Each file is first written to a
+/// temporary file and checked against the checksum reported by the instance, such that a corrupted or partial
+/// download never ends up under the name of the file. A failing file does not abort the remaining downloads.
+///
+/// Files whose directory label or name would leave `target_dir` are reported as failed and not downloaded.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `version` - The version to download, e.g. `:latest`, `:draft`, `:latest-published` or `1.0`.
+/// * `target_dir` - A `PathBuf` of the directory to download to, which is created if necessary.
+/// * `concurrency` - The maximum number of simultaneous downloads; zero is treated as one.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `DatasetDownload` summary, or a `String` error message if the version could not
+/// be retrieved.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, None)
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let download = access::download_dataset(&client, id, ":latest-published", PathBuf::from("data"), 4).await?;
+///
+/// for (path, error) in &download.failed {
+///     println!("Failed to download {}: {}", path.display(), error);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn download_dataset(
+    client: &BaseClient,
+    id: Identifier,
+    version: &str,
+    target_dir: PathBuf,
+    concurrency: usize,
+) -> Result<DatasetDownload, String> {
+    let options = GetDatasetOptions::default();
+    let response = get_dataset_version(client, id, version, &options).await?;
+    if response.status.is_err() {
+        return Err(format!(
+            "Failed to retrieve version {}: {}",
+            version,
+            response.message.map(|m| m.to_string()).unwrap_or_default()
+        ));
+    }
+
+    let dataset = response.data.ok_or("The response did not contain any data")?;
+    std::fs::create_dir_all(&target_dir).map_err(|e| e.to_string())?;
+
+    let mut download = DatasetDownload {
+        version: match (dataset.version_number, dataset.version_minor_number) {
+            (Some(major), Some(minor)) => Some(format!("{}.{}", major, minor)),
+            _ => None,
+        },
+        ..Default::default()
+    };
+
+    let results = stream::iter(&dataset.files)
+        .filter_map(|file| async move {
+            let data_file = file.data_file.as_ref()?;
+            let id = data_file.id?;
+            let label = file.label.clone().unwrap_or_else(|| id.to_string());
+
+            let Some(path) = relative_path(file) else {
+                return Some((PathBuf::from(label), Err("The path leaves the target directory".to_string())));
+            };
+
+            // The checksum of ingested files refers to the original rather than the archival version
+            let checksum = match data_file.tabular_data {
+                Some(true) => None,
+                _ => data_file
+                    .checksum
+                    .as_ref()
+                    .and_then(|checksum| Some((checksum.type_.clone()?, checksum.value.clone()?))),
+            };
+
+            Some((path, Ok((id, checksum))))
+        })
+        .map(|(path, file)| {
+            let target_dir = &target_dir;
+            async move {
+                let result = match file {
+                    Ok((id, checksum)) => download_verified(client, id, &target_dir.join(&path), checksum).await,
+                    Err(e) => Err(e),
+                };
+                (path, result)
+            }
+        })
+        .buffered(concurrency.max(1))
+        .collect::<Vec<_>>()
+        .await;
+
+    for (path, result) in results {
+        match result {
+            Ok(true) => download.downloaded.push(path),
+            Ok(false) => download.unverified.push(path),
+            Err(e) => download.failed.push((path, e)),
+        }
+    }
+
+    Ok(download)
+}
+
+// Downloads the file next to its target, compares the checksum, if any, and moves it into place.
+// Returns whether the file has been verified.
+async fn download_verified(
+    client: &BaseClient,
+    id: i64,
+    target: &Path,
+    checksum: Option<(String, String)>,
+) -> Result<bool, String> {
+    let mut partial = target.as_os_str().to_owned();
+    partial.push(".dvcheck");
+    let partial = PathBuf::from(partial);
+
+    download_to(client, id, &partial).await?;
+
+    let Some((algorithm, expected)) = checksum else {
+        std::fs::rename(&partial, target).map_err(|e| e.to_string())?;
+        return Ok(false);
+    };
+
+    let hashed = partial.clone();
+    let actual = tokio::task::spawn_blocking(move || file_checksum(&hashed, &algorithm))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    match actual {
+        Ok(actual) if actual.eq_ignore_ascii_case(&expected) => {
+            std::fs::rename(&partial, target).map_err(|e| e.to_string())?;
+            Ok(true)
+        }
+        Ok(actual) => {
+            let _ = std::fs::remove_file(&partial);
+            Err(format!("Checksum mismatch: expected {}, but received {}", expected, actual))
+        }
+        Err(e) => {
+            let _ = std::fs::remove_file(&partial);
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    /// Tests that files are placed into their directories and checked against their checksums.
+    ///
+    /// This test serves a version with three files from a mock server: one with a matching checksum,
+    /// one whose content does not match its checksum and an ingested tabular file. Only the first is
+    /// expected to be verified, while the corrupted one must not be written to its target path.
+    #[tokio::test]
+    async fn test_download_dataset() {
+        let server = MockServer::start();
+        let dir = std::env::temp_dir().join(format!("dvcli_dataset_{}", rand::random::<u32>()));
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+
+        server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/7/versions/:draft");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {
+                    "versionState": "DRAFT",
+                    "files": [
+                        {
+                            "label": "file.txt",
+                            "directoryLabel": "docs/notes",
+                            "dataFile": {"id": 1, "checksum": {"type": "MD5", "value": "a28bca1b906f539ba70ca3a0b1f2e773"}}
+                        },
+                        {
+                            "label": "corrupted.txt",
+                            "dataFile": {"id": 2, "checksum": {"type": "MD5", "value": "00000000000000000000000000000000"}}
+                        },
+                        {
+                            "label": "survey.tab",
+                            "dataFile": {"id": 3, "tabularData": true, "checksum": {"type": "MD5", "value": "0"}}
+                        }
+                    ]
+                }
+            }));
+        });
+
+        let content = std::fs::read("tests/fixtures/file.txt").unwrap();
+        for id in 1..=3 {
+            server.mock(|when, then| {
+                when.method(GET).path(format!("/api/access/datafile/{}", id));
+                then.status(200).body(&content);
+            });
+        }
+
+        let download = access::download_dataset(&client, Identifier::Id(7), ":draft", dir.clone(), 2)
+            .await
+            .expect("Failed to download dataset");
+
+        assert_eq!(download.downloaded, vec![PathBuf::from("docs/notes/file.txt")]);
+        assert_eq!(download.unverified, vec![PathBuf::from("survey.tab")]);
+        assert_eq!(download.failed.len(), 1);
+        assert!(download.version.is_none());
+        assert!(dir.join("docs/notes/file.txt").is_file());
+        assert!(!dir.join("corrupted.txt").exists());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
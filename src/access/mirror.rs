@@ -0,0 +1,387 @@
+use std::collections::BTreeMap;
+use std::path::{Component, Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    access::datafile::download_file,
+    client::BaseClient,
+    identifier::Identifier,
+    native_api::dataset::edit::File,
+    native_api::dataset::get::{get_dataset_version, GetDatasetOptions},
+};
+
+// The state of a mirror is stored next to the mirrored files. It records the
+// version and the checksums reported by the instance, such that a re-run only
+// downloads files whose checksum changed and removes files that are no longer
+// part of the dataset. Files not listed in the state are never touched, and a
+// directory holding the mirror of another dataset is refused altogether.
+pub const MIRROR_STATE_FILE: &str = ".dvmirror.json";
+
+/// The state of a local mirror of a dataset.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct MirrorState {
+    pub dataset: String,
+    pub version: Option<String>,
+    pub files: BTreeMap<PathBuf, MirroredFile>,
+}
+
+/// A file of the mirror along with the checksum it had on the instance.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct MirroredFile {
+    pub id: i64,
+    pub checksum: Option<String>,
+}
+
+/// The outcome of a mirror run.
+#[derive(Debug, Default)]
+pub struct MirrorSummary {
+    /// The mirrored version, e.g. `1.2`.
+    pub version: Option<String>,
+    /// Files that were new or changed and have been downloaded.
+    pub downloaded: Vec<PathBuf>,
+    /// Files whose checksum did not change.
+    pub unchanged: Vec<PathBuf>,
+    /// Files that are no longer part of the dataset and have been removed.
+    pub pruned: Vec<PathBuf>,
+    /// Files that could not be downloaded, along with the error message.
+    pub failed: Vec<(PathBuf, String)>,
+}
+
+impl MirrorSummary {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Maintains a local copy of the latest published version of a dataset.
+///
+/// This asynchronous function retrieves the latest published version and compares its files against the
+/// state of a previous run stored in the destination. New files and files whose checksum changed are
+/// downloaded into their directory within the destination, while files that have been removed from the
+/// dataset are deleted locally. The mirrored version is recorded in the state once all files are processed.
+/// A failing download does not abort the remaining ones and is retried on the next run. If the destination
+/// holds the mirror of another dataset, an error is returned before any file is downloaded or removed.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `destination` - A `PathBuf` of the directory holding the mirror, which is created if necessary.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `MirrorSummary`, or a `String` error message if the dataset could not be retrieved,
+/// the state could not be read or written, or the destination holds the mirror of another dataset.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, None)
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let summary = access::mirror_dataset(&client, id, PathBuf::from("mirror")).await?;
+///
+/// println!("Downloaded {} files", summary.downloaded.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn mirror_dataset(
+    client: &BaseClient,
+    id: Identifier,
+    destination: PathBuf,
+) -> Result<MirrorSummary, String> {
+    let requested = match &id {
+        Identifier::PersistentId(pid) => pid.clone(),
+        Identifier::Id(id) => id.to_string(),
+    };
+
+    let options = GetDatasetOptions::default();
+    let response = get_dataset_version(client, id, ":latest-published", &options).await?;
+    if response.status.is_err() {
+        return Err(format!(
+            "Failed to retrieve the published version: {}",
+            response.message.map(|m| m.to_string()).unwrap_or_default()
+        ));
+    }
+
+    let version = response.data.ok_or("The response did not contain any data")?;
+    let version_label = match (version.version_number, version.version_minor_number) {
+        (Some(major), Some(minor)) => Some(format!("{}.{}", major, minor)),
+        _ => None,
+    };
+
+    // The persistent identifier is recorded, such that the dataset is recognised however it is requested
+    let dataset = version.dataset_persistent_id.clone().unwrap_or_else(|| requested.clone());
+    let known: Vec<String> = [Some(dataset.clone()), Some(requested), version.dataset_id.map(|id| id.to_string())]
+        .into_iter()
+        .flatten()
+        .collect();
+
+    std::fs::create_dir_all(&destination).map_err(|e| e.to_string())?;
+    let previous = read_state(&destination)?;
+    if !previous.dataset.is_empty() && !known.contains(&previous.dataset) {
+        return Err(format!(
+            "{} holds the mirror of dataset {}, not of {}",
+            destination.display(),
+            previous.dataset,
+            dataset
+        ));
+    }
+
+    let mut state = MirrorState {
+        dataset,
+        version: previous.version.clone(),
+        files: BTreeMap::new(),
+    };
+    let mut summary = MirrorSummary {
+        version: version_label.clone(),
+        ..Default::default()
+    };
+
+    for file in &version.files {
+        let (Some(path), Some(id)) = (relative_path(file), file.data_file.as_ref().and_then(|f| f.id)) else {
+            continue;
+        };
+        let mirrored = MirroredFile {
+            id,
+            checksum: file
+                .data_file
+                .as_ref()
+                .and_then(|data_file| data_file.checksum.as_ref())
+                .and_then(|checksum| checksum.value.clone()),
+        };
+
+        let target = destination.join(&path);
+        if previous.files.get(&path) == Some(&mirrored) && mirrored.checksum.is_some() && target.is_file() {
+            summary.unchanged.push(path.clone());
+            state.files.insert(path, mirrored);
+            continue;
+        }
+
+        match download_to(client, id, &target).await {
+            Ok(()) => {
+                summary.downloaded.push(path.clone());
+                state.files.insert(path, mirrored);
+            }
+            Err(e) => {
+                // Keep the previous state, such that the file is retried but not pruned
+                if let Some(mirrored) = previous.files.get(&path) {
+                    state.files.insert(path.clone(), mirrored.clone());
+                }
+                summary.failed.push((path, e));
+            }
+        }
+    }
+
+    // Remove files of previous runs that are no longer part of the dataset
+    for path in previous.files.keys() {
+        if state.files.contains_key(path) {
+            continue;
+        }
+
+        let target = destination.join(path);
+        if target.is_file() {
+            std::fs::remove_file(&target).map_err(|e| e.to_string())?;
+        }
+        summary.pruned.push(path.clone());
+    }
+
+    if summary.is_success() {
+        state.version = version_label;
+    }
+    write_state(&destination, &state)?;
+
+    Ok(summary)
+}
+
+// Downloads to a temporary file first, such that an interrupted download
+// never replaces the mirrored file with a partial one
+pub(crate) async fn download_to(client: &BaseClient, id: i64, target: &Path) -> Result<(), String> {
+    if let Some(parent) = target.parent() {
+        std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
+    }
+
+    let mut partial = target.as_os_str().to_owned();
+    partial.push(".dvpart");
+    let partial = PathBuf::from(partial);
+    download_file(client, Identifier::Id(id), partial.clone(), None).await?;
+    std::fs::rename(&partial, target).map_err(|e| e.to_string())
+}
+
+// Joins the directory label and label of a file. Paths that would leave the
+// destination are rejected, since both are provided by the depositor.
+pub(crate) fn relative_path(file: &File) -> Option<PathBuf> {
+    let label = file.label.as_ref()?;
+    let path = match &file.directory_label {
+        Some(directory) => Path::new(directory).join(label),
+        None => PathBuf::from(label),
+    };
+
+    match path.components().all(|component| matches!(component, Component::Normal(_))) {
+        true => Some(path),
+        false => None,
+    }
+}
+
+fn read_state(destination: &Path) -> Result<MirrorState, String> {
+    let path = destination.join(MIRROR_STATE_FILE);
+    if !path.exists() {
+        return Ok(MirrorState::default());
+    }
+
+    let content = std::fs::read_to_string(&path).map_err(|e| e.to_string())?;
+    serde_json::from_str(&content).map_err(|e| format!("Failed to read {}: {}", path.display(), e))
+}
+
+fn write_state(destination: &Path, state: &MirrorState) -> Result<(), String> {
+    let content = serde_json::to_string_pretty(state).map_err(|e| e.to_string())?;
+    std::fs::write(destination.join(MIRROR_STATE_FILE), content).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::native_api::dataset::edit::File;
+    use crate::prelude::*;
+
+    use super::{read_state, relative_path};
+
+    fn version(files: serde_json::Value) -> serde_json::Value {
+        json!({
+            "status": "OK",
+            "data": {
+                "versionNumber": 1,
+                "versionMinorNumber": 2,
+                "versionState": "RELEASED",
+                "files": files
+            }
+        })
+    }
+
+    fn file(id: i64, label: &str, directory: Option<&str>, checksum: &str) -> serde_json::Value {
+        json!({
+            "label": label,
+            "directoryLabel": directory,
+            "dataFile": {"id": id, "checksum": {"type": "MD5", "value": checksum}}
+        })
+    }
+
+    /// Tests that a re-run only downloads changed files and prunes removed ones.
+    ///
+    /// This test serves two versions of a dataset from a mock server. The first run downloads both
+    /// files, the second run sees a changed checksum for one file and the removal of the other, and
+    /// is expected to download the changed file only and to delete the removed one.
+    #[tokio::test]
+    async fn test_mirror_dataset() {
+        let server = MockServer::start();
+        let dir = std::env::temp_dir().join(format!("dvcli_mirror_{}", rand::random::<u32>()));
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+
+        let mut first = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/datasets/:persistentId/versions/:latest-published")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123");
+            then.status(200).json_body(version(json!([
+                file(1, "data.csv", Some("raw"), "aaa"),
+                file(2, "readme.md", None, "bbb"),
+            ])));
+        });
+        let data = server.mock(|when, then| {
+            when.method(GET).path("/api/access/datafile/1");
+            then.status(200).body("a,b\n1,2\n");
+        });
+        let readme = server.mock(|when, then| {
+            when.method(GET).path("/api/access/datafile/2");
+            then.status(200).body("# Readme");
+        });
+
+        let summary = access::mirror_dataset(&client, id.clone(), dir.clone())
+            .await
+            .expect("Failed to mirror dataset");
+
+        assert_eq!(summary.downloaded.len(), 2);
+        assert_eq!(summary.version.as_deref(), Some("1.2"));
+        assert_eq!(std::fs::read_to_string(dir.join("raw/data.csv")).unwrap(), "a,b\n1,2\n");
+        assert_eq!(read_state(&dir).unwrap().version.as_deref(), Some("1.2"));
+
+        // Change the checksum of the readme and remove the data file
+        first.delete();
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/datasets/:persistentId/versions/:latest-published");
+            then.status(200).json_body(version(json!([file(2, "readme.md", None, "ccc")])));
+        });
+
+        let summary = access::mirror_dataset(&client, id, dir.clone())
+            .await
+            .expect("Failed to mirror dataset");
+
+        assert_eq!(summary.downloaded, vec![PathBuf::from("readme.md")]);
+        assert_eq!(summary.pruned, vec![PathBuf::from("raw/data.csv")]);
+        assert!(!dir.join("raw/data.csv").exists());
+        data.assert_hits(1);
+        readme.assert_hits(2);
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Tests that the mirror of another dataset is neither overwritten nor pruned.
+    #[tokio::test]
+    async fn test_mirror_dataset_other_dataset() {
+        let server = MockServer::start();
+        let dir = std::env::temp_dir().join(format!("dvcli_mirror_{}", rand::random::<u32>()));
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+
+        server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/42/versions/:latest-published");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"datasetId": 42, "datasetPersistentId": "doi:10.5072/FK2/OTHER", "files": []}
+            }));
+        });
+
+        // A previous run mirrored another dataset into the directory
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("data.csv"), "a,b\n").unwrap();
+        let state = json!({
+            "dataset": "doi:10.5072/FK2/ABC123",
+            "version": "1.0",
+            "files": {"data.csv": {"id": 1, "checksum": "aaa"}}
+        });
+        std::fs::write(dir.join(super::MIRROR_STATE_FILE), state.to_string()).unwrap();
+
+        let result = access::mirror_dataset(&client, Identifier::Id(42), dir.clone()).await;
+
+        assert!(result.unwrap_err().contains("doi:10.5072/FK2/ABC123"));
+        assert!(dir.join("data.csv").is_file());
+        assert_eq!(read_state(&dir).unwrap().dataset, "doi:10.5072/FK2/ABC123");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    /// Tests that paths leaving the destination are rejected.
+    #[test]
+    fn test_relative_path() {
+        let parse = |directory: Option<&str>, label: &str| -> File {
+            serde_json::from_value(json!({"label": label, "directoryLabel": directory})).unwrap()
+        };
+
+        assert_eq!(relative_path(&parse(Some("raw/2024"), "a.csv")), Some(PathBuf::from("raw/2024/a.csv")));
+        assert_eq!(relative_path(&parse(None, "a.csv")), Some(PathBuf::from("a.csv")));
+        assert_eq!(relative_path(&parse(Some("../.."), "a.csv")), None);
+        assert_eq!(relative_path(&parse(Some("/etc"), "passwd")), None);
+    }
+}
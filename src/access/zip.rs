@@ -0,0 +1,167 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use crate::{access::datafile::download, callback::CallbackFun, client::BaseClient, identifier::Identifier};
+
+/// Downloads all files of a dataset as a single ZIP archive.
+///
+/// This asynchronous function sends a GET request to the data access API, which bundles the files of the
+/// latest accessible version of the dataset, and streams the archive to disk. Note that instances limit the
+/// total size of ZIP downloads; files exceeding the limit are omitted and listed in a `MANIFEST.TXT` within
+/// the archive.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `destination` - A `PathBuf` of the file or directory to download to.
+/// * `callback` - An optional `CallbackFun` instance receiving the number of bytes written.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `PathBuf` the archive has been written to, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let path = access::download_dataset_zip(&client, id, PathBuf::from("."), None).await?;
+///
+/// println!("Downloaded to {}", path.display());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn download_dataset_zip(
+    client: &BaseClient,
+    id: Identifier,
+    destination: PathBuf,
+    callback: Option<CallbackFun>,
+) -> Result<PathBuf, String> {
+    // Endpoint metadata
+    let (url, parameters) = match id {
+        Identifier::PersistentId(id) => (
+            "api/access/dataset/:persistentId".to_string(),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/access/dataset/{}", id), None),
+    };
+
+    download(client, &url, parameters, destination, "dataverse_files.zip", callback).await
+}
+
+/// Downloads multiple files as a single ZIP archive.
+///
+/// This asynchronous function sends a GET request to the data access API, which bundles the given files,
+/// and streams the archive to disk. The same size limit as for `download_dataset_zip` applies.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `ids` - A slice of the numeric IDs of the files to download.
+/// * `destination` - A `PathBuf` of the file or directory to download to.
+/// * `callback` - An optional `CallbackFun` instance receiving the number of bytes written.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `PathBuf` the archive has been written to, or a `String` error message on failure.
+pub async fn download_files_zip(
+    client: &BaseClient,
+    ids: &[i64],
+    destination: PathBuf,
+    callback: Option<CallbackFun>,
+) -> Result<PathBuf, String> {
+    if ids.is_empty() {
+        return Err("At least one file ID is required".to_string());
+    }
+
+    // Endpoint metadata
+    let ids = ids.iter().map(|id| id.to_string()).collect::<Vec<_>>().join(",");
+    let url = format!("api/access/datafiles/{}", ids);
+
+    download(client, &url, None, destination, "dataverse_files.zip", callback).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use httpmock::prelude::*;
+
+    use crate::prelude::*;
+    use crate::test_utils::{create_test_dataset, extract_test_env};
+
+    /// Tests that multiple files are requested as a comma-separated list and stored under the
+    /// name provided by the server.
+    #[tokio::test]
+    async fn test_download_files_zip() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/api/access/datafiles/1,2,3");
+            then.status(200)
+                .header("Content-Disposition", "attachment; filename=\"dataverse_files.zip\"")
+                .body("PK");
+        });
+
+        let dir = std::env::temp_dir().join(format!("dvcli_zip_{}", rand::random::<u32>()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let path = access::download_files_zip(&client, &[1, 2, 3], dir.clone(), None)
+            .await
+            .expect("Failed to download files");
+
+        mock.assert();
+        assert_eq!(path, dir.join("dataverse_files.zip"));
+    }
+
+    /// Tests downloading a freshly created dataset as a ZIP archive.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables,
+    /// creates a dataset, uploads a file and downloads the dataset as a ZIP archive. The test asserts
+    /// that the archive has been written to the given path.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the download fails.
+    #[tokio::test]
+    async fn test_download_dataset_zip() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Create a dataset with a file
+        let (id, _) = create_test_dataset(&client, "Root").await;
+        dataset::upload_file_to_dataset(
+            &client,
+            Identifier::Id(id),
+            PathBuf::from("tests/fixtures/file.txt"),
+            None,
+            None,
+        )
+            .await
+            .expect("Failed to upload file");
+
+        // Download the dataset
+        let path = std::env::temp_dir().join(format!("dvcli_dataset_{}.zip", id));
+        let written = access::download_dataset_zip(&client, Identifier::Id(id), path.clone(), None)
+            .await
+            .expect("Failed to download dataset");
+
+        assert_eq!(written, path);
+        assert!(path.metadata().unwrap().len() > 0);
+    }
+}
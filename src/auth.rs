@@ -0,0 +1,150 @@
+use std::future::Future;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use reqwest::RequestBuilder;
+
+/// The credentials a `BaseClient` authenticates its requests with.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Auth {
+    /// An API token sent in the `X-Dataverse-key` header.
+    ApiToken(String),
+    /// An access token of an OIDC provider sent in the `Authorization` header, for instances
+    /// configured for bearer authentication.
+    BearerToken(String),
+    /// Requests are sent anonymously.
+    #[default]
+    None,
+}
+
+impl Auth {
+    // Adds the header of the credentials to the request
+    pub(crate) fn apply(&self, request: RequestBuilder) -> RequestBuilder {
+        match self {
+            Auth::ApiToken(token) => request.header("X-Dataverse-key", token),
+            Auth::BearerToken(token) => request.bearer_auth(token),
+            Auth::None => request,
+        }
+    }
+}
+
+type TokenRefreshInner = dyn Fn() -> BoxFuture<'static, Result<String, String>> + Send + Sync;
+
+/// Obtains a new bearer token once the current one has been rejected.
+///
+/// OIDC access tokens are short-lived. If the instance answers a request with `401 Unauthorized`,
+/// the client calls the refresh function, e.g. to exchange a refresh token with the provider, and
+/// sends the request once more with the new token.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::auth::{Auth, TokenRefresh};
+/// use dataverse::prelude::*;
+///
+/// let refresh = TokenRefresh::wrap(|| async {
+///     // Request a new access token from the OIDC provider
+///     Ok::<_, String>("new_access_token".to_string())
+/// });
+///
+/// let client = BaseClient::builder("https://demo.dataverse.org")
+///     .auth(Auth::BearerToken("access_token".to_string()))
+///     .token_refresh(refresh)
+///     .build()
+///     .expect("Failed to create client");
+/// ```
+#[derive(Clone)]
+pub struct TokenRefresh {
+    inner: Arc<TokenRefreshInner>,
+}
+
+impl TokenRefresh {
+    pub fn wrap<F, Fut>(refresh: F) -> Self
+    where
+        F: Fn() -> Fut + Send + Sync + 'static,
+        Fut: Future<Output = Result<String, String>> + Send + 'static,
+    {
+        TokenRefresh {
+            inner: Arc::new(move || Box::pin(refresh())),
+        }
+    }
+
+    pub async fn call(&self) -> Result<String, String> {
+        (self.inner)().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+    use crate::request::RequestType;
+
+    use super::{Auth, TokenRefresh};
+
+    /// Tests that rejected bearer tokens are refreshed and the request is sent again.
+    #[tokio::test]
+    async fn test_bearer_token_refresh() {
+        let server = MockServer::start();
+        let expired = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/users/:me")
+                .header("Authorization", "Bearer expired");
+            then.status(401).json_body(json!({"status": "ERROR", "message": "Bad token"}));
+        });
+        let refreshed = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/users/:me")
+                .header("Authorization", "Bearer fresh");
+            then.status(200).json_body(json!({"status": "OK", "data": {"identifier": "@user"}}));
+        });
+
+        let calls = Arc::new(AtomicUsize::new(0));
+        let counter = calls.clone();
+        let refresh = TokenRefresh::wrap(move || {
+            counter.fetch_add(1, Ordering::SeqCst);
+            async { Ok("fresh".to_string()) }
+        });
+
+        let client = BaseClient::builder(&server.base_url())
+            .auth(Auth::BearerToken("expired".to_string()))
+            .token_refresh(refresh)
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            let response = client.get("api/users/:me", None, &RequestType::Plain).await.unwrap();
+            assert_eq!(response.status(), 200);
+        }
+
+        // The new token is kept for subsequent requests
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(client.auth(), Auth::BearerToken("fresh".to_string()));
+        expired.assert_hits(1);
+        refreshed.assert_hits(2);
+    }
+
+    /// Tests that API tokens are sent in the Dataverse header and rejections are returned as-is.
+    #[tokio::test]
+    async fn test_api_token_header() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/api/users/:me").header("X-Dataverse-key", "token");
+            then.status(401).json_body(json!({"status": "ERROR", "message": "Bad token"}));
+        });
+
+        let client = BaseClient::builder(&server.base_url())
+            .auth(Auth::ApiToken("token".to_string()))
+            .build()
+            .unwrap();
+
+        let response = client.get("api/users/:me", None, &RequestType::Plain).await.unwrap();
+        assert_eq!(response.status(), 401);
+        mock.assert_hits(1);
+    }
+}
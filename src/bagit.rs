@@ -0,0 +1,248 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::checksum::{file_checksums, hashing_threads, sha256_hex};
+
+// Bags follow version 1.0 of the BagIt specification (RFC 8493). The payload is
+// copied into the `data` directory of the bag and listed in a SHA-256 payload
+// manifest, while all tag files are listed in a SHA-256 tag manifest.
+
+/// Options for the creation of a bag.
+#[derive(Debug, Clone, Default)]
+pub struct BagOptions {
+    /// The persistent identifier of the dataset, recorded as `External-Identifier` in `bag-info.txt`.
+    pub external_identifier: Option<String>,
+    /// Further entries of `bag-info.txt`, e.g. `Source-Organization` or `Contact-Email`.
+    pub info: Vec<(String, String)>,
+    /// The JSON metadata of the dataset, stored as the tag file `metadata/dataset.json`.
+    pub metadata: Option<String>,
+}
+
+/// A bag that has been written to disk.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Bag {
+    /// The base directory of the bag.
+    pub path: PathBuf,
+    /// The payload files by their path within the bag, e.g. `data/tables/results.csv`, and SHA-256 digest.
+    pub manifest: Vec<(String, String)>,
+    /// The total size of the payload in bytes.
+    pub payload_bytes: u64,
+}
+
+impl Bag {
+    /// Returns the `Payload-Oxum` of the bag, i.e. the size of the payload in bytes and its number of files.
+    pub fn payload_oxum(&self) -> String {
+        format!("{}.{}", self.payload_bytes, self.manifest.len())
+    }
+}
+
+/// Creates a BagIt bag from a local copy of a dataset, e.g. one fetched with `access::download_dataset`.
+///
+/// All files below `source`, including hidden ones, become the payload of the bag, keeping their relative
+/// paths. The checksums are computed from the copies within the bag, such that the manifest attests what has
+/// actually been written. The copies are hashed in parallel on all available cores.
+///
+/// # Arguments
+///
+/// * `source` - The directory holding the files of the dataset.
+/// * `bag` - The base directory of the bag to create, which must not exist or be empty.
+/// * `options` - The `BagOptions` holding the identifier, additional bag info and metadata.
+///
+/// # Returns
+///
+/// A `Result` wrapping the created `Bag`, or a `String` error message if the source cannot be read or the bag
+/// cannot be written.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use dataverse::bagit::{create_bag, BagOptions};
+///
+/// # async fn run() -> Result<(), String> {
+/// let options = BagOptions {
+///     external_identifier: Some("doi:10.5072/FK2/ABC123".to_string()),
+///     info: vec![("Source-Organization".to_string(), "University of Example".to_string())],
+///     ..Default::default()
+/// };
+///
+/// let bag = create_bag(Path::new("downloads/ABC123"), Path::new("bags/ABC123"), &options).await?;
+/// println!("Bagged {} files ({})", bag.manifest.len(), bag.payload_oxum());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn create_bag(source: &Path, bag: &Path, options: &BagOptions) -> Result<Bag, String> {
+    if !source.is_dir() {
+        return Err(format!("Not a directory: {}", source.display()));
+    }
+    if bag.exists() && fs::read_dir(bag).map_err(|e| e.to_string())?.next().is_some() {
+        return Err(format!("Bag directory is not empty: {}", bag.display()));
+    }
+
+    let mut files = Vec::new();
+    collect_files(source, &mut files)?;
+    files.sort();
+
+    // Copy the payload
+    let mut paths = Vec::new();
+    let mut jobs = Vec::new();
+    let mut payload_bytes = 0;
+    for file in &files {
+        let relative = file.strip_prefix(source).map_err(|e| e.to_string())?;
+        let target = bag.join("data").join(relative);
+        if let Some(parent) = target.parent() {
+            fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+        }
+        payload_bytes += fs::copy(file, &target).map_err(|e| format!("Failed to copy {}: {}", file.display(), e))?;
+
+        paths.push(format!("data/{}", bag_path(relative)));
+        jobs.push((target, "SHA-256".to_string()));
+    }
+
+    // Hash the copies, keeping the order of the files
+    let manifest = paths
+        .into_iter()
+        .zip(file_checksums(jobs, hashing_threads()).await)
+        .map(|(path, checksum)| checksum.map(|checksum| (path, checksum)))
+        .collect::<Result<Vec<_>, String>>()?;
+
+    let bag = Bag {
+        path: bag.to_path_buf(),
+        manifest,
+        payload_bytes,
+    };
+
+    // Write the tag files
+    let mut tags = vec![
+        ("bagit.txt".to_string(), "BagIt-Version: 1.0\nTag-File-Character-Encoding: UTF-8\n".to_string()),
+        ("bag-info.txt".to_string(), bag_info(&bag, options)),
+        ("manifest-sha256.txt".to_string(), manifest_content(&bag.manifest)),
+    ];
+    if let Some(metadata) = &options.metadata {
+        tags.push(("metadata/dataset.json".to_string(), metadata.clone()));
+    }
+
+    let mut tag_manifest = Vec::new();
+    for (name, content) in tags {
+        write_file(&bag.path.join(&name), &content)?;
+        tag_manifest.push((name, sha256_hex(content.as_bytes())));
+    }
+    write_file(&bag.path.join("tagmanifest-sha256.txt"), &manifest_content(&tag_manifest))?;
+
+    Ok(bag)
+}
+
+// Collects all files below the directory, recursively
+fn collect_files(dir: &Path, files: &mut Vec<PathBuf>) -> Result<(), String> {
+    let entries = fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+    for entry in entries {
+        let path = entry.map_err(|e| e.to_string())?.path();
+        if path.is_dir() {
+            collect_files(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn bag_info(bag: &Bag, options: &BagOptions) -> String {
+    let mut info = vec![
+        ("Bagging-Date".to_string(), chrono::Local::now().format("%Y-%m-%d").to_string()),
+        ("Bag-Software-Agent".to_string(), format!("dataverse-rs {}", env!("CARGO_PKG_VERSION"))),
+        ("Payload-Oxum".to_string(), bag.payload_oxum()),
+    ];
+    if let Some(identifier) = &options.external_identifier {
+        info.push(("External-Identifier".to_string(), identifier.clone()));
+    }
+    info.extend(options.info.iter().cloned());
+
+    info.iter()
+        .map(|(label, value)| format!("{}: {}\n", label, value.replace('\n', "\n  ")))
+        .collect()
+}
+
+fn manifest_content(entries: &[(String, String)]) -> String {
+    entries
+        .iter()
+        .map(|(path, checksum)| format!("{}  {}\n", checksum, path))
+        .collect()
+}
+
+// Joins the components with slashes and percent-encodes the characters the specification requires
+fn bag_path(relative: &Path) -> String {
+    relative
+        .components()
+        .map(|component| component.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+        .replace('%', "%25")
+        .replace('\r', "%0D")
+        .replace('\n', "%0A")
+}
+
+fn write_file(path: &Path, content: &str) -> Result<(), String> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent).map_err(|e| format!("Failed to create {}: {}", parent.display(), e))?;
+    }
+    fs::write(path, content).map_err(|e| format!("Failed to write {}: {}", path.display(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use crate::checksum::sha256_hex;
+
+    use super::{create_bag, BagOptions};
+
+    /// Tests that the payload, the manifests and the bag info are written.
+    #[tokio::test]
+    async fn test_create_bag() {
+        let dir = std::env::temp_dir().join(format!("dvcli_bagit_{}", rand::random::<u32>()));
+        let source = dir.join("source");
+        fs::create_dir_all(source.join("tables")).unwrap();
+        fs::write(source.join("readme.md"), "# Soil moisture").unwrap();
+        fs::write(source.join("tables").join("100% sample.csv"), "a,b\n1,2\n").unwrap();
+
+        let options = BagOptions {
+            external_identifier: Some("doi:10.5072/FK2/ABC123".to_string()),
+            info: vec![("Source-Organization".to_string(), "University of Example".to_string())],
+            metadata: Some("{\"title\": \"Soil moisture\"}".to_string()),
+        };
+        let bag = create_bag(&source, &dir.join("bag"), &options).await.unwrap();
+
+        assert_eq!(bag.payload_oxum(), "23.2");
+        assert_eq!(
+            fs::read_to_string(bag.path.join("data/tables/100% sample.csv")).unwrap(),
+            "a,b\n1,2\n"
+        );
+
+        let manifest = fs::read_to_string(bag.path.join("manifest-sha256.txt")).unwrap();
+        assert_eq!(
+            manifest,
+            format!(
+                "{}  data/readme.md\n{}  data/tables/100%25 sample.csv\n",
+                sha256_hex(b"# Soil moisture"),
+                sha256_hex(b"a,b\n1,2\n")
+            )
+        );
+
+        let info = fs::read_to_string(bag.path.join("bag-info.txt")).unwrap();
+        assert!(info.contains("Payload-Oxum: 23.2\n"));
+        assert!(info.contains("External-Identifier: doi:10.5072/FK2/ABC123\n"));
+        assert!(info.contains("Source-Organization: University of Example\n"));
+
+        let tag_manifest = fs::read_to_string(bag.path.join("tagmanifest-sha256.txt")).unwrap();
+        for tag in ["bagit.txt", "bag-info.txt", "manifest-sha256.txt", "metadata/dataset.json"] {
+            let checksum = sha256_hex(&fs::read(bag.path.join(tag)).unwrap());
+            assert!(tag_manifest.contains(&format!("{}  {}\n", checksum, tag)));
+        }
+
+        // Bags are never written into existing content
+        assert!(create_bag(&source, &bag.path, &options).await.is_err());
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,63 +1,199 @@
-use std::error::Error;
+use std::process::ExitCode;
+use std::time::Duration;
 
 use colored::Colorize;
 use structopt::StructOpt;
 
-use dataverse::cli::base::Matcher;
+#[cfg(feature = "admin")]
+use dataverse::cli::admin::AdminSubCommand;
+use dataverse::cli::auth::AuthSubCommand;
+use dataverse::cli::base::{exit_code, set_output_format, Matcher, OutputFormat};
 use dataverse::cli::collection::CollectionSubCommand;
+use dataverse::cli::config::{Config, ConnectionOverrides};
 use dataverse::cli::dataset::DatasetSubCommand;
+#[cfg(feature = "record")]
+use dataverse::cli::dev::DevSubCommand;
 use dataverse::cli::file::FileSubCommand;
+use dataverse::cli::i18n::{print_error, tr};
 use dataverse::cli::info::InfoSubCommand;
+#[cfg(feature = "metrics")]
+use dataverse::cli::metrics::MetricsSubCommand;
+use dataverse::cli::plan::PlanSubCommand;
+use dataverse::cli::stats::StatsSubCommand;
+use dataverse::cli::template::TemplateCommand;
+use dataverse::cli::user::UserSubCommand;
 use dataverse::client::BaseClient;
-
-static HEADER: &str = r#"
---- Dataverse Command Line Interface (DVCLI) ---
-"#;
+use dataverse::error::DataverseError;
+use dataverse::retry::LockRetry;
+use dataverse::verbosity::{set_verbosity, verbosity, Verbosity};
+#[cfg(feature = "record")]
+use dataverse::record::Recorder;
 
 // This is the basic overall structure of the CLI
 // Subcommands are defined in their respective modules
 // and are processed here.
 #[derive(StructOpt, Debug)]
 #[structopt(about = "CLI to interact with Dataverse")]
-enum DVCLI {
+struct DvCli {
+    // Named apart from --output and --format, which subcommands use for report paths and file formats
+    #[structopt(
+        long,
+        help = "Format to print data in: pretty, json, yaml or table",
+        default_value = "pretty"
+    )]
+    data_format: OutputFormat,
+
+    #[structopt(short, long, help = "Print the method of every call, also when not on a terminal")]
+    verbose: bool,
+
+    #[structopt(short, long, help = "Only print data and errors, without calls, progress bars or summaries")]
+    quiet: bool,
+
+    #[structopt(long, help = "Disable colored output, as does setting NO_COLOR")]
+    no_color: bool,
+
+    #[structopt(long, help = "URL of the instance, overriding DVCLI_URL and the stored instance")]
+    url: Option<String>,
+
+    #[structopt(long, help = "API token, overriding DVCLI_TOKEN and the stored token")]
+    token: Option<String>,
+
+    #[structopt(long, help = "Name of a stored instance to connect to, see 'dvcli auth login'")]
+    profile: Option<String>,
+
+    #[structopt(long, help = "Seconds to retry requests rejected because the dataset is locked, e.g. during an ingest")]
+    lock_retry: Option<u64>,
+
+    #[structopt(subcommand)]
+    command: Command,
+}
+
+#[derive(StructOpt, Debug)]
+enum Command {
+    Auth(AuthSubCommand),
     Info(InfoSubCommand),
     Collection(CollectionSubCommand),
     Dataset(DatasetSubCommand),
     File(FileSubCommand),
+    Stats(StatsSubCommand),
+    #[cfg(feature = "metrics")]
+    Metrics(MetricsSubCommand),
+    Plan(PlanSubCommand),
+    Template(TemplateCommand),
+    User(UserSubCommand),
+    #[cfg(feature = "admin")]
+    Admin(AdminSubCommand),
+    #[cfg(feature = "record")]
+    Dev(DevSubCommand),
 }
 
-fn main() {
-    let client = setup_client().expect("Failed to set up client.");
-    let dvcli = DVCLI::from_args();
+fn main() -> ExitCode {
+    let dvcli = DvCli::from_args();
 
-    if atty::is(atty::Stream::Stdout) {
-        println!("{}", HEADER.bold());
+    // Progress bars take the setting from the environment
+    if dvcli.no_color {
+        colored::control::set_override(false);
+        std::env::set_var("CLICOLOR", "0");
     }
 
-    match dvcli {
-        DVCLI::Info(command) => command.process(&client),
-        DVCLI::Collection(command) => command.process(&client),
-        DVCLI::Dataset(command) => command.process(&client),
-        DVCLI::File(command) => command.process(&client),
+    set_verbosity(Verbosity::from_flags(dvcli.quiet, dvcli.verbose));
+    if verbosity() > Verbosity::Quiet && atty::is(atty::Stream::Stderr) {
+        eprintln!("\n{}\n", tr("header", &[]).bold());
     }
+
+    let runtime = tokio::runtime::Runtime::new().unwrap();
+    set_output_format(dvcli.data_format);
+
+    // Logging in does not require any credentials yet
+    if let Command::Auth(command) = &dvcli.command {
+        return finish(runtime.block_on(command.process(dvcli.profile.as_deref())));
+    }
+
+    let overrides = ConnectionOverrides {
+        url: dvcli.url,
+        token: dvcli.token,
+        profile: dvcli.profile,
+    };
+    let client = match setup_client(&overrides) {
+        Ok(client) => client,
+        Err(error) => return finish(Err(error)),
+    };
+    let client = match dvcli.lock_retry {
+        Some(seconds) => client.with_lock_retry(LockRetry::new(Duration::from_secs(seconds))),
+        None => client,
+    };
+    let result = match dvcli.command {
+        #[cfg(feature = "record")]
+        Command::Dev(DevSubCommand::Record { dir, command }) => match Recorder::new(dir) {
+            Ok(recorder) => {
+                let client = client.with_recorder(recorder);
+                let args = std::iter::once("dvcli".to_string()).chain(command);
+                runtime.block_on(dispatch(DvCli::from_iter(args).command, &client))
+            }
+            Err(error) => {
+                let message = format!("{} {}", tr("fixtures-dir-failed", &[]), error);
+                Err(DataverseError::Io(std::io::Error::new(error.kind(), message)))
+            }
+        },
+        command => runtime.block_on(dispatch(command, &client)),
+    };
+
+    finish(result)
 }
 
-fn setup_client() -> Result<BaseClient, Box<dyn Error>> {
-    let (base_url, api_token) = extract_config_from_env();
-    let client = BaseClient::new(&base_url, api_token.as_ref())?;
-    Ok(client)
+// Errors of all subcommands are printed and mapped to exit codes here
+fn finish(result: Result<ExitCode, DataverseError>) -> ExitCode {
+    match result {
+        Ok(code) => code,
+        Err(error) => {
+            print_error(&error);
+            exit_code(&error)
+        }
+    }
 }
 
-// This function extracts the base URL and API token from the environment
-// variables DVCLI_URL and DVCLI_TOKEN, respectively.
-fn extract_config_from_env() -> (String, Option<String>) {
-    let base_url = std::env::var("DVCLI_URL").ok();
-    let api_token = std::env::var("DVCLI_TOKEN").ok();
+async fn dispatch(command: Command, client: &BaseClient) -> Result<ExitCode, DataverseError> {
+    match command {
+        Command::Auth(command) => command.process(None).await,
+        Command::Info(command) => command.process(client).await,
+        Command::Collection(command) => command.process(client).await,
+        Command::Dataset(command) => command.process(client).await,
+        Command::File(command) => command.process(client).await,
+        Command::Stats(command) => command.process(client).await,
+        #[cfg(feature = "metrics")]
+        Command::Metrics(command) => command.process(client).await,
+        Command::Plan(command) => command.process(client).await,
+        Command::Template(command) => command.process(client).await,
+        Command::User(command) => command.process(client).await,
+        #[cfg(feature = "admin")]
+        Command::Admin(command) => command.process(client).await,
+        #[cfg(feature = "record")]
+        Command::Dev(_) => Err(DataverseError::Input(tr("nested-recording", &[]))),
+    }
+}
+
+// The base URL and API token are taken from the --url, --token and --profile
+// options, the DVCLI_URL and DVCLI_TOKEN environment variables or the instance
+// stored by `dvcli auth login`, in this order
+fn setup_client(overrides: &ConnectionOverrides) -> Result<BaseClient, DataverseError> {
+    let config = Config::load()?;
+    let (base_url, api_token) = config.resolve(
+        overrides,
+        std::env::var("DVCLI_URL").ok(),
+        std::env::var("DVCLI_TOKEN").ok(),
+    )?;
 
-    // If there is no base URL, return None
-    if base_url.is_none() {
-        panic!("No base URL provided. Please set the DVCLI_URL environment variable.");
+    if reqwest::Url::parse(&base_url).is_err() {
+        return Err(DataverseError::Input(tr("invalid-url", &[("url", &base_url)])));
     }
 
-    (base_url.unwrap(), api_token)
-}
\ No newline at end of file
+    let builder = BaseClient::builder(&base_url).user_agent(concat!("dvcli/", env!("CARGO_PKG_VERSION")));
+    let builder = match &api_token {
+        Some(api_token) => builder.api_token(api_token),
+        None => builder,
+    };
+
+    builder
+        .build()
+        .map_err(|_| DataverseError::Request(tr("setup-client-failed", &[])))
+}
@@ -0,0 +1,285 @@
+//! Synchronous wrappers of the API for applications and build scripts without an async runtime.
+//!
+//! The wrappers run the asynchronous functions of this crate to completion on a runtime shared
+//! by all clients, which is started on first use. Functions without a wrapper can be run the same
+//! way using `block_on`:
+//!
+//! ```no_run
+//! use dataverse::blocking;
+//! use dataverse::prelude::*;
+//!
+//! let client = blocking::BaseClient::new("https://demo.dataverse.org", None)
+//!     .expect("Failed to create client");
+//!
+//! let version = blocking::info::get_version(&client).expect("Failed to get version");
+//! let limit = blocking::block_on(info::get_zip_download_limit(client.inner()));
+//! ```
+//!
+//! The wrappers must not be called from within an asynchronous context, such as a function
+//! running on a Tokio runtime, where blocking would stall the runtime. Use the asynchronous
+//! functions there instead.
+
+use std::future::Future;
+use std::sync::OnceLock;
+
+use tokio::runtime::Runtime;
+
+use crate::client::BaseClientBuilder;
+use crate::error::DataverseError;
+
+static RUNTIME: OnceLock<Runtime> = OnceLock::new();
+
+/// Runs a future of this crate to completion on the shared runtime.
+///
+/// # Panics
+///
+/// Panics if the runtime cannot be started, or if called from within an asynchronous context.
+pub fn block_on<F: Future>(future: F) -> F::Output {
+    RUNTIME
+        .get_or_init(|| {
+            tokio::runtime::Builder::new_multi_thread()
+                .enable_all()
+                .build()
+                .expect("Failed to start the runtime of the blocking client")
+        })
+        .block_on(future)
+}
+
+/// A client to make synchronous requests to a Dataverse instance.
+///
+/// The client wraps the asynchronous `BaseClient`, which can be accessed using `inner` to run
+/// functions without a wrapper using `block_on`.
+pub struct BaseClient {
+    inner: crate::client::BaseClient,
+}
+
+impl BaseClient {
+    pub fn new(base_url: &str, api_token: Option<&String>) -> Result<Self, DataverseError> {
+        crate::client::BaseClient::new(base_url, api_token).map(Self::from)
+    }
+
+    /// Builds the client from a `BaseClientBuilder` to configure timeouts, proxies and TLS.
+    pub fn from_builder(builder: BaseClientBuilder) -> Result<Self, DataverseError> {
+        builder.build().map(Self::from)
+    }
+
+    pub fn inner(&self) -> &crate::client::BaseClient {
+        &self.inner
+    }
+}
+
+impl From<crate::client::BaseClient> for BaseClient {
+    fn from(inner: crate::client::BaseClient) -> Self {
+        BaseClient { inner }
+    }
+}
+
+pub mod info {
+    use crate::native_api::info::version::VersionResponse;
+    use crate::response::Response;
+
+    use super::{block_on, BaseClient};
+
+    /// Retrieves the version of the instance. See `info::get_version`.
+    pub fn get_version(client: &BaseClient) -> Result<Response<VersionResponse>, String> {
+        block_on(crate::native_api::info::get_version(client.inner()))
+    }
+}
+
+pub mod collection {
+    use crate::native_api::collection::content::CollectionContent;
+    use crate::native_api::collection::create::{CollectionCreateBody, CollectionCreateResponse};
+    use crate::native_api::collection::delete::CollectionDeleteResponse;
+    use crate::native_api::collection::publish;
+    use crate::response::Response;
+
+    use super::{block_on, BaseClient};
+
+    /// Creates a collection within a parent collection. See `collection::create_collection`.
+    pub fn create_collection(
+        client: &BaseClient,
+        parent: &str,
+        body: CollectionCreateBody,
+    ) -> Result<Response<CollectionCreateResponse>, String> {
+        block_on(crate::native_api::collection::create_collection(client.inner(), parent, body))
+    }
+
+    /// Retrieves the contents of a collection. See `collection::get_content`.
+    pub fn get_content(client: &BaseClient, alias: &str) -> Result<Response<Vec<CollectionContent>>, String> {
+        block_on(crate::native_api::collection::get_content(client.inner(), alias))
+    }
+
+    /// Publishes a collection. See `collection::publish_collection`.
+    pub fn publish_collection(
+        client: &BaseClient,
+        alias: &str,
+    ) -> Result<Response<publish::CollectionCreateResponse>, String> {
+        block_on(crate::native_api::collection::publish_collection(client.inner(), alias))
+    }
+
+    /// Deletes an unpublished collection. See `collection::delete_collection`.
+    pub fn delete_collection(
+        client: &BaseClient,
+        alias: &str,
+    ) -> Result<Response<CollectionDeleteResponse>, String> {
+        block_on(crate::native_api::collection::delete_collection(client.inner(), alias))
+    }
+}
+
+pub mod dataset {
+    #[cfg(feature = "fs")]
+    use std::path::PathBuf;
+
+    #[cfg(feature = "fs")]
+    use crate::callback::CallbackFun;
+    use crate::identifier::Identifier;
+    use crate::native_api::dataset::create::{DatasetCreateBody, DatasetCreateResponse};
+    use crate::native_api::dataset::delete::UnpublishedDatasetDeleteResponse;
+    use crate::native_api::dataset::edit::{Dataset, EditMetadataBody, GetDatasetResponse};
+    use crate::native_api::dataset::publish::{DatasetPublishResponse, Version};
+    #[cfg(feature = "fs")]
+    use crate::native_api::dataset::upload::{UploadBody, UploadResponse};
+    use crate::response::Response;
+
+    use super::{block_on, BaseClient};
+
+    /// Creates a dataset within a collection. See `dataset::create_dataset`.
+    pub fn create_dataset(
+        client: &BaseClient,
+        parent: &str,
+        body: DatasetCreateBody,
+    ) -> Result<Response<DatasetCreateResponse>, String> {
+        block_on(crate::native_api::dataset::create_dataset(client.inner(), parent, body))
+    }
+
+    /// Retrieves the metadata of a dataset. See `dataset::get_dataset_meta`.
+    pub fn get_dataset_meta(client: &BaseClient, id: Identifier) -> Result<Response<GetDatasetResponse>, String> {
+        block_on(crate::native_api::dataset::get_dataset_meta(client.inner(), id))
+    }
+
+    /// Edits the metadata of a dataset. See `dataset::edit_dataset_metadata`.
+    pub fn edit_dataset_metadata(
+        client: &BaseClient,
+        pid: &str,
+        replace: &bool,
+        body: EditMetadataBody,
+    ) -> Result<Response<Dataset>, String> {
+        block_on(crate::native_api::dataset::edit_dataset_metadata(client.inner(), pid, replace, body))
+    }
+
+    /// Uploads a file to a dataset. See `dataset::upload_file_to_dataset`.
+    #[cfg(feature = "fs")]
+    pub fn upload_file_to_dataset(
+        client: &BaseClient,
+        id: Identifier,
+        fpath: PathBuf,
+        body: Option<UploadBody>,
+        callback: Option<CallbackFun>,
+    ) -> Result<Response<UploadResponse>, String> {
+        block_on(crate::native_api::dataset::upload_file_to_dataset(
+            client.inner(),
+            id,
+            fpath,
+            body,
+            callback,
+        ))
+    }
+
+    /// Publishes a dataset. See `dataset::publish::publish_dataset`.
+    pub fn publish_dataset(
+        client: &BaseClient,
+        pid: &str,
+        version: Version,
+    ) -> Result<Response<DatasetPublishResponse>, String> {
+        block_on(crate::native_api::dataset::publish::publish_dataset(client.inner(), pid, version))
+    }
+
+    /// Deletes an unpublished dataset. See `dataset::delete_dataset`.
+    pub fn delete_dataset(
+        client: &BaseClient,
+        id: &i64,
+    ) -> Result<Response<UnpublishedDatasetDeleteResponse>, String> {
+        block_on(crate::native_api::dataset::delete_dataset(client.inner(), id))
+    }
+}
+
+pub mod file {
+    #[cfg(feature = "fs")]
+    use std::{collections::HashMap, path::PathBuf};
+
+    #[cfg(feature = "fs")]
+    use crate::callback::CallbackFun;
+    #[cfg(feature = "fs")]
+    use crate::native_api::dataset::upload::{UploadBody, UploadResponse};
+    use crate::identifier::Identifier;
+    use crate::native_api::dataset::link::MessageResponse;
+    use crate::response::Response;
+
+    use super::{block_on, BaseClient};
+
+    /// Replaces a file of a dataset. See `file::replace_file`.
+    #[cfg(feature = "fs")]
+    pub fn replace_file(
+        client: &BaseClient,
+        id: &str,
+        fpath: PathBuf,
+        body: &Option<UploadBody>,
+        callbacks: Option<HashMap<String, CallbackFun>>,
+    ) -> Result<Response<UploadResponse>, String> {
+        block_on(crate::native_api::file::replace_file(client.inner(), id, fpath, body, callbacks))
+    }
+
+    /// Deletes a file from the draft of its dataset. See `file::delete_file`.
+    pub fn delete_file(client: &BaseClient, id: Identifier) -> Result<Response<MessageResponse>, String> {
+        block_on(crate::native_api::file::delete_file(client.inner(), id))
+    }
+}
+
+pub mod users {
+    use crate::native_api::users::me::UserResponse;
+    use crate::response::Response;
+
+    use super::{block_on, BaseClient};
+
+    /// Retrieves the user of the API token. See `users::get_me`.
+    pub fn get_me(client: &BaseClient) -> Result<Response<UserResponse>, String> {
+        block_on(crate::native_api::users::get_me(client.inner()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::identifier::Identifier;
+
+    use super::BaseClient;
+
+    /// Tests that requests are sent without an async runtime of the caller.
+    #[test]
+    fn test_blocking_requests() {
+        let server = MockServer::start();
+        let version = server.mock(|when, then| {
+            when.method(GET).path("/api/info/version");
+            then.status(200)
+                .json_body(json!({"status": "OK", "data": {"version": "6.2", "build": "1"}}));
+        });
+        let delete = server.mock(|when, then| {
+            when.method(DELETE).path("/api/files/5");
+            then.status(200)
+                .json_body(json!({"status": "OK", "data": {"message": "File deleted"}}));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+
+        let response = super::info::get_version(&client).expect("Failed to get version");
+        assert_eq!(response.data.unwrap().version.to_string(), "6.2");
+
+        let response = super::file::delete_file(&client, Identifier::Id(5)).expect("Failed to delete file");
+        assert!(response.status.is_ok());
+
+        version.assert();
+        delete.assert();
+    }
+}
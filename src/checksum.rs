@@ -0,0 +1,237 @@
+use std::fmt;
+#[cfg(feature = "fs")]
+use std::fs::File;
+#[cfg(feature = "fs")]
+use std::io::Read;
+#[cfg(feature = "fs")]
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[cfg(feature = "fs")]
+use futures::StreamExt;
+use md5::Md5;
+use sha1::Sha1;
+use sha2::digest::DynDigest;
+use sha2::{Digest, Sha256, Sha512};
+
+// Dataverse records a checksum for every file, using the algorithm configured
+// for the instance. The names below are the ones reported in the `checksum.type`
+// field of the file metadata.
+
+/// The checksum algorithms an instance can be configured with.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum ChecksumAlgorithm {
+    #[default]
+    Md5,
+    Sha1,
+    Sha256,
+    Sha512,
+}
+
+impl ChecksumAlgorithm {
+    /// Returns the name of the algorithm as reported in the `checksum.type` field of the file metadata.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChecksumAlgorithm::Md5 => "MD5",
+            ChecksumAlgorithm::Sha1 => "SHA-1",
+            ChecksumAlgorithm::Sha256 => "SHA-256",
+            ChecksumAlgorithm::Sha512 => "SHA-512",
+        }
+    }
+
+    /// Returns a hasher of the algorithm, e.g. to hash data as it is read.
+    pub fn hasher(&self) -> Box<dyn DynDigest + Send> {
+        match self {
+            ChecksumAlgorithm::Md5 => Box::new(Md5::new()),
+            ChecksumAlgorithm::Sha1 => Box::new(Sha1::new()),
+            ChecksumAlgorithm::Sha256 => Box::new(Sha256::new()),
+            ChecksumAlgorithm::Sha512 => Box::new(Sha512::new()),
+        }
+    }
+}
+
+impl FromStr for ChecksumAlgorithm {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_uppercase().as_str() {
+            "MD5" => Ok(ChecksumAlgorithm::Md5),
+            "SHA-1" | "SHA1" => Ok(ChecksumAlgorithm::Sha1),
+            "SHA-256" | "SHA256" => Ok(ChecksumAlgorithm::Sha256),
+            "SHA-512" | "SHA512" => Ok(ChecksumAlgorithm::Sha512),
+            _ => Err(format!("Unsupported checksum algorithm: {}", s)),
+        }
+    }
+}
+
+impl fmt::Display for ChecksumAlgorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+/// Computes the hex-encoded checksum of a file with an algorithm supported by Dataverse.
+///
+/// # Arguments
+///
+/// * `path` - The path of the file to hash.
+/// * `algorithm` - The name of the algorithm as reported by Dataverse, i.e. `MD5`, `SHA-1`, `SHA-256` or
+///   `SHA-512`. The name is matched case-insensitively.
+///
+/// # Returns
+///
+/// A `Result` wrapping the lowercase hex digest, or a `String` error message if the algorithm is unknown or
+/// the file cannot be read.
+#[cfg(feature = "fs")]
+pub fn file_checksum(path: &Path, algorithm: &str) -> Result<String, String> {
+    let algorithm = algorithm.parse::<ChecksumAlgorithm>()?;
+
+    digest_file(path, algorithm).map_err(|e| format!("Failed to hash {}: {}", path.display(), e))
+}
+
+/// Computes the checksums of many files in parallel on the blocking threads of the runtime.
+///
+/// Hashing is bound by CPU and disk rather than the network, and would stall the asynchronous runtime if
+/// executed on its worker threads. Each file is thus hashed by `file_checksum` on a blocking thread, with at
+/// most `concurrency` files being hashed at the same time.
+///
+/// # Arguments
+///
+/// * `jobs` - Pairs of the path of a file and the name of the algorithm to hash it with.
+/// * `concurrency` - The maximum number of files hashed simultaneously. Use `hashing_threads` to utilize
+///   all available cores.
+///
+/// # Returns
+///
+/// The result of each job, in the order of the jobs.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+/// use dataverse::checksum::{file_checksums, hashing_threads};
+///
+/// # async fn run() {
+/// let jobs = vec![
+///     (PathBuf::from("data.csv"), "MD5".to_string()),
+///     (PathBuf::from("readme.md"), "MD5".to_string()),
+/// ];
+///
+/// for checksum in file_checksums(jobs, hashing_threads()).await {
+///     println!("{:?}", checksum);
+/// }
+/// # }
+/// ```
+#[cfg(feature = "fs")]
+pub async fn file_checksums(
+    jobs: Vec<(PathBuf, String)>,
+    concurrency: usize,
+) -> Vec<Result<String, String>> {
+    futures::stream::iter(jobs)
+        .map(|(path, algorithm)| async move {
+            tokio::task::spawn_blocking(move || file_checksum(&path, &algorithm))
+                .await
+                .unwrap_or_else(|e| Err(format!("Failed to hash file: {}", e)))
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
+/// Returns the number of files to hash simultaneously, which defaults to the number of available cores.
+#[cfg(feature = "fs")]
+pub fn hashing_threads() -> usize {
+    std::thread::available_parallelism()
+        .map(|threads| threads.get())
+        .unwrap_or(1)
+}
+
+/// Computes the hex-encoded SHA-256 digest of the given bytes.
+pub fn sha256_hex(bytes: &[u8]) -> String {
+    hex(&Sha256::digest(bytes))
+}
+
+// Reads the file in chunks, such that large files are never held in memory
+#[cfg(feature = "fs")]
+fn digest_file(path: &Path, algorithm: ChecksumAlgorithm) -> std::io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = algorithm.hasher();
+    let mut buffer = vec![0; 64 * 1024];
+
+    loop {
+        let read = file.read(&mut buffer)?;
+        if read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..read]);
+    }
+
+    Ok(hex(&hasher.finalize()))
+}
+
+pub(crate) fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "fs")]
+    use std::path::{Path, PathBuf};
+
+    #[cfg(feature = "fs")]
+    use super::{file_checksum, file_checksums};
+    use super::{sha256_hex, ChecksumAlgorithm};
+
+    /// Tests the checksums of the test fixture with the algorithms supported by Dataverse.
+    #[cfg(feature = "fs")]
+    #[test]
+    fn test_file_checksum() {
+        let path = Path::new("tests/fixtures/file.txt");
+
+        assert_eq!(
+            file_checksum(path, "MD5").unwrap(),
+            "a28bca1b906f539ba70ca3a0b1f2e773"
+        );
+        assert_eq!(
+            file_checksum(path, "sha-256").unwrap(),
+            "c21f3ac6b5f6e45b1c0b292bcd5cc806298ecb033bc7030a6071e3c894d73054"
+        );
+        assert!(file_checksum(path, "CRC32").is_err());
+    }
+
+    /// Tests the parsing of algorithm names as reported by Dataverse.
+    #[test]
+    fn test_checksum_algorithm_from_str() {
+        assert_eq!("SHA-1".parse::<ChecksumAlgorithm>(), Ok(ChecksumAlgorithm::Sha1));
+        assert_eq!("sha512".parse::<ChecksumAlgorithm>(), Ok(ChecksumAlgorithm::Sha512));
+        assert_eq!(ChecksumAlgorithm::Sha256.to_string(), "SHA-256");
+        assert!("CRC32".parse::<ChecksumAlgorithm>().is_err());
+    }
+
+    /// Tests that parallel hashing returns the results in the order of the jobs.
+    #[cfg(feature = "fs")]
+    #[tokio::test]
+    async fn test_file_checksums() {
+        let path = PathBuf::from("tests/fixtures/file.txt");
+        let jobs = vec![
+            (path.clone(), "SHA-256".to_string()),
+            (PathBuf::from("tests/fixtures/missing.txt"), "MD5".to_string()),
+            (path.clone(), "MD5".to_string()),
+        ];
+
+        let results = file_checksums(jobs, 2).await;
+
+        assert_eq!(results.len(), 3);
+        assert_eq!(results[0], file_checksum(&path, "SHA-256"));
+        assert!(results[1].is_err());
+        assert_eq!(results[2].as_deref(), Ok("a28bca1b906f539ba70ca3a0b1f2e773"));
+    }
+
+    /// Tests the SHA-256 digest of raw bytes.
+    #[test]
+    fn test_sha256_hex() {
+        assert_eq!(
+            sha256_hex(b""),
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+    }
+}
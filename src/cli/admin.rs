@@ -0,0 +1,171 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use colored::Colorize;
+use structopt::StructOpt;
+
+use crate::client::BaseClient;
+use crate::costs::{self, StorageRates};
+use crate::error::DataverseError;
+use crate::identifier::Identifier;
+use crate::native_api::admin::locks::{self, LockFilter};
+use crate::native_api::admin::banners::{self, NewBannerMessage};
+use crate::native_api::admin::{ip_groups, settings};
+
+use super::base::{evaluate_and_print_response, parse_file, print_data, write_output, Matcher};
+use super::i18n::tr;
+use super::stats::ReportFormat;
+
+#[derive(StructOpt, Debug)]
+#[structopt(about = "Administrate the Dataverse instance")]
+pub enum AdminSubCommand {
+    #[structopt(about = "List, retrieve, set or delete database settings")]
+    Settings {
+        #[structopt(help = "Name of the setting, e.g. :SiteUrl, lists all settings if omitted")]
+        name: Option<String>,
+
+        #[structopt(long, help = "Value to set", conflicts_with = "delete", requires = "name")]
+        set: Option<String>,
+
+        #[structopt(long, help = "Delete the setting", requires = "name")]
+        delete: bool,
+    },
+
+    #[structopt(about = "List, add or delete banner messages")]
+    Banners {
+        #[structopt(long, help = "Path to the JSON or YAML file containing the texts per language to add")]
+        add: Option<PathBuf>,
+
+        #[structopt(long, help = "ID of the banner message to delete", conflicts_with = "add")]
+        delete: Option<i64>,
+    },
+
+    #[structopt(about = "List, create or delete IP groups")]
+    IpGroups {
+        #[structopt(help = "Alias or ID of the group to delete")]
+        group: Option<String>,
+
+        #[structopt(long, help = "Path to the JSON or YAML definition of the group to create", conflicts_with = "delete")]
+        create: Option<PathBuf>,
+
+        #[structopt(long, help = "Delete the group", requires = "group")]
+        delete: bool,
+    },
+
+    #[structopt(about = "List the locks of all datasets or remove the locks of a dataset")]
+    Locks {
+        #[structopt(long = "type", help = "Type of the locks to list or remove, e.g. Ingest")]
+        lock_type: Option<String>,
+
+        #[structopt(long, help = "Only list locks caused by this user", conflicts_with = "remove")]
+        user: Option<String>,
+
+        #[structopt(long, help = "(Persistent) Identifier of the dataset to remove the locks from")]
+        remove: Option<Identifier>,
+    },
+
+    #[structopt(about = "Estimate the monthly storage costs per collection")]
+    StorageCosts {
+        #[structopt(help = "Alias of the collection to start from", default_value = "root")]
+        root: String,
+
+        #[structopt(long, short, help = "Path to the TOML file containing the rates per GB-month")]
+        rates: PathBuf,
+
+        #[structopt(long, short, help = "Format of the report (json, csv)", default_value = "csv")]
+        format: ReportFormat,
+    },
+}
+
+impl Matcher for AdminSubCommand {
+    async fn process(&self, client: &BaseClient) -> Result<ExitCode, DataverseError> {
+        match self {
+            AdminSubCommand::Settings { name, set, delete } => match (name.as_deref().map(warn_about_name), set) {
+                (None, _) => {
+                    let response = settings::list_settings(client).await;
+                    evaluate_and_print_response(response)
+                }
+                (Some(name), Some(value)) => {
+                    let response = settings::put_setting(client, name, value).await;
+                    evaluate_and_print_response(response)
+                }
+                (Some(name), None) if *delete => {
+                    let response = settings::delete_setting(client, name).await;
+                    evaluate_and_print_response(response)
+                }
+                (Some(name), None) => {
+                    let response = settings::get_setting(client, name).await;
+                    evaluate_and_print_response(response)
+                }
+            },
+            AdminSubCommand::Banners { add, delete } => match (add, delete) {
+                (Some(banner), _) => {
+                    let banner = parse_file::<_, NewBannerMessage>(banner)?;
+                    let response = banners::add_banner_message(client, &banner).await;
+                    evaluate_and_print_response(response)
+                }
+                (None, Some(id)) => {
+                    let response = banners::delete_banner_message(client, *id).await;
+                    evaluate_and_print_response(response)
+                }
+                (None, None) => {
+                    let response = banners::list_banner_messages(client).await;
+                    evaluate_and_print_response(response)
+                }
+            },
+            AdminSubCommand::IpGroups { group, create, delete } => match (create, group) {
+                (Some(definition), _) => {
+                    let definition = parse_file::<_, ip_groups::IpGroup>(definition)?;
+                    let response = ip_groups::create_ip_group(client, &definition).await;
+                    evaluate_and_print_response(response)
+                }
+                (None, Some(group)) if *delete => {
+                    let response = ip_groups::delete_ip_group(client, group).await;
+                    evaluate_and_print_response(response)
+                }
+                _ => {
+                    let response = ip_groups::list_ip_groups(client).await;
+                    evaluate_and_print_response(response)
+                }
+            },
+            AdminSubCommand::Locks { lock_type, user, remove } => match remove {
+                Some(id) => {
+                    let response = locks::remove_locks(client, id.clone(), lock_type.as_deref()).await;
+                    evaluate_and_print_response(response)
+                }
+                None => {
+                    let filter = LockFilter {
+                        lock_type: lock_type.clone(),
+                        user: user.clone(),
+                    };
+                    let response = locks::list_locks(client, &filter).await;
+                    evaluate_and_print_response(response)
+                }
+            },
+            AdminSubCommand::StorageCosts { root, rates, format } => {
+                let rates = std::fs::read_to_string(rates)
+                    .map_err(|e| e.to_string())
+                    .and_then(|content| StorageRates::from_toml(&content))
+                    .map_err(|e| DataverseError::Input(tr("rates-failed", &[("error", &e)])))?;
+
+                let report = costs::build_cost_report(client, root, &rates)
+                    .await
+                    .map_err(DataverseError::Request)?;
+
+                match format {
+                    ReportFormat::Csv => write_output(report.to_csv(&rates), &None),
+                    ReportFormat::Json => print_data(report),
+                }
+            }
+        }
+    }
+}
+
+// Settings are easily named without their leading colon, which the library adds
+fn warn_about_name(name: &str) -> &str {
+    let normalized = settings::setting_name(name);
+    if normalized != name {
+        eprintln!("{}", tr("setting-colon-added", &[("name", &name), ("normalized", &normalized)]).yellow().bold());
+    }
+    name
+}
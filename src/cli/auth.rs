@@ -0,0 +1,116 @@
+use std::io::{BufRead, Write};
+use std::process::ExitCode;
+
+use structopt::StructOpt;
+
+use crate::client::BaseClient;
+use crate::error::DataverseError;
+use crate::native_api::users::me;
+
+use super::config::{normalize_url, Config, Profile, TokenStore};
+use super::i18n::tr;
+
+#[derive(StructOpt, Debug)]
+#[structopt(about = "Store the API token of an instance, such that it is not kept in environment variables")]
+pub enum AuthSubCommand {
+    #[structopt(about = "Validate and store the URL and API token of an instance")]
+    Login {
+        #[structopt(long, help = "URL of the instance, prompted for if omitted")]
+        url: Option<String>,
+    },
+
+    #[structopt(about = "Remove the stored API token of an instance")]
+    Logout {
+        #[structopt(long, help = "URL of the instance, defaults to the stored one")]
+        url: Option<String>,
+    },
+
+    #[structopt(about = "Show the stored instance and where its API token is kept")]
+    Status,
+}
+
+impl AuthSubCommand {
+    // Unlike other subcommands, these run before a client can be set up from
+    // the stored credentials. With a profile, its instance is used instead of
+    // the default one.
+    pub async fn process(&self, profile: Option<&str>) -> Result<ExitCode, DataverseError> {
+        match self {
+            AuthSubCommand::Login { url } => {
+                let url = match url {
+                    Some(url) => normalize_url(url),
+                    None => normalize_url(&prompt(&tr("prompt-url", &[]))?),
+                };
+                if reqwest::Url::parse(&url).is_err() {
+                    return Err(DataverseError::Input(tr("invalid-url", &[("url", &url)])));
+                }
+
+                let token = rpassword::prompt_password(tr("prompt-token", &[]))?;
+                let token = token.trim().to_string();
+
+                let client = BaseClient::new(&url, Some(&token))?;
+                let response = me::get_me(&client).await.map_err(DataverseError::Request)?;
+                let user = match (response.status.is_ok(), response.data) {
+                    (true, Some(user)) => user.identifier.unwrap_or_default(),
+                    (_, _) => {
+                        let error = response.message.map(|m| m.to_string()).unwrap_or_default();
+                        return Err(DataverseError::Api(tr("login-failed", &[("error", &error)])));
+                    }
+                };
+
+                let mut config = Config::load()?;
+                let store = config.store_token(&url, &token);
+                match profile {
+                    Some(name) => {
+                        config.profiles.insert(name.to_string(), Profile { url: url.clone() });
+                    }
+                    None => config.url = Some(url.clone()),
+                }
+                let path = config.save()?;
+
+                println!("{}", tr("logged-in", &[("url", &url), ("user", &user)]));
+                if store == TokenStore::ConfigFile {
+                    eprintln!("{}", tr("token-in-config", &[("path", &path.display())]));
+                }
+
+                Ok(ExitCode::SUCCESS)
+            }
+            AuthSubCommand::Logout { url } => {
+                let mut config = Config::load()?;
+                let Some(url) = url.clone().or(config.profile_url(profile)?) else {
+                    return Err(DataverseError::Input(tr("not-logged-in", &[])));
+                };
+
+                config.delete_token(&url);
+                config.save()?;
+
+                println!("{}", tr("logged-out", &[("url", &normalize_url(&url))]));
+                Ok(ExitCode::SUCCESS)
+            }
+            AuthSubCommand::Status => {
+                let config = Config::load()?;
+                let Some(url) = config.profile_url(profile)? else {
+                    return Err(DataverseError::Input(tr("not-logged-in", &[])));
+                };
+
+                let store = match config.load_token(&url) {
+                    Some((_, TokenStore::Keyring)) => tr("token-store-keyring", &[]),
+                    Some((_, TokenStore::ConfigFile)) => tr("token-store-config", &[]),
+                    None => tr("token-store-none", &[]),
+                };
+
+                println!("{}", tr("auth-status", &[("url", &url), ("store", &store)]));
+                Ok(ExitCode::SUCCESS)
+            }
+        }
+    }
+}
+
+// Prompts on stderr, such that stdout stays free for data
+fn prompt(message: &str) -> Result<String, DataverseError> {
+    eprint!("{}", message);
+    std::io::stderr().flush()?;
+
+    let mut line = String::new();
+    std::io::stdin().lock().read_line(&mut line)?;
+    Ok(line.trim().to_string())
+}
@@ -0,0 +1,102 @@
+use colored::Colorize;
+
+use crate::client::BaseClient;
+use crate::native_api::admin::banners::list_banner_messages;
+use crate::verbosity::{verbosity, Verbosity};
+
+use super::i18n::tr;
+
+// Banner messages announce events such as maintenance windows. Before long
+// transfers, the CLI can print them as a warning, which is enabled via the
+// DVCLI_CHECK_BANNERS environment variable, since the admin API providing
+// the banners is not reachable on most instances.
+
+/// Returns `true` if the `DVCLI_CHECK_BANNERS` environment variable enables the banner check.
+pub fn banner_check_enabled() -> bool {
+    match std::env::var("DVCLI_CHECK_BANNERS") {
+        Ok(value) => !matches!(value.to_lowercase().as_str(), "" | "0" | "false" | "no"),
+        Err(_) => false,
+    }
+}
+
+/// Prints the banner messages of the instance as warnings to stderr, if the check is enabled.
+pub async fn warn_about_banners(client: &BaseClient) {
+    if !banner_check_enabled() || verbosity() == Verbosity::Quiet {
+        return;
+    }
+
+    for message in fetch_banner_messages(client).await {
+        eprintln!("{} {}\n", tr("banner-warning", &[]).yellow().bold(), message);
+    }
+}
+
+// Failures are ignored, since the check must never prevent a transfer
+async fn fetch_banner_messages(client: &BaseClient) -> Vec<String> {
+    let Ok(response) = list_banner_messages(client).await else {
+        return vec![];
+    };
+
+    match response.status.is_ok() {
+        true => response
+            .data
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|banner| banner.display_value)
+            .filter(|message| !message.trim().is_empty())
+            .collect(),
+        false => vec![],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use crate::client::BaseClient;
+
+    use super::fetch_banner_messages;
+
+    /// Tests that banner messages are returned and that a blocked admin API yields no messages.
+    #[tokio::test]
+    async fn test_fetch_banner_messages() {
+        let server = MockServer::start();
+        let mut mock = server.mock(|when, then| {
+            when.method(GET).path("/api/admin/bannerMessage");
+            then.status(200).json_body(serde_json::json!({
+                "status": "OK",
+                "data": [
+                    {"id": 1, "displayValue": "Maintenance window tonight from 22:00 UTC"},
+                    {"id": 2, "displayValue": " "}
+                ]
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        assert_eq!(
+            fetch_banner_messages(&client).await,
+            vec!["Maintenance window tonight from 22:00 UTC".to_string()]
+        );
+
+        mock.delete();
+        mock = server.mock(|when, then| {
+            when.method(GET).path("/api/admin/bannerMessage");
+            then.status(403).json_body(serde_json::json!({
+                "status": "ERROR",
+                "message": "Endpoint available from localhost only"
+            }));
+        });
+
+        assert!(fetch_banner_messages(&client).await.is_empty());
+
+        // Neither error pages nor unreachable instances abort the command
+        mock.delete();
+        server.mock(|when, then| {
+            when.method(GET).path("/api/admin/bannerMessage");
+            then.status(502).body("<html><body>Bad Gateway</body></html>");
+        });
+        assert!(fetch_banner_messages(&client).await.is_empty());
+
+        let client = BaseClient::new("http://127.0.0.1:1", None).unwrap();
+        assert!(fetch_banner_messages(&client).await.is_empty());
+    }
+}
@@ -1,26 +1,189 @@
-use std::error::Error;
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
 use std::str::FromStr;
+use std::sync::OnceLock;
 
 use clap::ArgMatches;
 use serde::de::DeserializeOwned;
 use serde::Serialize;
 
 use crate::client::BaseClient;
-use crate::response::Response;
+use crate::error::DataverseError;
+use crate::response::{Response, Status};
 
-pub fn evaluate_and_print_response<T: Serialize>(response: Result<Response<T>, String>) {
-    match response {
-        Ok(response) => {
-            response.print_result();
+use super::fields::FieldSelection;
+use super::i18n::tr;
+use super::table::render_table;
+
+static OUTPUT_FORMAT: OnceLock<OutputFormat> = OnceLock::new();
+
+/// Formats in which the CLI prints the data of responses.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub enum OutputFormat {
+    /// Colored and indented JSON in a terminal, indented JSON otherwise.
+    #[default]
+    Pretty,
+    /// JSON on a single line, e.g. for processing with other tools.
+    Json,
+    Yaml,
+    /// A plain text table, e.g. for lists of files or collection contents.
+    Table,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "pretty" => Ok(OutputFormat::Pretty),
+            "json" => Ok(OutputFormat::Json),
+            "yaml" => Ok(OutputFormat::Yaml),
+            "table" => Ok(OutputFormat::Table),
+            _ => Err(format!("Unknown output format '{}', expected pretty, json, yaml or table", s)),
         }
-        Err(e) => {
-            println!("Error: {}", e);
+    }
+}
+
+impl OutputFormat {
+    /// Renders data in this format. Pretty output is left to `Response::print_result`.
+    pub fn render<T: Serialize>(&self, data: &T) -> Result<String, String> {
+        match self {
+            OutputFormat::Pretty => serde_json::to_string_pretty(data).map_err(|e| e.to_string()),
+            OutputFormat::Json => serde_json::to_string(data).map_err(|e| e.to_string()),
+            OutputFormat::Yaml => serde_yaml::to_string(data).map_err(|e| e.to_string()),
+            OutputFormat::Table => {
+                let value = serde_json::to_value(data).map_err(|e| e.to_string())?;
+                Ok(render_table(&value))
+            }
+        }
+    }
+}
+
+/// Sets the format in which data is printed for the rest of the process. Only the first call has an effect.
+pub fn set_output_format(format: OutputFormat) {
+    let _ = OUTPUT_FORMAT.set(format);
+}
+
+/// Returns the format in which data is printed, which is `Pretty` unless set otherwise.
+pub fn output_format() -> OutputFormat {
+    OUTPUT_FORMAT.get().copied().unwrap_or_default()
+}
+
+// Prints successful responses and turns failed ones into errors, which are
+// printed and mapped to an exit code by the binary
+pub fn evaluate_and_print_response<T: Serialize>(
+    response: Result<Response<T>, String>,
+) -> Result<ExitCode, DataverseError> {
+    let response = response.map_err(DataverseError::Request)?;
+
+    match response.status {
+        Status::OK => match output_format() {
+            OutputFormat::Pretty => {
+                response.print_result();
+                Ok(ExitCode::SUCCESS)
+            }
+            format => {
+                let output = format.render(&response.data).map_err(DataverseError::Input)?;
+                println!("{}", output.trim_end());
+                Ok(ExitCode::SUCCESS)
+            }
+        },
+        Status::ERROR => Err(DataverseError::Api(
+            response.message.map(|m| m.to_string()).unwrap_or_default(),
+        )),
+    }
+}
+
+// Prints successful responses reduced to the selected fields, if any
+pub fn evaluate_and_print_fields<T: Serialize>(
+    response: Result<Response<T>, String>,
+    fields: &Option<FieldSelection>,
+) -> Result<ExitCode, DataverseError> {
+    let Some(fields) = fields else {
+        return evaluate_and_print_response(response);
+    };
+
+    let response = response.map(|response| Response {
+        status: response.status,
+        data: response
+            .data
+            .map(|data| fields.project(&serde_json::to_value(data).unwrap_or_default())),
+        message: response.message,
+        requestUrl: response.requestUrl,
+        requestMethod: response.requestMethod,
+    });
+
+    evaluate_and_print_response(response)
+}
+
+// Prints only the identifiers of created or changed objects, one per line, such
+// that shell pipelines can capture them without parsing JSON
+pub fn evaluate_and_print_ids<T: Serialize>(
+    response: Result<Response<T>, String>,
+    print_id: bool,
+    identifiers: impl Fn(&T) -> Vec<String>,
+) -> Result<ExitCode, DataverseError> {
+    if !print_id {
+        return evaluate_and_print_response(response);
+    }
+
+    let response = response.map_err(DataverseError::Request)?;
+    match response.status {
+        Status::OK => {
+            for id in response.data.as_ref().map(identifiers).unwrap_or_default() {
+                println!("{}", id);
+            }
+            Ok(ExitCode::SUCCESS)
         }
+        Status::ERROR => Err(DataverseError::Api(
+            response.message.map(|m| m.to_string()).unwrap_or_default(),
+        )),
     }
 }
 
+// Prints data that has not been returned by the API, such as reports, in the
+// same way as successful responses
+pub fn print_data<T: Serialize>(data: T) -> Result<ExitCode, DataverseError> {
+    evaluate_and_print_response(Ok(Response {
+        status: Status::OK,
+        data: Some(data),
+        message: None,
+        requestUrl: None,
+        requestMethod: None,
+    }))
+}
+
+// Writes the content of a report to the given path or prints it to stdout
+pub fn write_output(
+    content: Result<String, String>,
+    output: &Option<PathBuf>,
+) -> Result<ExitCode, DataverseError> {
+    let content = content.map_err(DataverseError::Request)?;
+
+    match output {
+        Some(path) => {
+            fs::write(path, content)?;
+            println!("{}", tr("report-written", &[("path", &path.display())]));
+        }
+        None => print!("{}", content),
+    }
+
+    Ok(ExitCode::SUCCESS)
+}
+
+// Maps errors to the exit codes of the CLI, following the conventions of sysexits
+pub fn exit_code(error: &DataverseError) -> ExitCode {
+    let code = match error {
+        DataverseError::Api(_) => exitcode::DATAERR,
+        DataverseError::Request(_) => exitcode::UNAVAILABLE,
+        DataverseError::Input(_) => exitcode::USAGE,
+        DataverseError::Io(_) => exitcode::IOERR,
+    };
+
+    ExitCode::from(code as u8)
+}
+
 pub fn get_argument<U, T>(matches: &ArgMatches, arg_name: &str) -> T
 where
     U: AsRef<str> + Send + Sync + Clone + 'static,
@@ -29,15 +192,15 @@ where
 {
     let value = matches
         .get_one::<U>(arg_name)
-        .expect(&format!("{} is required.", arg_name))
+        .unwrap_or_else(|| panic!("{} is required.", arg_name))
         .as_ref()
         .parse::<T>()
-        .expect(&format!("{} is invalid.", arg_name));
+        .unwrap_or_else(|_| panic!("{} is invalid.", arg_name));
 
     value
 }
 
-pub fn parse_file<P, T>(path: P) -> Result<T, Box<dyn Error>>
+pub fn parse_file<P, T>(path: P) -> Result<T, DataverseError>
 where
     T: DeserializeOwned,
     P: AsRef<Path>,
@@ -49,11 +212,107 @@ where
     } else if let Ok(content) = serde_yaml::from_str(&content) {
         Ok(content)
     } else {
-        Err("Failed to parse the file as either JSON or YAML".into())
+        Err(DataverseError::Input(tr("parse-file-failed", &[])))
     }
 }
 
-// Trait to define the behavior of a matcher ()
+// Trait to define the behavior of a matcher, which processes a subcommand and
+// returns the exit code of the CLI. The binary drives it on its own runtime.
+#[allow(async_fn_in_trait)]
 pub trait Matcher {
-    fn process(&self, client: &BaseClient);
+    async fn process(&self, client: &BaseClient) -> Result<ExitCode, DataverseError>;
+}
+
+#[cfg(test)]
+mod tests {
+    use std::process::ExitCode;
+
+    use crate::error::DataverseError;
+    use crate::response::{Message, Response, Status};
+
+    use super::{evaluate_and_print_ids, evaluate_and_print_response, exit_code, OutputFormat};
+
+    /// Tests that error responses are turned into errors instead of terminating the process.
+    #[test]
+    fn test_evaluate_error_response() {
+        let response: Response<String> = Response {
+            status: Status::ERROR,
+            data: None,
+            message: Some(Message::PlainMessage("Dataset not found".to_string())),
+            requestUrl: None,
+            requestMethod: None,
+        };
+
+        match evaluate_and_print_response(Ok(response)) {
+            Err(DataverseError::Api(message)) => assert_eq!(message, "Dataset not found"),
+            other => panic!("Unexpected result: {:?}", other),
+        }
+
+        let result = evaluate_and_print_response::<String>(Err("Connection refused".to_string()));
+        assert!(matches!(result, Err(DataverseError::Request(_))));
+    }
+
+    /// Tests that successful responses result in a successful exit code.
+    #[test]
+    fn test_evaluate_ok_response() {
+        let response = Response {
+            status: Status::OK,
+            data: Some("data"),
+            message: None,
+            requestUrl: None,
+            requestMethod: None,
+        };
+
+        assert_eq!(evaluate_and_print_response(Ok(response)).unwrap(), ExitCode::SUCCESS);
+    }
+
+    /// Tests that identifiers are only extracted from successful responses.
+    #[test]
+    fn test_evaluate_and_print_ids() {
+        let response = Response {
+            status: Status::OK,
+            data: Some(vec![1, 2]),
+            message: None,
+            requestUrl: None,
+            requestMethod: None,
+        };
+        let ids = |data: &Vec<i32>| data.iter().map(|id| id.to_string()).collect();
+
+        assert_eq!(evaluate_and_print_ids(Ok(response), true, ids).unwrap(), ExitCode::SUCCESS);
+
+        let response: Response<Vec<i32>> = Response {
+            status: Status::ERROR,
+            data: None,
+            message: Some(Message::PlainMessage("Not allowed".to_string())),
+            requestUrl: None,
+            requestMethod: None,
+        };
+        assert!(matches!(
+            evaluate_and_print_ids(Ok(response), true, ids),
+            Err(DataverseError::Api(_))
+        ));
+    }
+
+    /// Tests the parsing of output formats and the rendering of data in them.
+    #[test]
+    fn test_output_format() {
+        let data = serde_json::json!({"id": 1, "label": "data.csv"});
+
+        assert_eq!("YAML".parse::<OutputFormat>().unwrap(), OutputFormat::Yaml);
+        assert!("xml".parse::<OutputFormat>().is_err());
+        assert_eq!(OutputFormat::Json.render(&data).unwrap(), r#"{"id":1,"label":"data.csv"}"#);
+        assert_eq!(OutputFormat::Yaml.render(&data).unwrap(), "id: 1\nlabel: data.csv\n");
+        assert!(OutputFormat::Table.render(&data).unwrap().starts_with("field"));
+    }
+
+    /// Tests the mapping of errors to exit codes.
+    #[test]
+    fn test_exit_code() {
+        let io = std::io::Error::new(std::io::ErrorKind::NotFound, "missing");
+
+        assert_eq!(exit_code(&DataverseError::Api(String::new())), ExitCode::from(65));
+        assert_eq!(exit_code(&DataverseError::Request(String::new())), ExitCode::from(69));
+        assert_eq!(exit_code(&DataverseError::Input(String::new())), ExitCode::from(64));
+        assert_eq!(exit_code(&DataverseError::Io(io)), ExitCode::from(74));
+    }
 }
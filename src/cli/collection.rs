@@ -1,14 +1,29 @@
 use std::path::PathBuf;
+use std::process::ExitCode;
 
 use structopt::StructOpt;
-use tokio::runtime::Runtime;
 
 use crate::client::BaseClient;
+use crate::error::DataverseError;
 use crate::native_api::collection::{content, delete};
+use crate::native_api::collection::attribute::{self, CollectionAttribute};
 use crate::native_api::collection::create::{self, CollectionCreateBody};
+use crate::native_api::collection::facets;
+use crate::native_api::collection::guestbooks;
 use crate::native_api::collection::publish;
+use crate::native_api::collection::relocate;
+use crate::native_api::collection::storage;
+use crate::native_api::collection::tree::{self, CollectionTree};
+use crate::native_api::dataset::link::MessageResponse;
+use crate::response::Response;
 
-use super::base::{evaluate_and_print_response, Matcher, parse_file};
+use super::base::{
+    evaluate_and_print_fields, evaluate_and_print_ids, evaluate_and_print_response, Matcher, OutputFormat,
+    output_format, parse_file, print_data,
+};
+use super::fields::FieldSelection;
+use super::i18n::tr;
+use super::summary::format_bytes;
 
 #[derive(StructOpt, Debug)]
 #[structopt(about = "Handle collections of a Dataverse instance")]
@@ -24,18 +39,39 @@ pub enum CollectionSubCommand {
             help = "Path to the JSON/YAML file containing the collection body"
         )]
         body: PathBuf,
+
+        #[structopt(long, help = "Only print the alias of the created collection, e.g. to capture it in a shell variable")]
+        print_id: bool,
     },
 
     #[structopt(about = "Collection content")]
     Content {
         #[structopt(help = "Alias of the collection")]
         alias: String,
+
+        #[structopt(
+            long,
+            help = "Comma-separated dotted paths of the fields to print, e.g. 'id,title'"
+        )]
+        fields: Option<FieldSelection>,
+    },
+
+    #[structopt(about = "Print the tree of sub-collections and datasets below a collection")]
+    Tree {
+        #[structopt(help = "Alias of the collection at the root of the tree")]
+        alias: String,
+
+        #[structopt(long, help = "Number of collections to retrieve simultaneously", default_value = "4")]
+        concurrency: usize,
     },
 
     #[structopt(about = "Publish a collection")]
     Publish {
         #[structopt(help = "Alias of the collection to publish")]
         alias: String,
+
+        #[structopt(long, help = "Only print the alias of the published collection, e.g. to capture it in a shell variable")]
+        print_id: bool,
     },
 
     #[structopt(about = "Delete a collection")]
@@ -43,34 +79,252 @@ pub enum CollectionSubCommand {
         #[structopt(help = "Alias of the collection to delete")]
         alias: String,
     },
+
+    #[structopt(about = "Move a collection, including its contents, into another collection")]
+    Move {
+        #[structopt(help = "Alias of the collection to move")]
+        alias: String,
+
+        #[structopt(help = "Alias of the collection to move it into")]
+        target: String,
+
+        #[structopt(
+            long,
+            short,
+            help = "Move even if metadata blocks, facets or guestbooks inherited from the current parent are unlinked"
+        )]
+        force: bool,
+    },
+
+    #[structopt(about = "Change a single attribute of a collection")]
+    Attribute {
+        #[structopt(help = "Alias of the collection to update")]
+        alias: String,
+
+        #[structopt(
+            long,
+            short,
+            help = "Attribute to change (alias, name, description, affiliation, filePIDsEnabled)"
+        )]
+        attribute: CollectionAttribute,
+
+        #[structopt(long, help = "New value of the attribute")]
+        value: String,
+    },
+
+    #[structopt(about = "Retrieve or set the search facets of one or more collections")]
+    Facets {
+        #[structopt(help = "Aliases of the collections", required = true)]
+        aliases: Vec<String>,
+
+        #[structopt(
+            long,
+            help = "Comma-separated dataset fields to set as facets, e.g. 'authorName,subject'",
+            use_delimiter = true
+        )]
+        set: Vec<String>,
+
+        #[structopt(
+            long,
+            help = "Path to a JSON/YAML file containing the list of facets to set",
+            conflicts_with = "set"
+        )]
+        body: Option<PathBuf>,
+    },
+
+    #[structopt(about = "Show the storage size and quota of a collection, or set or delete its quota")]
+    Storage {
+        #[structopt(help = "Alias of the collection")]
+        alias: String,
+
+        #[structopt(long, help = "Quota to set in bytes (requires a superuser token)", conflicts_with = "delete-quota")]
+        set_quota: Option<u64>,
+
+        #[structopt(long, help = "Delete the quota (requires a superuser token)")]
+        delete_quota: bool,
+
+        #[structopt(long, help = "Include cached derivatives of files, such as thumbnails, in the size")]
+        include_cached: bool,
+    },
+
+    #[structopt(about = "List the guestbooks of a collection")]
+    Guestbooks {
+        #[structopt(help = "Alias of the collection")]
+        alias: String,
+    },
+
+    #[structopt(about = "Download the guestbook responses of a collection as CSV")]
+    GuestbookResponses {
+        #[structopt(help = "Alias of the collection")]
+        alias: String,
+
+        #[structopt(long, short, help = "ID of the guestbook to limit the responses to")]
+        guestbook: Option<i64>,
+
+        #[structopt(
+            long,
+            short,
+            help = "Path of the file or directory to download to",
+            default_value = "."
+        )]
+        out: PathBuf,
+    },
 }
 
 impl Matcher for CollectionSubCommand {
-    fn process(&self, client: &BaseClient) {
-        let runtime = Runtime::new().unwrap();
+    async fn process(&self, client: &BaseClient) -> Result<ExitCode, DataverseError> {
         match self {
-            CollectionSubCommand::Content { alias } => {
+            CollectionSubCommand::Content { alias, fields } => {
                 let response =
-                    runtime.block_on(content::get_content(client, alias));
-                evaluate_and_print_response(response);
+                    content::get_content(client, alias).await;
+                evaluate_and_print_fields(response, fields)
             }
-            CollectionSubCommand::Create { parent, body } => {
-                let body: CollectionCreateBody =
-                    parse_file::<_, CollectionCreateBody>(body).expect("Failed to parse the file");
+            CollectionSubCommand::Tree { alias, concurrency } => {
+                let tree = tree::get_collection_tree(client, alias, *concurrency)
+                    .await
+                    .map_err(DataverseError::Request)?;
+
+                if output_format() != OutputFormat::Pretty {
+                    return print_data(tree);
+                }
+
+                let mut lines = vec![];
+                render_tree(&tree, "", "", &mut lines);
+                println!("{}", lines.join("\n"));
+                Ok(ExitCode::SUCCESS)
+            }
+            CollectionSubCommand::Create { parent, body, print_id } => {
+                let body: CollectionCreateBody = parse_file::<_, CollectionCreateBody>(body)?;
                 let response =
-                    runtime.block_on(create::create_collection(client, parent.as_str(), body));
-                evaluate_and_print_response(response);
+                    create::create_collection(client, parent.as_str(), body).await;
+                evaluate_and_print_ids(response, *print_id, |data| data.alias.iter().cloned().collect())
             }
-            CollectionSubCommand::Publish { alias } => {
+            CollectionSubCommand::Publish { alias, print_id } => {
                 let response =
-                    runtime.block_on(publish::publish_collection(client, alias.as_str()));
-                evaluate_and_print_response(response);
+                    publish::publish_collection(client, alias.as_str()).await;
+                evaluate_and_print_ids(response, *print_id, |data| data.alias.iter().cloned().collect())
             }
             CollectionSubCommand::Delete { alias } => {
                 let response =
-                    runtime.block_on(delete::delete_collection(client, alias));
-                evaluate_and_print_response(response);
+                    delete::delete_collection(client, alias).await;
+                evaluate_and_print_response(response)
+            }
+            CollectionSubCommand::Move { alias, target, force } => {
+                let response = relocate::move_collection(client, alias, target, *force).await;
+                evaluate_and_print_response(response)
             }
+            CollectionSubCommand::Attribute { alias, attribute, value } => {
+                let response = attribute::set_collection_attribute(
+                    client, alias, *attribute, value,
+                ).await;
+                evaluate_and_print_response(response)
+            }
+            CollectionSubCommand::Facets { aliases, set, body } => {
+                let facets = match body {
+                    Some(body) => parse_file::<_, Vec<String>>(body)?,
+                    None => set.clone(),
+                };
+
+                // Apply the same facets to every collection, stopping at the first failure
+                let mut code = ExitCode::SUCCESS;
+                for alias in aliases {
+                    code = match facets.is_empty() {
+                        true => evaluate_and_print_response(facets::get_facets(client, alias).await)?,
+                        false => evaluate_and_print_response(
+                            facets::set_facets(client, alias, &facets).await,
+                        )?,
+                    };
+                }
+
+                Ok(code)
+            }
+            CollectionSubCommand::Storage { alias, set_quota, delete_quota, include_cached } => {
+                if let Some(bytes) = set_quota {
+                    return evaluate_and_print_response(storage::set_storage_quota(client, alias, *bytes).await);
+                }
+                if *delete_quota {
+                    return evaluate_and_print_response(storage::delete_storage_quota(client, alias).await);
+                }
+
+                let (size, quota) = futures::join!(
+                    storage::get_storage_size(client, alias, *include_cached),
+                    storage::get_storage_quota(client, alias),
+                );
+                let size = message_bytes(size)?;
+                let quota = message_bytes(quota)?;
+
+                if output_format() != OutputFormat::Pretty {
+                    return print_data(serde_json::json!({"size": size, "quota": quota}));
+                }
+
+                let size = size.unwrap_or_default();
+                println!("{}", tr("storage-size", &[("size", &format_bytes(size)), ("bytes", &size)]));
+                match quota {
+                    Some(quota) => println!("{}", tr("storage-quota", &[("quota", &format_bytes(quota))])),
+                    None => println!("{}", tr("storage-no-quota", &[])),
+                }
+                Ok(ExitCode::SUCCESS)
+            }
+            CollectionSubCommand::Guestbooks { alias } => {
+                let response = guestbooks::list_guestbooks(client, alias).await;
+                evaluate_and_print_response(response)
+            }
+            CollectionSubCommand::GuestbookResponses { alias, guestbook, out } => {
+                let path = guestbooks::download_guestbook_responses(
+                    client, alias, *guestbook, out.clone(), None,
+                )
+                    .await
+                    .map_err(DataverseError::Request)?;
+
+                println!("{}", tr("report-written", &[("path", &path.display())]));
+                Ok(ExitCode::SUCCESS)
+            }
+        }
+    }
+}
+
+// Renders a collection and, indented below it, its sub-collections, one line per collection
+fn render_tree(tree: &CollectionTree, lead: &str, indent: &str, lines: &mut Vec<String>) {
+    let datasets = tree.datasets.len();
+    let published = tree.datasets.iter().filter(|dataset| dataset.is_published()).count();
+    let state = match tree.released {
+        Some(true) => tr("state-published", &[]),
+        _ => tr("state-unpublished", &[]),
+    };
+
+    lines.push(format!(
+        "{}{}",
+        lead,
+        tr(
+            "tree-collection",
+            &[
+                ("title", &tree.title.as_deref().unwrap_or(&tree.identifier)),
+                ("alias", &tree.alias.as_deref().unwrap_or(&tree.identifier)),
+                ("state", &state),
+                ("datasets", &datasets),
+                ("published", &published),
+            ],
+        )
+    ));
+
+    for (index, child) in tree.collections.iter().enumerate() {
+        let (branch, continuation) = match index + 1 == tree.collections.len() {
+            true => ("└── ", "    "),
+            false => ("├── ", "│   "),
         };
+        render_tree(child, &format!("{}{}", indent, branch), &format!("{}{}", indent, continuation), lines);
     }
 }
+
+// Reads the number of bytes from the message of a storage endpoint
+fn message_bytes(response: Result<Response<MessageResponse>, String>) -> Result<Option<u64>, DataverseError> {
+    let response = response.map_err(DataverseError::Request)?;
+    if response.status.is_err() {
+        return Err(DataverseError::Api(response.message.map(|m| m.to_string()).unwrap_or_default()));
+    }
+
+    Ok(response
+        .data
+        .and_then(|data| data.message)
+        .and_then(|message| storage::bytes_in_message(&message)))
+}
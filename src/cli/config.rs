@@ -0,0 +1,312 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::Write;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::error::DataverseError;
+
+use super::i18n::tr;
+
+// Tokens are stored in the keyring of the operating system, with the URL of the
+// instance as user name. Where no keyring is available, e.g. on headless
+// servers, they are written to the configuration file, which is then only
+// readable by its owner.
+const KEYRING_SERVICE: &str = "dvcli";
+
+/// Settings of the CLI that persist between invocations, stored as TOML.
+///
+/// The file is located in the configuration directory of the user, e.g. `~/.config/dvcli/config.toml`, unless
+/// the `DVCLI_CONFIG` environment variable points elsewhere.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct Config {
+    /// The instance to connect to when `DVCLI_URL` is not set.
+    pub url: Option<String>,
+    /// Tokens by instance URL that could not be stored in the keyring.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub tokens: BTreeMap<String, String>,
+    /// Further instances by name, selected with `--profile`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    pub profiles: BTreeMap<String, Profile>,
+}
+
+/// A named instance, whose token is stored by its URL like the one of the default instance.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Profile {
+    pub url: String,
+}
+
+/// Connection settings given on the command line, which take precedence over the environment.
+#[derive(Debug, Clone, Default)]
+pub struct ConnectionOverrides {
+    pub url: Option<String>,
+    pub token: Option<String>,
+    pub profile: Option<String>,
+}
+
+/// Where a token has been stored.
+#[derive(Debug, Clone, PartialEq)]
+pub enum TokenStore {
+    Keyring,
+    ConfigFile,
+}
+
+impl Config {
+    /// Returns the path of the configuration file, if a configuration directory can be determined.
+    pub fn path() -> Option<PathBuf> {
+        match std::env::var_os("DVCLI_CONFIG") {
+            Some(path) => Some(PathBuf::from(path)),
+            None => dirs::config_dir().map(|dir| dir.join("dvcli").join("config.toml")),
+        }
+    }
+
+    /// Loads the configuration, which is empty if the file does not exist yet.
+    pub fn load() -> Result<Self, DataverseError> {
+        match Config::path() {
+            Some(path) if path.exists() => {
+                let content = fs::read_to_string(&path)?;
+                toml::from_str(&content).map_err(|e| {
+                    DataverseError::Input(format!("Failed to read {}: {}", path.display(), e))
+                })
+            }
+            _ => Ok(Config::default()),
+        }
+    }
+
+    /// Writes the configuration, creating its directory if necessary.
+    pub fn save(&self) -> Result<PathBuf, DataverseError> {
+        let path = Config::path()
+            .ok_or_else(|| DataverseError::Input("No configuration directory found".to_string()))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+
+        let content = toml::to_string(self).map_err(|e| DataverseError::Input(e.to_string()))?;
+
+        // The file may hold tokens, so it is only readable by the user from the start. Files
+        // created by earlier versions are restricted before their content is replaced.
+        let mut options = fs::OpenOptions::new();
+        options.write(true).create(true).truncate(true);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::{OpenOptionsExt, PermissionsExt};
+            options.mode(0o600);
+            if path.exists() {
+                fs::set_permissions(&path, fs::Permissions::from_mode(0o600))?;
+            }
+        }
+
+        let mut file = options.open(&path)?;
+        file.write_all(content.as_bytes())?;
+
+        Ok(path)
+    }
+
+    /// Returns the URL of a profile, or of the default instance if no profile is given.
+    pub fn profile_url(&self, profile: Option<&str>) -> Result<Option<String>, DataverseError> {
+        match profile {
+            Some(name) => match self.profiles.get(name) {
+                Some(profile) => Ok(Some(profile.url.clone())),
+                None => Err(DataverseError::Input(tr("unknown-profile", &[("profile", &name)]))),
+            },
+            None => Ok(self.url.clone()),
+        }
+    }
+
+    /// Resolves the URL and token to connect with.
+    ///
+    /// Settings given on the command line take precedence over the `DVCLI_URL` and `DVCLI_TOKEN` environment
+    /// variables, which in turn take precedence over the configuration. A token from the environment is not
+    /// sent to an instance selected on the command line unless it is the one of `DVCLI_URL`, such that a
+    /// one-off call against another instance does not leak it. Without a token given otherwise, the token
+    /// stored for the resolved URL is used.
+    pub fn resolve(
+        &self,
+        overrides: &ConnectionOverrides,
+        env_url: Option<String>,
+        env_token: Option<String>,
+    ) -> Result<(String, Option<String>), DataverseError> {
+        let selected = match (&overrides.url, &overrides.profile) {
+            (Some(url), _) => Some(url.clone()),
+            (None, Some(profile)) => self.profile_url(Some(profile))?,
+            (None, None) => None,
+        };
+
+        let url = selected
+            .clone()
+            .or(env_url.clone())
+            .or(self.url.clone())
+            .ok_or_else(|| DataverseError::Input(tr("missing-base-url", &[])))?;
+
+        let env_token = match (&selected, &env_url) {
+            (Some(url), Some(env_url)) if normalize_url(url) == normalize_url(env_url) => env_token,
+            (Some(_), _) => None,
+            (None, _) => env_token,
+        };
+
+        let token = overrides
+            .token
+            .clone()
+            .or(env_token)
+            .or_else(|| self.load_token(&url).map(|(token, _)| token));
+
+        Ok((url, token))
+    }
+
+    /// Stores the token of an instance in the keyring, or in the configuration if no keyring is available.
+    ///
+    /// The configuration has to be saved afterwards for the latter to take effect.
+    pub fn store_token(&mut self, url: &str, token: &str) -> TokenStore {
+        let url = normalize_url(url);
+        let stored = keyring::Entry::new(KEYRING_SERVICE, &url).and_then(|entry| entry.set_password(token));
+
+        match stored {
+            Ok(()) => {
+                self.tokens.remove(&url);
+                TokenStore::Keyring
+            }
+            Err(_) => {
+                self.tokens.insert(url, token.to_string());
+                TokenStore::ConfigFile
+            }
+        }
+    }
+
+    /// Returns the stored token of an instance along with where it has been found.
+    pub fn load_token(&self, url: &str) -> Option<(String, TokenStore)> {
+        let url = normalize_url(url);
+        if let Some(token) = self.tokens.get(&url) {
+            return Some((token.clone(), TokenStore::ConfigFile));
+        }
+
+        keyring::Entry::new(KEYRING_SERVICE, &url)
+            .and_then(|entry| entry.get_password())
+            .ok()
+            .map(|token| (token, TokenStore::Keyring))
+    }
+
+    /// Removes the token of an instance from the keyring and the configuration.
+    pub fn delete_token(&mut self, url: &str) {
+        let url = normalize_url(url);
+        if let Ok(entry) = keyring::Entry::new(KEYRING_SERVICE, &url) {
+            let _ = entry.delete_credential();
+        }
+        self.tokens.remove(&url);
+    }
+}
+
+/// Removes trailing slashes, such that `https://demo.dataverse.org/` and `https://demo.dataverse.org` share
+/// their token.
+pub fn normalize_url(url: &str) -> String {
+    url.trim().trim_end_matches('/').to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{normalize_url, Config, ConnectionOverrides, Profile};
+
+    /// Tests that the configuration survives a TOML round trip and omits empty token tables.
+    #[test]
+    fn test_config_round_trip() {
+        let mut config = Config {
+            url: Some("https://demo.dataverse.org".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(toml::to_string(&config).unwrap(), "url = \"https://demo.dataverse.org\"\n");
+
+        config.tokens.insert("https://demo.dataverse.org".to_string(), "secret".to_string());
+        let parsed: Config = toml::from_str(&toml::to_string(&config).unwrap()).unwrap();
+
+        assert_eq!(parsed, config);
+        assert!(toml::from_str::<Config>("").unwrap().url.is_none());
+    }
+
+    // A configuration with a default instance and a profile, whose tokens are
+    // stored in the file to keep the keyring out of the tests
+    fn example_config() -> Config {
+        let mut config = Config {
+            url: Some("https://default.org".to_string()),
+            ..Default::default()
+        };
+        config.profiles.insert("test".to_string(), Profile { url: "https://test.org".to_string() });
+        config.tokens.insert("https://default.org".to_string(), "default-token".to_string());
+        config.tokens.insert("https://test.org".to_string(), "test-token".to_string());
+        config
+    }
+
+    /// Tests that flags take precedence over the environment, which takes precedence over the configuration.
+    #[test]
+    fn test_resolve_precedence() {
+        let config = example_config();
+        let env = || (Some("https://env.org".to_string()), Some("env-token".to_string()));
+
+        let none = ConnectionOverrides::default();
+        assert_eq!(
+            config.resolve(&none, None, None).unwrap(),
+            ("https://default.org".to_string(), Some("default-token".to_string()))
+        );
+
+        let (url, token) = env();
+        assert_eq!(
+            config.resolve(&none, url, token).unwrap(),
+            ("https://env.org".to_string(), Some("env-token".to_string()))
+        );
+
+        let profile = ConnectionOverrides { profile: Some("test".to_string()), ..Default::default() };
+        let (url, token) = env();
+        assert_eq!(
+            config.resolve(&profile, url, token).unwrap(),
+            ("https://test.org".to_string(), Some("test-token".to_string()))
+        );
+
+        let flags = ConnectionOverrides {
+            url: Some("https://env.org/".to_string()),
+            token: None,
+            profile: Some("test".to_string()),
+        };
+        let (url, token) = env();
+        assert_eq!(
+            config.resolve(&flags, url, token).unwrap(),
+            ("https://env.org/".to_string(), Some("env-token".to_string()))
+        );
+    }
+
+    /// Tests that tokens of the environment are not sent to other instances and unknown profiles are rejected.
+    #[test]
+    fn test_resolve_isolation() {
+        let config = example_config();
+        let flags = ConnectionOverrides {
+            url: Some("https://other.org".to_string()),
+            ..Default::default()
+        };
+
+        let (url, token) = config
+            .resolve(&flags, Some("https://env.org".to_string()), Some("env-token".to_string()))
+            .unwrap();
+        assert_eq!(url, "https://other.org");
+        assert_ne!(token.as_deref(), Some("env-token"));
+
+        let flags = ConnectionOverrides {
+            url: Some("https://other.org".to_string()),
+            token: Some("flag-token".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(config.resolve(&flags, None, None).unwrap().1.as_deref(), Some("flag-token"));
+
+        let unknown = ConnectionOverrides { profile: Some("missing".to_string()), ..Default::default() };
+        assert!(config.resolve(&unknown, None, None).is_err());
+        assert!(Config::default().resolve(&ConnectionOverrides::default(), None, None).is_err());
+    }
+
+    /// Tests that tokens stored in the configuration are found regardless of trailing slashes.
+    #[test]
+    fn test_load_token_from_config() {
+        let mut config = Config::default();
+        config.tokens.insert("https://demo.dataverse.org".to_string(), "secret".to_string());
+
+        let (token, _) = config.load_token("https://demo.dataverse.org/").unwrap();
+        assert_eq!(token, "secret");
+        assert_eq!(normalize_url(" https://demo.dataverse.org// "), "https://demo.dataverse.org");
+    }
+}
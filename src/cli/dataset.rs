@@ -1,19 +1,57 @@
 use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
 
+use colored::Colorize;
 use structopt::StructOpt;
 
+use crate::access;
 use crate::client::BaseClient;
+use crate::crosswalk;
+use crate::error::DataverseError;
+use crate::export::ExporterRegistry;
 use crate::identifier::Identifier;
-use crate::native_api::dataset::create::{self, DatasetCreateBody};
+use crate::native_api::dataset::attribute::{self, DatasetAttribute};
+use crate::native_api::dataset::batch::{self, CsvMapping};
+use crate::native_api::dataset::citation::{self, CitationFormat, CitationOptions};
+use crate::native_api::dataset::create::{self, DatasetCreateBody, IdempotencyKey};
 use crate::native_api::dataset::delete;
+use crate::native_api::dataset::describe;
+use crate::native_api::dataset::diff::{self, ChangeKind};
+use crate::native_api::dataset::directory::{self, DirectoryUploadOptions};
 use crate::native_api::dataset::edit;
 use crate::native_api::dataset::edit::EditMetadataBody;
-use crate::native_api::dataset::get;
+use crate::native_api::dataset::export;
+use crate::native_api::dataset::files::{self, AccessStatus, FileSearch};
+use crate::native_api::dataset::get::{self, GetDatasetOptions};
+use crate::native_api::dataset::ingest::{self, IngestWait};
 use crate::native_api::dataset::link;
+use crate::native_api::dataset::metadata::DatasetMetadataBuilder;
+use crate::native_api::dataset::pid;
+#[cfg(feature = "directupload")]
+use crate::native_api::dataset::remote::{self, RemoteFile};
+use crate::native_api::dataset::preview_url;
 use crate::native_api::dataset::publish::{self, Version};
+use crate::native_api::dataset::reorganize::{self, PathRule};
+use crate::native_api::dataset::review::{self, ReturnToAuthorBody};
+use crate::native_api::dataset::sync::{self, SyncOptions};
+use crate::native_api::dataset::thumbnail;
 use crate::native_api::dataset::upload::{self, UploadBody};
+use crate::native_api::dataset::validate;
+use crate::native_api::file::embargo::{self, EmbargoBody, UnembargoBody};
+use crate::native_api::users::me;
+use crate::plan::{Action, Plan};
+use crate::verify;
 
-use super::base::{evaluate_and_print_response, Matcher, parse_file};
+use super::banner::warn_about_banners;
+use super::base::{
+    evaluate_and_print_fields, evaluate_and_print_ids, evaluate_and_print_response, Matcher, OutputFormat,
+    output_format, parse_file, print_data, write_output,
+};
+use super::fields::FieldSelection;
+use super::i18n::{print_error, tr};
+use super::plan::execute_plan;
+use super::summary::{format_bytes, Session};
 
 #[derive(StructOpt, Debug)]
 #[structopt(about = "Handle datasets of the Dataverse instance")]
@@ -22,6 +60,120 @@ pub enum DatasetSubCommand {
     Get {
         #[structopt(help = "(Peristent) identifier of the dataset to retrieve")]
         id: Identifier,
+
+        #[structopt(long, help = "Version to retrieve, e.g. ':draft', ':latest-published' or '1.0'")]
+        version: Option<String>,
+
+        #[structopt(long, help = "Omit the file metadata")]
+        exclude_files: bool,
+
+        #[structopt(long, help = "Include deaccessioned versions")]
+        include_deaccessioned: bool,
+
+        #[structopt(long, help = "Include the collections the dataset belongs to")]
+        return_owners: bool,
+
+        #[structopt(
+            long,
+            help = "Comma-separated dotted paths of the fields to print, e.g. 'persistentId,latestVersion.versionState'"
+        )]
+        fields: Option<FieldSelection>,
+    },
+
+    #[structopt(about = "Summarise the state, versions, files and locks of a dataset")]
+    Describe {
+        #[structopt(help = "(Peristent) identifier of the dataset to describe")]
+        id: Identifier,
+    },
+
+    #[structopt(about = "Compare two versions of a dataset field by field and file by file")]
+    Diff {
+        #[structopt(help = "(Persistent) identifier of the dataset to compare")]
+        id: Identifier,
+
+        #[structopt(long, help = "Compare the draft with the latest published version")]
+        draft: bool,
+
+        #[structopt(
+            long,
+            help = "Older version to compare, e.g. '1.0'",
+            required_unless = "draft",
+            conflicts_with = "draft"
+        )]
+        from: Option<String>,
+
+        #[structopt(
+            long,
+            help = "Newer version to compare, e.g. ':latest'",
+            required_unless = "draft",
+            conflicts_with = "draft"
+        )]
+        to: Option<String>,
+    },
+
+    #[structopt(about = "Print the citation of a dataset version")]
+    Cite {
+        #[structopt(help = "(Peristent) identifier of the dataset to cite")]
+        id: Identifier,
+
+        #[structopt(
+            long,
+            help = "Version to cite, e.g. ':draft', ':latest-published' or '1.0'",
+            default_value = ":latest"
+        )]
+        version: String,
+
+        #[structopt(long, help = "Export the citation for reference managers: bibtex, ris or endnote")]
+        format: Option<CitationFormat>,
+
+        #[structopt(long, help = "Cite anonymously, using the token of an anonymized preview URL as API token")]
+        anonymized: bool,
+
+        #[structopt(long, help = "Include deaccessioned versions")]
+        include_deaccessioned: bool,
+    },
+
+    #[structopt(about = "List the files of a dataset, optionally narrowed down by the server")]
+    Files {
+        #[structopt(help = "(Peristent) identifier of the dataset whose files to list")]
+        id: Identifier,
+
+        #[structopt(
+            long,
+            help = "Version whose files to list, e.g. ':draft', ':latest-published' or '1.0'",
+            default_value = ":latest"
+        )]
+        version: String,
+
+        #[structopt(long, help = "Text to search in the names, directories and descriptions of the files")]
+        search: Option<String>,
+
+        #[structopt(long, help = "Category the files are tagged with, e.g. 'Data'")]
+        category: Option<String>,
+
+        #[structopt(long, help = "Content type of the files, e.g. 'text/csv'")]
+        content_type: Option<String>,
+
+        #[structopt(
+            long,
+            help = "Access status of the files: public, restricted, embargoed-then-restricted or embargoed-then-public"
+        )]
+        access_status: Option<AccessStatus>,
+
+        #[structopt(long, help = "Maximum number of files to list")]
+        limit: Option<u64>,
+
+        #[structopt(long, help = "Number of matching files to skip")]
+        offset: Option<u64>,
+
+        #[structopt(long, help = "Include deaccessioned versions")]
+        include_deaccessioned: bool,
+
+        #[structopt(
+            long,
+            help = "Comma-separated dotted paths of the fields to print, e.g. 'label,dataFile.id'"
+        )]
+        fields: Option<FieldSelection>,
     },
 
     #[structopt(about = "Create a dataset")]
@@ -35,6 +187,80 @@ pub enum DatasetSubCommand {
             help = "Path to the JSON/YAML file containing the dataset body"
         )]
         body: PathBuf,
+
+        #[structopt(long, help = "Treat the body as a dataverse_json export of an existing dataset")]
+        from_export: bool,
+
+        #[structopt(
+            long,
+            help = "Treat the body as a DataCite JSON or XML record, using your account as contact",
+            conflicts_with = "from-export"
+        )]
+        from_datacite: bool,
+
+        #[structopt(
+            long,
+            help = "Treat the body as a Dublin Core (oai_dc) record, using your account as contact",
+            conflicts_with_all = &["from-export", "from-datacite"]
+        )]
+        from_dublin_core: bool,
+
+        #[structopt(
+            long,
+            help = "Token to recognise a draft created by a previous attempt instead of creating a duplicate"
+        )]
+        idempotency_key: Option<String>,
+
+        #[structopt(
+            long,
+            help = "Reuse an existing draft with the same title instead of creating a duplicate",
+            conflicts_with = "idempotency-key"
+        )]
+        by_title: bool,
+
+        #[structopt(
+            long,
+            help = "Validate the body against the metadata blocks of the collection and only create the dataset if it is valid"
+        )]
+        validate: bool,
+
+        #[structopt(long, help = "Only print the persistent identifier of the created dataset, e.g. to capture it in a shell variable")]
+        print_id: bool,
+    },
+
+    #[structopt(about = "Check a dataset body for mistakes without creating anything")]
+    Validate {
+        #[structopt(help = "Path to the JSON/YAML file containing the dataset body")]
+        body: PathBuf,
+
+        #[structopt(
+            long,
+            short,
+            help = "Alias of a collection to also validate against its metadata blocks, e.g. required fields and vocabularies"
+        )]
+        collection: Option<String>,
+    },
+
+    #[structopt(about = "Create a dataset per row of a CSV file")]
+    CreateBatch {
+        #[structopt(long, short, help = "Alias of the collection to create the datasets in")]
+        collection: String,
+
+        #[structopt(long, help = "Path to the CSV file with a header row and a dataset per row")]
+        csv: PathBuf,
+
+        #[structopt(
+            long,
+            short,
+            help = "Path to the JSON/YAML file mapping citation fields to CSV columns, e.g. 'title: Titel'"
+        )]
+        mapping: Option<PathBuf>,
+
+        #[structopt(long, help = "Publish each dataset after its creation")]
+        publish: bool,
+
+        #[structopt(long, help = "Only print the persistent identifiers of the created datasets")]
+        print_id: bool,
     },
 
     #[structopt(about = "Publishes a dataset")]
@@ -49,6 +275,9 @@ pub enum DatasetSubCommand {
             default_value = "major"
         )]
         version: Version,
+
+        #[structopt(long, help = "Only print the persistent identifier of the published dataset, e.g. to capture it in a shell variable")]
+        print_id: bool,
     },
 
     #[structopt(about = "Deletes a dataset")]
@@ -57,6 +286,24 @@ pub enum DatasetSubCommand {
         id: i64,
     },
 
+    #[structopt(about = "Destroys a dataset, including published ones (superuser only)")]
+    Destroy {
+        #[structopt(help = "(Persistent) identifier of the dataset to destroy")]
+        id: Identifier,
+
+        #[structopt(long, help = "Confirm that the dataset and all its files shall be destroyed")]
+        confirm: bool,
+    },
+
+    #[structopt(about = "Reserve the persistent identifier of a draft dataset ahead of publication")]
+    ReservePid {
+        #[structopt(help = "(Persistent) identifier of the dataset to reserve the identifier of")]
+        id: Identifier,
+
+        #[structopt(long, help = "Delete the reservation instead (superuser only)")]
+        delete: bool,
+    },
+
     #[structopt(about = "Edit the metadata of a dataset")]
     Edit {
         #[structopt(long, short, help = "Perisistent identifier of the dataset to edit")]
@@ -73,6 +320,30 @@ pub enum DatasetSubCommand {
         replace: bool,
     },
 
+    #[structopt(about = "Set a single primitive metadata field of a dataset")]
+    SetField {
+        #[structopt(help = "Persistent identifier of the dataset to edit")]
+        pid: String,
+
+        #[structopt(long, short, help = "Type name of the field to set (e.g. title)")]
+        field: String,
+
+        #[structopt(long, help = "New value of the field")]
+        value: String,
+    },
+
+    #[structopt(about = "Change a single attribute of a dataset outside of its metadata blocks")]
+    Attribute {
+        #[structopt(help = "(Persistent) identifier of the dataset to update")]
+        id: Identifier,
+
+        #[structopt(long, short, help = "Attribute to change (citationDate, license, storageDriver)")]
+        attribute: DatasetAttribute,
+
+        #[structopt(long, help = "New value of the attribute")]
+        value: String,
+    },
+
     #[structopt(about = "Link a dataset to another collection")]
     Link {
         #[structopt(long, short, help = "(Persistent) identifier of the dataset to link")]
@@ -82,7 +353,7 @@ pub enum DatasetSubCommand {
         collection: String,
     },
 
-    #[structopt(about = "Upload a file to a dataset")]
+    #[structopt(about = "Upload one or more files to a dataset")]
     Upload {
         #[structopt(
             long,
@@ -91,66 +362,1270 @@ pub enum DatasetSubCommand {
         )]
         id: Identifier,
 
-        #[structopt(help = "Path to the file to upload")]
-        path: PathBuf,
+        #[structopt(help = "Paths to the files to upload", required = true)]
+        paths: Vec<PathBuf>,
 
         #[structopt(long, help = "Path to the JSON/YAML file containing the file body")]
         body: Option<PathBuf>,
+
+        #[structopt(long, help = "Store tabular files as is, without ingesting them")]
+        no_ingest: bool,
+
+        #[structopt(long, help = "Number of files to upload simultaneously", default_value = "1")]
+        concurrency: usize,
+
+        #[structopt(long, help = "Stop uploading once a file has failed")]
+        stop_on_error: bool,
+
+        #[structopt(long, help = "Verify size and checksum of the uploaded files against the dataset")]
+        verify: bool,
+
+        #[structopt(long, help = "Path to write the verification report to instead of stdout")]
+        report: Option<PathBuf>,
+
+        #[structopt(long, help = "Only print the IDs of the uploaded files, one per line")]
+        print_id: bool,
+
+        #[structopt(long, help = "Path to write a JSON summary of the uploads to")]
+        summary: Option<PathBuf>,
+    },
+
+    #[cfg(feature = "directupload")]
+    #[structopt(about = "Add files in a remote store to a dataset by reference, without uploading them")]
+    AddRemote {
+        #[structopt(help = "(Persistent) identifier of the dataset to add the files to")]
+        id: Identifier,
+
+        #[structopt(long, short, help = "ID of the remote store, as configured on the instance")]
+        store: String,
+
+        #[structopt(
+            long,
+            short,
+            help = "Path to the JSON/YAML file listing the files by path, checksum type and checksum"
+        )]
+        files: PathBuf,
+
+        #[structopt(long, help = "Maximum number of files registered per request", default_value = "500")]
+        batch_size: usize,
+    },
+
+    #[structopt(about = "Wait until the tabular files of a dataset have been ingested")]
+    WaitIngest {
+        #[structopt(help = "(Persistent) identifier of the dataset to wait for")]
+        id: Identifier,
+
+        #[structopt(long, help = "Seconds to wait before giving up", default_value = "600")]
+        timeout: u64,
+
+        #[structopt(long, help = "Seconds between two checks of the dataset locks", default_value = "2")]
+        interval: u64,
+    },
+
+    #[structopt(about = "Upload the files of a directory tree, preserving the folder structure")]
+    UploadDir {
+        #[structopt(
+            long,
+            short,
+            help = "(Persistent) identifier of the dataset to upload the files to"
+        )]
+        id: Identifier,
+
+        #[structopt(help = "Directory to upload the contents of")]
+        root: PathBuf,
+
+        #[structopt(
+            long,
+            help = "Glob pattern of the relative paths to upload, e.g. 'data/**/*.csv' (repeatable)",
+            number_of_values = 1
+        )]
+        include: Vec<String>,
+
+        #[structopt(
+            long,
+            help = "Glob pattern of the relative paths to skip, e.g. '**/*.tmp' (repeatable)",
+            number_of_values = 1
+        )]
+        exclude: Vec<String>,
+
+        #[structopt(long, help = "Upload hidden files and directories, such as '.git'")]
+        hidden: bool,
+
+        #[structopt(long, help = "Path to the JSON/YAML file containing the body applied to every file")]
+        body: Option<PathBuf>,
+
+        #[structopt(long, help = "Number of files to upload simultaneously", default_value = "1")]
+        concurrency: usize,
+
+        #[structopt(long, help = "Stop uploading once a file has failed")]
+        stop_on_error: bool,
+
+        #[structopt(long, help = "Write a plan of the uploads to this path instead of uploading")]
+        plan: Option<PathBuf>,
+
+        #[structopt(long, help = "Verify size and checksum of the uploaded files against the dataset")]
+        verify: bool,
+
+        #[structopt(long, help = "Path to write the verification report to instead of stdout")]
+        report: Option<PathBuf>,
+
+        #[structopt(long, help = "Only print the IDs of the uploaded files, one per line")]
+        print_id: bool,
+
+        #[structopt(long, help = "Path to write a JSON summary of the uploads to")]
+        summary: Option<PathBuf>,
+    },
+
+    #[structopt(about = "Create, retrieve or delete the preview URL of a draft dataset")]
+    PreviewUrl {
+        #[structopt(help = "(Persistent) identifier of the dataset")]
+        id: Identifier,
+
+        #[structopt(long, help = "Create a new preview URL", conflicts_with = "delete")]
+        create: bool,
+
+        #[structopt(long, help = "Delete the existing preview URL")]
+        delete: bool,
+
+        #[structopt(long, help = "Withhold identifying metadata from reviewers", requires = "create")]
+        anonymized: bool,
+    },
+
+    #[structopt(about = "Submit a draft dataset for review by the curators")]
+    SubmitForReview {
+        #[structopt(help = "(Persistent) identifier of the dataset")]
+        id: Identifier,
+    },
+
+    #[structopt(about = "Return a dataset in review to its authors")]
+    ReturnToAuthor {
+        #[structopt(help = "(Persistent) identifier of the dataset")]
+        id: Identifier,
+
+        #[structopt(long, short, help = "Reason for the return, which is sent to the authors")]
+        reason: String,
+    },
+
+    #[structopt(about = "Set or remove an embargo on files of a dataset")]
+    Embargo {
+        #[structopt(help = "(Persistent) identifier of the dataset containing the files")]
+        id: Identifier,
+
+        #[structopt(
+            long,
+            short,
+            help = "Comma-separated IDs of the files to (un)embargo",
+            use_delimiter = true,
+            required = true
+        )]
+        files: Vec<i64>,
+
+        #[structopt(
+            long,
+            short,
+            help = "Date (YYYY-MM-DD) at which the files become available",
+            required_unless = "remove"
+        )]
+        date: Option<String>,
+
+        #[structopt(long, help = "Reason for the embargo")]
+        reason: Option<String>,
+
+        #[structopt(long, help = "Remove the embargo instead of setting it")]
+        remove: bool,
+    },
+
+    #[structopt(about = "Download the files of a dataset")]
+    Download {
+        #[structopt(help = "(Persistent) identifier of the dataset to download")]
+        id: Identifier,
+
+        #[structopt(long, help = "Download the files as ZIP archives")]
+        zip: bool,
+
+        #[structopt(
+            long,
+            help = "Version to download, e.g. ':draft', ':latest-published' or '1.0' [default: :latest]",
+            conflicts_with = "zip"
+        )]
+        version: Option<String>,
+
+        #[structopt(
+            long,
+            help = "Number of files to download simultaneously [default: 4]",
+            conflicts_with = "zip"
+        )]
+        concurrency: Option<usize>,
+
+        #[structopt(
+            long,
+            short,
+            help = "Comma-separated IDs of the files to download instead of the whole dataset",
+            use_delimiter = true
+        )]
+        files: Vec<i64>,
+
+        #[structopt(
+            long,
+            short,
+            help = "Directory to download to, or the archive path when downloading selected files",
+            default_value = "."
+        )]
+        out: PathBuf,
+
+        #[structopt(long, help = "Path to write a JSON summary of the downloads to")]
+        summary: Option<PathBuf>,
+    },
+
+    #[structopt(about = "Mirror the latest published version of a dataset, downloading only changed files")]
+    Mirror {
+        #[structopt(help = "(Persistent) identifier of the dataset to mirror")]
+        id: Identifier,
+
+        #[structopt(long, short, help = "Directory holding the mirror", default_value = "mirror")]
+        dir: PathBuf,
+
+        #[structopt(long, help = "Path to write a JSON summary of the downloads to")]
+        summary: Option<PathBuf>,
+    },
+
+    #[structopt(about = "Synchronise a local directory with a dataset, uploading new and replacing changed files")]
+    Sync {
+        #[structopt(long, short, help = "(Persistent) identifier of the dataset to synchronise")]
+        id: Identifier,
+
+        #[structopt(help = "Directory to synchronise the dataset with")]
+        root: PathBuf,
+
+        #[structopt(
+            long,
+            help = "Glob pattern of the relative paths to synchronise, e.g. 'data/**/*.csv' (repeatable)",
+            number_of_values = 1
+        )]
+        include: Vec<String>,
+
+        #[structopt(
+            long,
+            help = "Glob pattern of the relative paths to skip, e.g. '**/*.tmp' (repeatable)",
+            number_of_values = 1
+        )]
+        exclude: Vec<String>,
+
+        #[structopt(long, help = "Synchronise hidden files and directories, such as '.git'")]
+        hidden: bool,
+
+        #[structopt(long, help = "Delete files of the dataset that are not present locally")]
+        delete: bool,
+
+        #[structopt(long, help = "Print the planned actions without executing them")]
+        dry_run: bool,
+
+        #[structopt(long, help = "Write the plan to this path, e.g. for review with 'plan apply'")]
+        plan: Option<PathBuf>,
+
+        #[structopt(long, help = "Path to write a JSON summary of the synchronisation to")]
+        summary: Option<PathBuf>,
+    },
+
+    #[structopt(about = "Move the files of a dataset to new directories given by prefix or regular expression rules")]
+    Reorganize {
+        #[structopt(help = "(Persistent) identifier of the dataset to reorganize")]
+        id: Identifier,
+
+        #[structopt(
+            long,
+            help = "Rule moving the files below a directory to another one, e.g. 'raw=data/raw' (repeatable)",
+            number_of_values = 1
+        )]
+        prefix: Vec<PathRule>,
+
+        #[structopt(
+            long,
+            help = "Path to the JSON/YAML file listing rules of 'prefix' and 'to' or 'pattern' and 'replacement'"
+        )]
+        rules: Option<PathBuf>,
+
+        #[structopt(long, help = "Print the planned moves without executing them")]
+        dry_run: bool,
+
+        #[structopt(long, help = "Write the plan to this path, e.g. for review with 'plan apply'")]
+        plan: Option<PathBuf>,
+
+        #[structopt(long, help = "Path to write a JSON summary of the moves to")]
+        summary: Option<PathBuf>,
+    },
+
+    #[structopt(about = "Export the metadata of a published dataset")]
+    Export {
+        #[structopt(help = "Persistent identifier of the dataset to export")]
+        pid: String,
+
+        #[structopt(
+            long,
+            short,
+            help = "Format to export (datacite, dublin-core, ro-crate, croissant) or the name of an exporter of the instance, e.g. 'ddi'"
+        )]
+        format: String,
+
+        #[structopt(long, short, help = "Path to write the export to instead of stdout")]
+        output: Option<PathBuf>,
+    },
+
+    #[structopt(about = "Download, set or remove the thumbnail of a dataset")]
+    Thumbnail {
+        #[structopt(help = "(Persistent) identifier of the dataset")]
+        id: Identifier,
+
+        #[structopt(
+            long,
+            help = "Path to an image to upload as thumbnail",
+            conflicts_with_all = &["file", "remove", "candidates"]
+        )]
+        set: Option<PathBuf>,
+
+        #[structopt(
+            long,
+            help = "ID of an image file of the dataset to use as thumbnail",
+            conflicts_with_all = &["remove", "candidates"]
+        )]
+        file: Option<i64>,
+
+        #[structopt(long, help = "Remove the thumbnail", conflicts_with = "candidates")]
+        remove: bool,
+
+        #[structopt(long, help = "List the image files that can be used as thumbnail")]
+        candidates: bool,
+
+        #[structopt(
+            long,
+            short,
+            help = "Path of the file or directory to download the thumbnail to",
+            default_value = "."
+        )]
+        out: PathBuf,
     },
 }
 
 impl Matcher for DatasetSubCommand {
-    fn process(&self, client: &BaseClient) {
-        let runtime = tokio::runtime::Runtime::new().unwrap();
+    async fn process(&self, client: &BaseClient) -> Result<ExitCode, DataverseError> {
         match self {
-            DatasetSubCommand::Get { id } => {
-                let response = runtime.block_on(get::get_dataset_meta(client, id.clone()));
-                evaluate_and_print_response(response);
-            }
-            DatasetSubCommand::Create { collection, body } => {
-                let body: DatasetCreateBody =
-                    parse_file::<_, DatasetCreateBody>(body).expect("Failed to parse the file");
-                let response = runtime
-                    .block_on(create::create_dataset(client, collection, body.clone()));
-                evaluate_and_print_response(response);
-            }
-            DatasetSubCommand::Publish { pid, version } => {
-                let response = runtime
-                    .block_on(publish::publish_dataset(client, pid, version.clone()));
-                evaluate_and_print_response(response);
+            DatasetSubCommand::Get {
+                id,
+                version,
+                exclude_files,
+                include_deaccessioned,
+                return_owners,
+                fields,
+            } => {
+                let options = GetDatasetOptions {
+                    exclude_files: *exclude_files,
+                    include_deaccessioned: *include_deaccessioned,
+                    return_owners: *return_owners,
+                };
+
+                match version {
+                    Some(version) => {
+                        let response = get::get_dataset_version(
+                            client, id.clone(), version, &options,
+                        ).await;
+                        evaluate_and_print_fields(response, fields)
+                    }
+                    None => {
+                        let response = get::get_dataset_meta_with_options(
+                            client, id.clone(), &options,
+                        ).await;
+                        evaluate_and_print_fields(response, fields)
+                    }
+                }
+            }
+            DatasetSubCommand::Describe { id } => {
+                let description = describe::describe_dataset(client, id.clone())
+                    .await
+                    .map_err(DataverseError::Request)?;
+
+                match output_format() {
+                    OutputFormat::Pretty => {
+                        print_description(&description);
+                        Ok(ExitCode::SUCCESS)
+                    }
+                    _ => print_data(description),
+                }
+            }
+            DatasetSubCommand::Diff { id, draft, from, to } => {
+                let (from, to) = match draft {
+                    true => (":latest-published", ":draft"),
+                    false => (from.as_deref().unwrap_or_default(), to.as_deref().unwrap_or_default()),
+                };
+                let diff = diff::compare_versions(client, id.clone(), from, to)
+                    .await
+                    .map_err(DataverseError::Request)?;
+
+                match output_format() {
+                    OutputFormat::Pretty => {
+                        print_diff(&diff);
+                        Ok(ExitCode::SUCCESS)
+                    }
+                    _ => print_data(diff),
+                }
+            }
+            DatasetSubCommand::Cite { id, version, format, anonymized, include_deaccessioned } => {
+                let options = CitationOptions {
+                    anonymized: *anonymized,
+                    include_deaccessioned: *include_deaccessioned,
+                };
+
+                if let Some(format) = format {
+                    let document = citation::export_citation(client, id.clone(), version, *format, &options).await;
+                    return write_output(document, &None);
+                }
+
+                // The citation is printed as plain text unless another output format is requested
+                let response = citation::get_citation(client, id.clone(), version, &options).await;
+                let plain = output_format() == OutputFormat::Pretty;
+                evaluate_and_print_ids(response, plain, |data| data.message.iter().cloned().collect())
+            }
+            DatasetSubCommand::Files {
+                id,
+                version,
+                search,
+                category,
+                content_type,
+                access_status,
+                limit,
+                offset,
+                include_deaccessioned,
+                fields,
+            } => {
+                let search = FileSearch {
+                    search_text: search.clone(),
+                    category: category.clone(),
+                    content_type: content_type.clone(),
+                    access_status: access_status.clone(),
+                    limit: *limit,
+                    offset: *offset,
+                    include_deaccessioned: *include_deaccessioned,
+                };
+
+                let response = files::list_dataset_files(client, id.clone(), version, &search).await;
+                evaluate_and_print_fields(response, fields)
+            }
+            DatasetSubCommand::Create {
+                collection,
+                body,
+                from_export,
+                from_datacite,
+                from_dublin_core,
+                idempotency_key,
+                by_title,
+                validate,
+                print_id,
+            } => {
+                let body: DatasetCreateBody = if *from_export {
+                    let export = parse_file::<_, serde_json::Value>(body)?;
+                    DatasetCreateBody::from_export(&export).map_err(DataverseError::Input)?
+                } else if *from_datacite || *from_dublin_core {
+                    let record = std::fs::read_to_string(body)?;
+                    let metadata = match from_datacite {
+                        true => crosswalk::from_datacite(&record),
+                        false => crosswalk::from_dublin_core(&record),
+                    }
+                    .map_err(DataverseError::Input)?;
+
+                    build_with_account_contact(client, metadata).await?
+                } else {
+                    parse_file::<_, DatasetCreateBody>(body)?
+                };
+                if *validate {
+                    let errors = validate::validate_dataset(client, collection, &body)
+                        .await
+                        .map_err(DataverseError::Request)?;
+                    if !errors.is_empty() {
+                        errors.iter().for_each(|error| print_error(error.to_string()));
+                        return Err(DataverseError::Input(tr("validation-failed", &[("count", &errors.len())])));
+                    }
+                }
+
+                let key = match (idempotency_key, by_title) {
+                    (Some(token), _) => Some(IdempotencyKey::Token(token.clone())),
+                    (None, true) => Some(IdempotencyKey::Title),
+                    (None, false) => None,
+                };
+                let response = match key {
+                    Some(key) => create::create_dataset_idempotent(
+                        client, collection, body, key,
+                    ).await,
+                    None => create::create_dataset(client, collection, body).await,
+                };
+                evaluate_and_print_ids(response, *print_id, |data| {
+                    data.persistent_id.iter().cloned().collect()
+                })
+            }
+            DatasetSubCommand::Validate { body, collection } => {
+                let body = parse_file::<_, serde_json::Value>(body)?;
+
+                let mut errors = validate::validate_structure(&body);
+                print_validation_errors(&tr("validate-schema", &[]), &errors);
+
+                // Fields are only matched with the metadata blocks once the structure is sound
+                if let (Some(collection), true) = (collection, errors.is_empty()) {
+                    let body: DatasetCreateBody = serde_json::from_value(body)
+                        .map_err(|e| DataverseError::Input(e.to_string()))?;
+                    let instance = validate::validate_dataset(client, collection, &body)
+                        .await
+                        .map_err(DataverseError::Request)?;
+
+                    let stage = tr("validate-instance", &[("collection", collection)]);
+                    print_validation_errors(&stage, &instance);
+                    errors.extend(instance);
+                }
+
+                match errors.is_empty() {
+                    true => {
+                        println!("\n{}", tr("validate-ok", &[]));
+                        Ok(ExitCode::SUCCESS)
+                    }
+                    false => Err(DataverseError::Input(tr("validate-failed", &[("count", &errors.len())]))),
+                }
+            }
+            DatasetSubCommand::CreateBatch { collection, csv, mapping, publish, print_id } => {
+                let content = std::fs::read_to_string(csv)?;
+                let mapping = match mapping {
+                    Some(path) => parse_file::<_, CsvMapping>(path)?,
+                    None => CsvMapping::default(),
+                };
+
+                let batch = batch::create_datasets_from_csv(client, collection, &content, &mapping, *publish)
+                    .await
+                    .map_err(DataverseError::Input)?;
+                print_batch_creation(&batch, *print_id)
+            }
+            DatasetSubCommand::Publish { pid, version, print_id } => {
+                let response = publish::publish_dataset(client, pid, version.clone()).await;
+                evaluate_and_print_ids(response, *print_id, |data| {
+                    match (&data.protocol, &data.authority, &data.identifier) {
+                        (Some(protocol), Some(authority), Some(identifier)) => {
+                            vec![format!("{}:{}/{}", protocol, authority, identifier)]
+                        }
+                        _ => data.id.iter().map(|id| id.to_string()).collect(),
+                    }
+                })
             }
             DatasetSubCommand::Delete { id } => {
-                let response = runtime
-                    .block_on(delete::delete_dataset(client, id));
-                evaluate_and_print_response(response);
+                let response = delete::delete_dataset(client, id).await;
+                evaluate_and_print_response(response)
+            }
+            DatasetSubCommand::Destroy { id, confirm } => {
+                if !confirm {
+                    return Err(DataverseError::Input(tr("destroy-confirm", &[])));
+                }
+
+                let response = delete::destroy_dataset(client, id.clone()).await;
+                evaluate_and_print_response(response)
+            }
+            DatasetSubCommand::ReservePid { id, delete } => {
+                let response = match delete {
+                    true => pid::delete_pid(client, id.clone()).await,
+                    false => pid::reserve_pid(client, id.clone()).await,
+                };
+                evaluate_and_print_response(response)
             }
             DatasetSubCommand::Edit { pid, body, replace } => {
-                let body = parse_file::<_, EditMetadataBody>(body)
-                    .expect("Failed to parse the file");
-                let response = runtime
-                    .block_on(edit::edit_dataset_metadata(client, pid, replace, body.clone()));
-                evaluate_and_print_response(response);
+                let body = parse_file::<_, EditMetadataBody>(body)?;
+                let response = edit::edit_dataset_metadata(client, pid, replace, body.clone()).await;
+                evaluate_and_print_response(response)
+            }
+            DatasetSubCommand::SetField { pid, field, value } => {
+                let field = edit::Field::primitive(field, value);
+                let response = edit::set_dataset_field(client, pid, field).await;
+                evaluate_and_print_response(response)
+            }
+            DatasetSubCommand::Attribute { id, attribute, value } => {
+                let response = attribute::set_dataset_attribute(client, id.clone(), *attribute, value).await;
+                evaluate_and_print_response(response)
             }
             DatasetSubCommand::Link { id, collection } => {
-                let response = runtime
-                    .block_on(link::link_dataset(client, id.clone(), collection));
-                evaluate_and_print_response(response);
+                let response = link::link_dataset(client, id.clone(), collection).await;
+                evaluate_and_print_response(response)
             }
-            DatasetSubCommand::Upload { id, path, body } => {
-                let body = body.as_ref().map(|body| {
-                    parse_file::<_, UploadBody>(body).expect("Failed to parse the file")
-                });
+            DatasetSubCommand::Upload {
+                id,
+                paths,
+                body,
+                no_ingest,
+                concurrency,
+                stop_on_error,
+                verify,
+                report,
+                print_id,
+                summary,
+            } => {
+                let mut body = match body {
+                    Some(body) => Some(parse_file::<_, UploadBody>(body)?),
+                    None => None,
+                };
+                if *no_ingest {
+                    let mut without_ingest = match body {
+                        Some(body) => body,
+                        None => UploadBody::builder()
+                            .try_into()
+                            .map_err(|e| DataverseError::Input(format!("Invalid upload body: {}", e)))?,
+                    };
+                    without_ingest.tab_ingest = Some(false);
+                    body = Some(without_ingest);
+                }
+
+                warn_about_banners(client).await;
+
+                let session = Session::start();
+                let code = if let [path] = paths.as_slice() {
+                    let response = upload::upload_file_to_dataset(
+                        client,
+                        id.clone(),
+                        path.to_str().unwrap().into(),
+                        body.clone(),
+                        None,
+                    ).await;
+
+                    let result = evaluate_and_print_ids(response, *print_id, |data| {
+                        data.file_ids().iter().map(|id| id.to_string()).collect()
+                    });
+                    let succeeded = usize::from(result.is_ok());
+                    session
+                        .finish(succeeded, 1 - succeeded, 0)
+                        .with_bytes_of(paths.iter().take(succeeded))
+                        .report(summary)?;
+                    result?
+                } else {
+                    let files = paths.iter().map(|path| (path.clone(), body.clone())).collect();
+                    let series = upload::upload_files_to_dataset(
+                        client,
+                        id.clone(),
+                        files,
+                        *concurrency,
+                        *stop_on_error,
+                    ).await;
+
+                    let result = print_upload_series(&series, *print_id);
+                    session
+                        .finish(series.succeeded.len(), series.failed.len(), series.skipped.len())
+                        .with_bytes_of(series.succeeded.iter().map(|(path, _)| path))
+                        .report(summary)?;
+                    result?
+                };
 
-                let response = runtime.block_on(upload::upload_file_to_dataset(
-                    client,
-                    id.clone(),
-                    path.to_str().unwrap().into(),
-                    body.clone(),
-                    None,
-                ));
+                if !verify {
+                    return Ok(code);
+                }
 
-                evaluate_and_print_response(response);
+                // All files are uploaded into the directory given by the body, if any
+                let directory = body.as_ref().and_then(|body| body.directory_label.clone());
+                let files: Vec<_> = paths.iter().map(|path| (path.clone(), directory.clone())).collect();
+                verify_uploaded(client, id, &files, report, *print_id).await
             }
+            DatasetSubCommand::UploadDir {
+                id,
+                root,
+                include,
+                exclude,
+                hidden,
+                body,
+                concurrency,
+                stop_on_error,
+                plan,
+                verify,
+                report,
+                print_id,
+                summary,
+            } => {
+                let options = DirectoryUploadOptions {
+                    include: include.clone(),
+                    exclude: exclude.clone(),
+                    hidden: *hidden,
+                    body: match body {
+                        Some(body) => Some(parse_file::<_, UploadBody>(body)?),
+                        None => None,
+                    },
+                    concurrency: *concurrency,
+                    stop_on_error: *stop_on_error,
+                };
+
+                if let Some(path) = plan {
+                    let files = directory::collect_directory_files(root, &options)
+                        .map_err(DataverseError::Input)?;
+
+                    let mut uploads = Plan::new();
+                    for (file, body) in files {
+                        let action = Action::Upload { dataset: id.clone(), path: file, body };
+                        uploads.push(action, "Part of the directory tree");
+                    }
+                    return write_output(uploads.to_json(), &Some(path.clone()));
+                }
+
+                warn_about_banners(client).await;
+
+                let session = Session::start();
+                let series = directory::upload_directory(client, id.clone(), root, &options)
+                    .await
+                    .map_err(DataverseError::Input)?;
+
+                let result = print_upload_series(&series, *print_id);
+                session
+                    .finish(series.succeeded.len(), series.failed.len(), series.skipped.len())
+                    .with_bytes_of(series.succeeded.iter().map(|(path, _)| path))
+                    .report(summary)?;
+                let code = result?;
+
+                if !verify {
+                    return Ok(code);
+                }
+
+                let files: Vec<_> = directory::collect_directory_files(root, &options)
+                    .map_err(DataverseError::Input)?
+                    .into_iter()
+                    .map(|(path, body)| (path, body.and_then(|body| body.directory_label)))
+                    .collect();
+                verify_uploaded(client, id, &files, report, *print_id).await
+            }
+            #[cfg(feature = "directupload")]
+            DatasetSubCommand::AddRemote { id, store, files, batch_size } => {
+                let files = parse_file::<_, Vec<RemoteFile>>(files)?;
+                let batches = remote::add_remote_files(client, id.clone(), store, &files, *batch_size).await;
+
+                for (names, error) in &batches.failed {
+                    print_error(tr("remote-failed", &[("files", &names.join(", ")), ("error", error)]));
+                }
+
+                let summary = tr(
+                    "remote-summary",
+                    &[("added", &batches.added()), ("failed", &batches.failed.len())],
+                );
+                match batches.is_success() {
+                    true => {
+                        println!("{}", summary);
+                        Ok(ExitCode::SUCCESS)
+                    }
+                    false => Err(DataverseError::Api(summary)),
+                }
+            }
+            DatasetSubCommand::WaitIngest { id, timeout, interval } => {
+                let options = IngestWait {
+                    interval: Duration::from_secs(*interval),
+                    timeout: Duration::from_secs(*timeout),
+                };
+                let report = ingest::wait_for_ingest(client, id.clone(), &options)
+                    .await
+                    .map_err(DataverseError::Request)?;
+                print_data(&report)?;
+
+                let failed = report.failed();
+                match failed.is_empty() {
+                    true => Ok(ExitCode::SUCCESS),
+                    false => Err(DataverseError::Api(tr("ingest-failed", &[("failed", &failed.len())]))),
+                }
+            }
+            DatasetSubCommand::PreviewUrl { id, create, delete, anonymized } => {
+                if *create {
+                    let response = preview_url::create_preview_url(
+                        client, id.clone(), *anonymized,
+                    ).await;
+                    evaluate_and_print_response(response)
+                } else if *delete {
+                    let response =
+                        preview_url::delete_preview_url(client, id.clone()).await;
+                    evaluate_and_print_response(response)
+                } else {
+                    let response =
+                        preview_url::get_preview_url(client, id.clone()).await;
+                    evaluate_and_print_response(response)
+                }
+            }
+            DatasetSubCommand::SubmitForReview { id } => {
+                let response = review::submit_for_review(client, id.clone()).await;
+                evaluate_and_print_response(response)
+            }
+            DatasetSubCommand::ReturnToAuthor { id, reason } => {
+                let body = ReturnToAuthorBody {
+                    reason_for_return: reason.clone(),
+                };
+                let response = review::return_to_author(client, id.clone(), body).await;
+                evaluate_and_print_response(response)
+            }
+            DatasetSubCommand::Embargo { id, files, date, reason, remove } => {
+                let response = if *remove {
+                    let body = UnembargoBody { file_ids: files.clone() };
+                    embargo::remove_embargo(client, id.clone(), body).await
+                } else {
+                    let body = EmbargoBody {
+                        date_available: date
+                            .clone()
+                            .ok_or_else(|| DataverseError::Input(tr("date-required", &[])))?,
+                        reason: reason.clone(),
+                        file_ids: files.clone(),
+                    };
+                    embargo::set_embargo(client, id.clone(), body).await
+                };
+                evaluate_and_print_response(response)
+            }
+            DatasetSubCommand::Download {
+                id,
+                zip,
+                version,
+                concurrency,
+                files,
+                out,
+                summary,
+            } => {
+                if !zip && !files.is_empty() {
+                    return Err(DataverseError::Input(tr("zip-only", &[])));
+                }
+
+                warn_about_banners(client).await;
+
+                if !zip {
+                    // Defaults are applied here, as clap treats defaulted arguments as present
+                    let version = version.as_deref().unwrap_or(":latest");
+                    let concurrency = concurrency.unwrap_or(4);
+
+                    let session = Session::start();
+                    let download = access::download_dataset(
+                        client, id.clone(), version, out.clone(), concurrency,
+                    )
+                        .await
+                        .map_err(DataverseError::Request)?;
+
+                    let result = print_dataset_download(&download);
+                    let downloaded = download.downloaded.iter().chain(&download.unverified);
+                    session
+                        .finish(download.downloaded.len() + download.unverified.len(), download.failed.len(), 0)
+                        .with_bytes_of(downloaded.map(|path| out.join(path)))
+                        .report(summary)?;
+                    return result;
+                }
+
+                if !files.is_empty() {
+                    let path = access::download_files_zip(client, files, out.clone(), None)
+                        .await
+                        .map_err(DataverseError::Request)?;
+
+                    println!("{}", tr("files-downloaded", &[("path", &path.display())]));
+                    return Ok(ExitCode::SUCCESS);
+                }
+
+                let session = Session::start();
+                let download = access::download_dataset_batched(client, id.clone(), out.clone())
+                    .await
+                    .map_err(DataverseError::Request)?;
+
+                let result = print_batched_download(&download);
+                let downloaded = download.archives.iter().chain(&download.files);
+                session
+                    .finish(download.archives.len() + download.files.len(), download.failed.len(), 0)
+                    .with_bytes_of(downloaded)
+                    .report(summary)?;
+                result
+            }
+            DatasetSubCommand::Mirror { id, dir, summary } => {
+                warn_about_banners(client).await;
+
+                let session = Session::start();
+                let mirror = access::mirror_dataset(client, id.clone(), dir.clone())
+                    .await
+                    .map_err(DataverseError::Request)?;
+
+                let result = print_mirror_summary(&mirror);
+                session
+                    .finish(mirror.downloaded.len(), mirror.failed.len(), mirror.unchanged.len())
+                    .with_bytes_of(mirror.downloaded.iter().map(|path| dir.join(path)))
+                    .report(summary)?;
+                result
+            }
+            DatasetSubCommand::Sync {
+                id,
+                root,
+                include,
+                exclude,
+                hidden,
+                delete,
+                dry_run,
+                plan,
+                summary,
+            } => {
+                let options = SyncOptions {
+                    include: include.clone(),
+                    exclude: exclude.clone(),
+                    hidden: *hidden,
+                    delete: *delete,
+                };
+                let actions = sync::plan_sync(client, id.clone(), root, &options)
+                    .await
+                    .map_err(DataverseError::Request)?;
+
+                if let Some(path) = plan {
+                    write_output(actions.to_json(), &Some(path.clone()))?;
+                }
+
+                if *dry_run {
+                    println!("{}", actions);
+                    return Ok(ExitCode::SUCCESS);
+                }
+
+                warn_about_banners(client).await;
+                execute_plan(client, &actions, plan.as_deref(), summary).await
+            }
+            DatasetSubCommand::Reorganize {
+                id,
+                prefix,
+                rules,
+                dry_run,
+                plan,
+                summary,
+            } => {
+                // Rules given on the command line are tried before those of the file
+                let mut all_rules = prefix.clone();
+                if let Some(path) = rules {
+                    all_rules.extend(parse_file::<_, Vec<PathRule>>(path)?);
+                }
+                if all_rules.is_empty() {
+                    return Err(DataverseError::Input(tr("reorganize-rules-missing", &[])));
+                }
+
+                let actions = reorganize::plan_reorganize(client, id.clone(), &all_rules)
+                    .await
+                    .map_err(DataverseError::Request)?;
+
+                if let Some(path) = plan {
+                    write_output(actions.to_json(), &Some(path.clone()))?;
+                }
+
+                if *dry_run {
+                    println!("{}", actions);
+                    return Ok(ExitCode::SUCCESS);
+                }
+
+                warn_about_banners(client).await;
+                execute_plan(client, &actions, plan.as_deref(), summary).await
+            }
+            DatasetSubCommand::Export { pid, format, output } => {
+                // Formats without a local exporter are rendered by the instance
+                let registry = ExporterRegistry::default();
+                let document = match registry.get(format) {
+                    Some(exporter) => {
+                        let export = export::export_dataset(client, pid, "dataverse_json")
+                            .await
+                            .map_err(DataverseError::Request)?;
+                        let export = serde_json::from_slice(&export)
+                            .map_err(|e| DataverseError::Api(e.to_string()))?;
+                        exporter.export(&export).map_err(DataverseError::Input)?
+                    }
+                    None => export::export_dataset(client, pid, format)
+                        .await
+                        .map_err(DataverseError::Request)?,
+                };
+
+                write_output(Ok(String::from_utf8_lossy(&document).into_owned()), output)
+            }
+            DatasetSubCommand::Thumbnail { id, set, file, remove, candidates, out } => {
+                let response = match (set, file) {
+                    (Some(path), _) => thumbnail::set_thumbnail_from_file(client, id.clone(), path.clone()).await,
+                    (None, Some(file)) => thumbnail::set_thumbnail_from_datafile(client, id.clone(), *file).await,
+                    (None, None) if *remove => thumbnail::remove_thumbnail(client, id.clone()).await,
+                    (None, None) if *candidates => {
+                        let response = thumbnail::list_thumbnail_candidates(client, id.clone()).await;
+                        return evaluate_and_print_response(response);
+                    }
+                    (None, None) => {
+                        let path = thumbnail::get_thumbnail(client, id.clone(), out.clone())
+                            .await
+                            .map_err(DataverseError::Request)?;
+
+                        println!("{}", tr("file-downloaded", &[("path", &path.display())]));
+                        return Ok(ExitCode::SUCCESS);
+                    }
+                };
+
+                evaluate_and_print_response(response)
+            }
+        }
+    }
+}
+
+// Crosswalked records carry no emails, so the account of the token becomes the contact
+async fn build_with_account_contact(
+    client: &BaseClient,
+    metadata: DatasetMetadataBuilder,
+) -> Result<DatasetCreateBody, DataverseError> {
+    let user = me::get_me(client).await.map_err(DataverseError::Request)?.data;
+    let (name, email) = match user {
+        Some(user) => (user.display_name.unwrap_or_default(), user.email),
+        None => (String::new(), None),
+    };
+    let email = email.ok_or_else(|| DataverseError::Input(tr("crosswalk-contact", &[])))?;
+
+    metadata.add_contact(&name, &email).build().map_err(DataverseError::Input)
+}
+
+// Prints the description of a dataset as labelled lines below its title
+fn print_description(description: &describe::DatasetDescription) {
+    let versions = match description.latest_published() {
+        Some(published) => tr(
+            "describe-versions-published",
+            &[
+                ("count", &description.versions.len()),
+                ("version", &published.version),
+                ("date", &published.release_time.as_deref().unwrap_or_default()),
+            ],
+        ),
+        None => tr("describe-versions-unpublished", &[("count", &description.versions.len())]),
+    };
+    let locks = match description.locks.is_empty() {
+        true => tr("describe-no-locks", &[]),
+        false => description
+            .locks
+            .iter()
+            .map(|lock| match (&lock.user, &lock.date) {
+                (Some(user), Some(date)) => format!("{} ({}, {})", lock.lock_type, user, date),
+                _ => lock.lock_type.clone(),
+            })
+            .collect::<Vec<_>>()
+            .join("; "),
+    };
+
+    // Drafts carry no version number
+    let state = description.state.clone().unwrap_or_default();
+    let state = match description.version == "DRAFT" {
+        true => state,
+        false => format!("{} ({})", state, description.version),
+    };
+
+    let lines = [
+        (tr("describe-authors", &[]), description.authors.join("; ")),
+        (tr("describe-state", &[]), state),
+        (tr("describe-versions", &[]), versions),
+        (
+            tr("describe-files", &[]),
+            format!("{} ({})", description.file_count, format_bytes(description.total_size)),
+        ),
+        (tr("describe-license", &[]), description.license.clone().unwrap_or_default()),
+        (tr("describe-updated", &[]), description.last_update.clone().unwrap_or_default()),
+        (tr("describe-locks", &[]), locks),
+    ];
+
+    println!("{}", description.title.as_deref().unwrap_or_default().bold());
+    println!("{}", description.persistent_id.as_deref().unwrap_or_default());
+    let width = lines.iter().map(|(label, _)| label.chars().count()).max().unwrap_or_default();
+    for (label, value) in lines {
+        println!("  {:width$}  {}", format!("{}:", label), value, width = width + 1);
+    }
+}
+
+// Prints the changed fields and files, marking additions, removals and modifications
+fn print_diff(diff: &diff::VersionDiff) {
+    println!("{}", tr("diff-header", &[("from", &diff.from), ("to", &diff.to)]).bold());
+    if diff.is_empty() {
+        println!("  {}", tr("diff-none", &[]).green());
+        return;
+    }
+
+    let marker = |change: ChangeKind| match change {
+        ChangeKind::Added => "+".green(),
+        ChangeKind::Removed => "-".red(),
+        ChangeKind::Modified => "~".yellow(),
+        ChangeKind::Replaced => "!".yellow(),
+    };
+
+    if !diff.fields.is_empty() {
+        println!("{}", tr("diff-fields", &[]));
+    }
+    for field in &diff.fields {
+        let value = match (&field.before, &field.after) {
+            (Some(before), Some(after)) => format!("{} → {}", before, after),
+            (before, after) => before.clone().or(after.clone()).unwrap_or_default(),
         };
+        println!("  {} {}: {}", marker(field.change), field.field, value);
+    }
+
+    if !diff.files.is_empty() {
+        println!("{}", tr("diff-files", &[]));
+    }
+    for file in &diff.files {
+        match file.details.is_empty() {
+            true => println!("  {} {}", marker(file.change), file.path),
+            false => println!("  {} {} ({})", marker(file.change), file.path, file.details.join(", ")),
+        }
+    }
+}
+
+// Prints the errors found by a stage of the validation, one field path per line
+fn print_validation_errors(stage: &str, errors: &[validate::ValidationError]) {
+    println!("{}", stage.bold());
+    if errors.is_empty() {
+        println!("  {}", tr("validate-no-errors", &[]).green());
+    }
+    for error in errors {
+        println!("  {}: {}", error.path.yellow(), error.message);
+    }
+}
+
+// Prints the created datasets and failed rows, and fails if any row failed. With
+// `print_id`, stdout only receives the persistent identifiers.
+fn print_batch_creation(batch: &batch::BatchCreation, print_id: bool) -> Result<ExitCode, DataverseError> {
+    for (line, pid) in &batch.created {
+        match print_id {
+            true => println!("{}", pid),
+            false => println!("{}", tr("batch-created", &[("line", line), ("pid", pid)])),
+        }
+    }
+    for (line, error) in &batch.failed {
+        print_error(tr("batch-failed", &[("line", line), ("error", error)]));
+    }
+
+    let summary = tr(
+        "batch-summary",
+        &[("created", &batch.created.len()), ("failed", &batch.failed.len())],
+    );
+
+    match batch.is_success() {
+        true => {
+            if !print_id {
+                println!("\n{}", summary);
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        false => Err(DataverseError::Api(summary)),
+    }
+}
+
+// Verifies uploaded files against the dataset and prints or writes the report
+async fn verify_uploaded(
+    client: &BaseClient,
+    id: &Identifier,
+    files: &[(PathBuf, Option<String>)],
+    report: &Option<PathBuf>,
+    print_id: bool,
+) -> Result<ExitCode, DataverseError> {
+    let verification = verify::verify_uploads(client, id.clone(), files)
+        .await
+        .map_err(DataverseError::Request)?;
+
+    match report {
+        Some(_) => write_output(verification.to_json(), report)?,
+        None if print_id => ExitCode::SUCCESS,
+        None => print_data(&verification)?,
+    };
+
+    match verification.is_verified() {
+        true => Ok(ExitCode::SUCCESS),
+        false => Err(DataverseError::Api(tr(
+            "verification-failed",
+            &[("failed", &verification.failures())],
+        ))),
+    }
+}
+
+// Prints the outcome per file and fails if any of the uploads failed. With
+// `print_id`, stdout only receives the IDs of the uploaded files.
+fn print_upload_series(
+    series: &upload::UploadSeries,
+    print_id: bool,
+) -> Result<ExitCode, DataverseError> {
+    for (path, response) in &series.succeeded {
+        let ids = response.file_ids().iter().map(|id| id.to_string()).collect::<Vec<_>>();
+        match print_id {
+            true => ids.iter().for_each(|id| println!("{}", id)),
+            false => println!("{}", tr("uploaded", &[("path", &path.display()), ("ids", &ids.join(", "))])),
+        }
+    }
+    for (path, error) in &series.failed {
+        print_error(tr("upload-failed", &[("path", &path.display()), ("error", error)]));
+    }
+    for path in series.skipped.iter().filter(|_| !print_id) {
+        println!("{}", tr("skipped", &[("path", &path.display())]));
+    }
+
+    let summary = tr(
+        "upload-summary",
+        &[
+            ("succeeded", &series.succeeded.len()),
+            ("failed", &series.failed.len()),
+            ("skipped", &series.skipped.len()),
+        ],
+    );
+
+    match series.is_success() {
+        true => {
+            if !print_id {
+                println!("\n{}", summary);
+            }
+            Ok(ExitCode::SUCCESS)
+        }
+        false => Err(DataverseError::Api(summary)),
+    }
+}
+
+// Prints the downloaded paths and fails if any of the batches failed
+fn print_batched_download(
+    download: &access::batch::BatchedDownload,
+) -> Result<ExitCode, DataverseError> {
+    for path in download.archives.iter().chain(&download.files) {
+        println!("{}", tr("downloaded", &[("path", &path.display())]));
+    }
+    for (ids, error) in &download.failed {
+        let ids = ids.iter().map(|id| id.to_string()).collect::<Vec<_>>();
+        print_error(tr("download-failed", &[("ids", &ids.join(", ")), ("error", error)]));
+    }
+
+    match download.failed.len() {
+        0 => Ok(ExitCode::SUCCESS),
+        failed => Err(DataverseError::Api(tr("downloads-failed", &[("failed", &failed)]))),
+    }
+}
+
+// Prints the downloaded files and fails if any of them could not be downloaded or verified
+fn print_dataset_download(download: &access::dataset::DatasetDownload) -> Result<ExitCode, DataverseError> {
+    for path in &download.downloaded {
+        println!("{}", tr("downloaded", &[("path", &path.display())]));
+    }
+    for path in &download.unverified {
+        println!("{}", tr("downloaded-unverified", &[("path", &path.display())]));
+    }
+    for (path, error) in &download.failed {
+        print_error(tr("mirror-failed", &[("path", &path.display()), ("error", error)]));
+    }
+
+    match download.failed.len() {
+        0 => Ok(ExitCode::SUCCESS),
+        failed => Err(DataverseError::Api(tr("downloads-failed", &[("failed", &failed)]))),
+    }
+}
+
+// Prints the downloaded and removed files and fails if any of the downloads failed
+fn print_mirror_summary(summary: &access::mirror::MirrorSummary) -> Result<ExitCode, DataverseError> {
+    for path in &summary.downloaded {
+        println!("{}", tr("downloaded", &[("path", &path.display())]));
+    }
+    for path in &summary.pruned {
+        println!("{}", tr("pruned", &[("path", &path.display())]));
+    }
+    for (path, error) in &summary.failed {
+        print_error(tr("mirror-failed", &[("path", &path.display()), ("error", error)]));
+    }
+
+    let message = tr(
+        "mirror-summary",
+        &[
+            ("version", &summary.version.as_deref().unwrap_or("-")),
+            ("downloaded", &summary.downloaded.len()),
+            ("unchanged", &summary.unchanged.len()),
+            ("pruned", &summary.pruned.len()),
+            ("failed", &summary.failed.len()),
+        ],
+    );
+
+    match summary.is_success() {
+        true => {
+            println!("\n{}", message);
+            Ok(ExitCode::SUCCESS)
+        }
+        false => Err(DataverseError::Api(message)),
     }
 }
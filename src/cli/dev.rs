@@ -0,0 +1,31 @@
+use std::path::PathBuf;
+
+use structopt::StructOpt;
+
+// Developer tooling, only available with the `record` feature. Since recording
+// re-runs another subcommand, it is dispatched by the binary itself instead of
+// implementing the `Matcher` trait.
+#[derive(StructOpt, Debug)]
+#[structopt(about = "Developer tools for working on this crate")]
+pub enum DevSubCommand {
+    #[structopt(
+        about = "Execute a command and record its requests and responses as fixtures",
+        setting = structopt::clap::AppSettings::TrailingVarArg
+    )]
+    Record {
+        #[structopt(
+            long,
+            short,
+            help = "Directory to write the fixtures to",
+            default_value = "tests/fixtures/recorded"
+        )]
+        dir: PathBuf,
+
+        #[structopt(
+            help = "Command to execute, e.g. 'dataset get 42'",
+            required = true,
+            allow_hyphen_values = true
+        )]
+        command: Vec<String>,
+    },
+}
@@ -0,0 +1,161 @@
+use std::str::FromStr;
+
+use serde_json::{Map, Value};
+
+// Projects JSON output to a selection of fields, given as dotted paths such as
+// `latestVersion.versionState`. Arrays along a path are traversed element-wise,
+// such that `files.label` selects the label of every file.
+
+/// A comma-separated list of dotted paths, as passed to `--fields`.
+#[derive(Debug, Clone, PartialEq)]
+pub struct FieldSelection(Vec<String>);
+
+impl FromStr for FieldSelection {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let fields: Vec<String> = s
+            .split(',')
+            .map(|field| field.trim().to_string())
+            .filter(|field| !field.is_empty())
+            .collect();
+
+        match fields.is_empty() {
+            true => Err("At least one field is required".to_string()),
+            false => Ok(FieldSelection(fields)),
+        }
+    }
+}
+
+impl FieldSelection {
+    /// Reduces the value to the selected fields, keeping their nesting.
+    ///
+    /// Fields that do not exist are left out. If the value itself is an array, the selection is applied to
+    /// every element.
+    pub fn project(&self, value: &Value) -> Value {
+        if let Value::Array(items) = value {
+            return Value::Array(items.iter().map(|item| self.project(item)).collect());
+        }
+
+        let mut result = Value::Object(Map::new());
+        for field in &self.0 {
+            let path: Vec<&str> = field.split('.').collect();
+            if let Some(selected) = select(value, &path) {
+                merge(&mut result, selected);
+            }
+        }
+
+        result
+    }
+}
+
+// Selects the value at the path, wrapped in the objects and arrays leading to it
+fn select(value: &Value, path: &[&str]) -> Option<Value> {
+    let Some((key, rest)) = path.split_first() else {
+        return Some(value.clone());
+    };
+
+    match value {
+        Value::Object(map) => {
+            let selected = select(map.get(*key)?, rest)?;
+            Some(Value::Object(Map::from_iter([(key.to_string(), selected)])))
+        }
+        Value::Array(items) => Some(Value::Array(
+            items
+                .iter()
+                .map(|item| select(item, path).unwrap_or(Value::Object(Map::new())))
+                .collect(),
+        )),
+        _ => None,
+    }
+}
+
+// Merges objects by key and arrays element-wise, such that several fields
+// below the same array end up in the same elements
+fn merge(target: &mut Value, source: Value) {
+    match (target, source) {
+        (Value::Object(target), Value::Object(source)) => {
+            for (key, value) in source {
+                match target.get_mut(&key) {
+                    Some(existing) => merge(existing, value),
+                    None => {
+                        target.insert(key, value);
+                    }
+                }
+            }
+        }
+        (Value::Array(target), Value::Array(source)) => {
+            for (existing, value) in target.iter_mut().zip(source) {
+                merge(existing, value);
+            }
+        }
+        (target, source) => *target = source,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::FieldSelection;
+
+    fn dataset() -> serde_json::Value {
+        json!({
+            "id": 42,
+            "persistentId": "doi:10.5072/FK2/ABC123",
+            "latestVersion": {
+                "versionState": "DRAFT",
+                "files": [
+                    {"label": "data.csv", "dataFile": {"id": 1, "filesize": 10}},
+                    {"label": "readme.md", "dataFile": {"id": 2, "filesize": 20}}
+                ]
+            }
+        })
+    }
+
+    /// Tests the projection to top-level and nested fields.
+    #[test]
+    fn test_project_nested_fields() {
+        let fields: FieldSelection = "persistentId, latestVersion.versionState, missing"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            fields.project(&dataset()),
+            json!({
+                "persistentId": "doi:10.5072/FK2/ABC123",
+                "latestVersion": {"versionState": "DRAFT"}
+            })
+        );
+    }
+
+    /// Tests that several fields below an array are merged into the same elements.
+    #[test]
+    fn test_project_fields_in_arrays() {
+        let fields: FieldSelection = "latestVersion.files.label,latestVersion.files.dataFile.id"
+            .parse()
+            .unwrap();
+
+        assert_eq!(
+            fields.project(&dataset()),
+            json!({
+                "latestVersion": {
+                    "files": [
+                        {"label": "data.csv", "dataFile": {"id": 1}},
+                        {"label": "readme.md", "dataFile": {"id": 2}}
+                    ]
+                }
+            })
+        );
+
+        let items = json!([{"id": 1, "title": "A"}, {"id": 2, "title": "B"}]);
+        let fields: FieldSelection = "title".parse().unwrap();
+
+        assert_eq!(fields.project(&items), json!([{"title": "A"}, {"title": "B"}]));
+    }
+
+    /// Tests that an empty selection is rejected.
+    #[test]
+    fn test_empty_selection() {
+        assert!(" , ".parse::<FieldSelection>().is_err());
+    }
+}
@@ -1,11 +1,30 @@
 use std::path::PathBuf;
+use std::process::ExitCode;
 
 use structopt::StructOpt;
 
-use crate::{client::BaseClient, native_api::dataset::upload::UploadBody};
-use crate::native_api::file::replace;
+use crate::{client::BaseClient, identifier::Identifier, native_api::dataset::upload::UploadBody};
+use crate::access;
+use crate::access::datafile::TabularFormat;
+use crate::error::DataverseError;
+use crate::native_api::file::access_requests;
+use crate::native_api::file::datadicts;
+use crate::native_api::file::ingest;
+use crate::native_api::file::metadata::{self, FileMetadataBody};
+#[cfg(feature = "directupload")]
+use crate::native_api::file::fixity;
+use crate::native_api::file::replace::{self, ManifestEntry, Replacement};
 
-use super::base::{evaluate_and_print_response, Matcher, parse_file};
+use super::banner::warn_about_banners;
+use super::base::{
+    evaluate_and_print_ids, evaluate_and_print_response, Matcher, parse_file, print_data, write_output,
+};
+#[cfg(feature = "directupload")]
+use super::i18n::print_error;
+use super::i18n::tr;
+use super::plan::execute_plan;
+#[cfg(feature = "directupload")]
+use super::summary::Session;
 
 #[derive(StructOpt, Debug)]
 #[structopt(about = "Handle files of a Dataverse instance")]
@@ -27,38 +46,395 @@ pub enum FileSubCommand {
 
         #[structopt(long, short, help = "Force the replacement of the file")]
         force: bool,
+
+        #[structopt(long, help = "Only print the ID of the new file, e.g. to capture it in a shell variable")]
+        print_id: bool,
+    },
+
+    #[structopt(about = "Download a file")]
+    Download {
+        #[structopt(help = "Identifier of the file to download")]
+        id: Identifier,
+
+        #[structopt(
+            long,
+            short,
+            help = "Path of the file or directory to download to",
+            default_value = "."
+        )]
+        out: PathBuf,
+
+        #[structopt(
+            long,
+            short,
+            help = "Format of a tabular file to download (original, tab, RData, prep)"
+        )]
+        format: Option<TabularFormat>,
+    },
+
+    #[structopt(about = "Replace the files listed in a manifest by local files")]
+    ReplaceBulk {
+        #[structopt(help = "Path to the JSON/YAML manifest listing entries of 'file' (ID or label) and 'path'")]
+        manifest: PathBuf,
+
+        #[structopt(long, short, help = "(Persistent) identifier of the dataset, required to reference files by label")]
+        dataset: Option<Identifier>,
+
+        #[structopt(long, short, help = "Allow replacing files by files of a different content type")]
+        force: bool,
+
+        #[structopt(long, help = "Upload directly to the storage and replace all files in a single request")]
+        direct: bool,
+
+        #[structopt(long, help = "Number of files to upload simultaneously with --direct", default_value = "4")]
+        concurrency: usize,
+
+        #[structopt(long, help = "Print the planned replacements without executing them")]
+        dry_run: bool,
+
+        #[structopt(long, help = "Write the plan to this path, e.g. for review with 'plan apply'")]
+        plan: Option<PathBuf>,
+
+        #[structopt(long, help = "Path to write a JSON summary of the replacements to")]
+        summary: Option<PathBuf>,
+    },
+
+    #[structopt(about = "Move a file to another directory of its dataset or rename it, without re-uploading it")]
+    Move {
+        #[structopt(
+            help = "Identifier of the file to move or rename",
+            required_unless = "id-option",
+            conflicts_with = "id-option"
+        )]
+        id: Option<Identifier>,
+
+        #[structopt(long = "id", value_name = "id", help = "Identifier of the file, as an alternative to the positional argument")]
+        id_option: Option<Identifier>,
+
+        #[structopt(long, alias = "to", help = "Directory to move the file to, e.g. 'data/cleaned/'")]
+        dir: Option<String>,
+
+        #[structopt(long, short, help = "New name of the file")]
+        label: Option<String>,
+    },
+
+    #[structopt(about = "Change the metadata of a file without replacing it")]
+    Edit {
+        #[structopt(long, short, help = "Identifier of the file to edit")]
+        id: Identifier,
+
+        #[structopt(long, short, help = "Path to the JSON/YAML file containing the metadata body")]
+        body: Option<PathBuf>,
+
+        #[structopt(long, short, help = "New name of the file")]
+        label: Option<String>,
+
+        #[structopt(long, help = "New description of the file")]
+        description: Option<String>,
+
+        #[structopt(long, help = "Directory of the file within its dataset, e.g. 'data/cleaned'")]
+        directory: Option<String>,
+
+        #[structopt(
+            long,
+            help = "Comma-separated categories of the file, e.g. 'Data,Documentation'",
+            use_delimiter = true
+        )]
+        categories: Vec<String>,
+
+        #[structopt(long, help = "Whether access to the file is restricted (true, false)")]
+        restrict: Option<bool>,
+    },
+
+    #[structopt(about = "Add categories and tabular tags to a file")]
+    Tag {
+        #[structopt(help = "Identifier of the file to tag")]
+        id: Identifier,
+
+        #[structopt(long, help = "Category to add, e.g. 'Documentation' (repeatable)", number_of_values = 1)]
+        category: Vec<String>,
+
+        #[structopt(
+            long,
+            help = "Tabular tag to add, e.g. 'Survey' or 'Time Series' (repeatable)",
+            number_of_values = 1
+        )]
+        tab_tag: Vec<String>,
+
+        #[structopt(long, help = "Replace the existing categories and tabular tags instead of adding to them")]
+        replace: bool,
+    },
+
+    #[structopt(about = "Start the tabular ingest of a file again, e.g. after it has failed")]
+    Reingest {
+        #[structopt(help = "Identifier of the file to reingest")]
+        id: Identifier,
+    },
+
+    #[structopt(about = "Revert the tabular ingest of a file to serve its original format")]
+    Uningest {
+        #[structopt(help = "Identifier of the file to uningest")]
+        id: Identifier,
+    },
+
+    #[structopt(about = "Request access to a restricted file")]
+    RequestAccess {
+        #[structopt(help = "Identifier of the restricted file")]
+        id: Identifier,
+    },
+
+    #[structopt(about = "List the access requests of a restricted file, or grant or reject one")]
+    AccessRequests {
+        #[structopt(help = "Identifier of the restricted file")]
+        id: Identifier,
+
+        #[structopt(long, help = "Identifier of the user to grant access to, e.g. '@jdoe'")]
+        grant: Option<String>,
+
+        #[structopt(long, help = "Identifier of the user to reject, e.g. '@jdoe'", conflicts_with = "grant")]
+        reject: Option<String>,
+    },
+
+    #[structopt(about = "Retrieve the variable metadata of an ingested tabular file")]
+    Variables {
+        #[structopt(help = "Identifier of the tabular file")]
+        id: Identifier,
     },
 }
 
 impl Matcher for FileSubCommand {
-    fn process(&self, client: &BaseClient) {
-        let runtime = tokio::runtime::Runtime::new().unwrap();
+    async fn process(&self, client: &BaseClient) -> Result<ExitCode, DataverseError> {
         match self {
             FileSubCommand::Replace {
                 id,
                 path,
                 body,
                 force,
+                print_id,
             } => {
-                let body = prepare_replace_body(body, force);
+                let body = prepare_replace_body(body, force)?;
+                warn_about_banners(client).await;
+
                 let response =
-                    runtime.block_on(replace::replace_file(client, id, path.clone(), &body, None));
+                    replace::replace_file(client, id, path.clone(), &body, None).await;
+
+                evaluate_and_print_ids(response, *print_id, |data| {
+                    data.file_ids().iter().map(|id| id.to_string()).collect()
+                })
+            }
+            FileSubCommand::Download { id, out, format } => {
+                warn_about_banners(client).await;
+
+                let path = match format {
+                    Some(format) => {
+                        access::download_tabular_file(client, id.clone(), *format, out.clone(), None).await
+                    }
+                    None => access::download_file(client, id.clone(), out.clone(), None).await,
+                }
+                .map_err(DataverseError::Request)?;
+
+                println!("{}", tr("file-downloaded", &[("path", &path.display())]));
+                Ok(ExitCode::SUCCESS)
+            }
+            FileSubCommand::Move { id, id_option, dir, label } => {
+                // Either one is present, as enforced by the argument parser
+                let id = id.as_ref().or(id_option.as_ref()).expect("Missing file identifier");
+                let response = match (dir, label) {
+                    (Some(dir), label) => metadata::move_file(client, id.clone(), dir, label.as_deref()).await,
+                    (None, Some(label)) => metadata::rename_file(client, id.clone(), label).await,
+                    (None, None) => return Err(DataverseError::Input(tr("move-missing", &[]))),
+                };
+                evaluate_and_print_response(response)
+            }
+            FileSubCommand::Edit {
+                id,
+                body,
+                label,
+                description,
+                directory,
+                categories,
+                restrict,
+            } => {
+                // Options take precedence over the fields of the body
+                let mut body = match body {
+                    Some(body) => parse_file::<_, FileMetadataBody>(body)?,
+                    None => FileMetadataBody::builder()
+                        .try_into()
+                        .map_err(|e| DataverseError::Input(format!("Invalid file metadata: {}", e)))?,
+                };
+                body.label = label.clone().or(body.label);
+                body.description = description.clone().or(body.description);
+                body.directory_label = directory.clone().or(body.directory_label);
+                body.restrict = restrict.or(body.restrict);
+                if !categories.is_empty() {
+                    body.categories = categories.clone();
+                }
+
+                let response = metadata::update_file_metadata(client, id.clone(), &body).await;
+                evaluate_and_print_response(response)
+            }
+            FileSubCommand::Tag { id, category, tab_tag, replace } => {
+                if category.is_empty() && tab_tag.is_empty() {
+                    return Err(DataverseError::Input(tr("tag-missing", &[])));
+                }
 
-                evaluate_and_print_response(response);
+                let mut exit = ExitCode::SUCCESS;
+                if !category.is_empty() {
+                    let response = metadata::set_file_categories(client, id.clone(), category, *replace).await;
+                    exit = evaluate_and_print_response(response)?;
+                }
+                if !tab_tag.is_empty() {
+                    let response = metadata::set_tabular_tags(client, id.clone(), tab_tag, *replace).await;
+                    exit = evaluate_and_print_response(response)?;
+                }
+                Ok(exit)
             }
-        };
+            FileSubCommand::ReplaceBulk {
+                manifest,
+                dataset,
+                force,
+                direct,
+                concurrency,
+                dry_run,
+                plan,
+                summary,
+            } => {
+                let entries = parse_file::<_, Vec<ManifestEntry>>(manifest)?;
+                let replacements = replace::resolve_manifest(client, dataset.clone(), &entries)
+                    .await
+                    .map_err(DataverseError::Input)?;
+                let actions = replace::plan_replacements(&replacements, *force);
+
+                if let Some(path) = plan {
+                    write_output(actions.to_json(), &Some(path.clone()))?;
+                }
+
+                if *dry_run {
+                    println!("{}", actions);
+                    return Ok(ExitCode::SUCCESS);
+                }
+
+                warn_about_banners(client).await;
+                match (direct, dataset) {
+                    (false, _) => execute_plan(client, &actions, plan.as_deref(), summary).await,
+                    (true, Some(dataset)) => {
+                        replace_direct(client, dataset, &replacements, *concurrency, *force, summary).await
+                    }
+                    (true, None) => Err(DataverseError::Input(tr("direct-dataset-missing", &[]))),
+                }
+            }
+            FileSubCommand::Reingest { id } => {
+                let response = ingest::reingest_file(client, id.clone()).await;
+                evaluate_and_print_response(response)
+            }
+            FileSubCommand::Uningest { id } => {
+                let response = ingest::uningest_file(client, id.clone()).await;
+                evaluate_and_print_response(response)
+            }
+            FileSubCommand::RequestAccess { id } => {
+                let response = access_requests::request_access(client, id.clone()).await;
+                evaluate_and_print_response(response)
+            }
+            FileSubCommand::AccessRequests { id, grant, reject } => match (grant, reject) {
+                (Some(user), _) => {
+                    let response = access_requests::grant_access(client, id.clone(), user).await;
+                    evaluate_and_print_response(response)
+                }
+                (None, Some(user)) => {
+                    let response = access_requests::reject_access(client, id.clone(), user).await;
+                    evaluate_and_print_response(response)
+                }
+                (None, None) => {
+                    let response = access_requests::list_access_requests(client, id.clone()).await;
+                    evaluate_and_print_response(response)
+                }
+            },
+            FileSubCommand::Variables { id } => {
+                let dictionary = datadicts::get_data_dictionary(client, id.clone())
+                    .await
+                    .map_err(DataverseError::Request)?;
+                print_data(dictionary)
+            }
+        }
+    }
+}
+
+// Uploads the replacing files to the storage and replaces them in a single request
+#[cfg(feature = "directupload")]
+async fn replace_direct(
+    client: &BaseClient,
+    dataset: &Identifier,
+    replacements: &[Replacement],
+    concurrency: usize,
+    force: bool,
+    summary: &Option<PathBuf>,
+) -> Result<ExitCode, DataverseError> {
+    let session = Session::start();
+    let algorithm = fixity::get_fixity_algorithm(client).await.map_err(DataverseError::Request)?;
+    let (series, result) =
+        replace::replace_files_direct(client, dataset.clone(), replacements, algorithm, concurrency, force).await;
+
+    for (path, error) in &series.failed {
+        print_error(tr("upload-failed", &[("path", &path.display()), ("error", error)]));
+    }
+
+    let uploaded: Vec<&Replacement> = replacements
+        .iter()
+        .filter(|replacement| series.succeeded.iter().any(|(path, _)| path == &replacement.path))
+        .collect();
+    let replaced = match &result {
+        Some(Ok(_)) => {
+            for replacement in &uploaded {
+                println!(
+                    "{}",
+                    tr("replaced", &[("file", &replacement.file), ("path", &replacement.path.display())])
+                );
+            }
+            uploaded.len()
+        }
+        Some(Err(error)) => {
+            print_error(error);
+            0
+        }
+        None => 0,
+    };
+
+    let failed = replacements.len() - replaced;
+    session
+        .finish(replaced, failed, 0)
+        .with_bytes_of(uploaded.iter().map(|replacement| &replacement.path))
+        .report(summary)?;
+
+    match failed {
+        0 => Ok(ExitCode::SUCCESS),
+        _ => Err(DataverseError::Api(tr("replace-summary", &[("replaced", &replaced), ("failed", &failed)]))),
     }
 }
 
-fn prepare_replace_body(body: &Option<PathBuf>, force: &bool) -> Option<UploadBody> {
+#[cfg(not(feature = "directupload"))]
+async fn replace_direct(
+    _client: &BaseClient,
+    _dataset: &Identifier,
+    _replacements: &[Replacement],
+    _concurrency: usize,
+    _force: bool,
+    _summary: &Option<PathBuf>,
+) -> Result<ExitCode, DataverseError> {
+    Err(DataverseError::Input(tr("direct-unavailable", &[])))
+}
+
+fn prepare_replace_body(
+    body: &Option<PathBuf>,
+    force: &bool,
+) -> Result<Option<UploadBody>, DataverseError> {
     match body {
         Some(body) => {
-            let mut body = parse_file::<_, UploadBody>(body).unwrap();
+            let mut body = parse_file::<_, UploadBody>(body)?;
             if body.force_replace.is_none() {
                 body.force_replace = Some(force.to_owned());
             }
-            Some(body)
+            Ok(Some(body))
         }
-        _ => None,
+        _ => Ok(None),
     }
 }
@@ -0,0 +1,124 @@
+use std::collections::HashMap;
+use std::fmt::Display;
+use std::sync::OnceLock;
+
+// User-facing messages of the CLI are looked up by key in a catalog per
+// language. The language is selected via the DVCLI_LANG environment variable
+// and falls back to English for unknown languages and missing keys.
+static EN: &str = include_str!("../../locales/en.toml");
+static DE: &str = include_str!("../../locales/de.toml");
+
+static CATALOG: OnceLock<Catalog> = OnceLock::new();
+
+type Catalog = HashMap<String, String>;
+
+/// Languages with a message catalog.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Language {
+    English,
+    German,
+}
+
+impl Language {
+    /// Determines the language from a locale such as `de`, `de_DE` or `de-DE.UTF-8`.
+    pub fn from_locale(locale: &str) -> Self {
+        let code = locale
+            .split(['_', '-', '.'])
+            .next()
+            .unwrap_or_default()
+            .to_lowercase();
+
+        match code.as_str() {
+            "de" => Language::German,
+            _ => Language::English,
+        }
+    }
+
+    /// Determines the language from the `DVCLI_LANG` environment variable.
+    pub fn from_env() -> Self {
+        match std::env::var("DVCLI_LANG") {
+            Ok(locale) => Language::from_locale(&locale),
+            Err(_) => Language::English,
+        }
+    }
+
+    fn source(&self) -> &'static str {
+        match self {
+            Language::English => EN,
+            Language::German => DE,
+        }
+    }
+}
+
+/// Translates a message and replaces its `{name}` placeholders with the given arguments.
+///
+/// Keys missing in the selected catalog are taken from the English one. If the key is not found at all,
+/// the key itself is returned, such that a missing translation never hides a message.
+pub fn tr(key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let catalog = CATALOG.get_or_init(|| load(Language::from_env()));
+    format_message(catalog, key, args)
+}
+
+/// Prints an error message prefixed with the translated `Error:` label to stderr.
+pub fn print_error(error: impl Display) {
+    eprintln!("{} {}", tr("error", &[]), error);
+}
+
+fn load(language: Language) -> Catalog {
+    let mut catalog = parse(EN);
+    if language != Language::English {
+        catalog.extend(parse(language.source()));
+    }
+    catalog
+}
+
+fn parse(source: &str) -> Catalog {
+    toml::from_str(source).expect("Failed to parse message catalog")
+}
+
+fn format_message(catalog: &Catalog, key: &str, args: &[(&str, &dyn Display)]) -> String {
+    let mut message = catalog.get(key).cloned().unwrap_or_else(|| key.to_string());
+    for (name, value) in args {
+        message = message.replace(&format!("{{{}}}", name), &value.to_string());
+    }
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{format_message, load, parse, Language, DE, EN};
+
+    /// Tests that both catalogs define the same keys.
+    #[test]
+    fn test_catalogs_are_complete() {
+        let mut en: Vec<String> = parse(EN).into_keys().collect();
+        let mut de: Vec<String> = parse(DE).into_keys().collect();
+        en.sort();
+        de.sort();
+
+        assert_eq!(en, de);
+    }
+
+    /// Tests the selection of the language from different locale notations.
+    #[test]
+    fn test_language_from_locale() {
+        assert_eq!(Language::from_locale("de"), Language::German);
+        assert_eq!(Language::from_locale("de_DE.UTF-8"), Language::German);
+        assert_eq!(Language::from_locale("DE-at"), Language::German);
+        assert_eq!(Language::from_locale("en_US"), Language::English);
+        assert_eq!(Language::from_locale("fr"), Language::English);
+    }
+
+    /// Tests the substitution of placeholders and the fallback for unknown keys.
+    #[test]
+    fn test_format_message() {
+        let catalog = load(Language::German);
+        let path = "report.csv";
+
+        assert_eq!(
+            format_message(&catalog, "report-written", &[("path", &path)]),
+            "Bericht nach report.csv geschrieben"
+        );
+        assert_eq!(format_message(&catalog, "unknown-key", &[]), "unknown-key");
+    }
+}
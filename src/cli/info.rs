@@ -1,5 +1,8 @@
+use std::process::ExitCode;
+
 use crate::client::BaseClient;
-use crate::native_api;
+use crate::error::DataverseError;
+use crate::native_api::info;
 use structopt::StructOpt;
 
 use super::base::{evaluate_and_print_response, Matcher};
@@ -9,16 +12,32 @@ use super::base::{evaluate_and_print_response, Matcher};
 pub enum InfoSubCommand {
     #[structopt(about = "Retrieve the version of the Dataverse instance")]
     Version,
+
+    #[structopt(about = "Retrieve the host name of the Dataverse instance")]
+    Server,
+
+    #[structopt(about = "Retrieve the terms of use for the API")]
+    ApiTerms,
+
+    #[structopt(about = "List the metadata formats datasets can be exported in")]
+    ExportFormats,
+
+    #[structopt(about = "Retrieve the maximum total size of a ZIP download")]
+    ZipLimit,
+
+    #[structopt(about = "Retrieve the maximum duration of embargoes in months")]
+    MaxEmbargo,
 }
 
 impl Matcher for InfoSubCommand {
-    fn process(&self, client: &BaseClient) {
-        let runtime = tokio::runtime::Runtime::new().unwrap();
-        let response = match self {
-            InfoSubCommand::Version =>
-                runtime.block_on(native_api::info::version::get_version(client)),
-        };
-
-        evaluate_and_print_response(response);
+    async fn process(&self, client: &BaseClient) -> Result<ExitCode, DataverseError> {
+        match self {
+            InfoSubCommand::Version => evaluate_and_print_response(info::get_version(client).await),
+            InfoSubCommand::Server => evaluate_and_print_response(info::get_server(client).await),
+            InfoSubCommand::ApiTerms => evaluate_and_print_response(info::get_api_terms_of_use(client).await),
+            InfoSubCommand::ExportFormats => evaluate_and_print_response(info::get_export_formats(client).await),
+            InfoSubCommand::ZipLimit => evaluate_and_print_response(info::get_zip_download_limit(client).await),
+            InfoSubCommand::MaxEmbargo => evaluate_and_print_response(info::get_max_embargo_duration(client).await),
+        }
     }
 }
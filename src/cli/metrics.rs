@@ -0,0 +1,128 @@
+use std::process::ExitCode;
+
+use serde::Serialize;
+use structopt::StructOpt;
+
+use crate::client::BaseClient;
+use crate::error::DataverseError;
+use crate::native_api::metrics::{breakdown, counts};
+use crate::native_api::metrics::counts::{Metric, MetricPeriod};
+use crate::response::Response;
+
+use super::base::{evaluate_and_print_response, write_output, Matcher};
+use super::stats::ReportFormat;
+
+#[derive(StructOpt, Debug)]
+#[structopt(about = "Retrieve usage metrics of the Dataverse instance")]
+pub enum MetricsSubCommand {
+    #[structopt(about = "Count dataverses, datasets, files or downloads")]
+    Count {
+        #[structopt(help = "Metric to count (dataverses, datasets, files, downloads)")]
+        metric: Metric,
+
+        #[structopt(long, help = "Count up to the end of a month (YYYY-MM)", conflicts_with = "past-days")]
+        to_month: Option<String>,
+
+        #[structopt(long, help = "Count the objects added within the given number of days")]
+        past_days: Option<u32>,
+
+        #[structopt(long, short, help = "Alias of the collection to scope the metric to")]
+        parent: Option<String>,
+
+        #[structopt(long, short, help = "Output format (json, csv)", default_value = "json")]
+        format: ReportFormat,
+    },
+
+    #[structopt(about = "Cumulative counts per month")]
+    Monthly {
+        #[structopt(help = "Metric to count (dataverses, datasets, files, downloads)")]
+        metric: Metric,
+
+        #[structopt(long, short, help = "Alias of the collection to scope the metric to")]
+        parent: Option<String>,
+
+        #[structopt(long, short, help = "Output format (json, csv)", default_value = "json")]
+        format: ReportFormat,
+    },
+
+    #[structopt(about = "Number of datasets per subject")]
+    BySubject {
+        #[structopt(long, short, help = "Alias of the collection to scope the metric to")]
+        parent: Option<String>,
+
+        #[structopt(long, short, help = "Output format (json, csv)", default_value = "json")]
+        format: ReportFormat,
+    },
+
+    #[structopt(about = "Number of dataverses per category")]
+    ByCategory {
+        #[structopt(long, short, help = "Alias of the collection to scope the metric to")]
+        parent: Option<String>,
+
+        #[structopt(long, short, help = "Output format (json, csv)", default_value = "json")]
+        format: ReportFormat,
+    },
+}
+
+impl Matcher for MetricsSubCommand {
+    async fn process(&self, client: &BaseClient) -> Result<ExitCode, DataverseError> {
+        match self {
+            MetricsSubCommand::Count {
+                metric,
+                to_month,
+                past_days,
+                parent,
+                format,
+            } => {
+                let period = match (to_month, past_days) {
+                    (Some(month), _) => MetricPeriod::ToMonth(month.clone()),
+                    (None, Some(days)) => MetricPeriod::PastDays(*days),
+                    (None, None) => MetricPeriod::Total,
+                };
+
+                let response = counts::get_metric(
+                    client,
+                    *metric,
+                    &period,
+                    parent.as_deref(),
+                ).await;
+                let response = response.map(|r| Response {
+                    status: r.status,
+                    data: r.data.map(|count| vec![count]),
+                    message: r.message,
+                    requestUrl: r.requestUrl,
+                    requestMethod: r.requestMethod,
+                });
+                print_metric(response, format)
+            }
+            MetricsSubCommand::Monthly { metric, parent, format } => {
+                let response =
+                    counts::get_monthly_metric(client, *metric, parent.as_deref()).await;
+                print_metric(response, format)
+            }
+            MetricsSubCommand::BySubject { parent, format } => {
+                let response =
+                    breakdown::get_datasets_by_subject(client, parent.as_deref()).await;
+                print_metric(response, format)
+            }
+            MetricsSubCommand::ByCategory { parent, format } => {
+                let response =
+                    breakdown::get_dataverses_by_category(client, parent.as_deref()).await;
+                print_metric(response, format)
+            }
+        }
+    }
+}
+
+// Prints the rows of a metric either as the usual JSON response or as CSV
+fn print_metric<T: Serialize>(
+    response: Result<Response<Vec<T>>, String>,
+    format: &ReportFormat,
+) -> Result<ExitCode, DataverseError> {
+    match (response, format) {
+        (Ok(response), ReportFormat::Csv) if response.status.is_ok() => {
+            write_output(counts::to_csv(&response.data.unwrap_or_default()), &None)
+        }
+        (response, _) => evaluate_and_print_response(response),
+    }
+}
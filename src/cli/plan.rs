@@ -0,0 +1,104 @@
+use std::path::{Path, PathBuf};
+use std::process::ExitCode;
+
+use structopt::StructOpt;
+
+use crate::client::BaseClient;
+use crate::error::DataverseError;
+use crate::plan::Plan;
+
+use super::base::Matcher;
+use super::i18n::tr;
+use super::summary::Session;
+
+#[derive(StructOpt, Debug)]
+#[structopt(about = "Review and apply plans of bulk operations")]
+pub enum PlanSubCommand {
+    #[structopt(about = "Print the actions of a plan without executing them")]
+    Show {
+        #[structopt(help = "Path to the JSON file of the plan")]
+        path: PathBuf,
+    },
+
+    #[structopt(about = "Execute the actions of a reviewed plan")]
+    Apply {
+        #[structopt(help = "Path to the JSON file of the plan")]
+        path: PathBuf,
+
+        #[structopt(long, help = "Path to write a JSON summary of the execution to")]
+        summary: Option<PathBuf>,
+    },
+}
+
+impl Matcher for PlanSubCommand {
+    async fn process(&self, client: &BaseClient) -> Result<ExitCode, DataverseError> {
+        match self {
+            PlanSubCommand::Show { path } => {
+                println!("{}", read_plan(path)?);
+                Ok(ExitCode::SUCCESS)
+            }
+            PlanSubCommand::Apply { path, summary } => {
+                let plan = read_plan(path)?;
+                execute_plan(client, &plan, Some(path), summary).await
+            }
+        }
+    }
+}
+
+/// Executes a plan, printing the outcome of every action along with a session summary.
+///
+/// The `journal` is the file the plan has been stored in, if any. It is reported on failure, as the plan can
+/// be applied again once edited.
+pub(super) async fn execute_plan(
+    client: &BaseClient,
+    plan: &Plan,
+    journal: Option<&Path>,
+    summary: &Option<PathBuf>,
+) -> Result<ExitCode, DataverseError> {
+    let session = Session::start();
+    let executed = plan.execute(client).await;
+
+    let mut failure = None;
+    for outcome in &executed {
+        match &outcome.result {
+            Ok(message) => println!("{}", message),
+            Err(e) => {
+                failure = Some(tr(
+                    "action-failed",
+                    &[
+                        ("action", &outcome.action.action.name()),
+                        ("target", &outcome.action.action),
+                        ("error", e),
+                    ],
+                ))
+            }
+        }
+    }
+
+    let message = tr(
+        "plan-executed",
+        &[("executed", &executed.len()), ("total", &plan.actions.len())],
+    );
+    println!("{}", message);
+
+    let failed = executed.iter().filter(|outcome| outcome.result.is_err()).count();
+    let mut session = session.finish(
+        executed.len() - failed,
+        failed,
+        plan.actions.len() - executed.len(),
+    );
+    if let (Some(_), Some(journal)) = (&failure, journal) {
+        session = session.with_journal(journal);
+    }
+    session.report(summary)?;
+
+    match failure {
+        Some(message) => Err(DataverseError::Api(message)),
+        None => Ok(ExitCode::SUCCESS),
+    }
+}
+
+fn read_plan(path: &PathBuf) -> Result<Plan, DataverseError> {
+    let content = std::fs::read_to_string(path)?;
+    Plan::from_json(&content).map_err(DataverseError::Input)
+}
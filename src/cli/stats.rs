@@ -0,0 +1,107 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::str::FromStr;
+
+use structopt::StructOpt;
+
+use crate::client::BaseClient;
+use crate::error::DataverseError;
+use crate::stats;
+
+#[cfg(feature = "metrics")]
+use super::base::print_data;
+use super::base::{write_output, Matcher};
+
+#[derive(StructOpt, Debug)]
+#[structopt(about = "Aggregate statistics of a Dataverse instance")]
+pub enum StatsSubCommand {
+    #[cfg(feature = "metrics")]
+    #[structopt(about = "Report datasets, files and bytes per collection and publication status")]
+    Report {
+        #[structopt(help = "Alias of the collection to start from", default_value = "root")]
+        root: String,
+
+        #[structopt(long, help = "Only report the totals of the metrics API, without traversing the collections")]
+        totals_only: bool,
+
+        #[structopt(long, short, help = "Format of the report (json, csv)", default_value = "json")]
+        format: ReportFormat,
+
+        #[structopt(long, short, help = "Path to write the report to instead of stdout")]
+        output: Option<PathBuf>,
+    },
+
+    #[structopt(about = "Report files whose embargo ends within the given number of days as CSV")]
+    Embargoes {
+        #[structopt(help = "Alias of the collection to start from", default_value = "root")]
+        root: String,
+
+        #[structopt(long, short, help = "Number of days to look ahead", default_value = "30")]
+        days: i64,
+
+        #[structopt(long, short, help = "Path to write the report to instead of stdout")]
+        output: Option<PathBuf>,
+    },
+
+    #[structopt(about = "Report role assignments grouped by assignee as CSV")]
+    Assignments {
+        #[structopt(help = "Alias of the collection to start from", default_value = "root")]
+        root: String,
+
+        #[structopt(long, short, help = "Only report assignments of this role, e.g. 'admin'")]
+        role: Option<String>,
+
+        #[structopt(long, short, help = "Path to write the report to instead of stdout")]
+        output: Option<PathBuf>,
+    },
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ReportFormat {
+    Json,
+    Csv,
+}
+
+impl FromStr for ReportFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "json" => Ok(ReportFormat::Json),
+            "csv" => Ok(ReportFormat::Csv),
+            _ => Err(format!("Invalid report format: {}", s)),
+        }
+    }
+}
+
+impl Matcher for StatsSubCommand {
+    async fn process(&self, client: &BaseClient) -> Result<ExitCode, DataverseError> {
+        match self {
+            #[cfg(feature = "metrics")]
+            StatsSubCommand::Report { root, totals_only, format, output } => {
+                let report = stats::build_instance_report(client, root, !totals_only)
+                    .await
+                    .map_err(DataverseError::Request)?;
+
+                match (format, output) {
+                    (ReportFormat::Json, None) => print_data(report),
+                    (ReportFormat::Json, Some(_)) => write_output(report.to_json(), output),
+                    (ReportFormat::Csv, _) => write_output(report.to_csv(), output),
+                }
+            }
+            StatsSubCommand::Embargoes { root, days, output } => {
+                let report = stats::build_embargo_report(client, root, *days)
+                    .await
+                    .map_err(DataverseError::Request)?;
+
+                write_output(report.to_csv(), output)
+            }
+            StatsSubCommand::Assignments { root, role, output } => {
+                let report = stats::build_assignment_report(client, root, role.as_deref())
+                    .await
+                    .map_err(DataverseError::Request)?;
+
+                write_output(report.to_csv(), output)
+            }
+        }
+    }
+}
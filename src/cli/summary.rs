@@ -0,0 +1,160 @@
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use serde::Serialize;
+
+use crate::error::DataverseError;
+use crate::verbosity::{verbosity, Verbosity};
+
+use super::i18n::tr;
+
+/// Tracks the duration of a batch command to summarise it once it has finished.
+#[derive(Debug)]
+pub struct Session {
+    started: Instant,
+}
+
+impl Session {
+    /// Starts a session at the current instant.
+    pub fn start() -> Self {
+        Session { started: Instant::now() }
+    }
+
+    /// Summarises the operations of the session, which are expected to be finished by now.
+    pub fn finish(&self, succeeded: usize, failed: usize, skipped: usize) -> SessionSummary {
+        SessionSummary {
+            attempted: succeeded + failed,
+            succeeded,
+            failed,
+            skipped,
+            bytes: 0,
+            elapsed_secs: self.started.elapsed().as_secs_f64(),
+            journal: None,
+        }
+    }
+}
+
+/// At-a-glance result of a batch command, such as an upload of many files or the execution of a plan.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SessionSummary {
+    /// Number of operations that have been attempted
+    pub attempted: usize,
+    /// Number of operations that have succeeded
+    pub succeeded: usize,
+    /// Number of operations that have failed
+    pub failed: usize,
+    /// Number of operations that have not been attempted
+    pub skipped: usize,
+    /// Number of bytes uploaded or downloaded
+    pub bytes: u64,
+    /// Duration of the command in seconds
+    pub elapsed_secs: f64,
+    /// File to resume the command from, e.g. after fixing the cause of failures
+    pub journal: Option<PathBuf>,
+}
+
+impl SessionSummary {
+    /// Sets the number of transferred bytes to the total size of the given local files.
+    ///
+    /// Files that cannot be read, e.g. because they have been moved in the meantime, are not counted.
+    pub fn with_bytes_of(mut self, paths: impl IntoIterator<Item = impl AsRef<Path>>) -> Self {
+        self.bytes = paths
+            .into_iter()
+            .filter_map(|path| fs::metadata(path).ok())
+            .map(|metadata| metadata.len())
+            .sum();
+        self
+    }
+
+    /// Sets the file to resume the command from.
+    pub fn with_journal(mut self, journal: &Path) -> Self {
+        self.journal = Some(journal.to_path_buf());
+        self
+    }
+
+    /// Prints the summary to stderr, which keeps stdout free for identifiers and data, and writes it as
+    /// JSON to the given path, if any. Quiet sessions only write the JSON.
+    pub fn report(&self, output: &Option<PathBuf>) -> Result<(), DataverseError> {
+        if verbosity() > Verbosity::Quiet {
+            eprintln!("\n{}", self);
+        }
+
+        if let Some(path) = output {
+            let json = serde_json::to_string_pretty(self)
+                .map_err(|e| DataverseError::Input(e.to_string()))?;
+            fs::write(path, json)?;
+        }
+
+        Ok(())
+    }
+}
+
+impl std::fmt::Display for SessionSummary {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let summary = tr(
+            "session-summary",
+            &[
+                ("attempted", &self.attempted),
+                ("succeeded", &self.succeeded),
+                ("failed", &self.failed),
+                ("skipped", &self.skipped),
+                ("bytes", &format_bytes(self.bytes)),
+                ("elapsed", &format!("{:.1}", self.elapsed_secs)),
+            ],
+        );
+        write!(f, "{}", summary)?;
+
+        if let Some(journal) = &self.journal {
+            write!(f, "\n{}", tr("session-journal", &[("path", &journal.display())]))?;
+        }
+
+        Ok(())
+    }
+}
+
+// Formats a number of bytes with a binary unit, e.g. 1.5 MiB
+pub(crate) fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+
+    let mut size = bytes as f64;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    match unit {
+        0 => format!("{} {}", bytes, UNITS[0]),
+        _ => format!("{:.1} {}", size, UNITS[unit]),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use super::{format_bytes, Session};
+
+    /// Tests that failed operations count as attempted, while skipped ones do not.
+    #[test]
+    fn test_finish_session() {
+        let summary = Session::start()
+            .finish(3, 1, 2)
+            .with_bytes_of(&[PathBuf::from("tests/fixtures/file.txt")]);
+
+        assert_eq!(summary.attempted, 4);
+        assert_eq!(summary.skipped, 2);
+        assert!(summary.bytes > 0);
+        assert!(summary.journal.is_none());
+    }
+
+    /// Tests the formatting of byte counts with binary units.
+    #[test]
+    fn test_format_bytes() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024 * 1024), "5.0 GiB");
+    }
+}
@@ -0,0 +1,181 @@
+use serde_json::{Map, Value};
+
+// Cells longer than this are cut off, such that a single long description
+// does not stretch the whole table
+const MAX_CELL_WIDTH: usize = 48;
+
+/// Renders data as a plain text table for reading in a terminal.
+///
+/// Lists of objects, such as collection contents or the files of a dataset, become one row per element with
+/// a column per field. Nested objects are flattened into dotted columns, e.g. `dataFile.id`. Any other object
+/// becomes a table of its fields and values. Objects with a single field are unwrapped first, such that lists
+/// reduced with `--fields` are rendered as rows as well.
+pub fn render_table(value: &Value) -> String {
+    let mut value = value;
+    while let Value::Object(map) = value {
+        match map.len() {
+            1 => value = map.values().next().unwrap(),
+            _ => break,
+        }
+    }
+
+    match value {
+        Value::Array(items) if items.iter().all(Value::is_object) && !items.is_empty() => {
+            let rows: Vec<Vec<(String, String)>> = items.iter().map(flatten).collect();
+
+            // Columns in the order of their first occurrence
+            let mut columns: Vec<String> = vec![];
+            for (column, _) in rows.iter().flatten() {
+                if !columns.contains(column) {
+                    columns.push(column.clone());
+                }
+            }
+
+            let cells = rows
+                .iter()
+                .map(|row| {
+                    columns
+                        .iter()
+                        .map(|column| {
+                            row.iter()
+                                .find(|(name, _)| name == column)
+                                .map(|(_, cell)| cell.clone())
+                                .unwrap_or_default()
+                        })
+                        .collect()
+                })
+                .collect();
+
+            format_table(columns, cells)
+        }
+        Value::Array(items) => items.iter().map(cell).collect::<Vec<_>>().join("\n"),
+        Value::Object(_) => {
+            let cells = flatten(value).into_iter().map(|(key, value)| vec![key, value]).collect();
+            format_table(vec!["field".to_string(), "value".to_string()], cells)
+        }
+        scalar => cell(scalar),
+    }
+}
+
+// Flattens nested objects into dotted keys along with the text of their values
+fn flatten(value: &Value) -> Vec<(String, String)> {
+    fn visit(prefix: &str, map: &Map<String, Value>, fields: &mut Vec<(String, String)>) {
+        for (key, value) in map {
+            let key = match prefix.is_empty() {
+                true => key.clone(),
+                false => format!("{}.{}", prefix, key),
+            };
+
+            match value {
+                Value::Object(nested) => visit(&key, nested, fields),
+                value => fields.push((key, cell(value))),
+            }
+        }
+    }
+
+    let mut fields = vec![];
+    match value {
+        Value::Object(map) => visit("", map, &mut fields),
+        value => fields.push(("value".to_string(), cell(value))),
+    }
+    fields
+}
+
+fn cell(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(text) => text.clone(),
+        Value::Array(items) if items.iter().all(|item| !item.is_object() && !item.is_array()) => {
+            items.iter().map(cell).collect::<Vec<_>>().join(", ")
+        }
+        Value::Array(items) => format!("[{} items]", items.len()),
+        value => value.to_string(),
+    }
+}
+
+fn format_table(columns: Vec<String>, rows: Vec<Vec<String>>) -> String {
+    let truncate = |text: &str| -> String {
+        let text = text.replace('\n', " ");
+        match text.chars().count() > MAX_CELL_WIDTH {
+            true => format!("{}…", text.chars().take(MAX_CELL_WIDTH - 1).collect::<String>()),
+            false => text,
+        }
+    };
+
+    let columns: Vec<String> = columns.iter().map(|column| truncate(column)).collect();
+    let rows: Vec<Vec<String>> = rows
+        .iter()
+        .map(|row| row.iter().map(|cell| truncate(cell)).collect())
+        .collect();
+
+    let widths: Vec<usize> = (0..columns.len())
+        .map(|index| {
+            rows.iter()
+                .map(|row| row[index].chars().count())
+                .chain(std::iter::once(columns[index].chars().count()))
+                .max()
+                .unwrap_or_default()
+        })
+        .collect();
+
+    let line = |cells: &[String]| -> String {
+        cells
+            .iter()
+            .zip(&widths)
+            .map(|(cell, width)| format!("{:<width$}", cell, width = width))
+            .collect::<Vec<_>>()
+            .join("  ")
+            .trim_end()
+            .to_string()
+    };
+
+    let separator: Vec<String> = widths.iter().map(|width| "-".repeat(*width)).collect();
+    std::iter::once(line(&columns))
+        .chain(std::iter::once(line(&separator)))
+        .chain(rows.iter().map(|row| line(row)))
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::render_table;
+
+    /// Tests that lists of objects become rows with flattened columns.
+    #[test]
+    fn test_render_list_as_table() {
+        let files = json!({
+            "latestVersion": {
+                "files": [
+                    {"label": "data.csv", "dataFile": {"id": 1, "filesize": 120}},
+                    {"label": "readme.md", "directoryLabel": "docs", "dataFile": {"id": 2}, "categories": ["Documentation"]}
+                ]
+            }
+        });
+
+        let table = render_table(&files);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert_eq!(lines[0], "dataFile.filesize  dataFile.id  label      categories     directoryLabel");
+        assert_eq!(lines[2], "120                1            data.csv");
+        assert_eq!(lines[3], "                   2            readme.md  Documentation  docs");
+    }
+
+    /// Tests that single objects become a table of their fields and long values are cut off.
+    #[test]
+    fn test_render_object_as_table() {
+        let dataset = json!({"id": 7, "description": "x".repeat(60), "keywords": ["a", "b"], "authors": [{}, {}]});
+
+        let table = render_table(&dataset);
+        let lines: Vec<&str> = table.lines().collect();
+
+        assert!(lines[0].starts_with("field"));
+        assert!(lines.contains(&"id           7"));
+        assert!(lines.contains(&"keywords     a, b"));
+        assert!(lines.contains(&"authors      [2 items]"));
+        assert!(lines.iter().any(|line| line.ends_with('…')));
+        assert_eq!(render_table(&json!("6.2")), "6.2");
+    }
+}
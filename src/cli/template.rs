@@ -0,0 +1,40 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+use structopt::StructOpt;
+
+use crate::client::BaseClient;
+use crate::error::DataverseError;
+use crate::schemas::Schema;
+
+use super::base::{write_output, Matcher};
+
+#[derive(StructOpt, Debug)]
+#[structopt(about = "Print the JSON Schemas of request bodies for use in other tools")]
+pub struct TemplateCommand {
+    #[structopt(
+        long,
+        help = "Name of the schema to print, e.g. 'dataset-create'. Lists all schemas if omitted"
+    )]
+    schema: Option<Schema>,
+
+    #[structopt(long, short, help = "Path to write the schema to instead of stdout")]
+    output: Option<PathBuf>,
+}
+
+impl Matcher for TemplateCommand {
+    async fn process(&self, _client: &BaseClient) -> Result<ExitCode, DataverseError> {
+        let Some(schema) = self.schema else {
+            for schema in Schema::ALL {
+                println!("{}", schema);
+            }
+            return Ok(ExitCode::SUCCESS);
+        };
+
+        let content = serde_json::to_string_pretty(&schema.to_value())
+            .map(|content| content + "\n")
+            .map_err(|e| e.to_string());
+
+        write_output(content, &self.output)
+    }
+}
@@ -0,0 +1,53 @@
+use std::process::ExitCode;
+
+use structopt::StructOpt;
+
+use crate::client::BaseClient;
+use crate::error::DataverseError;
+use crate::native_api::users::{me, token};
+
+use super::base::{evaluate_and_print_fields, evaluate_and_print_response, Matcher};
+use super::fields::FieldSelection;
+
+#[derive(StructOpt, Debug)]
+#[structopt(about = "Handle the user of the API token")]
+pub enum UserSubCommand {
+    #[structopt(about = "Retrieve the user the API token belongs to")]
+    Me {
+        #[structopt(
+            long,
+            help = "Comma-separated dotted paths of the fields to print, e.g. 'identifier,email'"
+        )]
+        fields: Option<FieldSelection>,
+    },
+
+    #[structopt(about = "Show the expiration of the API token, or recreate or revoke it")]
+    Token {
+        #[structopt(long, help = "Replace the token with a new one", conflicts_with = "revoke")]
+        recreate: bool,
+
+        #[structopt(long, help = "Revoke the token")]
+        revoke: bool,
+    },
+}
+
+impl Matcher for UserSubCommand {
+    async fn process(&self, client: &BaseClient) -> Result<ExitCode, DataverseError> {
+        match self {
+            UserSubCommand::Me { fields } => {
+                let response = me::get_me(client).await;
+                evaluate_and_print_fields(response, fields)
+            }
+            UserSubCommand::Token { recreate, revoke } => {
+                let response = if *recreate {
+                    token::recreate_token(client, true).await
+                } else if *revoke {
+                    token::revoke_token(client).await
+                } else {
+                    token::get_token_expiration(client).await
+                };
+                evaluate_and_print_response(response)
+            }
+        }
+    }
+}
@@ -1,32 +1,133 @@
 use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
 
-use atty::Stream;
-use colored::Colorize;
 use reqwest::Client;
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::{Certificate, Proxy};
 use reqwest::Url;
 use serde::Deserialize;
 
+use crate::auth::{Auth, TokenRefresh};
+use crate::error::DataverseError;
+#[cfg(feature = "record")]
+use crate::record::Recorder;
 use crate::request::RequestType;
 use crate::response::Response;
+#[cfg(feature = "fs")]
+use crate::queue::DatasetQueues;
+#[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
+use crate::retry::check_lock;
+#[cfg(feature = "fs")]
+use crate::retry::LockRetry;
+use crate::transport::{ReqwestTransport, Transport};
+#[cfg(feature = "cli")]
+use crate::verbosity::{verbosity, Verbosity};
+
+// Bodies of this size are reported, as sending them may take a while
+const LARGE_BODY_SIZE: usize = 1024 * 1024;
 
 pub struct BaseClient {
     base_url: Url,
-    api_token: Option<String>,
+    auth: RwLock<Auth>,
+    token_refresh: Option<TokenRefresh>,
     client: Client,
+    transport: Arc<dyn Transport>,
+    compression: Option<usize>,
+    #[cfg(feature = "fs")]
+    lock_retry: Option<LockRetry>,
+    #[cfg(feature = "fs")]
+    queues: DatasetQueues,
+    #[cfg(feature = "record")]
+    recorder: Option<std::sync::Arc<Recorder>>,
 }
 
 // This is the base client that will be used to make requests to the API.
 // Its acts as a wrapper around the reqwest::blocking::Client and provides
 // methods to make GET, POST, PUT, and DELETE requests.
 impl BaseClient {
-    pub fn new(base_url: &String, api_token: Option<&String>) -> Result<Self, reqwest::Error> {
-        let base_url = Url::parse(base_url).unwrap();
-        let client = Client::new();
-        Ok(BaseClient {
-            base_url,
-            api_token: api_token.map(|s| s.to_owned().to_string()),
-            client,
-        })
+    pub fn new(base_url: &str, api_token: Option<&String>) -> Result<Self, DataverseError> {
+        let builder = BaseClient::builder(base_url);
+        match api_token {
+            Some(api_token) => builder.api_token(api_token).build(),
+            None => builder.build(),
+        }
+    }
+
+    /// Creates a builder to configure timeouts, proxies and TLS of the client.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use std::time::Duration;
+    /// use dataverse::prelude::*;
+    ///
+    /// let client = BaseClient::builder("https://dataverse.example.edu")
+    ///     .api_token("api_token")
+    ///     .connect_timeout(Duration::from_secs(10))
+    ///     .timeout(Duration::from_secs(300))
+    ///     .proxy("http://proxy.example.edu:3128")
+    ///     .user_agent("ingest-pipeline/1.0")
+    ///     .build()
+    ///     .expect("Failed to create client");
+    /// ```
+    pub fn builder(base_url: &str) -> BaseClientBuilder {
+        BaseClientBuilder {
+            base_url: base_url.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn base_url(&self) -> &Url {
+        &self.base_url
+    }
+
+    /// Returns the current credentials, which reflect refreshed bearer tokens.
+    pub fn auth(&self) -> Auth {
+        self.auth.read().unwrap().clone()
+    }
+
+    // Requests to the storage of an instance, such as presigned upload URLs,
+    // are sent without the API token
+    #[cfg(feature = "directupload")]
+    pub(crate) fn http_client(&self) -> &Client {
+        &self.client
+    }
+
+    // Requests adding files to a dataset wait in its queue, as the instance
+    // rejects additions while the dataset is locked by a previous one
+    #[cfg(feature = "fs")]
+    pub(crate) fn queues(&self) -> &DatasetQueues {
+        &self.queues
+    }
+
+    // Sends all requests of the instance through the given transport, e.g. a mock
+    // serving fixtures in tests. Requests to storage always use the network.
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Arc::new(transport);
+        self
+    }
+
+    // Compresses JSON and multipart bodies without files of at least `threshold`
+    // bytes with gzip. The instance has to accept gzip encoded requests.
+    pub fn with_compression(mut self, threshold: usize) -> Self {
+        self.compression = Some(threshold);
+        self
+    }
+
+    // Sends requests rejected due to a dataset lock, e.g. during an ingest, again
+    // until the lock is released or the retry times out
+    #[cfg(feature = "fs")]
+    pub fn with_lock_retry(mut self, retry: LockRetry) -> Self {
+        self.lock_retry = Some(retry);
+        self
+    }
+
+    // Records all requests and their responses as fixtures
+    #[cfg(feature = "record")]
+    pub fn with_recorder(mut self, recorder: Recorder) -> Self {
+        self.recorder = Some(std::sync::Arc::new(recorder));
+        self
     }
 
     pub async fn get(
@@ -83,22 +184,228 @@ impl BaseClient {
     ) -> Result<reqwest::Response, reqwest::Error> {
         // Process the URL and build the request based on the context
         let url = self.base_url.join(path).unwrap();
-        let request = context
-            .to_request(self.client.request(method, url.clone())).await;
-        let request = match parameters {
-            Some(parameters) => request.query(&parameters),
-            None => request,
+        let compressed = self.compression.and_then(|threshold| context.compress(threshold));
+
+        print_call(&method, url.to_string());
+        match &compressed {
+            Some(compressed) => print_body_size(compressed.original_size(), Some(compressed.size())),
+            None => match context.body_size() {
+                Some(size) if size >= LARGE_BODY_SIZE => print_body_size(size, None),
+                _ => {}
+            },
+        }
+
+        let send = || async {
+            let request = self.client.request(method.clone(), url.clone());
+            let request = match &compressed {
+                Some(compressed) => compressed.to_request(request),
+                None => context.to_request(request).await,
+            };
+            let request = match &parameters {
+                Some(parameters) => request.query(parameters),
+                None => request,
+            };
+
+            // The lock is released before sending, as the credentials may be refreshed meanwhile
+            let request = self.auth.read().unwrap().apply(request);
+            match request.build() {
+                Ok(request) => self.transport.send(request).await,
+                Err(error) => Err(error),
+            }
         };
 
-        print_call(url.to_string());
+        let mut response = send().await;
+
+        // Expired bearer tokens are refreshed once and the request is sent again
+        if let (Ok(rejected), Some(refresh)) = (&response, &self.token_refresh) {
+            let is_bearer = matches!(*self.auth.read().unwrap(), Auth::BearerToken(_));
+            if rejected.status() == reqwest::StatusCode::UNAUTHORIZED && is_bearer {
+                match refresh.call().await {
+                    Ok(token) => {
+                        *self.auth.write().unwrap() = Auth::BearerToken(token);
+                        response = send().await;
+                    }
+                    Err(error) => print_error(format!("Failed to refresh the bearer token: {}", error)),
+                }
+            }
+        }
+
+        #[cfg(all(feature = "fs", not(target_arch = "wasm32")))]
+        if let Some(retry) = &self.lock_retry {
+            let started = std::time::Instant::now();
+            for attempt in 0.. {
+                let (checked, locked) = check_lock(response?).await?;
+                response = Ok(checked);
+
+                match retry.delay(attempt, started.elapsed()) {
+                    Some(delay) if locked => {
+                        print_lock_retry(delay);
+                        tokio::time::sleep(delay).await;
+                        response = send().await;
+                    }
+                    _ => break,
+                }
+            }
+        }
+
+        #[cfg(feature = "record")]
+        if let Some(recorder) = &self.recorder {
+            return recorder
+                .record(&method, &url, &parameters, context, response?)
+                .await;
+        }
+
+        response
+    }
+}
+
+/// Builder of a `BaseClient`, created by `BaseClient::builder`.
+///
+/// Options that are not set keep the defaults of `reqwest`, i.e. no timeouts and proxies
+/// taken from the `HTTP_PROXY` and `HTTPS_PROXY` environment variables. On `wasm32`, the
+/// requests are sent by the browser and all options except the credentials are ignored.
+#[derive(Default)]
+pub struct BaseClientBuilder {
+    base_url: String,
+    auth: Auth,
+    token_refresh: Option<TokenRefresh>,
+    connect_timeout: Option<Duration>,
+    timeout: Option<Duration>,
+    proxy: Option<String>,
+    root_certificates: Vec<Vec<u8>>,
+    accept_invalid_certs: bool,
+    user_agent: Option<String>,
+    transport: Option<Arc<dyn Transport>>,
+}
+
+impl BaseClientBuilder {
+    /// Sets the API token sent with every request to the instance.
+    pub fn api_token(mut self, api_token: &str) -> Self {
+        self.auth = Auth::ApiToken(api_token.to_string());
+        self
+    }
+
+    /// Sets the credentials sent with every request to the instance, e.g. an OIDC bearer token.
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Sets the function to obtain a new bearer token with once the instance rejects the current one.
+    pub fn token_refresh(mut self, refresh: TokenRefresh) -> Self {
+        self.token_refresh = Some(refresh);
+        self
+    }
+
+    /// Sets the timeout for establishing connections.
+    pub fn connect_timeout(mut self, timeout: Duration) -> Self {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Sets the timeout of whole requests, from connecting until the response body has been read.
+    /// Keep in mind that uploads and downloads of large files take a while.
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Sends all requests through the proxy at the given URL, e.g. `http://proxy:3128`.
+    pub fn proxy(mut self, url: &str) -> Self {
+        self.proxy = Some(url.to_string());
+        self
+    }
+
+    /// Trusts the PEM encoded root certificate in addition to the system roots, e.g. the
+    /// certificate authority of an institution. Can be called multiple times.
+    pub fn root_certificate(mut self, pem: &[u8]) -> Self {
+        self.root_certificates.push(pem.to_vec());
+        self
+    }
+
+    /// Accepts invalid TLS certificates, such as self-signed certificates of on-premise test
+    /// servers.
+    ///
+    /// # Warning
+    ///
+    /// Any certificate is trusted, which exposes the API token to whoever intercepts the
+    /// connection. Never enable this for production instances.
+    pub fn danger_accept_invalid_certs(mut self, accept: bool) -> Self {
+        self.accept_invalid_certs = accept;
+        self
+    }
+
+    /// Sets the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: &str) -> Self {
+        self.user_agent = Some(user_agent.to_string());
+        self
+    }
+
+    /// Sends the requests through the given transport instead of the network, which ignores
+    /// the timeout, proxy and TLS options.
+    pub fn transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
 
-        // Add the API token if it exists
-        let request = match &self.api_token {
-            Some(api_token) => request.header("X-Dataverse-key", api_token),
-            None => request,
+    /// Builds the client.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `BaseClient`, or a `DataverseError` if the base URL, the proxy URL
+    /// or a root certificate is invalid, or the TLS backend cannot be initialized.
+    pub fn build(self) -> Result<BaseClient, DataverseError> {
+        let base_url = Url::parse(&self.base_url)
+            .map_err(|err| DataverseError::Input(format!("Invalid base URL '{}': {}", self.base_url, err)))?;
+
+        #[cfg(not(target_arch = "wasm32"))]
+        let client = {
+            let mut client = Client::builder().danger_accept_invalid_certs(self.accept_invalid_certs);
+            if let Some(timeout) = self.connect_timeout {
+                client = client.connect_timeout(timeout);
+            }
+            if let Some(timeout) = self.timeout {
+                client = client.timeout(timeout);
+            }
+            if let Some(proxy) = &self.proxy {
+                client = client.proxy(Proxy::all(proxy)?);
+            }
+            for pem in &self.root_certificates {
+                client = client.add_root_certificate(Certificate::from_pem(pem)?);
+            }
+            if let Some(user_agent) = &self.user_agent {
+                client = client.user_agent(user_agent);
+            }
+            client.build()?
+        };
+
+        // Browsers manage connections, proxies, certificates and the user agent themselves
+        #[cfg(target_arch = "wasm32")]
+        let client = {
+            let _ = (self.connect_timeout, self.timeout, &self.proxy, &self.root_certificates);
+            let _ = (self.accept_invalid_certs, &self.user_agent);
+            Client::builder().build()?
         };
 
-        request.send().await
+        let transport = match self.transport {
+            Some(transport) => transport,
+            None => Arc::new(ReqwestTransport::new(client.clone())),
+        };
+
+        Ok(BaseClient {
+            base_url,
+            auth: RwLock::new(self.auth),
+            token_refresh: self.token_refresh,
+            client,
+            transport,
+            compression: None,
+            #[cfg(feature = "fs")]
+            lock_retry: None,
+            #[cfg(feature = "fs")]
+            queues: DatasetQueues::default(),
+            #[cfg(feature = "record")]
+            recorder: None,
+        })
     }
 }
 
@@ -110,48 +417,78 @@ where
     T: for<'de> Deserialize<'de>,
 {
     // Check if the response is an error
-    let response = match response {
-        Ok(response) => response,
-        Err(err) => {
-            print_error(err.to_string());
-            panic!();
-        }
-    };
+    let response = response.map_err(|err| err.to_string())?;
 
     // Try to read the response into the response struct
-    let raw_content = response.text().await.unwrap();
-    let json = serde_json::from_str::<Response<T>>(&raw_content);
-
-    match json {
-        Ok(json) => Ok(json),
-        Err(err) => {
-            print_error(
-                format!(
-                    "{} - {}",
-                    err.to_string().red().bold(),
-                    raw_content.red().bold(),
-                )
-                    .to_string(),
-            );
-            panic!("{}", err.to_string());
-        }
-    }
+    let raw_content = response.text().await.map_err(|err| err.to_string())?;
+
+    serde_json::from_str::<Response<T>>(&raw_content).map_err(|err| format!("{} - {}", err, raw_content))
 }
 
+// Terminal output is only produced for the command line interface, such that
+// services embedding the client are not cluttered with it. It is written to
+// stderr, such that stdout only holds the data of responses.
+#[cfg(feature = "cli")]
 fn print_error(error: String) {
-    println!("\n{} {}\n", "Error:".red().bold(), error, );
+    use colored::Colorize;
+    eprintln!("\n{} {}\n", "Error:".red().bold(), error.red().bold());
+}
+
+#[cfg(not(feature = "cli"))]
+fn print_error(_error: String) {}
+
+// Diagnostics are shown on terminals, or always when verbose
+#[cfg(feature = "cli")]
+fn show_diagnostics() -> bool {
+    match verbosity() {
+        Verbosity::Quiet => false,
+        Verbosity::Normal => atty::is(atty::Stream::Stderr),
+        Verbosity::Verbose => true,
+    }
+}
+
+#[cfg(feature = "cli")]
+fn print_call(method: &reqwest::Method, url: String) {
+    use colored::Colorize;
+    if !show_diagnostics() {
+        return;
+    }
+
+    match verbosity() {
+        Verbosity::Verbose => eprintln!("{}: {} {}", "Calling".to_string().blue().bold(), method, url),
+        _ => eprintln!("{}: {}", "Calling".to_string().blue().bold(), url),
+    }
+}
+
+#[cfg(not(feature = "cli"))]
+fn print_call(_method: &reqwest::Method, _url: String) {}
+
+#[cfg(feature = "cli")]
+fn print_body_size(size: usize, compressed: Option<usize>) {
+    use colored::Colorize;
+    if show_diagnostics() {
+        let size = match compressed {
+            Some(compressed) => format!("{} bytes (gzip: {} bytes)", size, compressed),
+            None => format!("{} bytes", size),
+        };
+        eprintln!("{}: {}", "Sending".to_string().blue().bold(), size);
+    }
 }
 
-fn print_call(url: String) {
-    if atty::is(Stream::Stdout) {
-        println!(
-            "{}: {}",
-            "Calling".to_string().blue().bold(),
-            url.to_string()
-        );
+#[cfg(not(feature = "cli"))]
+fn print_body_size(_size: usize, _compressed: Option<usize>) {}
+
+#[cfg(feature = "cli")]
+fn print_lock_retry(delay: Duration) {
+    use colored::Colorize;
+    if show_diagnostics() {
+        eprintln!("{}: dataset is locked, retrying in {:.1}s", "Waiting".to_string().yellow().bold(), delay.as_secs_f64());
     }
 }
 
+#[cfg(all(feature = "fs", not(feature = "cli")))]
+fn print_lock_retry(_delay: Duration) {}
+
 #[cfg(test)]
 mod tests {
     use httpmock::prelude::*;
@@ -289,4 +626,63 @@ mod tests {
 
         mock.assert();
     }
+
+    #[tokio::test]
+    async fn test_builder_options() {
+        let client = BaseClient::builder(&MOCK_SERVER.base_url())
+            .api_token("token")
+            .user_agent("pipeline/1.0")
+            .timeout(std::time::Duration::from_millis(200))
+            .build()
+            .unwrap();
+
+        let mock = MOCK_SERVER.mock(|when, then| {
+            when.method(httpmock::Method::GET)
+                .path("/test_builder")
+                .header("User-Agent", "pipeline/1.0")
+                .header("X-Dataverse-key", "token");
+            then.status(200).body("test");
+        });
+        let slow = MOCK_SERVER.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/test_timeout");
+            then.status(200).delay(std::time::Duration::from_secs(2));
+        });
+
+        let response = client.get("test_builder", None, &RequestType::Plain).await;
+        assert!(response.is_ok());
+        mock.assert();
+
+        let response = client.get("test_timeout", None, &RequestType::Plain).await;
+        assert!(response.unwrap_err().is_timeout());
+        slow.assert();
+
+        let invalid = BaseClient::builder(&MOCK_SERVER.base_url())
+            .root_certificate(b"not a certificate")
+            .build();
+        assert!(invalid.is_err());
+
+        let invalid = BaseClient::new("not a url", None);
+        assert!(matches!(invalid, Err(DataverseError::Input(_))));
+    }
+
+    #[tokio::test]
+    async fn test_evaluate_response_errors() {
+        let client = BaseClient::new(&MOCK_SERVER.base_url(), None).unwrap();
+
+        let mock = MOCK_SERVER.mock(|when, then| {
+            when.method(httpmock::Method::GET).path("/test_no_json");
+            then.status(502).body("<html><body>Bad Gateway</body></html>");
+        });
+
+        // Bodies that are no JSON, such as error pages of proxies, are returned as errors
+        let response = client.get("test_no_json", None, &RequestType::Plain).await;
+        let error = evaluate_response::<ExampleBody>(response).await.unwrap_err();
+        assert!(error.contains("Bad Gateway"));
+        mock.assert();
+
+        // So are unreachable instances
+        let client = BaseClient::new("http://127.0.0.1:1", None).unwrap();
+        let response = client.get("test", None, &RequestType::Plain).await;
+        assert!(evaluate_response::<ExampleBody>(response).await.is_err());
+    }
 }
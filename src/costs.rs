@@ -0,0 +1,324 @@
+use std::collections::{BTreeMap, HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::BaseClient,
+    identifier::Identifier,
+    native_api::collection::tree::walk_tree,
+    native_api::dataset::edit::Dataset,
+    native_api::dataset::get::{list_dataset_versions, GetDatasetOptions},
+};
+
+// Costs are quoted per GB-month, where a GB refers to 10^9 bytes
+const BYTES_PER_GB: f64 = 1_000_000_000.0;
+
+/// A cost model assigning a price per GB-month to each storage driver.
+///
+/// Rates are read from a TOML file such as the following, where the keys of `stores` are the
+/// storage driver IDs found in the storage identifiers of files (e.g. `s3://bucket:...`):
+///
+/// ```toml
+/// currency = "EUR"
+/// default = 0.02
+///
+/// [stores]
+/// s3 = 0.023
+/// file = 0.01
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct StorageRates {
+    pub currency: Option<String>,
+    pub default: Option<f64>,
+    #[serde(default)]
+    pub stores: HashMap<String, f64>,
+}
+
+impl StorageRates {
+    /// Parses the rates from the content of a TOML file.
+    pub fn from_toml(content: &str) -> Result<Self, String> {
+        toml::from_str(content).map_err(|e| e.to_string())
+    }
+
+    /// Returns the rate of a store, falling back to the default rate.
+    pub fn rate(&self, store: &str) -> Option<f64> {
+        self.stores.get(store).copied().or(self.default)
+    }
+}
+
+/// Estimated monthly storage costs of a collection tree.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CostReport {
+    pub root: String,
+    pub currency: Option<String>,
+    pub total_bytes: u64,
+    pub total_cost: f64,
+    pub collections: Vec<CollectionCost>,
+}
+
+/// Storage sizes and estimated monthly costs of a single collection, not including its sub-collections.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CollectionCost {
+    pub collection: String,
+    pub title: Option<String>,
+    pub parent: Option<String>,
+    pub bytes_by_store: BTreeMap<String, u64>,
+    pub cost: f64,
+}
+
+impl CostReport {
+    /// Serializes the report into CSV, one row per collection and store.
+    pub fn to_csv(&self, rates: &StorageRates) -> Result<String, String> {
+        let mut writer = csv::Writer::from_writer(vec![]);
+        writer
+            .write_record(["collection", "title", "parent", "store", "bytes", "rate", "cost"])
+            .map_err(|e| e.to_string())?;
+
+        for collection in &self.collections {
+            for (store, bytes) in &collection.bytes_by_store {
+                let rate = rates.rate(store).unwrap_or_default();
+                writer
+                    .write_record([
+                        collection.collection.clone(),
+                        collection.title.clone().unwrap_or_default(),
+                        collection.parent.clone().unwrap_or_default(),
+                        store.clone(),
+                        bytes.to_string(),
+                        rate.to_string(),
+                        format!("{:.2}", cost(*bytes, rate)),
+                    ])
+                    .map_err(|e| e.to_string())?;
+            }
+        }
+
+        let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+        String::from_utf8(bytes).map_err(|e| e.to_string())
+    }
+
+    // Prices the storage of a collection and adds it to the totals of the report
+    fn add(&mut self, mut collection: CollectionCost, rates: &StorageRates) -> Result<(), String> {
+        for (store, bytes) in &collection.bytes_by_store {
+            let rate = rates
+                .rate(store)
+                .ok_or_else(|| format!("No rate given for store '{}' and no default rate set", store))?;
+
+            collection.cost += cost(*bytes, rate);
+            self.total_bytes += bytes;
+        }
+
+        self.total_cost += collection.cost;
+        self.collections.push(collection);
+
+        Ok(())
+    }
+}
+
+/// Estimates the monthly storage costs of a collection and all of its sub-collections.
+///
+/// This asynchronous function traverses the collection tree starting at the given collection and sums up the
+/// sizes of the files of every dataset per storage driver. Files of all versions are included, as files that
+/// have been removed from the draft or replaced are kept as long as a published version refers to them. Files
+/// shared between versions are counted once. The sizes are then priced with the given `StorageRates`, which
+/// allows to charge back storage costs per collection.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `root` - A string slice that holds the alias or ID of the collection to start from.
+/// * `rates` - A reference to the `StorageRates` to price the storage with.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `CostReport` on success, or a `String` error message on failure or if a store
+/// without a rate has been found and no default rate is given.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::costs::{build_cost_report, StorageRates};
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let rates = StorageRates::from_toml("default = 0.02")?;
+/// let report = build_cost_report(&client, "root", &rates).await?;
+///
+/// println!("{}", report.to_csv(&rates)?);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn build_cost_report(
+    client: &BaseClient,
+    root: &str,
+    rates: &StorageRates,
+) -> Result<CostReport, String> {
+    let mut report = CostReport {
+        root: root.to_string(),
+        currency: rates.currency.clone(),
+        ..Default::default()
+    };
+
+    for node in walk_tree(client, root, 1).await? {
+        let datasets = node.dataset_ids();
+        let mut collection = CollectionCost {
+            collection: node.collection,
+            title: node.title,
+            parent: node.parent,
+            ..Default::default()
+        };
+
+        // Deaccessioned versions are hidden by default, but their files are still stored
+        let options = GetDatasetOptions {
+            include_deaccessioned: true,
+            ..Default::default()
+        };
+
+        for id in datasets {
+            let response = list_dataset_versions(client, Identifier::Id(id), &options).await?;
+            if response.status.is_err() {
+                return Err(response.message.map(|m| m.to_string()).unwrap_or_default());
+            }
+
+            let versions = response.data.unwrap_or_default();
+            for (store, bytes) in stored_bytes(&versions) {
+                *collection.bytes_by_store.entry(store).or_default() += bytes;
+            }
+        }
+
+        report.add(collection, rates)?;
+    }
+
+    Ok(report)
+}
+
+// Sums up the sizes of the files of all versions per store, counting every file once
+fn stored_bytes(versions: &[Dataset]) -> BTreeMap<String, u64> {
+    let mut seen = HashSet::new();
+    let mut bytes_by_store = BTreeMap::new();
+
+    let data_files = versions
+        .iter()
+        .flat_map(|version| version.files.iter())
+        .filter_map(|file| file.data_file.as_ref());
+
+    for data_file in data_files {
+        let key = (data_file.id, data_file.storage_identifier.clone());
+        if !seen.insert(key) {
+            continue;
+        }
+
+        let store = store_of(data_file.storage_identifier.as_deref());
+        let size = data_file.filesize.unwrap_or_default().max(0) as u64;
+        *bytes_by_store.entry(store).or_default() += size;
+    }
+
+    bytes_by_store
+}
+
+fn cost(bytes: u64, rate: f64) -> f64 {
+    bytes as f64 / BYTES_PER_GB * rate
+}
+
+// Extracts the storage driver ID of a storage identifier such as `s3://bucket:1234`
+fn store_of(storage_identifier: Option<&str>) -> String {
+    storage_identifier
+        .and_then(|id| id.split_once("://"))
+        .map(|(store, _)| store.to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    use crate::native_api::dataset::edit::Dataset;
+
+    use super::{store_of, stored_bytes, CollectionCost, CostReport, StorageRates};
+
+    /// Tests pricing collections with store-specific and default rates.
+    #[test]
+    fn test_cost_report() {
+        let rates = StorageRates::from_toml(
+            r#"
+            currency = "EUR"
+            default = 0.01
+
+            [stores]
+            s3 = 0.02
+            "#,
+        )
+            .expect("Failed to parse rates");
+
+        let mut report = CostReport::default();
+        report
+            .add(
+                CollectionCost {
+                    collection: "root".to_string(),
+                    bytes_by_store: BTreeMap::from([
+                        ("s3".to_string(), 50_000_000_000),
+                        ("file".to_string(), 100_000_000_000),
+                    ]),
+                    ..Default::default()
+                },
+                &rates,
+            )
+            .expect("Failed to add collection");
+
+        assert_eq!(report.total_bytes, 150_000_000_000);
+        assert!((report.total_cost - 2.0).abs() < 1e-9);
+
+        let csv = report.to_csv(&rates).expect("Failed to write CSV");
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], "collection,title,parent,store,bytes,rate,cost");
+        assert_eq!(lines[1], "root,,,file,100000000000,0.01,1.00");
+        assert_eq!(lines[2], "root,,,s3,50000000000,0.02,1.00");
+    }
+
+    /// Tests that stores without a rate are rejected if no default rate is given.
+    #[test]
+    fn test_cost_report_missing_rate() {
+        let rates = StorageRates::from_toml("[stores]\ns3 = 0.02").unwrap();
+
+        let result = CostReport::default().add(
+            CollectionCost {
+                bytes_by_store: BTreeMap::from([("file".to_string(), 1)]),
+                ..Default::default()
+            },
+            &rates,
+        );
+
+        assert!(result.is_err());
+    }
+
+    /// Tests the extraction of storage driver IDs.
+    #[test]
+    fn test_store_of() {
+        assert_eq!(store_of(Some("s3://bucket:18f0f4f1e2b")), "s3");
+        assert_eq!(store_of(Some("file://18f0f4f1e2b")), "file");
+        assert_eq!(store_of(Some("18f0f4f1e2b")), "unknown");
+        assert_eq!(store_of(None), "unknown");
+    }
+
+    /// Tests that files of earlier versions are counted, and files shared between versions only once.
+    #[test]
+    fn test_stored_bytes() {
+        let file = |id: i64, storage: &str, size: i64| {
+            serde_json::json!({"dataFile": {"id": id, "storageIdentifier": storage, "filesize": size}})
+        };
+        let versions: Vec<Dataset> = serde_json::from_value(serde_json::json!([
+            {"versionState": "DRAFT", "files": [file(1, "s3://bucket:1", 100), file(3, "file://3", 5)]},
+            {"versionState": "RELEASED", "files": [file(1, "s3://bucket:1", 100), file(2, "s3://bucket:2", 40)]}
+        ]))
+        .expect("Failed to parse versions");
+
+        let bytes = stored_bytes(&versions);
+
+        assert_eq!(bytes.get("s3"), Some(&140));
+        assert_eq!(bytes.get("file"), Some(&5));
+    }
+}
@@ -0,0 +1,444 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::native_api::dataset::metadata::{DatasetMetadataBuilder, SUBJECTS};
+
+// Licenses recognised by their URI, named as in the default license list of Dataverse
+const LICENSES: [(&str, &str, &str); 2] = [
+    ("publicdomain/zero/1.0", "CC0 1.0", "http://creativecommons.org/publicdomain/zero/1.0"),
+    ("licenses/by/4.0", "CC BY 4.0", "http://creativecommons.org/licenses/by/4.0"),
+];
+
+/// Converts a DataCite record into the citation metadata of a new dataset.
+///
+/// DataCite JSON, as served by the DataCite REST API, and DataCite XML of the metadata schema 4 are
+/// accepted; documents starting with `<` are read as XML. The record is mapped as follows:
+///
+/// * The main title becomes the title, a title of type `Subtitle` the subtitle.
+/// * Creators become authors along with their first affiliation and ORCID iD.
+/// * Descriptions become descriptions.
+/// * Subjects matching the subject vocabulary of Dataverse become subjects, all others keywords. Records
+///   without a matching subject are assigned `Other`.
+/// * The DOI is kept as an other identifier with the agency `DataCite`.
+/// * CC0 1.0 and CC BY 4.0 rights become the license. Other rights are skipped, such that the default
+///   license of the instance applies.
+///
+/// DataCite records do not contain contact emails, which thus have to be added to the returned builder.
+///
+/// # Arguments
+///
+/// * `content` - The DataCite record as JSON or XML.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `DatasetMetadataBuilder` holding the converted metadata, or a `String` error message
+/// if the record cannot be parsed or has no title.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::crosswalk::from_datacite;
+///
+/// # fn run() -> Result<(), String> {
+/// let record = std::fs::read_to_string("record.json").map_err(|e| e.to_string())?;
+/// let body = from_datacite(&record)?
+///     .add_contact("Doe, Jane", "jane.doe@example.edu")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_datacite(content: &str) -> Result<DatasetMetadataBuilder, String> {
+    let record = match content.trim_start().starts_with('<') {
+        true => Record::from_xml(content)?,
+        false => {
+            let value: Value = serde_json::from_str(content).map_err(|e| e.to_string())?;
+            Record::from_json(&value)?
+        }
+    };
+
+    record.into_builder()
+}
+
+// The parts of a DataCite record that have a counterpart in the citation block
+#[derive(Debug, Default, PartialEq)]
+struct Record {
+    doi: Option<String>,
+    titles: Vec<(String, Option<String>)>,
+    creators: Vec<Creator>,
+    descriptions: Vec<String>,
+    subjects: Vec<String>,
+    rights_uris: Vec<String>,
+}
+
+#[derive(Debug, PartialEq)]
+struct Creator {
+    name: String,
+    affiliation: Option<String>,
+    orcid: Option<String>,
+}
+
+impl Record {
+    // Reads the attributes of a REST API document, or a bare attributes object
+    fn from_json(value: &Value) -> Result<Self, String> {
+        let attributes = value.pointer("/data/attributes").unwrap_or(value);
+        if !attributes.is_object() {
+            return Err("The DataCite record is not an object".to_string());
+        }
+
+        let list = |key: &str| attributes[key].as_array().cloned().unwrap_or_default();
+        let text = |value: &Value, key: &str| value[key].as_str().map(|s| s.trim().to_string());
+
+        let creators = list("creators")
+            .iter()
+            .filter_map(|creator| {
+                let name = text(creator, "name").or_else(|| {
+                    match (text(creator, "familyName"), text(creator, "givenName")) {
+                        (Some(family), Some(given)) => Some(format!("{}, {}", family, given)),
+                        (family, given) => family.or(given),
+                    }
+                })?;
+
+                // Affiliations are objects since schema 4.3 and plain strings before
+                let affiliation = creator["affiliation"].as_array().and_then(|affiliations| {
+                    let first = affiliations.first()?;
+                    first.as_str().map(String::from).or_else(|| text(first, "name"))
+                });
+
+                let orcid = creator["nameIdentifiers"].as_array().and_then(|identifiers| {
+                    identifiers
+                        .iter()
+                        .find(|id| id["nameIdentifierScheme"].as_str() == Some("ORCID"))
+                        .and_then(|id| text(id, "nameIdentifier"))
+                });
+
+                Some(Creator { name, affiliation, orcid: orcid.map(|id| strip_orcid(&id)) })
+            })
+            .collect();
+
+        Ok(Record {
+            doi: text(attributes, "doi"),
+            titles: list("titles")
+                .iter()
+                .filter_map(|title| Some((text(title, "title")?, text(title, "titleType"))))
+                .collect(),
+            creators,
+            descriptions: list("descriptions")
+                .iter()
+                .filter_map(|description| text(description, "description"))
+                .collect(),
+            subjects: list("subjects")
+                .iter()
+                .filter_map(|subject| text(subject, "subject"))
+                .collect(),
+            rights_uris: list("rightsList")
+                .iter()
+                .filter_map(|rights| text(rights, "rightsUri"))
+                .collect(),
+        })
+    }
+
+    fn from_xml(xml: &str) -> Result<Self, String> {
+        let resource: Resource = quick_xml::de::from_str(xml).map_err(|e| e.to_string())?;
+
+        let creators = resource
+            .creators
+            .map(|creators| creators.creator)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|creator| Creator {
+                name: creator.creator_name.text.trim().to_string(),
+                affiliation: creator.affiliation.first().map(|a| a.text.trim().to_string()),
+                orcid: creator
+                    .name_identifier
+                    .iter()
+                    .find(|id| id.scheme.as_deref() == Some("ORCID"))
+                    .map(|id| strip_orcid(id.text.trim())),
+            })
+            .collect();
+
+        Ok(Record {
+            doi: resource
+                .identifier
+                .filter(|id| id.kind.as_deref() == Some("DOI"))
+                .map(|id| id.text.trim().to_string()),
+            titles: resource
+                .titles
+                .map(|titles| titles.title)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|title| (title.text.trim().to_string(), title.kind))
+                .collect(),
+            creators,
+            descriptions: resource
+                .descriptions
+                .map(|descriptions| descriptions.description)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|description| description.text.trim().to_string())
+                .collect(),
+            subjects: resource
+                .subjects
+                .map(|subjects| subjects.subject)
+                .unwrap_or_default()
+                .into_iter()
+                .map(|subject| subject.text.trim().to_string())
+                .collect(),
+            rights_uris: resource
+                .rights_list
+                .map(|rights| rights.rights)
+                .unwrap_or_default()
+                .into_iter()
+                .filter_map(|rights| rights.uri)
+                .collect(),
+        })
+    }
+
+    fn into_builder(self) -> Result<DatasetMetadataBuilder, String> {
+        let title = self
+            .titles
+            .iter()
+            .find(|(_, kind)| kind.is_none())
+            .or(self.titles.first())
+            .ok_or("The DataCite record does not contain a title")?;
+
+        let mut builder = DatasetMetadataBuilder::new().title(&title.0);
+        if let Some((subtitle, _)) = self.titles.iter().find(|(_, kind)| kind.as_deref() == Some("Subtitle")) {
+            builder = builder.subtitle(subtitle);
+        }
+
+        for creator in &self.creators {
+            builder = builder.add_author(&creator.name, creator.affiliation.as_deref(), creator.orcid.as_deref());
+        }
+
+        for description in self.descriptions.iter().filter(|d| !d.is_empty()) {
+            builder = builder.description(description);
+        }
+
+        builder = add_subjects(builder, &self.subjects);
+
+        if let Some(doi) = &self.doi {
+            builder = builder.add_other_id("DataCite", &format!("doi:{}", doi));
+        }
+
+        if let Some((name, uri)) = self.rights_uris.iter().find_map(|uri| find_license(uri)) {
+            builder = builder.license(name, uri);
+        }
+
+        Ok(builder)
+    }
+}
+
+// Adds subjects of the vocabulary of Dataverse as subjects and all others as keywords
+pub(crate) fn add_subjects(mut builder: DatasetMetadataBuilder, subjects: &[String]) -> DatasetMetadataBuilder {
+    let mut matched = false;
+    for subject in subjects {
+        match SUBJECTS.iter().find(|known| known.eq_ignore_ascii_case(subject)) {
+            Some(known) => {
+                builder = builder.subject(known);
+                matched = true;
+            }
+            None => builder = builder.add_keyword(subject),
+        }
+    }
+
+    match matched {
+        true => builder,
+        false => builder.subject("Other"),
+    }
+}
+
+// Finds the name and URI of a known license by its URI or name
+pub(crate) fn find_license(rights: &str) -> Option<(&'static str, &'static str)> {
+    LICENSES
+        .iter()
+        .find(|(pattern, name, _)| rights.contains(pattern) || rights.eq_ignore_ascii_case(name))
+        .map(|(_, name, uri)| (*name, *uri))
+}
+
+fn strip_orcid(id: &str) -> String {
+    id.trim_start_matches("https://orcid.org/")
+        .trim_start_matches("http://orcid.org/")
+        .to_string()
+}
+
+// Raw structure of DataCite XML, reduced to the mapped elements
+#[derive(Deserialize)]
+struct Resource {
+    identifier: Option<XmlIdentifier>,
+    titles: Option<Titles>,
+    creators: Option<Creators>,
+    subjects: Option<Subjects>,
+    descriptions: Option<Descriptions>,
+    #[serde(rename = "rightsList")]
+    rights_list: Option<RightsList>,
+}
+
+#[derive(Deserialize)]
+struct Text {
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct XmlIdentifier {
+    #[serde(rename = "@identifierType")]
+    kind: Option<String>,
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct Titles {
+    #[serde(default)]
+    title: Vec<Title>,
+}
+
+#[derive(Deserialize)]
+struct Title {
+    #[serde(rename = "@titleType")]
+    kind: Option<String>,
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct Creators {
+    #[serde(default)]
+    creator: Vec<XmlCreator>,
+}
+
+#[derive(Deserialize)]
+struct XmlCreator {
+    #[serde(rename = "creatorName")]
+    creator_name: Text,
+    #[serde(rename = "nameIdentifier", default)]
+    name_identifier: Vec<NameIdentifier>,
+    #[serde(default)]
+    affiliation: Vec<Text>,
+}
+
+#[derive(Deserialize)]
+struct NameIdentifier {
+    #[serde(rename = "@nameIdentifierScheme")]
+    scheme: Option<String>,
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct Subjects {
+    #[serde(default)]
+    subject: Vec<Text>,
+}
+
+#[derive(Deserialize)]
+struct Descriptions {
+    #[serde(default)]
+    description: Vec<Text>,
+}
+
+#[derive(Deserialize)]
+struct RightsList {
+    #[serde(default)]
+    rights: Vec<Rights>,
+}
+
+#[derive(Deserialize)]
+struct Rights {
+    #[serde(rename = "@rightsURI")]
+    uri: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::{from_datacite, Creator, Record};
+
+    const XML: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<resource xmlns="http://datacite.org/schema/kernel-4">
+  <identifier identifierType="DOI">10.1234/ABC</identifier>
+  <creators>
+    <creator>
+      <creatorName nameType="Personal">Doe, Jane</creatorName>
+      <nameIdentifier nameIdentifierScheme="ORCID" schemeURI="https://orcid.org">https://orcid.org/0000-0002-1825-0097</nameIdentifier>
+      <affiliation>University of Example</affiliation>
+    </creator>
+  </creators>
+  <titles>
+    <title xml:lang="en">Soil moisture</title>
+    <title titleType="Subtitle">Hourly measurements</title>
+  </titles>
+  <subjects>
+    <subject>Earth and environmental sciences</subject>
+    <subject>hydrology</subject>
+  </subjects>
+  <rightsList>
+    <rights rightsURI="https://creativecommons.org/licenses/by/4.0/legalcode">CC BY 4.0</rights>
+  </rightsList>
+  <descriptions>
+    <description descriptionType="Abstract">Measurements at ten sites.</description>
+  </descriptions>
+</resource>"#;
+
+    /// Tests that JSON and XML records of the same dataset are read alike.
+    #[test]
+    fn test_read_datacite_json_and_xml() {
+        let document = json!({
+            "data": {
+                "type": "dois",
+                "attributes": {
+                    "doi": "10.1234/ABC",
+                    "titles": [{"title": "Soil moisture"}, {"title": "Hourly measurements", "titleType": "Subtitle"}],
+                    "creators": [{
+                        "name": "Doe, Jane",
+                        "affiliation": [{"name": "University of Example"}],
+                        "nameIdentifiers": [{
+                            "nameIdentifier": "https://orcid.org/0000-0002-1825-0097",
+                            "nameIdentifierScheme": "ORCID"
+                        }]
+                    }],
+                    "subjects": [{"subject": "Earth and environmental sciences"}, {"subject": "hydrology"}],
+                    "rightsList": [{"rights": "CC BY 4.0", "rightsUri": "https://creativecommons.org/licenses/by/4.0/legalcode"}],
+                    "descriptions": [{"description": "Measurements at ten sites.", "descriptionType": "Abstract"}]
+                }
+            }
+        });
+
+        let from_json = Record::from_json(&document).unwrap();
+        let from_xml = Record::from_xml(XML).unwrap();
+
+        assert_eq!(from_json, from_xml);
+        assert_eq!(
+            from_json.creators,
+            vec![Creator {
+                name: "Doe, Jane".to_string(),
+                affiliation: Some("University of Example".to_string()),
+                orcid: Some("0000-0002-1825-0097".to_string()),
+            }]
+        );
+    }
+
+    /// Tests the mapping of a record onto the citation block.
+    #[test]
+    fn test_from_datacite() {
+        let body = from_datacite(XML)
+            .unwrap()
+            .add_contact("Doe, Jane", "jane.doe@example.edu")
+            .build()
+            .expect("Failed to build the dataset body");
+
+        let value = serde_json::to_value(&body).unwrap();
+        let fields = value["datasetVersion"]["metadataBlocks"]["citation"]["fields"].as_array().unwrap();
+        let field = |name: &str| fields.iter().find(|f| f["typeName"] == name).unwrap()["value"].clone();
+
+        assert_eq!(field("title"), json!("Soil moisture"));
+        assert_eq!(field("subtitle"), json!("Hourly measurements"));
+        assert_eq!(field("subject"), json!(["Earth and Environmental Sciences"]));
+        assert_eq!(field("keyword")[0]["keywordValue"]["value"], json!("hydrology"));
+        assert_eq!(field("otherId")[0]["otherIdValue"]["value"], json!("doi:10.1234/ABC"));
+        assert_eq!(value["datasetVersion"]["license"]["name"], json!("CC BY 4.0"));
+
+        assert!(from_datacite(r#"{"titles": []}"#).is_err());
+    }
+}
@@ -0,0 +1,216 @@
+use quick_xml::events::Event;
+use quick_xml::name::{Namespace, ResolveResult};
+use quick_xml::NsReader;
+
+use crate::native_api::dataset::metadata::DatasetMetadataBuilder;
+
+use super::datacite::{add_subjects, find_license};
+
+const DC_NAMESPACE: &[u8] = b"http://purl.org/dc/elements/1.1/";
+
+/// Converts a simple Dublin Core (`oai_dc`) record into the citation metadata of a new dataset.
+///
+/// The record may be passed on its own or within the `GetRecord` or `ListRecords` response of an OAI-PMH
+/// server, as only elements of the Dublin Core namespace are read. The elements are mapped as follows:
+///
+/// * The first `title` becomes the title.
+/// * Each `creator` becomes an author.
+/// * Each `description` becomes a description.
+/// * Subjects matching the subject vocabulary of Dataverse become subjects, all others keywords. Records
+///   without a matching subject are assigned `Other`.
+/// * DOIs and Handles among the `identifier` elements are kept as other identifiers.
+/// * The first `rights` naming CC0 1.0 or CC BY 4.0 by name or URI becomes the license.
+///
+/// Dublin Core records do not contain contact emails, which thus have to be added to the returned builder.
+///
+/// # Arguments
+///
+/// * `xml` - The `oai_dc` XML of the record.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `DatasetMetadataBuilder` holding the converted metadata, or a `String` error message
+/// if the XML cannot be parsed or the record has no title.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::crosswalk::from_dublin_core;
+///
+/// # fn run() -> Result<(), String> {
+/// let record = std::fs::read_to_string("record.xml").map_err(|e| e.to_string())?;
+/// let body = from_dublin_core(&record)?
+///     .add_contact("Doe, Jane", "jane.doe@example.edu")
+///     .build()?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn from_dublin_core(xml: &str) -> Result<DatasetMetadataBuilder, String> {
+    let elements = read_elements(xml)?;
+    let values = |name: &str| -> Vec<String> {
+        elements
+            .iter()
+            .filter(|(element, value)| element == name && !value.is_empty())
+            .map(|(_, value)| value.clone())
+            .collect()
+    };
+
+    let title = values("title")
+        .into_iter()
+        .next()
+        .ok_or("The Dublin Core record does not contain a title")?;
+
+    let mut builder = DatasetMetadataBuilder::new().title(&title);
+    for creator in values("creator") {
+        builder = builder.add_author(&creator, None, None);
+    }
+    for description in values("description") {
+        builder = builder.description(&description);
+    }
+    builder = add_subjects(builder, &values("subject"));
+
+    for identifier in values("identifier") {
+        if let Some((agency, value)) = persistent_id(&identifier) {
+            builder = builder.add_other_id(agency, &value);
+        }
+    }
+
+    if let Some((name, uri)) = values("rights").iter().find_map(|rights| find_license(rights)) {
+        builder = builder.license(name, uri);
+    }
+
+    Ok(builder)
+}
+
+// Collects the local names and texts of all Dublin Core elements in document order
+fn read_elements(xml: &str) -> Result<Vec<(String, String)>, String> {
+    let mut reader = NsReader::from_str(xml);
+    let mut elements = Vec::new();
+    let mut current: Option<(String, String)> = None;
+
+    loop {
+        match reader.read_resolved_event().map_err(|e| e.to_string())? {
+            (ResolveResult::Bound(Namespace(DC_NAMESPACE)), Event::Start(start)) => {
+                let name = String::from_utf8_lossy(start.local_name().as_ref()).to_string();
+                current = Some((name, String::new()));
+            }
+            (_, Event::Text(text)) => {
+                if let Some((_, value)) = current.as_mut() {
+                    value.push_str(&text.unescape().map_err(|e| e.to_string())?);
+                }
+            }
+            (_, Event::CData(data)) => {
+                if let Some((_, value)) = current.as_mut() {
+                    value.push_str(&String::from_utf8_lossy(&data));
+                }
+            }
+            (_, Event::End(_)) => {
+                if let Some((name, value)) = current.take() {
+                    elements.push((name, value.trim().to_string()));
+                }
+            }
+            (_, Event::Eof) => break,
+            _ => {}
+        }
+    }
+
+    Ok(elements)
+}
+
+// Returns the agency and the prefixed value of DOIs and Handles, in either URL or prefix notation
+fn persistent_id(identifier: &str) -> Option<(&'static str, String)> {
+    let schemes = [
+        ("DOI", "doi:", ["https://doi.org/", "http://dx.doi.org/"]),
+        ("Handle", "hdl:", ["https://hdl.handle.net/", "http://hdl.handle.net/"]),
+    ];
+
+    schemes.iter().find_map(|(agency, prefix, urls)| {
+        std::iter::once(*prefix)
+            .chain(urls.iter().copied())
+            .find_map(|start| identifier.strip_prefix(start))
+            .map(|id| (*agency, format!("{}{}", prefix, id)))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::export::{DublinCoreExporter, Exporter};
+
+    use super::from_dublin_core;
+
+    /// Tests that a record exported as Dublin Core is read back.
+    #[test]
+    fn test_dublin_core_round_trip() {
+        let content = std::fs::read_to_string("tests/fixtures/dataverse_json_export.json").unwrap();
+        let export: serde_json::Value = serde_json::from_str(&content).unwrap();
+        let xml = String::from_utf8(DublinCoreExporter.export(&export).unwrap()).unwrap();
+
+        let body = from_dublin_core(&xml)
+            .unwrap()
+            .add_contact("Roe, Jane", "jane.roe@example.edu")
+            .build()
+            .expect("Failed to build the dataset body");
+
+        let value = serde_json::to_value(&body).unwrap();
+        let fields = value["datasetVersion"]["metadataBlocks"]["citation"]["fields"].as_array().unwrap();
+        let field = |name: &str| fields.iter().find(|f| f["typeName"] == name).unwrap()["value"].clone();
+
+        assert_eq!(field("title"), json!("Exported Dataset"));
+        assert_eq!(field("author")[0]["authorName"]["value"], json!("Doe, John"));
+        assert_eq!(field("author")[1]["authorName"]["value"], json!("Roe, Jane"));
+        assert_eq!(
+            field("dsDescription")[0]["dsDescriptionValue"]["value"],
+            json!("Measurements & notes of an <example> study.")
+        );
+        assert_eq!(field("otherId")[0]["otherIdValue"]["value"], json!("doi:10.5072/FK2/ABC123"));
+    }
+
+    /// Tests that only Dublin Core elements of an OAI-PMH response are read.
+    #[test]
+    fn test_dublin_core_in_oai_pmh_response() {
+        let xml = r#"<?xml version="1.0" encoding="UTF-8"?>
+<OAI-PMH xmlns="http://www.openarchives.org/OAI/2.0/">
+  <GetRecord>
+    <record>
+      <header>
+        <identifier>oai:example.edu:doi:10.1234/ABC</identifier>
+      </header>
+      <metadata>
+        <oai_dc:dc xmlns:oai_dc="http://www.openarchives.org/OAI/2.0/oai_dc/" xmlns:dc="http://purl.org/dc/elements/1.1/">
+          <dc:title>Soil moisture</dc:title>
+          <dc:creator>Doe, Jane</dc:creator>
+          <dc:subject>soil</dc:subject>
+          <dc:title>Bodenfeuchte</dc:title>
+          <dc:subject>Earth and Environmental Sciences</dc:subject>
+          <dc:description><![CDATA[Hourly <measurements>.]]></dc:description>
+          <dc:identifier>https://hdl.handle.net/10622/XYZ</dc:identifier>
+          <dc:rights>CC0 1.0</dc:rights>
+        </oai_dc:dc>
+      </metadata>
+    </record>
+  </GetRecord>
+</OAI-PMH>"#;
+
+        let body = from_dublin_core(xml)
+            .unwrap()
+            .add_contact("Doe, Jane", "jane.doe@example.edu")
+            .build()
+            .unwrap();
+
+        let value = serde_json::to_value(&body).unwrap();
+        let fields = value["datasetVersion"]["metadataBlocks"]["citation"]["fields"].as_array().unwrap();
+        let field = |name: &str| fields.iter().find(|f| f["typeName"] == name).unwrap()["value"].clone();
+
+        assert_eq!(field("title"), json!("Soil moisture"));
+        assert_eq!(field("subject"), json!(["Earth and Environmental Sciences"]));
+        assert_eq!(field("keyword")[0]["keywordValue"]["value"], json!("soil"));
+        assert_eq!(field("dsDescription")[0]["dsDescriptionValue"]["value"], json!("Hourly <measurements>."));
+        assert_eq!(field("otherId").as_array().unwrap().len(), 1);
+        assert_eq!(field("otherId")[0]["otherIdAgency"]["value"], json!("Handle"));
+        assert_eq!(value["datasetVersion"]["license"]["name"], json!("CC0 1.0"));
+
+        assert!(from_dublin_core("<oai_dc:dc xmlns:oai_dc=\"x\"/>").is_err());
+    }
+}
@@ -0,0 +1,43 @@
+use std::fmt;
+
+// Most functions of the API client report errors as plain strings. This type
+// distinguishes the origin of an error where it matters to the caller, such as
+// the command line interface mapping errors to exit codes.
+
+/// An error raised while interacting with a Dataverse instance.
+#[derive(Debug)]
+pub enum DataverseError {
+    /// The instance responded with an error status and message.
+    Api(String),
+    /// The request could not be sent or its response could not be processed.
+    Request(String),
+    /// The input provided by the user is invalid.
+    Input(String),
+    /// Reading or writing a local file failed.
+    Io(std::io::Error),
+}
+
+impl fmt::Display for DataverseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DataverseError::Api(message) => write!(f, "{}", message),
+            DataverseError::Request(message) => write!(f, "{}", message),
+            DataverseError::Input(message) => write!(f, "{}", message),
+            DataverseError::Io(error) => write!(f, "{}", error),
+        }
+    }
+}
+
+impl std::error::Error for DataverseError {}
+
+impl From<std::io::Error> for DataverseError {
+    fn from(error: std::io::Error) -> Self {
+        DataverseError::Io(error)
+    }
+}
+
+impl From<reqwest::Error> for DataverseError {
+    fn from(error: reqwest::Error) -> Self {
+        DataverseError::Request(error.to_string())
+    }
+}
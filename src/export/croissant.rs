@@ -0,0 +1,94 @@
+use serde_json::{json, Value};
+
+use super::{DatasetSummary, Exporter};
+
+/// Exports the metadata of a dataset as Croissant 1.0 JSON-LD, the format used by ML dataset tooling.
+///
+/// The files of the dataset are described as `FileObject` entries, using their paths within the dataset as
+/// content URLs.
+pub struct CroissantExporter;
+
+impl Exporter for CroissantExporter {
+    fn format(&self) -> &str {
+        "croissant"
+    }
+
+    fn mime_type(&self) -> &str {
+        "application/ld+json"
+    }
+
+    fn export(&self, dataset: &Value) -> Result<Vec<u8>, String> {
+        let summary = DatasetSummary::from_value(dataset)?;
+
+        let distribution: Vec<Value> = summary
+            .files
+            .iter()
+            .map(|file| {
+                let mut object = json!({
+                    "@type": "cr:FileObject",
+                    "@id": file.path,
+                    "name": file.path,
+                    "contentUrl": file.path,
+                    "contentSize": file.size.map(|size| format!("{} B", size)),
+                    "encodingFormat": file.content_type,
+                });
+                match (file.checksum_type.as_deref(), &file.checksum) {
+                    (Some("MD5"), Some(checksum)) => object["md5"] = json!(checksum),
+                    (Some("SHA-256"), Some(checksum)) => object["sha256"] = json!(checksum),
+                    _ => {}
+                }
+                object
+            })
+            .collect();
+
+        let document = json!({
+            "@context": {
+                "@language": "en",
+                "@vocab": "https://schema.org/",
+                "cr": "http://mlcommons.org/croissant/",
+                "sc": "https://schema.org/",
+            },
+            "@type": "sc:Dataset",
+            "conformsTo": "http://mlcommons.org/croissant/1.0",
+            "name": summary.title,
+            "description": summary.descriptions.join("\n\n"),
+            "url": summary.url,
+            "identifier": summary.persistent_id,
+            "license": summary.license.as_ref().map(|license| license.uri.clone().unwrap_or(license.name.clone())),
+            "creator": summary
+                .authors
+                .iter()
+                .map(|author| json!({ "@type": "sc:Person", "name": author.name }))
+                .collect::<Vec<_>>(),
+            "keywords": summary.subjects.iter().chain(&summary.keywords).collect::<Vec<_>>(),
+            "publisher": summary.publisher,
+            "datePublished": summary.publication_date,
+            "version": summary.version,
+            "distribution": distribution,
+        });
+        serde_json::to_vec_pretty(&document).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::export::Exporter;
+
+    use super::CroissantExporter;
+
+    /// Tests that files are described as file objects along with their checksums.
+    #[test]
+    fn test_croissant_export() {
+        let content = std::fs::read_to_string("tests/fixtures/dataverse_json_export.json").unwrap();
+        let export: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+        let document = CroissantExporter.export(&export).expect("Failed to export");
+        let document: serde_json::Value = serde_json::from_slice(&document).unwrap();
+
+        assert_eq!(document["name"], "Exported Dataset");
+        assert_eq!(document["creator"][0]["name"], "Doe, John");
+        assert_eq!(document["distribution"][0]["contentUrl"], "docs/file.txt");
+        assert_eq!(document["distribution"][0]["contentSize"], "12 B");
+        assert_eq!(document["distribution"][0]["md5"], "a28bca1b906f539ba70ca3a0b1f2e773");
+    }
+}
@@ -0,0 +1,96 @@
+use serde_json::{json, Value};
+
+use super::{DatasetSummary, Exporter};
+
+/// Exports the metadata of a dataset as DataCite JSON, as accepted by the DataCite REST API.
+pub struct DataCiteExporter;
+
+impl Exporter for DataCiteExporter {
+    fn format(&self) -> &str {
+        "datacite"
+    }
+
+    fn mime_type(&self) -> &str {
+        "application/vnd.datacite.datacite+json"
+    }
+
+    fn export(&self, dataset: &Value) -> Result<Vec<u8>, String> {
+        let summary = DatasetSummary::from_value(dataset)?;
+
+        let creators: Vec<Value> = summary
+            .authors
+            .iter()
+            .map(|author| {
+                let mut creator = json!({ "name": author.name, "nameType": "Personal" });
+                if let Some(affiliation) = &author.affiliation {
+                    creator["affiliation"] = json!([{ "name": affiliation }]);
+                }
+                if let (Some(scheme), Some(identifier)) = (&author.identifier_scheme, &author.identifier) {
+                    creator["nameIdentifiers"] = json!([{
+                        "nameIdentifier": identifier,
+                        "nameIdentifierScheme": scheme,
+                    }]);
+                }
+                creator
+            })
+            .collect();
+
+        let subjects: Vec<Value> = summary
+            .subjects
+            .iter()
+            .chain(&summary.keywords)
+            .map(|subject| json!({ "subject": subject }))
+            .collect();
+
+        let mut attributes = json!({
+            "titles": summary.title.iter().map(|title| json!({ "title": title })).collect::<Vec<_>>(),
+            "creators": creators,
+            "publisher": summary.publisher,
+            "publicationYear": summary.publication_year(),
+            "types": { "resourceTypeGeneral": "Dataset" },
+            "descriptions": summary
+                .descriptions
+                .iter()
+                .map(|description| json!({ "description": description, "descriptionType": "Abstract" }))
+                .collect::<Vec<_>>(),
+            "subjects": subjects,
+            "version": summary.version,
+            "url": summary.url,
+        });
+
+        if let Some(doi) = summary.persistent_id.as_ref().and_then(|pid| pid.strip_prefix("doi:")) {
+            attributes["doi"] = json!(doi);
+        }
+        if let Some(license) = &summary.license {
+            attributes["rightsList"] = json!([{ "rights": license.name, "rightsUri": license.uri }]);
+        }
+
+        let document = json!({ "data": { "type": "dois", "attributes": attributes } });
+        serde_json::to_vec_pretty(&document).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::export::Exporter;
+
+    use super::DataCiteExporter;
+
+    /// Tests the conversion of an export into DataCite JSON.
+    #[test]
+    fn test_datacite_export() {
+        let content = std::fs::read_to_string("tests/fixtures/dataverse_json_export.json").unwrap();
+        let export: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+        let document = DataCiteExporter.export(&export).expect("Failed to export");
+        let document: serde_json::Value = serde_json::from_slice(&document).unwrap();
+        let attributes = &document["data"]["attributes"];
+
+        assert_eq!(attributes["doi"], "10.5072/FK2/ABC123");
+        assert_eq!(attributes["titles"][0]["title"], "Exported Dataset");
+        assert_eq!(attributes["publicationYear"], 2024);
+        assert_eq!(attributes["creators"][0]["nameIdentifiers"][0]["nameIdentifierScheme"], "ORCID");
+        assert_eq!(attributes["subjects"][1]["subject"], "testing");
+        assert_eq!(attributes["rightsList"][0]["rights"], "CC0 1.0");
+    }
+}
@@ -0,0 +1,74 @@
+use serde_json::Value;
+
+use super::{DatasetSummary, Exporter};
+
+/// Exports the metadata of a dataset as simple Dublin Core (`oai_dc`) XML, as served by OAI-PMH.
+pub struct DublinCoreExporter;
+
+impl Exporter for DublinCoreExporter {
+    fn format(&self) -> &str {
+        "dublin-core"
+    }
+
+    fn mime_type(&self) -> &str {
+        "application/xml"
+    }
+
+    fn export(&self, dataset: &Value) -> Result<Vec<u8>, String> {
+        let summary = DatasetSummary::from_value(dataset)?;
+
+        let mut elements: Vec<(&str, &str)> = Vec::new();
+        elements.extend(summary.title.iter().map(|title| ("title", title.as_str())));
+        elements.extend(summary.authors.iter().map(|author| ("creator", author.name.as_str())));
+        elements.extend(summary.subjects.iter().chain(&summary.keywords).map(|subject| ("subject", subject.as_str())));
+        elements.extend(summary.descriptions.iter().map(|description| ("description", description.as_str())));
+        elements.extend(summary.publisher.iter().map(|publisher| ("publisher", publisher.as_str())));
+        elements.extend(summary.publication_date.iter().map(|date| ("date", date.as_str())));
+        elements.push(("type", "Dataset"));
+        elements.extend(summary.url.as_ref().or(summary.persistent_id.as_ref()).map(|id| ("identifier", id.as_str())));
+        elements.extend(summary.license.iter().map(|license| ("rights", license.name.as_str())));
+
+        let mut xml = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<oai_dc:dc xmlns:oai_dc=\"http://www.openarchives.org/OAI/2.0/oai_dc/\" ",
+            "xmlns:dc=\"http://purl.org/dc/elements/1.1/\">\n",
+        ));
+        for (element, value) in elements {
+            xml.push_str(&format!("  <dc:{0}>{1}</dc:{0}>\n", element, escape(value)));
+        }
+        xml.push_str("</oai_dc:dc>\n");
+
+        Ok(xml.into_bytes())
+    }
+}
+
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::export::Exporter;
+
+    use super::DublinCoreExporter;
+
+    /// Tests the conversion of an export into escaped Dublin Core XML.
+    #[test]
+    fn test_dublin_core_export() {
+        let content = std::fs::read_to_string("tests/fixtures/dataverse_json_export.json").unwrap();
+        let export: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+        let xml = DublinCoreExporter.export(&export).expect("Failed to export");
+        let xml = String::from_utf8(xml).unwrap();
+
+        assert!(xml.contains("<dc:title>Exported Dataset</dc:title>"));
+        assert!(xml.contains("<dc:creator>Roe, Jane</dc:creator>"));
+        assert!(xml.contains("<dc:description>Measurements &amp; notes of an &lt;example&gt; study.</dc:description>"));
+        assert!(xml.contains("<dc:identifier>https://doi.org/10.5072/FK2/ABC123</dc:identifier>"));
+        assert!(xml.ends_with("</oai_dc:dc>\n"));
+    }
+}
@@ -0,0 +1,148 @@
+use serde_json::Value;
+
+use super::{CroissantExporter, DataCiteExporter, DublinCoreExporter, RoCrateExporter};
+
+// Exporters convert the metadata of a dataset into another format locally,
+// without relying on the exporters configured on the instance. The input is
+// the `dataverse_json` representation of a dataset, either as exported by the
+// instance or as returned when retrieving a dataset.
+
+/// A conversion of dataset metadata into a format such as DataCite or RO-Crate.
+///
+/// Implement this trait to add institution-specific formats and register the implementation in an
+/// `ExporterRegistry`, which makes it available to `dvcli dataset export --format`.
+///
+/// # Examples
+///
+/// ```
+/// use dataverse::export::{DatasetSummary, Exporter, ExporterRegistry};
+///
+/// struct TitleExporter;
+///
+/// impl Exporter for TitleExporter {
+///     fn format(&self) -> &str {
+///         "title"
+///     }
+///
+///     fn mime_type(&self) -> &str {
+///         "text/plain"
+///     }
+///
+///     fn export(&self, dataset: &serde_json::Value) -> Result<Vec<u8>, String> {
+///         let summary = DatasetSummary::from_value(dataset)?;
+///         Ok(summary.title.unwrap_or_default().into_bytes())
+///     }
+/// }
+///
+/// let mut registry = ExporterRegistry::default();
+/// registry.register(TitleExporter);
+///
+/// assert!(registry.formats().contains(&"title"));
+/// ```
+pub trait Exporter: Send + Sync {
+    /// The identifier of the format, as passed to `--format`.
+    fn format(&self) -> &str;
+
+    /// The MIME type of the exported document.
+    fn mime_type(&self) -> &str;
+
+    /// Converts the `dataverse_json` representation of a dataset into the format.
+    fn export(&self, dataset: &Value) -> Result<Vec<u8>, String>;
+}
+
+/// A collection of exporters, looked up by their format identifier.
+///
+/// The default registry contains the built-in exporters for DataCite, Dublin Core, RO-Crate and Croissant.
+pub struct ExporterRegistry {
+    exporters: Vec<Box<dyn Exporter>>,
+}
+
+impl Default for ExporterRegistry {
+    fn default() -> Self {
+        let mut registry = ExporterRegistry::empty();
+        registry.register(DataCiteExporter);
+        registry.register(DublinCoreExporter);
+        registry.register(RoCrateExporter);
+        registry.register(CroissantExporter);
+        registry
+    }
+}
+
+impl ExporterRegistry {
+    /// Creates a registry without any exporters.
+    pub fn empty() -> Self {
+        ExporterRegistry { exporters: Vec::new() }
+    }
+
+    /// Adds an exporter, replacing a registered exporter of the same format.
+    pub fn register(&mut self, exporter: impl Exporter + 'static) {
+        self.exporters.retain(|registered| !registered.format().eq_ignore_ascii_case(exporter.format()));
+        self.exporters.push(Box::new(exporter));
+    }
+
+    /// Returns the exporter of the format, which is matched case-insensitively.
+    pub fn get(&self, format: &str) -> Option<&dyn Exporter> {
+        self.exporters
+            .iter()
+            .find(|exporter| exporter.format().eq_ignore_ascii_case(format))
+            .map(|exporter| exporter.as_ref())
+    }
+
+    /// Returns the formats of all registered exporters.
+    pub fn formats(&self) -> Vec<&str> {
+        self.exporters.iter().map(|exporter| exporter.format()).collect()
+    }
+
+    /// Converts the dataset with the exporter of the format.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the exported document, or a `String` error message if no exporter is registered
+    /// for the format or the conversion fails.
+    pub fn export(&self, format: &str, dataset: &Value) -> Result<Vec<u8>, String> {
+        match self.get(format) {
+            Some(exporter) => exporter.export(dataset),
+            None => Err(format!(
+                "Unknown export format '{}', expected one of: {}",
+                format,
+                self.formats().join(", ")
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::{json, Value};
+
+    use super::{Exporter, ExporterRegistry};
+
+    struct IdExporter;
+
+    impl Exporter for IdExporter {
+        fn format(&self) -> &str {
+            "datacite"
+        }
+
+        fn mime_type(&self) -> &str {
+            "text/plain"
+        }
+
+        fn export(&self, dataset: &Value) -> Result<Vec<u8>, String> {
+            Ok(dataset["id"].to_string().into_bytes())
+        }
+    }
+
+    /// Tests the lookup of built-in exporters and that registered ones take precedence.
+    #[test]
+    fn test_registry() {
+        let mut registry = ExporterRegistry::default();
+        assert_eq!(registry.formats(), vec!["datacite", "dublin-core", "ro-crate", "croissant"]);
+        assert_eq!(registry.get("RO-Crate").map(|e| e.format()), Some("ro-crate"));
+        assert!(registry.export("bibtex", &json!({})).is_err());
+
+        registry.register(IdExporter);
+        assert_eq!(registry.formats().len(), 4);
+        assert_eq!(registry.export("datacite", &json!({"id": 42})).unwrap(), b"42");
+    }
+}
@@ -0,0 +1,112 @@
+use serde_json::{json, Value};
+
+use super::{DatasetSummary, Exporter};
+
+/// Exports the metadata of a dataset as RO-Crate 1.1 metadata file (`ro-crate-metadata.json`).
+///
+/// The files of the dataset are described as data entities of the root dataset, using their paths within
+/// the dataset as identifiers, such that the metadata file can be placed next to a download of the dataset.
+pub struct RoCrateExporter;
+
+impl Exporter for RoCrateExporter {
+    fn format(&self) -> &str {
+        "ro-crate"
+    }
+
+    fn mime_type(&self) -> &str {
+        "application/ld+json"
+    }
+
+    fn export(&self, dataset: &Value) -> Result<Vec<u8>, String> {
+        let summary = DatasetSummary::from_value(dataset)?;
+
+        let mut root = json!({
+            "@id": "./",
+            "@type": "Dataset",
+            "name": summary.title,
+            "description": summary.descriptions.join("\n\n"),
+            "datePublished": summary.publication_date,
+            "identifier": summary.url.as_ref().or(summary.persistent_id.as_ref()),
+            "publisher": summary.publisher,
+            "version": summary.version,
+            "keywords": summary.subjects.iter().chain(&summary.keywords).collect::<Vec<_>>(),
+            "author": (1..=summary.authors.len())
+                .map(|index| json!({ "@id": format!("#author-{}", index) }))
+                .collect::<Vec<_>>(),
+            "hasPart": summary.files.iter().map(|file| json!({ "@id": file.path })).collect::<Vec<_>>(),
+        });
+        if let Some(license) = &summary.license {
+            root["license"] = match &license.uri {
+                Some(uri) => json!({ "@id": uri }),
+                None => json!(license.name),
+            };
+        }
+
+        let mut graph = vec![
+            json!({
+                "@id": "ro-crate-metadata.json",
+                "@type": "CreativeWork",
+                "conformsTo": { "@id": "https://w3id.org/ro/crate/1.1" },
+                "about": { "@id": "./" },
+            }),
+            root,
+        ];
+
+        for (index, author) in summary.authors.iter().enumerate() {
+            let mut person = json!({
+                "@id": format!("#author-{}", index + 1),
+                "@type": "Person",
+                "name": author.name,
+            });
+            if let Some(affiliation) = &author.affiliation {
+                person["affiliation"] = json!(affiliation);
+            }
+            if let (Some("ORCID"), Some(orcid)) = (author.identifier_scheme.as_deref(), &author.identifier) {
+                person["identifier"] = json!(format!("https://orcid.org/{}", orcid));
+            }
+            graph.push(person);
+        }
+
+        for file in &summary.files {
+            graph.push(json!({
+                "@id": file.path,
+                "@type": "File",
+                "name": file.path.rsplit('/').next(),
+                "contentSize": file.size,
+                "encodingFormat": file.content_type,
+            }));
+        }
+
+        let document = json!({
+            "@context": "https://w3id.org/ro/crate/1.1/context",
+            "@graph": graph,
+        });
+        serde_json::to_vec_pretty(&document).map_err(|e| e.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::export::Exporter;
+
+    use super::RoCrateExporter;
+
+    /// Tests that the crate links the root dataset to its authors and files.
+    #[test]
+    fn test_ro_crate_export() {
+        let content = std::fs::read_to_string("tests/fixtures/dataverse_json_export.json").unwrap();
+        let export: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+        let document = RoCrateExporter.export(&export).expect("Failed to export");
+        let document: serde_json::Value = serde_json::from_slice(&document).unwrap();
+        let graph = document["@graph"].as_array().unwrap();
+
+        assert_eq!(graph[0]["about"]["@id"], "./");
+        assert_eq!(graph[1]["name"], "Exported Dataset");
+        assert_eq!(graph[1]["author"][1]["@id"], "#author-2");
+        assert_eq!(graph[1]["hasPart"][0]["@id"], "docs/file.txt");
+        assert_eq!(graph[1]["license"]["@id"], "http://creativecommons.org/publicdomain/zero/1.0");
+        assert_eq!(graph[2]["identifier"], "https://orcid.org/0000-0002-1825-0097");
+        assert_eq!(graph[4]["name"], "file.txt");
+    }
+}
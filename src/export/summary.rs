@@ -0,0 +1,233 @@
+use serde_json::Value;
+
+// Most formats only cover the citation metadata of a dataset. The summary
+// collects these fields from the metadata blocks once, such that exporters do
+// not need to navigate the nested field structure themselves.
+
+/// The citation metadata and files of a dataset, as used by most export formats.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DatasetSummary {
+    /// The persistent identifier, e.g. `doi:10.5072/FK2/ABC123`.
+    pub persistent_id: Option<String>,
+    /// The resolvable URL of the persistent identifier.
+    pub url: Option<String>,
+    pub title: Option<String>,
+    pub authors: Vec<Author>,
+    pub descriptions: Vec<String>,
+    pub subjects: Vec<String>,
+    pub keywords: Vec<String>,
+    pub publisher: Option<String>,
+    /// The publication date in the form `YYYY-MM-DD`.
+    pub publication_date: Option<String>,
+    /// The version, e.g. `1.0`, which is absent for drafts.
+    pub version: Option<String>,
+    pub license: Option<License>,
+    pub files: Vec<FileSummary>,
+}
+
+/// An author of a dataset.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Author {
+    pub name: String,
+    pub affiliation: Option<String>,
+    /// The scheme of the identifier, e.g. `ORCID`.
+    pub identifier_scheme: Option<String>,
+    pub identifier: Option<String>,
+}
+
+/// The license of a dataset.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct License {
+    pub name: String,
+    pub uri: Option<String>,
+}
+
+/// A file of a dataset.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileSummary {
+    pub id: Option<i64>,
+    /// The path of the file within the dataset, including its directory.
+    pub path: String,
+    pub content_type: Option<String>,
+    pub size: Option<i64>,
+    pub checksum_type: Option<String>,
+    pub checksum: Option<String>,
+}
+
+impl DatasetSummary {
+    /// Collects the summary from the `dataverse_json` representation of a dataset.
+    ///
+    /// Both the export of a dataset, which holds the version in `datasetVersion`, and the representation
+    /// returned when retrieving a dataset, which holds it in `latestVersion`, are accepted.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `DatasetSummary`, or a `String` error message if the dataset does not contain
+    /// a version.
+    pub fn from_value(dataset: &Value) -> Result<Self, String> {
+        let version = dataset
+            .get("datasetVersion")
+            .or_else(|| dataset.get("latestVersion"))
+            .ok_or("The dataset does not contain a version")?;
+        let blocks = &version["metadataBlocks"];
+
+        let persistent_id = match (text(&dataset["protocol"]), text(&dataset["authority"]), text(&dataset["identifier"])) {
+            (Some(protocol), Some(authority), Some(identifier)) => {
+                Some(format!("{}:{}/{}", protocol, authority, identifier))
+            }
+            _ => text(&version["datasetPersistentId"]),
+        };
+
+        let authors = compound_values(blocks, "author")
+            .into_iter()
+            .filter_map(|author| {
+                Some(Author {
+                    name: text(&author["authorName"]["value"])?,
+                    affiliation: text(&author["authorAffiliation"]["value"]),
+                    identifier_scheme: text(&author["authorIdentifierScheme"]["value"]),
+                    identifier: text(&author["authorIdentifier"]["value"]),
+                })
+            })
+            .collect();
+
+        let version_label = match (version["versionNumber"].as_i64(), version["versionMinorNumber"].as_i64()) {
+            (Some(major), Some(minor)) => Some(format!("{}.{}", major, minor)),
+            _ => None,
+        };
+
+        let license = text(&version["license"]["name"]).map(|name| License {
+            name,
+            uri: text(&version["license"]["uri"]),
+        });
+
+        let files = version["files"]
+            .as_array()
+            .map(|files| files.iter().filter_map(file_summary).collect())
+            .unwrap_or_default();
+
+        Ok(DatasetSummary {
+            persistent_id,
+            url: text(&dataset["persistentUrl"]),
+            title: find_field(blocks, "title").and_then(|field| text(&field["value"])),
+            authors,
+            descriptions: compound_texts(blocks, "dsDescription", "dsDescriptionValue"),
+            subjects: find_field(blocks, "subject")
+                .map(|field| texts(&field["value"]))
+                .unwrap_or_default(),
+            keywords: compound_texts(blocks, "keyword", "keywordValue"),
+            publisher: text(&dataset["publisher"]),
+            publication_date: text(&dataset["publicationDate"])
+                .or_else(|| text(&version["releaseTime"]).map(|time| time.chars().take(10).collect())),
+            version: version_label,
+            license,
+            files,
+        })
+    }
+
+    /// Returns the year of publication, e.g. for citations.
+    pub fn publication_year(&self) -> Option<i64> {
+        self.publication_date.as_ref()?.get(..4)?.parse().ok()
+    }
+}
+
+fn file_summary(file: &Value) -> Option<FileSummary> {
+    let label = text(&file["label"])?;
+    let path = match text(&file["directoryLabel"]) {
+        Some(directory) => format!("{}/{}", directory.trim_end_matches('/'), label),
+        None => label,
+    };
+    let data_file = &file["dataFile"];
+
+    Some(FileSummary {
+        id: data_file["id"].as_i64(),
+        path,
+        content_type: text(&data_file["contentType"]),
+        size: data_file["filesize"].as_i64(),
+        checksum_type: text(&data_file["checksum"]["type"]),
+        checksum: text(&data_file["checksum"]["value"]),
+    })
+}
+
+// Searches all metadata blocks for a field of the given type name
+fn find_field<'a>(blocks: &'a Value, type_name: &str) -> Option<&'a Value> {
+    blocks
+        .as_object()?
+        .values()
+        .filter_map(|block| block["fields"].as_array())
+        .flatten()
+        .find(|field| field["typeName"] == type_name)
+}
+
+// Returns the entries of a compound field, which is a single object unless
+// the field allows multiple values
+fn compound_values<'a>(blocks: &'a Value, type_name: &str) -> Vec<&'a Value> {
+    match find_field(blocks, type_name).map(|field| &field["value"]) {
+        Some(Value::Array(values)) => values.iter().collect(),
+        Some(value @ Value::Object(_)) => vec![value],
+        _ => Vec::new(),
+    }
+}
+
+fn compound_texts(blocks: &Value, type_name: &str, child: &str) -> Vec<String> {
+    compound_values(blocks, type_name)
+        .into_iter()
+        .filter_map(|value| text(&value[child]["value"]))
+        .collect()
+}
+
+fn texts(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(values) => values.iter().filter_map(text).collect(),
+        value => text(value).into_iter().collect(),
+    }
+}
+
+fn text(value: &Value) -> Option<String> {
+    value.as_str().map(|value| value.trim().to_string()).filter(|value| !value.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Author, DatasetSummary, License};
+
+    /// Tests collecting the citation metadata and files of an export.
+    ///
+    /// # Fixture Files
+    /// - `./tests/fixtures/dataverse_json_export.json`: An export of a released dataset.
+    #[test]
+    fn test_summary_from_export() {
+        let content = std::fs::read_to_string("tests/fixtures/dataverse_json_export.json")
+            .expect("Failed to read export fixture");
+        let export: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+        let summary = DatasetSummary::from_value(&export).expect("Failed to summarize export");
+
+        assert_eq!(summary.persistent_id.as_deref(), Some("doi:10.5072/FK2/ABC123"));
+        assert_eq!(summary.title.as_deref(), Some("Exported Dataset"));
+        assert_eq!(summary.subjects, vec!["Other"]);
+        assert_eq!(summary.keywords, vec!["testing"]);
+        assert_eq!(summary.version.as_deref(), Some("1.0"));
+        assert_eq!(summary.publication_year(), Some(2024));
+        assert_eq!(
+            summary.authors[0],
+            Author {
+                name: "Doe, John".to_string(),
+                affiliation: Some("Dataverse University".to_string()),
+                identifier_scheme: Some("ORCID".to_string()),
+                identifier: Some("0000-0002-1825-0097".to_string()),
+            }
+        );
+        assert_eq!(summary.authors[1].affiliation, None);
+        assert_eq!(
+            summary.license,
+            Some(License {
+                name: "CC0 1.0".to_string(),
+                uri: Some("http://creativecommons.org/publicdomain/zero/1.0".to_string()),
+            })
+        );
+        assert_eq!(summary.files[0].path, "docs/file.txt");
+        assert_eq!(summary.files[0].checksum_type.as_deref(), Some("MD5"));
+
+        assert!(DatasetSummary::from_value(&serde_json::json!({"id": 1})).is_err());
+    }
+}
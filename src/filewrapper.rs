@@ -1,38 +1,22 @@
-use std::fmt::Write as FmtWrite;
 use std::path::PathBuf;
 use std::sync::Arc;
 
-use bytes::Bytes;
-use futures::StreamExt;
-use indicatif::{MultiProgress, ProgressBar, ProgressState, ProgressStyle};
 use reqwest::multipart::Part;
 use tokio::fs::File;
-use tokio::io::AsyncRead;
+use tokio::io::{AsyncRead, AsyncWrite, AsyncWriteExt};
 use tokio_util::io::ReaderStream;
 
 use crate::callback::CallbackFun;
 
 pub async fn create_multipart(
     file_path: &PathBuf,
-    multi_pb: Arc<MultiProgress>,
     callback: Option<CallbackFun>,
 ) -> Result<Part, Box<dyn std::error::Error>> {
     // Open the file and get its length
     let file = File::open(file_path).await?;
     let file_length = file.metadata().await?.len();
 
-    // Create a progress bar and add it to the MultiProgress
-    let pb: Arc<ProgressBar> = Arc::new(multi_pb.add(ProgressBar::new(file_length)));
-    pb.set_style(
-        ProgressStyle::with_template(
-            "\n{spinner:.green} [{elapsed_precise}] {bar:.gray/black} {bytes}/{total_bytes} ({eta})\n",
-        )
-            .unwrap()
-            .with_key("eta", |state: &ProgressState, w: &mut dyn FmtWrite| {
-                write!(w, "{:.1}s", state.eta().as_secs_f64()).unwrap()
-            })
-            .progress_chars("■ "),
-    );
+    let pb = Arc::new(progress::bar(file_length));
 
     // Wrap the file in a ProgressReader to track progress
     let reader = ProgressReader {
@@ -42,7 +26,7 @@ pub async fn create_multipart(
     };
 
     // Create a stream from the ProgressReader
-    let stream = ReaderStream::new(reader).map(|result| result.map(Bytes::from));
+    let stream = ReaderStream::new(reader);
 
     // Create a multipart part
     let filename = file_path
@@ -63,7 +47,7 @@ pub async fn create_multipart(
 // as data is read from it using the AsyncRead trait.
 struct ProgressReader {
     inner: Box<dyn AsyncRead + Unpin + Send + Sync>,
-    pb: Arc<ProgressBar>,
+    pb: Arc<progress::Bar>,
     callback: Option<CallbackFun>,
 }
 
@@ -87,4 +71,120 @@ impl AsyncRead for ProgressReader {
 
         result
     }
-}
\ No newline at end of file
+}
+
+// Streams the body of a response into a file and returns the number of bytes written
+pub async fn write_response(
+    mut response: reqwest::Response,
+    file_path: &PathBuf,
+    callback: Option<CallbackFun>,
+) -> Result<u64, Box<dyn std::error::Error>> {
+    let pb = Arc::new(progress::bar(response.content_length().unwrap_or_default()));
+
+    // Wrap the file in a ProgressWriter to track progress
+    let mut writer = ProgressWriter {
+        inner: Box::new(File::create(file_path).await?),
+        pb: Arc::clone(&pb),
+        callback,
+    };
+
+    // Write the response chunk by chunk, such that large files are not held in memory
+    let mut written = 0;
+    while let Some(chunk) = response.chunk().await? {
+        writer.write_all(&chunk).await?;
+        written += chunk.len() as u64;
+    }
+
+    writer.flush().await?;
+    pb.finish();
+
+    Ok(written)
+}
+
+// A writer that tracks progress and updates a progress bar
+// as data is written to it using the AsyncWrite trait.
+struct ProgressWriter {
+    inner: Box<dyn AsyncWrite + Unpin + Send + Sync>,
+    pb: Arc<progress::Bar>,
+    callback: Option<CallbackFun>,
+}
+
+impl AsyncWrite for ProgressWriter {
+    fn poll_write(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+        buf: &[u8],
+    ) -> std::task::Poll<std::io::Result<usize>> {
+        let result = std::pin::Pin::new(&mut self.inner).poll_write(cx, buf);
+
+        if let std::task::Poll::Ready(Ok(bytes_written)) = result {
+            if bytes_written > 0 {
+                self.pb.inc(bytes_written as u64);
+
+                if let Some(callback) = &self.callback {
+                    callback.call(bytes_written as u64);
+                }
+            }
+        }
+
+        result
+    }
+
+    fn poll_flush(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::pin::Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(
+        mut self: std::pin::Pin<&mut Self>,
+        cx: &mut std::task::Context<'_>,
+    ) -> std::task::Poll<std::io::Result<()>> {
+        std::pin::Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
+
+// Progress bars are only rendered with the `progress` feature. Without it,
+// transfers are reported through the callbacks alone.
+#[cfg(feature = "progress")]
+mod progress {
+    use std::fmt::Write as FmtWrite;
+
+    use indicatif::{ProgressBar, ProgressState, ProgressStyle};
+
+    use crate::verbosity::{verbosity, Verbosity};
+
+    pub(super) type Bar = ProgressBar;
+
+    pub(super) fn bar(length: u64) -> Bar {
+        if verbosity() == Verbosity::Quiet {
+            return ProgressBar::hidden();
+        }
+
+        ProgressBar::new(length).with_style(
+            ProgressStyle::with_template(
+                "\n{spinner:.green} [{elapsed_precise}] {bar:.gray/black} {bytes}/{total_bytes} ({eta})\n",
+            )
+                .unwrap()
+                .with_key("eta", |state: &ProgressState, w: &mut dyn FmtWrite| {
+                    write!(w, "{:.1}s", state.eta().as_secs_f64()).unwrap()
+                })
+                .progress_chars("■ "),
+        )
+    }
+}
+
+#[cfg(not(feature = "progress"))]
+mod progress {
+    pub(super) struct Bar;
+
+    impl Bar {
+        pub(super) fn inc(&self, _delta: u64) {}
+        pub(super) fn finish(&self) {}
+    }
+
+    pub(super) fn bar(_length: u64) -> Bar {
+        Bar
+    }
+}
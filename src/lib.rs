@@ -1,71 +1,326 @@
 #![warn(unused_crate_dependencies)]
+
+// Responses are only built from local bodies outside of `wasm32`
+#[cfg(target_arch = "wasm32")]
+use http as _;
+
+pub mod auth;
+#[cfg(feature = "fs")]
+pub mod bagit;
+#[cfg(feature = "blocking")]
+pub mod blocking;
 pub mod client;
+pub mod costs;
+pub mod error;
 pub mod identifier;
+#[cfg(feature = "fs")]
 pub mod filewrapper;
+#[cfg(feature = "fs")]
+pub mod plan;
+#[cfg(feature = "fs")]
+pub mod queue;
+#[cfg(feature = "record")]
+pub mod record;
 pub mod request;
 pub mod response;
+#[cfg(feature = "fs")]
+pub mod retry;
+pub mod schemas;
+pub mod stats;
+#[cfg(feature = "sword")]
+pub mod sword;
+pub mod transport;
+pub mod urls;
 pub mod utils;
+pub mod verbosity;
+#[cfg(feature = "fs")]
+pub mod verify;
 pub mod callback;
+pub mod checksum;
+
+pub mod access {
+    // Re-export the data access API modules
+    #[cfg(feature = "fs")]
+    pub use batch::download_dataset_batched;
+    #[cfg(feature = "fs")]
+    pub use dataset::download_dataset;
+    #[cfg(feature = "fs")]
+    pub use datafile::{download_file, download_tabular_file};
+    #[cfg(feature = "fs")]
+    pub use mirror::mirror_dataset;
+    #[cfg(feature = "fs")]
+    pub use zip::{download_dataset_zip, download_files_zip};
+
+    #[cfg(feature = "fs")]
+    pub mod batch;
+    #[cfg(feature = "fs")]
+    pub mod dataset;
+    pub mod datafile;
+    #[cfg(feature = "fs")]
+    pub mod mirror;
+    #[cfg(feature = "fs")]
+    pub mod zip;
+}
+
+pub mod crosswalk {
+    // Re-export the conversions of metadata records from other schemas
+    pub use datacite::from_datacite;
+    pub use dublin_core::from_dublin_core;
+
+    pub mod datacite;
+    pub mod dublin_core;
+}
+
+pub mod export {
+    // Re-export the exporters and the trait to implement further formats
+    pub use croissant::CroissantExporter;
+    pub use datacite::DataCiteExporter;
+    pub use dublin_core::DublinCoreExporter;
+    pub use exporter::{Exporter, ExporterRegistry};
+    pub use ro_crate::RoCrateExporter;
+    pub use summary::DatasetSummary;
+
+    pub mod croissant;
+    pub mod datacite;
+    pub mod dublin_core;
+    pub mod exporter;
+    pub mod ro_crate;
+    pub mod summary;
+}
+
+pub mod models {
+    // Typed representations of documents produced by the instance
+    pub mod schema_org;
+}
 
 pub mod native_api {
+    pub mod admin {
+        #[cfg(feature = "admin")]
+        pub mod archive;
+        pub mod banners;
+        #[cfg(feature = "admin")]
+        pub mod builtin_users;
+        #[cfg(feature = "harvest")]
+        pub mod harvest {
+            // Re-export the harvesting API modules
+            pub use clients::{create_client, delete_client, get_client, list_clients, modify_client};
+
+            pub mod clients;
+        }
+        #[cfg(feature = "admin")]
+        pub mod index;
+        #[cfg(feature = "admin")]
+        pub mod ip_groups;
+        #[cfg(feature = "admin")]
+        pub mod locks;
+        pub mod permissions;
+        #[cfg(feature = "admin")]
+        pub mod pids;
+        #[cfg(feature = "admin")]
+        pub mod settings;
+        #[cfg(feature = "admin")]
+        pub mod workflows;
+    }
     pub mod collection {
         // Re-export the collection API modules
+        pub use assignments::list_assignments;
+        pub use attribute::set_collection_attribute;
         pub use content::get_content;
         pub use create::create_collection;
         pub use delete::delete_collection;
+        pub use facets::{get_facets, set_facets};
+        pub use get::get_collection;
+        #[cfg(feature = "fs")]
+        pub use guestbooks::download_guestbook_responses;
+        pub use guestbooks::{list_guestbooks, set_guestbook_enabled};
+        pub use metadatablocks::get_metadata_blocks;
         pub use publish::publish_collection;
+        pub use relocate::move_collection;
+        pub use storage::{delete_storage_quota, get_storage_quota, get_storage_size, set_storage_quota};
+        pub use tree::get_collection_tree;
 
+        pub mod assignments;
+        pub mod attribute;
         pub mod content;
         pub mod create;
         pub mod delete;
+        pub mod facets;
+        pub mod get;
+        pub mod guestbooks;
+        pub mod metadatablocks;
         pub mod publish;
+        pub mod relocate;
+        pub mod storage;
+        pub mod tree;
     }
     pub mod info {
         // Re-export the info API modules
+        pub use embargo::get_max_embargo_duration;
+        pub use export_formats::get_export_formats;
+        pub use server::{get_api_terms_of_use, get_server};
         pub use version::get_version;
+        pub use zip_limit::get_zip_download_limit;
 
+        pub mod embargo;
+        pub mod export_formats;
+        pub mod server;
         pub mod version;
+        pub mod zip_limit;
     }
     pub mod dataset {
         // Re-export the dataset API modules
+        pub use assignments::list_dataset_assignments;
+        pub use attribute::set_dataset_attribute;
+        pub use batch::create_datasets_from_csv;
+        pub use citation::{export_citation, get_citation};
         pub use create::create_dataset;
-        pub use delete::delete_dataset;
+        pub use delete::{delete_dataset, destroy_dataset};
+        pub use describe::describe_dataset;
+        pub use diff::compare_versions;
+        #[cfg(feature = "fs")]
+        pub use directory::upload_directory;
         pub use edit::edit_dataset_metadata;
+        pub use export::{export_croissant, export_dataset, export_schema_org};
+        pub use files::list_dataset_files;
         pub use get::get_dataset_meta;
+        #[cfg(feature = "fs")]
+        pub use ingest::wait_for_ingest;
         pub use link::link_dataset;
-        pub use upload::upload_file_to_dataset;
+        pub use locks::get_locks;
+        pub use metadata::DatasetMetadataBuilder;
+        pub use pid::{delete_pid, reserve_pid};
+        pub use preview_url::{create_preview_url, delete_preview_url, get_preview_url};
+        #[cfg(feature = "fs")]
+        pub use reorganize::plan_reorganize;
+        pub use review::{return_to_author, submit_for_review};
+        #[cfg(feature = "fs")]
+        pub use sync::{plan_sync, sync_directory};
+        #[cfg(feature = "fs")]
+        pub use thumbnail::{get_thumbnail, set_thumbnail_from_file};
+        pub use thumbnail::{list_thumbnail_candidates, remove_thumbnail, set_thumbnail_from_datafile};
+        #[cfg(feature = "fs")]
+        pub use upload::{upload_file_to_dataset, upload_files_to_dataset};
+        pub use validate::{validate_against_blocks, validate_dataset, validate_structure};
 
+        pub mod assignments;
+        pub mod attribute;
+        pub mod batch;
+        pub mod citation;
         pub mod create;
         pub mod delete;
+        pub mod describe;
+        pub mod diff;
+        #[cfg(feature = "directupload")]
+        pub mod direct_upload;
+        #[cfg(feature = "fs")]
+        pub mod directory;
         pub mod edit;
+        pub mod export;
+        pub mod files;
         pub mod get;
+        #[cfg(feature = "fs")]
+        pub mod ingest;
         pub mod link;
+        pub mod locks;
+        pub mod metadata;
+        pub mod pid;
+        pub mod preview_url;
         pub mod publish;
+        #[cfg(feature = "directupload")]
+        pub mod remote;
+        #[cfg(feature = "fs")]
+        pub mod reorganize;
+        pub mod review;
+        #[cfg(feature = "fs")]
+        pub mod sync;
+        pub mod thumbnail;
+        #[cfg(feature = "fs")]
         pub mod upload;
+        pub mod validate;
     }
     pub mod file {
+        pub use access_requests::{grant_access, list_access_requests, reject_access, request_access};
+        pub use attribute::set_file_attribute;
+        pub use datadicts::get_data_dictionary;
+        pub use delete::delete_file;
+        pub use embargo::{remove_embargo, set_embargo};
+        pub use fixity::get_fixity_algorithm;
+        pub use ingest::{reingest_file, uningest_file};
+        pub use metadata::{move_file, rename_file, set_file_categories, set_tabular_tags, update_file_metadata};
+        #[cfg(feature = "fs")]
         pub use replace::replace_file;
+        #[cfg(feature = "directupload")]
+        pub use replace::replace_multiple;
 
+        pub mod access_requests;
+        pub mod attribute;
+        pub mod datadicts;
+        pub mod delete;
+        pub mod embargo;
+        pub mod fixity;
+        pub mod ingest;
+        pub mod metadata;
+        #[cfg(feature = "fs")]
         pub mod replace;
     }
+    #[cfg(feature = "metrics")]
+    pub mod metrics {
+        // Re-export the metrics API modules
+        pub use breakdown::{get_dataverses_by_category, get_datasets_by_subject};
+        pub use counts::{get_metric, get_monthly_metric};
+
+        pub mod breakdown;
+        pub mod counts;
+    }
+    pub mod users {
+        // Re-export the users API modules
+        pub use me::get_me;
+        pub use token::{get_token_expiration, recreate_token, revoke_token};
+
+        pub mod me;
+        pub mod token;
+    }
 }
 
 pub mod prelude {
+    pub use super::access;
+    pub use super::native_api::admin;
     pub use super::callback::CallbackFun;
-    pub use super::client::BaseClient;
+    pub use super::client::{BaseClient, BaseClientBuilder};
     pub use super::identifier::Identifier;
     pub use super::native_api::collection;
     pub use super::native_api::dataset;
     pub use super::native_api::file;
     pub use super::native_api::info;
+    #[cfg(feature = "metrics")]
+    pub use super::native_api::metrics;
+    pub use super::native_api::users;
 }
 
+#[cfg(feature = "cli")]
 pub mod cli {
+    #[cfg(feature = "admin")]
+    pub mod admin;
+    pub mod auth;
+    pub mod banner;
     pub mod base;
     pub mod collection;
+    pub mod config;
     pub mod dataset;
+    #[cfg(feature = "record")]
+    pub mod dev;
+    pub mod fields;
     pub mod file;
+    pub mod i18n;
     pub mod info;
+    #[cfg(feature = "metrics")]
+    pub mod metrics;
+    pub mod plan;
+    pub mod stats;
+    pub mod summary;
+    pub mod table;
+    pub mod template;
+    pub mod user;
 }
 
 #[cfg(test)]
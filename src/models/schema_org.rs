@@ -0,0 +1,316 @@
+//! Typed representations of the schema.org and Croissant JSON-LD exports of a dataset.
+//!
+//! Both exports describe a `Dataset` of schema.org, where Croissant adds the `conformsTo` entry and record
+//! sets for ML tooling. JSON-LD allows single values in place of lists and plain strings in place of nested
+//! objects, and the exports of different Dataverse versions differ in this respect. The types therefore
+//! accept both forms, e.g. `creator` is always a list and `publisher` always an `Organization`.
+//!
+//! ```no_run
+//! use dataverse::prelude::*;
+//!
+//! # async fn run() -> Result<(), String> {
+//! let client = BaseClient::new("https://demo.dataverse.org", None)
+//!     .expect("Failed to create client");
+//!
+//! let dataset = dataset::export_croissant(&client, "doi:10.5072/FK2/ABC123").await?;
+//! for file in &dataset.distribution {
+//!     println!("{:?}: {:?} bytes", file.content_url, file.content_size);
+//! }
+//! # Ok(())
+//! # }
+//! ```
+
+use serde::de::{DeserializeOwned, Deserializer};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+/// A dataset as described by the `schema.org` and `croissant` exporters.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct SchemaOrgDataset {
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    /// The Croissant version the document conforms to, e.g. `http://mlcommons.org/croissant/1.0`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conforms_to: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(default, deserialize_with = "text", skip_serializing_if = "Option::is_none")]
+    pub version: Option<String>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub description: Vec<String>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub keywords: Vec<String>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub creator: Vec<Person>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub license: Option<License>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_published: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date_modified: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publisher: Option<Organization>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub included_in_data_catalog: Option<Organization>,
+    /// The files of the dataset.
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub distribution: Vec<FileObject>,
+    /// The record sets describing the structure of tabular files, only provided by Croissant.
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub record_set: Vec<RecordSet>,
+}
+
+impl SchemaOrgDataset {
+    /// Parses a schema.org or Croissant document, as returned by `dataset::export_dataset`.
+    pub fn from_slice(document: &[u8]) -> Result<Self, String> {
+        serde_json::from_slice(document).map_err(|e| format!("Invalid schema.org document: {}", e))
+    }
+
+    /// Returns whether the document is a Croissant description.
+    pub fn is_croissant(&self) -> bool {
+        self.conforms_to
+            .as_deref()
+            .is_some_and(|uri| uri.starts_with("http://mlcommons.org/croissant/"))
+    }
+}
+
+/// A creator of a dataset, which may be a person or an organization.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Person {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub given_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub family_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub affiliation: Option<Organization>,
+    /// The identifier of the person, e.g. an ORCID URL.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub same_as: Option<String>,
+}
+
+/// An organization, such as a publisher or an affiliation. Plain names are read into `name`.
+#[derive(Serialize, Debug, Clone, Default, PartialEq)]
+pub struct Organization {
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+impl<'de> Deserialize<'de> for Organization {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        struct Object {
+            #[serde(rename = "@type")]
+            kind: Option<String>,
+            name: Option<String>,
+            url: Option<String>,
+        }
+
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Form {
+            Name(String),
+            Object(Object),
+        }
+
+        Ok(match Form::deserialize(deserializer)? {
+            Form::Name(name) => Organization { name: Some(name), ..Default::default() },
+            Form::Object(object) => Organization { kind: object.kind, name: object.name, url: object.url },
+        })
+    }
+}
+
+/// The license of a dataset, given by its URL or as a `CreativeWork`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum License {
+    Url(String),
+    Work {
+        #[serde(skip_serializing_if = "Option::is_none")]
+        name: Option<String>,
+        #[serde(skip_serializing_if = "Option::is_none")]
+        url: Option<String>,
+    },
+}
+
+impl License {
+    /// Returns the URL of the license, if known.
+    pub fn url(&self) -> Option<&str> {
+        match self {
+            License::Url(url) => Some(url),
+            License::Work { url, .. } => url.as_deref(),
+        }
+    }
+}
+
+/// A file of a dataset, described as `DataDownload` by schema.org and as `cr:FileObject` by Croissant.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FileObject {
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(rename = "@type", skip_serializing_if = "Option::is_none")]
+    pub kind: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding_format: Option<String>,
+    /// The size in bytes, read from numbers as well as texts such as `12 B`.
+    #[serde(default, deserialize_with = "size", skip_serializing_if = "Option::is_none")]
+    pub content_size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub md5: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha256: Option<String>,
+}
+
+/// A set of records, such as the rows of a tabular file, along with its fields.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RecordSet {
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub field: Vec<Field>,
+}
+
+/// A field of a record set, such as a column of a tabular file.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Field {
+    #[serde(rename = "@id", skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// The types of the values, e.g. `sc:Integer`.
+    #[serde(default, deserialize_with = "one_or_many")]
+    pub data_type: Vec<String>,
+}
+
+// Reads a single value or a list of values into a list, skipping nulls
+fn one_or_many<'de, D, T>(deserializer: D) -> Result<Vec<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: DeserializeOwned,
+{
+    match Value::deserialize(deserializer)? {
+        Value::Null => Ok(Vec::new()),
+        Value::Array(values) => values
+            .into_iter()
+            .filter(|value| !value.is_null())
+            .map(|value| serde_json::from_value(value).map_err(serde::de::Error::custom))
+            .collect(),
+        value => serde_json::from_value(value).map(|value| vec![value]).map_err(serde::de::Error::custom),
+    }
+}
+
+// Reads texts and numbers, such as versions given as `1` or `"1.0"`
+fn text<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<String>, D::Error> {
+    Ok(match Value::deserialize(deserializer)? {
+        Value::String(text) => Some(text),
+        Value::Number(number) => Some(number.to_string()),
+        _ => None,
+    })
+}
+
+// Reads sizes given in bytes as numbers or as texts with the unit `B`
+fn size<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<u64>, D::Error> {
+    Ok(match Value::deserialize(deserializer)? {
+        Value::Number(number) => number.as_u64(),
+        Value::String(text) => text.trim().trim_end_matches('B').trim().parse().ok(),
+        _ => None,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use crate::export::{CroissantExporter, Exporter};
+
+    use super::{License, SchemaOrgDataset};
+
+    /// Tests that a schema.org export with single values and nested objects is read into lists.
+    #[test]
+    fn test_parse_schema_org() {
+        let document = json!({
+            "@context": "http://schema.org",
+            "@type": "Dataset",
+            "@id": "https://doi.org/10.5072/FK2/ABC123",
+            "identifier": "https://doi.org/10.5072/FK2/ABC123",
+            "name": "Soil moisture",
+            "creator": {
+                "@type": "Person",
+                "name": "Doe, Jane",
+                "givenName": "Jane",
+                "familyName": "Doe",
+                "affiliation": {"@type": "Organization", "name": "University of Example"},
+                "sameAs": "https://orcid.org/0000-0002-1825-0097"
+            },
+            "version": 2,
+            "description": "Hourly measurements.",
+            "keywords": ["Earth and Environmental Sciences", "soil"],
+            "license": {"@type": "CreativeWork", "name": "CC0 1.0", "url": "http://creativecommons.org/publicdomain/zero/1.0"},
+            "publisher": {"@type": "Organization", "name": "Demo Dataverse"},
+            "distribution": [{
+                "@type": "DataDownload",
+                "name": "data.csv",
+                "encodingFormat": "text/csv",
+                "contentSize": 1024,
+                "contentUrl": "https://demo.dataverse.org/api/access/datafile/5"
+            }]
+        });
+
+        let dataset = SchemaOrgDataset::from_slice(&serde_json::to_vec(&document).unwrap()).unwrap();
+
+        assert!(!dataset.is_croissant());
+        assert_eq!(dataset.version.as_deref(), Some("2"));
+        assert_eq!(dataset.description, vec!["Hourly measurements."]);
+        assert_eq!(dataset.creator[0].affiliation.as_ref().unwrap().name.as_deref(), Some("University of Example"));
+        assert_eq!(dataset.license.as_ref().and_then(License::url), Some("http://creativecommons.org/publicdomain/zero/1.0"));
+        assert_eq!(dataset.distribution[0].content_size, Some(1024));
+        assert!(dataset.record_set.is_empty());
+    }
+
+    /// Tests that a Croissant document is read, including plain publishers and sizes in text.
+    #[test]
+    fn test_parse_croissant() {
+        let content = std::fs::read_to_string("tests/fixtures/dataverse_json_export.json").unwrap();
+        let export: serde_json::Value = serde_json::from_str(&content).unwrap();
+        let document = CroissantExporter.export(&export).unwrap();
+
+        let dataset = SchemaOrgDataset::from_slice(&document).unwrap();
+
+        assert!(dataset.is_croissant());
+        assert_eq!(dataset.name.as_deref(), Some("Exported Dataset"));
+        assert_eq!(dataset.creator[0].name.as_deref(), Some("Doe, John"));
+        assert_eq!(dataset.distribution[0].content_url.as_deref(), Some("docs/file.txt"));
+        assert_eq!(dataset.distribution[0].content_size, Some(12));
+        assert_eq!(dataset.distribution[0].md5.as_deref(), Some("a28bca1b906f539ba70ca3a0b1f2e773"));
+    }
+}
@@ -0,0 +1,311 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+// This module is only available with the `admin` feature. Archiving requires an
+// archiver to be configured on the instance, e.g. via the `:ArchiverClassName`
+// setting, and a superuser token.
+
+/// The state of the archival copy of a dataset version.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "lowercase")]
+pub enum ArchivalState {
+    Success,
+    Pending,
+    Failure,
+}
+
+/// The archival status of a dataset version, as recorded by the archiver.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ArchivalStatus {
+    pub status: ArchivalState,
+    /// A message of the archiver, typically the location of the bag or the reason of a failure.
+    #[serde(default)]
+    pub message: Option<String>,
+}
+
+/// Submits a version of a dataset to the configured archiver.
+///
+/// The archiver creates a BagIt bag of the version and transfers it to the archive asynchronously, such that a
+/// successful response only confirms the submission. Use [`get_archival_status`] to follow its progress.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the dataset.
+/// * `version` - A string slice that holds the version to archive, e.g. `1.0`.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "http://localhost:8080".to_string();
+/// let client = BaseClient::new(&base_url, Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let response = admin::archive::submit_to_archive(&client, id, "1.0").await?;
+///
+/// println!("{:?}", response.data);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn submit_to_archive(
+    client: &BaseClient,
+    id: Identifier,
+    version: &str,
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let (url, parameters) = match id {
+        Identifier::PersistentId(id) => (
+            format!("api/admin/submitDatasetVersionToArchive/:persistentId/{}", version),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (
+            format!("api/admin/submitDatasetVersionToArchive/{}/{}", id, version),
+            None,
+        ),
+    };
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.post(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Retrieves the archival status of a version of a dataset.
+///
+/// Versions that have not been submitted to the archiver yet have no status, in which case the instance
+/// responds with an error.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the dataset.
+/// * `version` - A string slice that holds the version, e.g. `1.0`.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<ArchivalStatus>`, which contains the HTTP response status and the archival
+/// status, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::admin::archive::ArchivalState;
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "http://localhost:8080".to_string();
+/// let client = BaseClient::new(&base_url, Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let response = admin::archive::get_archival_status(&client, Identifier::Id(42), "1.0").await?;
+///
+/// if let Some(status) = response.data {
+///     if status.status == ArchivalState::Failure {
+///         eprintln!("Archiving failed: {}", status.message.unwrap_or_default());
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_archival_status(
+    client: &BaseClient,
+    id: Identifier,
+    version: &str,
+) -> Result<Response<ArchivalStatus>, String> {
+    let (url, parameters) = archival_status_endpoint(id, version);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<ArchivalStatus>(response).await
+}
+
+/// Records the archival status of a version of a dataset.
+///
+/// Archivers running outside of the instance use this to report the outcome of a transfer, which is then
+/// shown to curators. Setting a status requires a superuser token.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the dataset.
+/// * `version` - A string slice that holds the version, e.g. `1.0`.
+/// * `status` - A reference to the `ArchivalStatus` to record.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::admin::archive::{ArchivalState, ArchivalStatus};
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "http://localhost:8080".to_string();
+/// let client = BaseClient::new(&base_url, Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let status = ArchivalStatus {
+///     status: ArchivalState::Success,
+///     message: Some("s3://archive/doi-10-5072-fk2-abc123.v1.0.zip".to_string()),
+/// };
+/// admin::archive::set_archival_status(&client, Identifier::Id(42), "1.0", &status).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn set_archival_status(
+    client: &BaseClient,
+    id: Identifier,
+    version: &str,
+    status: &ArchivalStatus,
+) -> Result<Response<MessageResponse>, String> {
+    let (url, parameters) = archival_status_endpoint(id, version);
+
+    // Build body
+    let body = serde_json::to_string(status).map_err(|e| e.to_string())?;
+
+    // Send request
+    let context = RequestType::JSON { body };
+    let response = client.put(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Deletes the archival status of a version of a dataset, e.g. to submit it to the archiver again.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the dataset.
+/// * `version` - A string slice that holds the version, e.g. `1.0`.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+pub async fn delete_archival_status(
+    client: &BaseClient,
+    id: Identifier,
+    version: &str,
+) -> Result<Response<MessageResponse>, String> {
+    let (url, parameters) = archival_status_endpoint(id, version);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+fn archival_status_endpoint(id: Identifier, version: &str) -> (String, Option<HashMap<String, String>>) {
+    match id {
+        Identifier::PersistentId(id) => (
+            format!("api/datasets/:persistentId/{}/archivalStatus", version),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/datasets/{}/{}/archivalStatus", id, version), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    use super::{ArchivalState, ArchivalStatus};
+
+    /// Tests that a version is submitted by its persistent identifier and its status is parsed.
+    #[tokio::test]
+    async fn test_submit_and_get_status() {
+        let server = MockServer::start();
+        let submit = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/admin/submitDatasetVersionToArchive/:persistentId/1.0")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"message": "DatasetVersion id=7 submitted to Archive, status: pending"}
+            }));
+        });
+        let status = server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/42/1.0/archivalStatus");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"status": "failure", "message": "Bag could not be transferred"}
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+
+        let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+        let response = admin::archive::submit_to_archive(&client, id, "1.0").await.unwrap();
+        assert!(response.status.is_ok());
+
+        let response = admin::archive::get_archival_status(&client, Identifier::Id(42), "1.0").await.unwrap();
+        let data = response.data.unwrap();
+        assert_eq!(data.status, ArchivalState::Failure);
+        assert_eq!(data.message.as_deref(), Some("Bag could not be transferred"));
+
+        submit.assert();
+        status.assert();
+    }
+
+    /// Tests that an archiver records its outcome and that the status can be deleted again.
+    #[tokio::test]
+    async fn test_set_and_delete_status() {
+        let server = MockServer::start();
+        let set = server.mock(|when, then| {
+            when.method(PUT)
+                .path("/api/datasets/:persistentId/1.0/archivalStatus")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123")
+                .json_body(json!({"status": "success", "message": "s3://archive/bag.zip"}));
+            then.status(200).json_body(json!({"status": "OK", "data": {"message": "Status updated"}}));
+        });
+        let delete = server.mock(|when, then| {
+            when.method(DELETE).path("/api/datasets/42/1.0/archivalStatus");
+            then.status(200).json_body(json!({"status": "OK", "data": {"message": "Status deleted"}}));
+        });
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+        let status = ArchivalStatus {
+            status: ArchivalState::Success,
+            message: Some("s3://archive/bag.zip".to_string()),
+        };
+
+        let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+        let response = admin::archive::set_archival_status(&client, id, "1.0", &status).await.unwrap();
+        assert!(response.status.is_ok());
+
+        let response = admin::archive::delete_archival_status(&client, Identifier::Id(42), "1.0").await.unwrap();
+        assert!(response.status.is_ok());
+
+        set.assert();
+        delete.assert();
+    }
+}
@@ -0,0 +1,204 @@
+use serde::{Deserialize, Serialize, Serializer};
+use typify::import_types;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+import_types!(schema = "models/admin/banners.json");
+
+/// A banner message to add, given in one or more languages.
+///
+/// The instance displays the text in the language of the user interface, falling back to the
+/// default language of the instance.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct NewBannerMessage {
+    /// Whether users can dismiss the banner, after which it is no longer shown to them.
+    #[serde(serialize_with = "bool_as_string", default)]
+    pub dismissible_by_user: bool,
+    pub message_texts: Vec<BannerMessageText>,
+}
+
+/// The text of a banner message in a language.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct BannerMessageText {
+    /// The language code, e.g. `en` or `de`.
+    pub lang: String,
+    pub message: String,
+}
+
+// The instance expects the flag as a string
+fn bool_as_string<S: Serializer>(value: &bool, serializer: S) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(value)
+}
+
+/// Lists the banner messages of the instance.
+///
+/// Banner messages are announcements displayed on top of every page, such as scheduled maintenance. Note
+/// that the endpoint is part of the admin API, which is usually only reachable from the host the instance
+/// is running on.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<BannerMessage>>`, which contains the HTTP response status and the
+/// banner messages in the language of the instance, if the request is successful, or a `String` error
+/// message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "http://localhost:8080".to_string();
+/// let client = BaseClient::new(&base_url, None)
+///     .expect("Failed to create client");
+///
+/// let response = admin::banners::list_banner_messages(&client).await?;
+///
+/// for banner in response.data.unwrap_or_default() {
+///     println!("{}", banner.display_value.unwrap_or_default());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn list_banner_messages(client: &BaseClient) -> Result<Response<Vec<BannerMessage>>, String> {
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get("api/admin/bannerMessage", None, &context).await;
+
+    evaluate_response::<Vec<BannerMessage>>(response).await
+}
+
+/// Adds a banner message, which is displayed until it is deleted.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `banner` - A reference to the `NewBannerMessage` holding the texts per language.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::admin::banners::{BannerMessageText, NewBannerMessage};
+///
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "http://localhost:8080".to_string();
+/// let client = BaseClient::new(&base_url, None)
+///     .expect("Failed to create client");
+///
+/// let banner = NewBannerMessage {
+///     dismissible_by_user: false,
+///     message_texts: vec![
+///         BannerMessageText { lang: "en".to_string(), message: "Maintenance tonight from 22:00 UTC".to_string() },
+///         BannerMessageText { lang: "de".to_string(), message: "Wartung heute ab 22:00 UTC".to_string() },
+///     ],
+/// };
+///
+/// admin::banners::add_banner_message(&client, &banner).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn add_banner_message(
+    client: &BaseClient,
+    banner: &NewBannerMessage,
+) -> Result<Response<MessageResponse>, String> {
+    // Build body
+    let body = serde_json::to_string(banner).map_err(|e| e.to_string())?;
+
+    // Send request
+    let context = RequestType::JSON { body };
+    let response = client.post("api/admin/bannerMessage", None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Deletes a banner message.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - The ID of the banner message, as returned by `list_banner_messages`.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+pub async fn delete_banner_message(client: &BaseClient, id: i64) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/admin/bannerMessage/{}", id);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    use super::{BannerMessage, BannerMessageText, NewBannerMessage};
+
+    /// Tests parsing banner messages as returned by the instance.
+    #[test]
+    fn test_parse_banner_messages() {
+        let banners: Vec<BannerMessage> = serde_json::from_str(
+            r#"[{"id": 1, "displayValue": "Maintenance window tonight from 22:00 UTC"}]"#,
+        )
+            .expect("Failed to parse banner messages");
+
+        assert_eq!(banners[0].id, Some(1));
+        assert_eq!(
+            banners[0].display_value.as_deref(),
+            Some("Maintenance window tonight from 22:00 UTC")
+        );
+    }
+
+    /// Tests that a banner is added with its texts and the dismissible flag as string.
+    #[tokio::test]
+    async fn test_add_banner_message() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST).path("/api/admin/bannerMessage").json_body(json!({
+                "dismissibleByUser": "true",
+                "messageTexts": [
+                    {"lang": "en", "message": "Maintenance tonight"},
+                    {"lang": "de", "message": "Wartung heute Abend"}
+                ]
+            }));
+            then.status(200).json_body(json!({"status": "OK", "data": {"message": "Banner Message added successfully."}}));
+        });
+
+        let banner = NewBannerMessage {
+            dismissible_by_user: true,
+            message_texts: vec![
+                BannerMessageText { lang: "en".to_string(), message: "Maintenance tonight".to_string() },
+                BannerMessageText { lang: "de".to_string(), message: "Wartung heute Abend".to_string() },
+            ],
+        };
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let response = admin::banners::add_banner_message(&client, &banner).await.unwrap();
+        assert!(response.status.is_ok());
+
+        mock.assert();
+    }
+}
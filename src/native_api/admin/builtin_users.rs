@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use typify::import_types;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    request::RequestType,
+    response::Response,
+};
+
+import_types!(
+    schema = "models/admin/builtin_users.json",
+    struct_builder = true,
+);
+
+/// Creates a builtin user and returns its API token.
+///
+/// This asynchronous function sends a POST request to the builtin users endpoint. The endpoint is protected
+/// by the `BuiltinUsers.KEY` setting of the instance rather than an API token, and is usually only enabled
+/// in test environments. It is hence suited to provision users for integration tests or by admin scripts.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `body` - A `BuiltinUserBody` instance holding the user name, name and e-mail address of the user.
+/// * `password` - A string slice that holds the password of the new user.
+/// * `key` - A string slice that holds the builtin users key configured on the instance.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<CreateUserResponse>`, which contains the HTTP response status and the created
+/// user along with its API token, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::admin::builtin_users::BuiltinUserBody;
+///
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "http://localhost:8080".to_string();
+/// let client = BaseClient::new(&base_url, None)
+///     .expect("Failed to create client");
+///
+/// let body = BuiltinUserBody {
+///     user_name: "jdoe".to_string(),
+///     first_name: "John".to_string(),
+///     last_name: "Doe".to_string(),
+///     email: "jdoe@example.com".to_string(),
+///     affiliation: None,
+///     position: None,
+/// };
+///
+/// let response = admin::builtin_users::create_user(&client, body, "s3cret!", "burrito").await?;
+///
+/// println!("API token: {:?}", response.data.and_then(|d| d.api_token));
+/// # Ok(())
+/// # }
+/// ```
+pub async fn create_user(
+    client: &BaseClient,
+    body: BuiltinUserBody,
+    password: &str,
+    key: &str,
+) -> Result<Response<CreateUserResponse>, String> {
+    // Build Parameters
+    let parameters = HashMap::from([
+        ("password".to_string(), password.to_string()),
+        ("key".to_string(), key.to_string()),
+    ]);
+
+    // Send request
+    let context = RequestType::JSON {
+        body: serde_json::to_string(&body).unwrap(),
+    };
+    let response = client.post("api/builtin-users", Some(parameters), &context).await;
+
+    evaluate_response::<CreateUserResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_utils::extract_test_env;
+
+    use super::BuiltinUserBody;
+
+    /// Tests the creation of a builtin user.
+    ///
+    /// This test sets up a client without an API token, creates a user with a random user name and
+    /// verifies that the returned API token can be used to retrieve the new user.
+    ///
+    /// The test is skipped if no builtin users key is configured, as instances do not set one by default.
+    ///
+    /// # Environment Variables
+    /// - `BASE_URL`: The base URL of the instance.
+    /// - `BUILTIN_USERS_KEY`: The builtin users key configured on the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the user could not be created.
+    #[tokio::test]
+    async fn test_create_user() {
+        let Ok(key) = std::env::var("BUILTIN_USERS_KEY") else {
+            eprintln!("Skipping test_create_user, as BUILTIN_USERS_KEY is not set");
+            return;
+        };
+
+        // Set up the client
+        let (_, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, None).expect("Failed to create client");
+
+        // Create the user
+        let user_name = format!("user_{}", rand::random::<u32>());
+        let body = BuiltinUserBody {
+            user_name: user_name.clone(),
+            first_name: "Test".to_string(),
+            last_name: "User".to_string(),
+            email: format!("{}@example.com", user_name),
+            affiliation: None,
+            position: None,
+        };
+
+        let response = admin::builtin_users::create_user(&client, body, "Password123!", &key)
+            .await
+            .expect("Failed to create user");
+        assert!(response.status.is_ok());
+
+        // Use the returned token
+        let api_token = response.data.unwrap().api_token.expect("No API token returned");
+        let client = BaseClient::new(&base_url, Some(&api_token)).expect("Failed to create client");
+        let me = users::get_me(&client).await.expect("Failed to get user");
+
+        assert_eq!(me.data.unwrap().identifier, Some(format!("@{}", user_name)));
+    }
+}
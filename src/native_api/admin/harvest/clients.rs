@@ -0,0 +1,249 @@
+use serde::{Deserialize, Serialize};
+use typify::import_types;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+import_types!(
+    schema = "models/admin/harvest_clients.json",
+    struct_builder = true,
+);
+
+/// Lists all harvesting clients of the instance.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<HarvestingClientList>`, which contains the HTTP response status and the
+/// configured harvesting clients, if the request is successful, or a `String` error message on failure.
+pub async fn list_clients(client: &BaseClient) -> Result<Response<HarvestingClientList>, String> {
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get("api/harvest/clients", None, &context).await;
+
+    evaluate_response::<HarvestingClientList>(response).await
+}
+
+/// Retrieves a single harvesting client by its nickname.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `nickname` - A string slice that holds the nickname of the harvesting client.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<HarvestingClient>`, which contains the HTTP response status and the
+/// harvesting client including the results of its last harvest, if the request is successful, or a
+/// `String` error message on failure.
+pub async fn get_client(client: &BaseClient, nickname: &str) -> Result<Response<HarvestingClient>, String> {
+    // Endpoint metadata
+    let url = format!("api/harvest/clients/{}", nickname);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), None, &context).await;
+
+    evaluate_response::<HarvestingClient>(response).await
+}
+
+/// Creates a harvesting client, which harvests an OAI-PMH server into a collection.
+///
+/// This asynchronous function sends a POST request to the harvesting clients endpoint and requires a
+/// superuser API token. The harvested datasets are placed in the collection given by `dataverseAlias`.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `nickname` - A string slice that holds the nickname under which the client is registered.
+/// * `body` - A `HarvestingClientBody` holding the harvest URL, metadata format, set and schedule.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<HarvestingClient>`, which contains the HTTP response status and the
+/// created harvesting client, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::admin::harvest::clients::HarvestingClientBody;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let body = HarvestingClientBody::builder()
+///     .dataverse_alias("harvested".to_string())
+///     .harvest_url("https://demo.dataverse.org/oai".to_string())
+///     .metadata_format("oai_dc".to_string())
+///     .set(Some("openaire_data".to_string()))
+///     .schedule(Some("none".to_string()))
+///     .try_into()
+///     .map_err(|e| format!("{:?}", e))?;
+///
+/// let response = admin::harvest::create_client(&client, "demo", body).await?;
+///
+/// println!("Created client: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn create_client(
+    client: &BaseClient,
+    nickname: &str,
+    body: HarvestingClientBody,
+) -> Result<Response<HarvestingClient>, String> {
+    // Endpoint metadata
+    let url = format!("api/harvest/clients/{}", nickname);
+
+    // Send request
+    let context = RequestType::JSON {
+        body: serde_json::to_string(&body).unwrap(),
+    };
+    let response = client.post(url.as_str(), None, &context).await;
+
+    evaluate_response::<HarvestingClient>(response).await
+}
+
+/// Modifies an existing harvesting client.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `nickname` - A string slice that holds the nickname of the harvesting client.
+/// * `body` - A `HarvestingClientBody` holding the new configuration of the client.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<HarvestingClient>`, which contains the HTTP response status and the
+/// modified harvesting client, if the request is successful, or a `String` error message on failure.
+pub async fn modify_client(
+    client: &BaseClient,
+    nickname: &str,
+    body: HarvestingClientBody,
+) -> Result<Response<HarvestingClient>, String> {
+    // Endpoint metadata
+    let url = format!("api/harvest/clients/{}", nickname);
+
+    // Send request
+    let context = RequestType::JSON {
+        body: serde_json::to_string(&body).unwrap(),
+    };
+    let response = client.put(url.as_str(), None, &context).await;
+
+    evaluate_response::<HarvestingClient>(response).await
+}
+
+/// Deletes a harvesting client along with the datasets it has harvested.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `nickname` - A string slice that holds the nickname of the harvesting client.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn delete_client(client: &BaseClient, nickname: &str) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/harvest/clients/{}", nickname);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_utils::{create_test_collection, extract_test_env};
+
+    use super::{HarvestingClientBody, HarvestingClientList};
+
+    /// Tests the lifecycle of a harvesting client.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables,
+    /// creates a harvesting client targeting a test collection, modifies its schedule and deletes it
+    /// again. The test asserts that every step was successful and that the changes are reflected.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if any of the requests fail.
+    #[tokio::test]
+    async fn test_harvesting_client_lifecycle() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        let alias = create_test_collection(&client, "Root").await;
+        let nickname = format!("dvcli{}", rand::random::<u32>());
+        let body = |schedule: &str| -> HarvestingClientBody {
+            HarvestingClientBody::builder()
+                .dataverse_alias(alias.clone())
+                .harvest_url("https://demo.dataverse.org/oai".to_string())
+                .metadata_format("oai_dc".to_string())
+                .schedule(Some(schedule.to_string()))
+                .try_into()
+                .expect("Failed to build harvesting client body")
+        };
+
+        // Create the harvesting client
+        let response = admin::harvest::create_client(&client, &nickname, body("none"))
+            .await.expect("Failed to create harvesting client");
+        assert!(response.status.is_ok());
+
+        // Modify and retrieve it
+        let response = admin::harvest::modify_client(&client, &nickname, body("weekly"))
+            .await.expect("Failed to modify harvesting client");
+        assert!(response.status.is_ok());
+
+        let response = admin::harvest::get_client(&client, &nickname)
+            .await.expect("Failed to get harvesting client");
+        assert_eq!(response.data.unwrap().nick_name.as_deref(), Some(nickname.as_str()));
+
+        // Delete it
+        let response = admin::harvest::delete_client(&client, &nickname)
+            .await.expect("Failed to delete harvesting client");
+        assert!(response.status.is_ok());
+    }
+
+    /// Tests parsing the list of harvesting clients as returned by the instance.
+    #[test]
+    fn test_parse_harvesting_client_list() {
+        let list: HarvestingClientList = serde_json::from_value(serde_json::json!({
+            "harvestingClients": [{
+                "nickName": "zenodo",
+                "dataverseAlias": "zenodoHarvested",
+                "type": "oai",
+                "harvestUrl": "https://zenodo.org/oai2d",
+                "metadataFormat": "oai_dc",
+                "set": "user-dataverse",
+                "schedule": "none",
+                "status": "inActive",
+                "lastDatasetsHarvested": 12
+            }]
+        }))
+            .expect("Failed to parse harvesting clients");
+
+        let zenodo = &list.harvesting_clients[0];
+        assert_eq!(zenodo.nick_name.as_deref(), Some("zenodo"));
+        assert_eq!(zenodo.type_.as_deref(), Some("oai"));
+        assert_eq!(zenodo.last_datasets_harvested, Some(12));
+    }
+}
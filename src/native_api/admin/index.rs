@@ -0,0 +1,194 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+// This module is only available with the `admin` feature. Reindexing is needed if
+// the search index diverged from the database, e.g. after bulk changes or an
+// interrupted indexing run. The index is updated asynchronously by the instance.
+
+/// The consistency of the search index with the database.
+///
+/// Each category maps the kind of objects, e.g. `datasets` or `dataverses`, to the IDs or documents that are
+/// inconsistent. An empty status means that the index is in sync with the database.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexStatus {
+    #[serde(default)]
+    pub content_in_database_but_stale_in_or_missing_from_index: HashMap<String, Vec<serde_json::Value>>,
+    #[serde(default)]
+    pub content_in_index_but_not_database: HashMap<String, Vec<serde_json::Value>>,
+    #[serde(default)]
+    pub permissions_in_database_but_stale_in_or_missing_from_index: HashMap<String, Vec<serde_json::Value>>,
+    #[serde(default)]
+    pub permissions_in_index_but_not_database: HashMap<String, Vec<serde_json::Value>>,
+}
+
+impl IndexStatus {
+    /// Returns whether the index contains no stale, missing or orphaned entries.
+    pub fn is_consistent(&self) -> bool {
+        [
+            &self.content_in_database_but_stale_in_or_missing_from_index,
+            &self.content_in_index_but_not_database,
+            &self.permissions_in_database_but_stale_in_or_missing_from_index,
+            &self.permissions_in_index_but_not_database,
+        ]
+            .iter()
+            .all(|category| category.values().all(Vec::is_empty))
+    }
+}
+
+/// Reindexes a dataset, including its files.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "http://localhost:8080".to_string();
+/// let client = BaseClient::new(&base_url, None)
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// admin::index::reindex_dataset(&client, id).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn reindex_dataset(client: &BaseClient, id: Identifier) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let (url, parameters) = match id {
+        Identifier::PersistentId(id) => (
+            "api/admin/index/dataset".to_string(),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/admin/index/datasets/{}", id), None),
+    };
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Reindexes a collection. Its datasets and sub-collections are not reindexed.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - The numeric ID of the collection.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+pub async fn reindex_collection(client: &BaseClient, id: i64) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/admin/index/dataverses/{}", id);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Compares the search index with the database.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<IndexStatus>`, which contains the HTTP response status and the
+/// inconsistencies found, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "http://localhost:8080".to_string();
+/// let client = BaseClient::new(&base_url, None)
+///     .expect("Failed to create client");
+///
+/// let response = admin::index::get_index_status(&client).await?;
+///
+/// if !response.data.unwrap_or_default().is_consistent() {
+///     println!("The search index is out of sync");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_index_status(client: &BaseClient) -> Result<Response<IndexStatus>, String> {
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get("api/admin/index/status", None, &context).await;
+
+    evaluate_response::<IndexStatus>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    /// Tests that a dataset is reindexed by its persistent identifier and the status is evaluated.
+    #[tokio::test]
+    async fn test_reindex_and_status() {
+        let server = MockServer::start();
+        let reindex = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/admin/index/dataset")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123");
+            then.status(200).json_body(json!({"status": "OK", "data": {"message": "indexed dataset 42"}}));
+        });
+        let status = server.mock(|when, then| {
+            when.method(GET).path("/api/admin/index/status");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {
+                    "contentInDatabaseButStaleInOrMissingFromIndex": {"dataverses": [], "datasets": [42]},
+                    "contentInIndexButNotDatabase": {"dataverses": [], "datasets": [], "files": []},
+                    "permissionsInDatabaseButStaleInOrMissingFromIndex": {"dvobjects": []},
+                    "permissionsInIndexButNotDatabase": {"permissions": []}
+                }
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+
+        let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+        let response = admin::index::reindex_dataset(&client, id).await.unwrap();
+        assert!(response.status.is_ok());
+
+        let response = admin::index::get_index_status(&client).await.unwrap();
+        let index = response.data.unwrap();
+        assert!(!index.is_consistent());
+        assert!(admin::index::IndexStatus::default().is_consistent());
+
+        reindex.assert();
+        status.assert();
+    }
+}
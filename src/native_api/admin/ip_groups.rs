@@ -0,0 +1,184 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+// This module is only available with the `admin` feature. IP groups grant roles
+// to all requests originating from the given addresses, e.g. a campus network,
+// once the group has been assigned a role on a collection or dataset.
+
+/// The definition of an IP group, as sent to create it and returned by the instance.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct IpGroup {
+    /// The numeric ID, which is assigned by the instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub alias: String,
+    /// The identifier used to assign roles, e.g. `&ip/campus`, which is assigned by the instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub identifier: Option<String>,
+    pub name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    /// Inclusive ranges of IPv4 or IPv6 addresses, each given by its first and last address.
+    #[serde(default)]
+    pub ranges: Vec<(String, String)>,
+    /// Single IPv4 or IPv6 addresses.
+    #[serde(default)]
+    pub addresses: Vec<String>,
+}
+
+/// Lists all IP groups of the instance.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<IpGroup>>`, which contains the HTTP response status and the IP groups,
+/// if the request is successful, or a `String` error message on failure.
+pub async fn list_ip_groups(client: &BaseClient) -> Result<Response<Vec<IpGroup>>, String> {
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get("api/admin/groups/ip", None, &context).await;
+
+    evaluate_response::<Vec<IpGroup>>(response).await
+}
+
+/// Creates an IP group from its definition.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `group` - A reference to the `IpGroup` to create. Its `id` and `identifier` are ignored.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<IpGroup>`, which contains the HTTP response status and the created group,
+/// if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::admin::ip_groups::IpGroup;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("http://localhost:8080", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let group = IpGroup {
+///     id: None,
+///     alias: "campus".to_string(),
+///     identifier: None,
+///     name: "Campus network".to_string(),
+///     description: None,
+///     ranges: vec![("192.0.2.0".to_string(), "192.0.2.255".to_string())],
+///     addresses: vec![],
+/// };
+///
+/// let response = admin::ip_groups::create_ip_group(&client, &group).await?;
+/// println!("{:?}", response.data.and_then(|group| group.identifier));
+/// # Ok(())
+/// # }
+/// ```
+pub async fn create_ip_group(client: &BaseClient, group: &IpGroup) -> Result<Response<IpGroup>, String> {
+    // Build body
+    let body = serde_json::to_string(&IpGroup {
+        id: None,
+        identifier: None,
+        ..group.clone()
+    })
+    .map_err(|e| e.to_string())?;
+
+    // Send request
+    let context = RequestType::JSON { body };
+    let response = client.post("api/admin/groups/ip", None, &context).await;
+
+    evaluate_response::<IpGroup>(response).await
+}
+
+/// Deletes an IP group. Groups that are still assigned roles cannot be deleted.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `group` - A string slice that holds the alias or numeric ID of the group.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn delete_ip_group(client: &BaseClient, group: &str) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/admin/groups/ip/{}", group);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    use super::IpGroup;
+
+    /// Tests that a group is created from its definition and that listed groups are parsed.
+    #[tokio::test]
+    async fn test_create_and_list_ip_groups() {
+        let server = MockServer::start();
+        let group = json!({
+            "id": 3,
+            "alias": "campus",
+            "identifier": "&ip/campus",
+            "name": "Campus network",
+            "ranges": [["192.0.2.0", "192.0.2.255"]],
+            "addresses": ["2001:db8::1"]
+        });
+        let create = server.mock(|when, then| {
+            when.method(POST).path("/api/admin/groups/ip").json_body(json!({
+                "alias": "campus",
+                "name": "Campus network",
+                "ranges": [["192.0.2.0", "192.0.2.255"]],
+                "addresses": ["2001:db8::1"]
+            }));
+            then.status(201).json_body(json!({"status": "OK", "data": group}));
+        });
+        let list = server.mock(|when, then| {
+            when.method(GET).path("/api/admin/groups/ip");
+            then.status(200).json_body(json!({"status": "OK", "data": [group]}));
+        });
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+        let definition: IpGroup = serde_json::from_value(json!({
+            "alias": "campus",
+            "name": "Campus network",
+            "ranges": [["192.0.2.0", "192.0.2.255"]],
+            "addresses": ["2001:db8::1"]
+        }))
+        .unwrap();
+
+        let response = admin::ip_groups::create_ip_group(&client, &definition).await.unwrap();
+        assert_eq!(response.data.unwrap().identifier.as_deref(), Some("&ip/campus"));
+
+        let response = admin::ip_groups::list_ip_groups(&client).await.unwrap();
+        let groups = response.data.unwrap();
+        assert_eq!(groups[0].id, Some(3));
+        assert_eq!(groups[0].ranges[0].1, "192.0.2.255");
+
+        create.assert();
+        list.assert();
+    }
+}
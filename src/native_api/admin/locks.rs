@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    native_api::dataset::locks::Lock,
+    request::RequestType,
+    response::Response,
+};
+
+// This module is only available with the `admin` feature. Both endpoints require
+// a superuser token, e.g. to release ingest locks that remained after a failure.
+
+/// Filters for listing the locks of all datasets.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LockFilter {
+    /// Only lists locks of this type, e.g. `Ingest` or `InReview`.
+    pub lock_type: Option<String>,
+    /// Only lists locks caused by the user of this identifier, e.g. `jdoe`.
+    pub user: Option<String>,
+}
+
+/// Lists the locks of all datasets of the instance.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `filter` - A reference to the `LockFilter` narrowing down the listed locks.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<Lock>>`, which contains the HTTP response status and the locks,
+/// each naming the persistent identifier of its dataset, if the request is successful, or a `String`
+/// error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::admin::locks::LockFilter;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let filter = LockFilter {
+///     lock_type: Some("Ingest".to_string()),
+///     ..Default::default()
+/// };
+/// let response = admin::locks::list_locks(&client, &filter).await?;
+///
+/// for lock in response.data.unwrap_or_default() {
+///     println!("{} since {}", lock.dataset.unwrap_or_default(), lock.date.unwrap_or_default());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn list_locks(client: &BaseClient, filter: &LockFilter) -> Result<Response<Vec<Lock>>, String> {
+    // Build Parameters
+    let parameters: HashMap<String, String> = [("type", &filter.lock_type), ("userIdentifier", &filter.user)]
+        .into_iter()
+        .filter_map(|(key, value)| value.clone().map(|value| (key.to_string(), value)))
+        .collect();
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get("api/datasets/locks", Some(parameters), &context).await;
+
+    evaluate_response::<Vec<Lock>>(response).await
+}
+
+/// Removes the locks of a dataset.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `lock_type` - The type of the locks to remove, e.g. `Ingest`, or `None` to remove all locks.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+pub async fn remove_locks(
+    client: &BaseClient,
+    id: Identifier,
+    lock_type: Option<&str>,
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let (url, mut parameters) = match id {
+        Identifier::PersistentId(id) => (
+            "api/datasets/:persistentId/locks".to_string(),
+            HashMap::from([("persistentId".to_string(), id)]),
+        ),
+        Identifier::Id(id) => (format!("api/datasets/{}/locks", id), HashMap::new()),
+    };
+
+    // Build Parameters
+    if let Some(lock_type) = lock_type {
+        parameters.insert("type".to_string(), lock_type.to_string());
+    }
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), Some(parameters), &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    use super::LockFilter;
+
+    /// Tests that locks are listed by type and that the locks of a dataset are removed.
+    #[tokio::test]
+    async fn test_list_and_remove_locks() {
+        let server = MockServer::start();
+        let list = server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/locks").query_param("type", "Ingest");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": [{
+                    "lockType": "Ingest",
+                    "date": "2024-06-01T10:00:00Z",
+                    "user": "jdoe",
+                    "dataset": "doi:10.5072/FK2/ABC123",
+                    "message": "Ingest in progress"
+                }]
+            }));
+        });
+        let remove = server.mock(|when, then| {
+            when.method(DELETE).path("/api/datasets/42/locks").query_param("type", "Ingest");
+            then.status(200).json_body(json!({"status": "OK", "data": {"message": "lock type Ingest removed"}}));
+        });
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+        let filter = LockFilter {
+            lock_type: Some("Ingest".to_string()),
+            ..Default::default()
+        };
+
+        let response = admin::locks::list_locks(&client, &filter).await.unwrap();
+        let locks = response.data.unwrap();
+        assert_eq!(locks[0].dataset.as_deref(), Some("doi:10.5072/FK2/ABC123"));
+
+        let response = admin::locks::remove_locks(&client, Identifier::Id(42), Some("Ingest")).await.unwrap();
+        assert!(response.status.is_ok());
+
+        list.assert();
+        remove.assert();
+    }
+}
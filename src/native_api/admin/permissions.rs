@@ -0,0 +1,192 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    request::RequestType,
+    response::Response,
+};
+
+/// The permissions of a user on a collection, dataset or file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ObjectPermissions {
+    /// The identifier of the user, e.g. `@dataverseAdmin`.
+    pub user: String,
+    /// The names of the granted permissions, e.g. `EditDataset` or `PublishDataverse`.
+    #[serde(default)]
+    pub permissions: Vec<String>,
+}
+
+impl ObjectPermissions {
+    /// Returns whether the permission of the given name, e.g. `PublishDataset`, is granted.
+    pub fn has(&self, permission: &str) -> bool {
+        self.permissions.iter().any(|granted| granted == permission)
+    }
+}
+
+/// The permissions of the user of the API token on a dataset.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DatasetPermissions {
+    #[serde(default)]
+    pub can_view_unpublished_dataset: bool,
+    #[serde(default)]
+    pub can_edit_dataset: bool,
+    #[serde(default)]
+    pub can_publish_dataset: bool,
+    #[serde(default)]
+    pub can_manage_dataset_permissions: bool,
+    #[serde(default)]
+    pub can_delete_dataset_draft: bool,
+}
+
+/// Retrieves the permissions of the user of the API token on a collection, dataset or file.
+///
+/// Note that the endpoint is part of the admin API, which is usually only reachable from the host the
+/// instance is running on. Use `get_dataset_permissions` to check the permissions on a dataset otherwise.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `object` - A string slice that holds the alias or ID of a collection, or the ID or persistent
+///   identifier of a dataset or file.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<ObjectPermissions>`, which contains the HTTP response status and the
+/// granted permissions, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "http://localhost:8080".to_string();
+/// let client = BaseClient::new(&base_url, Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let response = admin::permissions::get_permissions(&client, "physics").await?;
+///
+/// if response.data.is_some_and(|permissions| permissions.has("PublishDataverse")) {
+///     println!("The collection can be published");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_permissions(client: &BaseClient, object: &str) -> Result<Response<ObjectPermissions>, String> {
+    // Endpoint metadata
+    let url = format!("api/admin/permissions/{}", object);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), None, &context).await;
+
+    evaluate_response::<ObjectPermissions>(response).await
+}
+
+/// Retrieves the permissions of the user of the API token on a dataset.
+///
+/// This allows checking whether a dataset can be edited or published before attempting to, instead of
+/// failing with a `403 Forbidden` response.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<DatasetPermissions>`, which contains the HTTP response status and the
+/// permissions on the dataset, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let response = admin::permissions::get_dataset_permissions(&client, id).await?;
+///
+/// if !response.data.unwrap_or_default().can_publish_dataset {
+///     println!("Publishing requires a curator");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_dataset_permissions(
+    client: &BaseClient,
+    id: Identifier,
+) -> Result<Response<DatasetPermissions>, String> {
+    // Endpoint metadata
+    let (url, parameters) = match id {
+        Identifier::PersistentId(id) => (
+            "api/datasets/:persistentId/userPermissions".to_string(),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/datasets/{}/userPermissions", id), None),
+    };
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<DatasetPermissions>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    /// Tests that the permissions on a collection and a dataset are parsed.
+    #[tokio::test]
+    async fn test_get_permissions() {
+        let server = MockServer::start();
+        let object = server.mock(|when, then| {
+            when.method(GET).path("/api/admin/permissions/physics");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"user": "@jdoe", "permissions": ["ViewUnpublishedDataverse", "AddDataset"]}
+            }));
+        });
+        let dataset = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/datasets/:persistentId/userPermissions")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {
+                    "canViewUnpublishedDataset": true,
+                    "canEditDataset": true,
+                    "canPublishDataset": false,
+                    "canManageDatasetPermissions": false,
+                    "canDeleteDatasetDraft": true
+                }
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+
+        let response = admin::permissions::get_permissions(&client, "physics").await.unwrap();
+        let permissions = response.data.unwrap();
+        assert!(permissions.has("AddDataset"));
+        assert!(!permissions.has("PublishDataverse"));
+
+        let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+        let response = admin::permissions::get_dataset_permissions(&client, id).await.unwrap();
+        let permissions = response.data.unwrap();
+        assert!(permissions.can_edit_dataset);
+        assert!(!permissions.can_publish_dataset);
+
+        object.assert();
+        dataset.assert();
+    }
+}
@@ -0,0 +1,237 @@
+use std::collections::HashMap;
+
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::pid::reserve_pid,
+    request::RequestType,
+    response::Response,
+};
+
+// This module is only available with the `admin` feature. All endpoints require a
+// superuser token, e.g. to reconcile the identifiers of the instance with DataCite.
+
+/// The datasets whose persistent identifiers have not been reserved at the PID provider.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct UnreservedPids {
+    #[serde(rename = "numUnreserved", default)]
+    pub num_unreserved: i64,
+    #[serde(rename = "count", default)]
+    pub datasets: Vec<UnreservedPid>,
+}
+
+/// A dataset whose persistent identifier has not been reserved.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct UnreservedPid {
+    pub id: i64,
+    pub pid: String,
+}
+
+/// A PID provider configured on the instance.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct PidProvider {
+    pub id: String,
+    #[serde(default)]
+    pub label: Option<String>,
+    /// The kind of provider, e.g. `datacite`, `ezid`, `handle` or `perma`.
+    #[serde(rename = "type", default)]
+    pub provider_type: Option<String>,
+    #[serde(default)]
+    pub protocol: Option<String>,
+    #[serde(default)]
+    pub authority: Option<String>,
+    #[serde(default)]
+    pub shoulder: Option<String>,
+    #[serde(default)]
+    pub identifier_generation_style: Option<String>,
+    #[serde(default)]
+    pub datafile_pid_format: Option<String>,
+}
+
+/// Lists the datasets whose persistent identifiers have not been reserved at the PID provider.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<UnreservedPids>`, which contains the HTTP response status and the datasets,
+/// if the request is successful, or a `String` error message on failure.
+pub async fn list_unreserved_pids(client: &BaseClient) -> Result<Response<UnreservedPids>, String> {
+    let context = RequestType::Plain;
+    let response = client.get("api/pids/unreserved", None, &context).await;
+
+    evaluate_response::<UnreservedPids>(response).await
+}
+
+/// Reserves the persistent identifiers of all datasets that have not been reserved yet.
+///
+/// The identifiers are reserved with at most `concurrency` requests at a time. A failed reservation does
+/// not stop the others, such that the outcome is reported per dataset.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `concurrency` - The maximum number of reservations requested simultaneously; zero is treated as one.
+///
+/// # Returns
+///
+/// A `Result` wrapping the persistent identifier of each dataset along with the outcome of its reservation,
+/// or a `String` error message if the unreserved identifiers could not be listed.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// for (pid, outcome) in admin::pids::reserve_unreserved_pids(&client, 4).await? {
+///     if let Err(error) = outcome {
+///         eprintln!("Failed to reserve {}: {}", pid, error);
+///     }
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn reserve_unreserved_pids(
+    client: &BaseClient,
+    concurrency: usize,
+) -> Result<Vec<(String, Result<(), String>)>, String> {
+    let response = list_unreserved_pids(client).await?;
+    if response.status.is_err() {
+        return Err(response.message.map(|m| m.to_string()).unwrap_or_default());
+    }
+
+    let datasets = response.data.unwrap_or_default().datasets;
+    let outcomes = futures::stream::iter(datasets)
+        .map(|dataset| async move {
+            let outcome = match reserve_pid(client, Identifier::PersistentId(dataset.pid.clone())).await {
+                Ok(response) if response.status.is_ok() => Ok(()),
+                Ok(response) => Err(response.message.map(|m| m.to_string()).unwrap_or_default()),
+                Err(error) => Err(error),
+            };
+            (dataset.pid, outcome)
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+
+    Ok(outcomes)
+}
+
+/// Retrieves the information the PID provider holds about a persistent identifier.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `pid` - A string slice that holds the persistent identifier, e.g. `doi:10.5072/FK2/ABC123`.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<serde_json::Value>`, which contains the HTTP response status and the
+/// information as returned by the provider, if the request is successful, or a `String` error message on
+/// failure.
+pub async fn get_pid_info(client: &BaseClient, pid: &str) -> Result<Response<serde_json::Value>, String> {
+    // Build Parameters
+    let parameters = HashMap::from([("persistentId".to_string(), pid.to_string())]);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get("api/pids", Some(parameters), &context).await;
+
+    evaluate_response::<serde_json::Value>(response).await
+}
+
+/// Lists the PID providers configured on the instance.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<PidProvider>>`, which contains the HTTP response status and the
+/// providers, if the request is successful, or a `String` error message on failure.
+pub async fn list_pid_providers(client: &BaseClient) -> Result<Response<Vec<PidProvider>>, String> {
+    let context = RequestType::Plain;
+    let response = client.get("api/pids/providers", None, &context).await;
+
+    evaluate_response::<Vec<PidProvider>>(response).await
+}
+
+/// Retrieves the PID provider responsible for a persistent identifier.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `pid` - A string slice that holds the persistent identifier, e.g. `doi:10.5072/FK2/ABC123`.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<PidProvider>`, which contains the HTTP response status and the provider,
+/// if the request is successful, or a `String` error message on failure.
+pub async fn get_pid_provider(client: &BaseClient, pid: &str) -> Result<Response<PidProvider>, String> {
+    // Endpoint metadata
+    let url = format!("api/pids/providers/{}", pid);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), None, &context).await;
+
+    evaluate_response::<PidProvider>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    /// Tests that all unreserved identifiers are reserved and failures are reported per dataset.
+    #[tokio::test]
+    async fn test_reserve_unreserved_pids() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/api/pids/unreserved");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {
+                    "numUnreserved": 2,
+                    "count": [
+                        {"id": 1, "pid": "doi:10.5072/FK2/AAA111"},
+                        {"id": 2, "pid": "doi:10.5072/FK2/BBB222"}
+                    ]
+                }
+            }));
+        });
+        let reserved = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/pids/:persistentId/reserve")
+                .query_param("persistentId", "doi:10.5072/FK2/AAA111");
+            then.status(200).json_body(json!({"status": "OK", "data": {"message": "PID reserved"}}));
+        });
+        server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/pids/:persistentId/reserve")
+                .query_param("persistentId", "doi:10.5072/FK2/BBB222");
+            then.status(400).json_body(json!({"status": "ERROR", "message": "Provider unavailable"}));
+        });
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+        let outcomes = admin::pids::reserve_unreserved_pids(&client, 2).await.unwrap();
+
+        assert_eq!(outcomes[0], ("doi:10.5072/FK2/AAA111".to_string(), Ok(())));
+        assert_eq!(outcomes[1].0, "doi:10.5072/FK2/BBB222");
+        assert!(outcomes[1].1.as_ref().unwrap_err().contains("Provider unavailable"));
+
+        reserved.assert();
+    }
+}
@@ -0,0 +1,212 @@
+use std::borrow::Cow;
+use std::collections::HashMap;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+// This module is only available with the `admin` feature. Note that the admin API
+// is usually only reachable from the host the instance is running on.
+
+/// Lists all database settings of the instance.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<HashMap<String, String>>`, which contains the HTTP response status and the
+/// settings by name, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "http://localhost:8080".to_string();
+/// let client = BaseClient::new(&base_url, None)
+///     .expect("Failed to create client");
+///
+/// let response = admin::settings::list_settings(&client).await?;
+///
+/// for (name, value) in response.data.unwrap_or_default() {
+///     println!("{} = {}", name, value);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn list_settings(client: &BaseClient) -> Result<Response<HashMap<String, String>>, String> {
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get("api/admin/settings", None, &context).await;
+
+    evaluate_response::<HashMap<String, String>>(response).await
+}
+
+/// Retrieves a single database setting of the instance.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `name` - A string slice that holds the name of the setting, such as `:SiteUrl`, which is normalized with `setting_name`.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the value
+/// of the setting as message, if the request is successful, or a `String` error message on failure.
+pub async fn get_setting(client: &BaseClient, name: &str) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/admin/settings/{}", setting_name(name));
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Sets a database setting of the instance.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `name` - A string slice that holds the name of the setting, such as `:SiteUrl`, which is normalized with `setting_name`.
+/// * `value` - A string slice that holds the new value of the setting.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<HashMap<String, String>>`, which contains the HTTP response status and the
+/// stored setting, if the request is successful, or a `String` error message on failure.
+pub async fn put_setting(
+    client: &BaseClient,
+    name: &str,
+    value: &str,
+) -> Result<Response<HashMap<String, String>>, String> {
+    // Endpoint metadata
+    let url = format!("api/admin/settings/{}", setting_name(name));
+
+    // Send request
+    let context = RequestType::JSON {
+        body: value.to_string(),
+    };
+    let response = client.put(url.as_str(), None, &context).await;
+
+    evaluate_response::<HashMap<String, String>>(response).await
+}
+
+/// Deletes a database setting of the instance, which restores its default.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `name` - A string slice that holds the name of the setting, such as `:SiteUrl`, which is normalized with `setting_name`.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn delete_setting(client: &BaseClient, name: &str) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/admin/settings/{}", setting_name(name));
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+// Settings of the instance which are prefixed with a colon. The prefix is easily forgotten,
+// but not all settings have one, e.g. `BuiltinUsers.KEY`, so other names are sent as given.
+const COLON_SETTINGS: [&str; 12] = [
+    "AllowApiTokenLookupViaApi",
+    "ApplicationTermsOfUse",
+    "BlockedApiEndpoints",
+    "BlockedApiKey",
+    "BlockedApiPolicy",
+    "DoiProvider",
+    "FileFixityChecksumAlgorithm",
+    "MaxFileUploadSizeInBytes",
+    "Protocol",
+    "Shoulder",
+    "SiteUrl",
+    "SystemEmail",
+];
+
+/// Normalizes the name of a setting by adding the leading colon to well-known database settings.
+///
+/// # Returns
+///
+/// The name with a leading colon if it refers to a well-known database setting given without one, e.g.
+/// `:SiteUrl` for `SiteUrl`, or the name as given otherwise.
+pub fn setting_name(name: &str) -> Cow<'_, str> {
+    match COLON_SETTINGS.contains(&name) {
+        true => Cow::Owned(format!(":{}", name)),
+        false => Cow::Borrowed(name),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_utils::extract_test_env;
+
+    use super::setting_name;
+
+    /// Tests setting, retrieving and deleting a database setting.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables,
+    /// and cycles a custom setting through its lifecycle. The test asserts that the stored value is
+    /// returned and listed, and that it is gone after deletion.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if any of the requests fail.
+    #[tokio::test]
+    async fn test_setting_lifecycle() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        let name = format!(":DvcliTestSetting{}", rand::random::<u32>());
+
+        // Set the setting
+        let response = admin::settings::put_setting(&client, &name, "enabled")
+            .await.expect("Failed to put setting");
+        assert!(response.status.is_ok());
+
+        // Retrieve and list it
+        let response = admin::settings::get_setting(&client, &name)
+            .await.expect("Failed to get setting");
+        assert_eq!(response.data.unwrap().message.as_deref(), Some("enabled"));
+
+        let response = admin::settings::list_settings(&client)
+            .await.expect("Failed to list settings");
+        assert!(response.data.unwrap().contains_key(&name));
+
+        // Delete it
+        let response = admin::settings::delete_setting(&client, &name)
+            .await.expect("Failed to delete setting");
+        assert!(response.status.is_ok());
+
+        let response = admin::settings::get_setting(&client, &name)
+            .await.expect("Failed to get setting");
+        assert!(response.status.is_err());
+    }
+
+    /// Tests that well-known settings get their colon and other names are sent as given.
+    #[test]
+    fn test_setting_name() {
+        assert_eq!(setting_name(":SiteUrl"), ":SiteUrl");
+        assert_eq!(setting_name("SiteUrl"), ":SiteUrl");
+        assert_eq!(setting_name("BuiltinUsers.KEY"), "BuiltinUsers.KEY");
+    }
+}
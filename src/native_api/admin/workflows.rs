@@ -0,0 +1,353 @@
+use std::{collections::HashMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+// This module is only available with the `admin` feature. Workflows run a sequence
+// of steps when triggered, e.g. to notify an external system before a dataset is
+// published. They only run once registered as the default for their trigger.
+
+/// An event that starts the default workflow registered for it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TriggerType {
+    PrePublishDataset,
+    PostPublishDataset,
+}
+
+impl TriggerType {
+    /// Returns the name of the trigger as expected by the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TriggerType::PrePublishDataset => "PrePublishDataset",
+            TriggerType::PostPublishDataset => "PostPublishDataset",
+        }
+    }
+}
+
+impl FromStr for TriggerType {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace('-', "").as_str() {
+            "prepublishdataset" => Ok(TriggerType::PrePublishDataset),
+            "postpublishdataset" => Ok(TriggerType::PostPublishDataset),
+            _ => Err(format!(
+                "Unknown trigger '{}', expected PrePublishDataset or PostPublishDataset",
+                s
+            )),
+        }
+    }
+}
+
+/// A workflow, as registered on the instance.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Workflow {
+    /// The numeric ID, which is assigned by the instance.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<i64>,
+    pub name: String,
+    /// The steps, which are run in order. Listed workflows omit them.
+    #[serde(default)]
+    pub steps: Vec<WorkflowStep>,
+}
+
+/// A step of a workflow.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct WorkflowStep {
+    /// The provider of the step, `:internal` for the steps shipped with the instance.
+    #[serde(default = "internal_provider")]
+    pub provider: String,
+    /// The type of the step, e.g. `log`, `pause`, `http/sr` or `archiver`.
+    pub step_type: String,
+    #[serde(default)]
+    pub parameters: HashMap<String, String>,
+    /// Database settings passed to the step, mapped to whether they are required.
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub required_settings: HashMap<String, String>,
+}
+
+impl WorkflowStep {
+    /// Creates a step of the given type provided by the instance, without parameters.
+    pub fn internal(step_type: &str) -> Self {
+        WorkflowStep {
+            provider: internal_provider(),
+            step_type: step_type.to_string(),
+            parameters: HashMap::new(),
+            required_settings: HashMap::new(),
+        }
+    }
+
+    /// Creates a step that sends an HTTP request and pauses the workflow until the response, e.g.
+    /// `OK.*`, is matched by `expected_response`, otherwise rolling it back.
+    pub fn http_request(url: &str, method: &str, expected_response: &str) -> Self {
+        WorkflowStep::internal("http/sr")
+            .with_parameter("url", url)
+            .with_parameter("method", method)
+            .with_parameter("expectedResponse", expected_response)
+    }
+
+    /// Adds a parameter to the step.
+    pub fn with_parameter(mut self, key: &str, value: &str) -> Self {
+        self.parameters.insert(key.to_string(), value.to_string());
+        self
+    }
+}
+
+fn internal_provider() -> String {
+    ":internal".to_string()
+}
+
+/// Lists the workflows registered on the instance, without their steps.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<Workflow>>`, which contains the HTTP response status and the workflows,
+/// if the request is successful, or a `String` error message on failure.
+pub async fn list_workflows(client: &BaseClient) -> Result<Response<Vec<Workflow>>, String> {
+    let context = RequestType::Plain;
+    let response = client.get("api/admin/workflows", None, &context).await;
+
+    evaluate_response::<Vec<Workflow>>(response).await
+}
+
+/// Retrieves a workflow along with its steps.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - The ID of the workflow.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Workflow>`, which contains the HTTP response status and the workflow,
+/// if the request is successful, or a `String` error message on failure.
+pub async fn get_workflow(client: &BaseClient, id: i64) -> Result<Response<Workflow>, String> {
+    // Endpoint metadata
+    let url = format!("api/admin/workflows/{}", id);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), None, &context).await;
+
+    evaluate_response::<Workflow>(response).await
+}
+
+/// Registers a workflow. It only runs once it is set as the default for a trigger.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `workflow` - A reference to the `Workflow` to register. Its `id` is ignored.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Workflow>`, which contains the HTTP response status and the registered
+/// workflow, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::admin::workflows::{TriggerType, Workflow, WorkflowStep};
+///
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "http://localhost:8080".to_string();
+/// let client = BaseClient::new(&base_url, Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let workflow = Workflow {
+///     id: None,
+///     name: "Notify the repository office".to_string(),
+///     steps: vec![
+///         WorkflowStep::internal("log").with_parameter("message", "Publication requested"),
+///         WorkflowStep::http_request("https://office.example.edu/hook/${invocationId}", "POST", "OK.*"),
+///     ],
+/// };
+///
+/// let response = admin::workflows::register_workflow(&client, &workflow).await?;
+///
+/// if let Some(id) = response.data.and_then(|workflow| workflow.id) {
+///     admin::workflows::set_default_workflow(&client, TriggerType::PrePublishDataset, id).await?;
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn register_workflow(client: &BaseClient, workflow: &Workflow) -> Result<Response<Workflow>, String> {
+    // Build body
+    let body = serde_json::to_string(&Workflow {
+        id: None,
+        ..workflow.clone()
+    })
+    .map_err(|e| e.to_string())?;
+
+    // Send request
+    let context = RequestType::JSON { body };
+    let response = client.post("api/admin/workflows", None, &context).await;
+
+    evaluate_response::<Workflow>(response).await
+}
+
+/// Deletes a workflow. Workflows set as the default for a trigger cannot be deleted.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - The ID of the workflow.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+pub async fn delete_workflow(client: &BaseClient, id: i64) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/admin/workflows/{}", id);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Retrieves the default workflow of a trigger.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `trigger` - The `TriggerType` to retrieve the default workflow of.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Workflow>`, which contains the HTTP response status and the workflow,
+/// if the request is successful, or a `String` error message on failure, e.g. if no default is set.
+pub async fn get_default_workflow(client: &BaseClient, trigger: TriggerType) -> Result<Response<Workflow>, String> {
+    // Endpoint metadata
+    let url = format!("api/admin/workflows/default/{}", trigger.as_str());
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), None, &context).await;
+
+    evaluate_response::<Workflow>(response).await
+}
+
+/// Sets the default workflow of a trigger, which then runs whenever the trigger occurs.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `trigger` - The `TriggerType` to set the default workflow of.
+/// * `id` - The ID of the workflow.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+pub async fn set_default_workflow(
+    client: &BaseClient,
+    trigger: TriggerType,
+    id: i64,
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/admin/workflows/default/{}", trigger.as_str());
+
+    // Send request
+    let context = RequestType::JSON { body: id.to_string() };
+    let response = client.put(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Removes the default workflow of a trigger, such that no workflow runs when it occurs.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `trigger` - The `TriggerType` to remove the default workflow of.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+pub async fn delete_default_workflow(
+    client: &BaseClient,
+    trigger: TriggerType,
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/admin/workflows/default/{}", trigger.as_str());
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    use super::{TriggerType, Workflow, WorkflowStep};
+
+    /// Tests that a workflow is registered with typed steps and set as default for its trigger.
+    #[tokio::test]
+    async fn test_register_and_set_default() {
+        let server = MockServer::start();
+        let register = server.mock(|when, then| {
+            when.method(POST).path("/api/admin/workflows").json_body(json!({
+                "name": "Notify",
+                "steps": [
+                    {"provider": ":internal", "stepType": "log", "parameters": {"message": "Requested"}},
+                    {
+                        "provider": ":internal",
+                        "stepType": "http/sr",
+                        "parameters": {"url": "https://example.edu/hook", "method": "POST", "expectedResponse": "OK.*"}
+                    }
+                ]
+            }));
+            then.status(201).json_body(json!({
+                "status": "OK",
+                "data": {"id": 5, "name": "Notify", "steps": [{"stepType": "log", "parameters": {"message": "Requested"}}]}
+            }));
+        });
+        let default = server.mock(|when, then| {
+            when.method(PUT).path("/api/admin/workflows/default/PrePublishDataset").body("5");
+            then.status(200).json_body(json!({"status": "OK", "data": {"message": "Default workflow set"}}));
+        });
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+        let workflow = Workflow {
+            id: None,
+            name: "Notify".to_string(),
+            steps: vec![
+                WorkflowStep::internal("log").with_parameter("message", "Requested"),
+                WorkflowStep::http_request("https://example.edu/hook", "POST", "OK.*"),
+            ],
+        };
+
+        let response = admin::workflows::register_workflow(&client, &workflow).await.unwrap();
+        let registered = response.data.unwrap();
+        assert_eq!(registered.id, Some(5));
+        assert_eq!(registered.steps[0].provider, ":internal");
+
+        let trigger = "pre-publish-dataset".parse::<TriggerType>().unwrap();
+        let response = admin::workflows::set_default_workflow(&client, trigger, 5).await.unwrap();
+        assert!(response.status.is_ok());
+
+        register.assert();
+        default.assert();
+    }
+}
@@ -0,0 +1,92 @@
+use serde::{Deserialize, Serialize};
+use typify::import_types;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    request::RequestType,
+    response::Response,
+};
+
+import_types!(schema = "models/collection/assignments.json");
+
+/// Lists the role assignments of a collection.
+///
+/// This asynchronous function sends a GET request to the assignments endpoint of a collection. Only the
+/// assignments defined on the collection itself are returned, not those inherited from its parents.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<RoleAssignment>>`, which contains the HTTP response status and the
+/// role assignments of the collection, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let response = collection::list_assignments(&client, "my_collection").await?;
+///
+/// for assignment in response.data.unwrap_or_default() {
+///     println!("{:?} is {:?}", assignment.assignee, assignment.role_alias);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn list_assignments(
+    client: &BaseClient,
+    alias: &str,
+) -> Result<Response<Vec<RoleAssignment>>, String> {
+    // Endpoint metadata
+    let url = format!("api/dataverses/{}/assignments", alias);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), None, &context).await;
+
+    evaluate_response::<Vec<RoleAssignment>>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_utils::{create_test_collection, extract_test_env};
+
+    /// Tests listing the role assignments of a new collection.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables,
+    /// creates a collection and lists its assignments. The creator is assigned the admin role of the
+    /// collection, hence the list must not be empty.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the request fails.
+    #[tokio::test]
+    async fn test_list_assignments() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        let alias = create_test_collection(&client, "Root").await;
+        let response = collection::list_assignments(&client, &alias)
+            .await
+            .expect("Failed to list assignments");
+
+        assert!(response.status.is_ok());
+        assert!(!response.data.unwrap().is_empty());
+    }
+}
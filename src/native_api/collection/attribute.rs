@@ -0,0 +1,159 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::collection::create::CollectionCreateResponse,
+    request::RequestType,
+    response::Response,
+};
+
+/// Attributes of a collection that can be changed individually.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CollectionAttribute {
+    Alias,
+    Name,
+    Description,
+    Affiliation,
+    FilePIDsEnabled,
+}
+
+impl CollectionAttribute {
+    pub fn as_str(&self) -> &str {
+        match self {
+            CollectionAttribute::Alias => "alias",
+            CollectionAttribute::Name => "name",
+            CollectionAttribute::Description => "description",
+            CollectionAttribute::Affiliation => "affiliation",
+            CollectionAttribute::FilePIDsEnabled => "filePIDsEnabled",
+        }
+    }
+}
+
+impl FromStr for CollectionAttribute {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "alias" => Ok(CollectionAttribute::Alias),
+            "name" => Ok(CollectionAttribute::Name),
+            "description" => Ok(CollectionAttribute::Description),
+            "affiliation" => Ok(CollectionAttribute::Affiliation),
+            "filePIDsEnabled" => Ok(CollectionAttribute::FilePIDsEnabled),
+            _ => Err(format!("Invalid collection attribute: {}", s)),
+        }
+    }
+}
+
+/// Changes a single attribute of a collection.
+///
+/// This asynchronous function sends a PUT request to the attribute endpoint of a collection, which updates
+/// only the given attribute. Hence, small changes such as a new description do not require to send the
+/// complete collection body.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection to update.
+/// * `attribute` - The `CollectionAttribute` to change.
+/// * `value` - A string slice that holds the new value of the attribute.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<CollectionCreateResponse>`, which contains the HTTP response status and the
+/// updated collection if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::collection::attribute::CollectionAttribute;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let response = collection::set_collection_attribute(
+///     &client, "my_collection", CollectionAttribute::Description, "A new description",
+/// ).await?;
+///
+/// println!("Updated collection: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn set_collection_attribute(
+    client: &BaseClient,
+    alias: &str,
+    attribute: CollectionAttribute,
+    value: &str,
+) -> Result<Response<CollectionCreateResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/dataverses/{}/attribute/{}", alias, attribute.as_str());
+
+    // Build Parameters
+    let parameters = Some(HashMap::from([("value".to_string(), value.to_owned())]));
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.put(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<CollectionCreateResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_utils::{create_test_collection, extract_test_env};
+
+    use super::CollectionAttribute;
+
+    /// Tests changing the description of a collection.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables,
+    /// creates a test collection and updates its description. The test asserts that the request was
+    /// successful and that the returned collection carries the new description.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the update fails.
+    #[tokio::test]
+    async fn test_set_collection_description() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Create a collection and change its description
+        let alias = create_test_collection(&client, "Root").await;
+        let response = collection::set_collection_attribute(
+            &client, &alias, CollectionAttribute::Description, "Updated description",
+        ).await.expect("Failed to set attribute");
+
+        // Assert the request was successful
+        assert!(response.status.is_ok());
+        assert_eq!(
+            response.data.unwrap().description.as_deref(),
+            Some("Updated description")
+        );
+    }
+
+    /// Tests the parsing of collection attributes from string literals.
+    #[test]
+    fn test_collection_attribute_from_str() {
+        for attribute in [
+            CollectionAttribute::Alias,
+            CollectionAttribute::Name,
+            CollectionAttribute::Description,
+            CollectionAttribute::Affiliation,
+            CollectionAttribute::FilePIDsEnabled,
+        ] {
+            assert_eq!(attribute.as_str().parse::<CollectionAttribute>(), Ok(attribute));
+        }
+
+        assert!("invalid".parse::<CollectionAttribute>().is_err());
+    }
+}
@@ -1,3 +1,6 @@
+// The enums generated by typify implement `ToString` directly
+#![allow(clippy::to_string_trait_impl)]
+
 use serde::{Deserialize, Serialize};
 use serde_json;
 use typify::import_types;
@@ -0,0 +1,143 @@
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+/// Retrieves the search facets of a collection.
+///
+/// This asynchronous function sends a GET request to the facets endpoint of a collection. The facets are
+/// returned as the names of the dataset fields they are based on, e.g. `authorName` or `subject`. Collections
+/// without own facets report the facets inherited from their parent.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<String>>`, which contains the HTTP response status and the names of
+/// the facets, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let response = collection::get_facets(&client, "my_collection").await?;
+///
+/// println!("Facets: {:?}", response.data);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_facets(client: &BaseClient, alias: &str) -> Result<Response<Vec<String>>, String> {
+    // Endpoint metadata
+    let url = format!("api/dataverses/{}/facets", alias);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), None, &context).await;
+
+    evaluate_response::<Vec<String>>(response).await
+}
+
+/// Sets the search facets of a collection.
+///
+/// This asynchronous function sends a POST request to the facets endpoint of a collection, replacing its
+/// facets with the given ones. The collection thereby stops inheriting the facets of its parent. Since only
+/// field names are sent, the same facets can be applied to any number of collections.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection.
+/// * `facets` - The names of the dataset fields to use as facets, in the order they are displayed.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let facets = vec!["authorName".to_string(), "subject".to_string()];
+/// let response = collection::set_facets(&client, "my_collection", &facets).await?;
+///
+/// println!("Set facets: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn set_facets(
+    client: &BaseClient,
+    alias: &str,
+    facets: &[String],
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/dataverses/{}/facets", alias);
+
+    // Send request
+    let context = RequestType::JSON {
+        body: serde_json::to_string(facets).unwrap(),
+    };
+    let response = client.post(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_utils::{create_test_collection, extract_test_env};
+
+    /// Tests setting and retrieving the facets of a collection.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables,
+    /// creates a test collection, sets its facets and retrieves them again. The test asserts that both
+    /// requests were successful and that the retrieved facets match the ones set.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if any of the requests fail.
+    #[tokio::test]
+    async fn test_set_and_get_facets() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Create a collection and set its facets
+        let alias = create_test_collection(&client, "Root").await;
+        let facets = vec!["authorName".to_string(), "subject".to_string()];
+        let response = collection::set_facets(&client, &alias, &facets)
+            .await
+            .expect("Failed to set facets");
+        assert!(response.status.is_ok());
+
+        // Retrieve the facets again
+        let response = collection::get_facets(&client, &alias)
+            .await
+            .expect("Failed to get facets");
+        assert!(response.status.is_ok());
+        assert_eq!(response.data.unwrap(), facets);
+    }
+}
@@ -0,0 +1,49 @@
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::collection::create::CollectionCreateResponse,
+    request::RequestType,
+    response::Response,
+};
+
+/// Retrieves the details of a collection, such as its name, alias and whether it has been published.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<CollectionCreateResponse>`, which contains the HTTP response status and the
+/// details of the collection, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let response = collection::get_collection(&client, "my_collection").await?;
+///
+/// if let Some(collection) = response.data {
+///     println!("{:?} released: {:?}", collection.name, collection.is_released);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_collection(
+    client: &BaseClient,
+    alias: &str,
+) -> Result<Response<CollectionCreateResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/dataverses/{}", alias);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), None, &context).await;
+
+    evaluate_response::<CollectionCreateResponse>(response).await
+}
@@ -0,0 +1,203 @@
+#[cfg(feature = "fs")]
+use std::{collections::HashMap, path::PathBuf};
+
+use serde::{Deserialize, Serialize};
+use typify::import_types;
+
+#[cfg(feature = "fs")]
+use crate::{access::datafile::download, callback::CallbackFun};
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+import_types!(schema = "models/collection/guestbooks.json");
+
+/// Lists the guestbooks of a collection.
+///
+/// Guestbooks collect information such as name and institution from users before they download files.
+/// Both enabled and disabled guestbooks are returned.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<Guestbook>>`, which contains the HTTP response status and the
+/// guestbooks of the collection, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let response = collection::list_guestbooks(&client, "my_collection").await?;
+///
+/// for guestbook in response.data.unwrap_or_default() {
+///     println!("{:?}: {:?}", guestbook.id, guestbook.name);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn list_guestbooks(client: &BaseClient, alias: &str) -> Result<Response<Vec<Guestbook>>, String> {
+    // Endpoint metadata
+    let url = format!("api/guestbooks/{}/list", alias);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), None, &context).await;
+
+    evaluate_response::<Vec<Guestbook>>(response).await
+}
+
+/// Enables or disables a guestbook of a collection.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection.
+/// * `id` - The ID of the guestbook.
+/// * `enabled` - Whether the guestbook should be enabled.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn set_guestbook_enabled(
+    client: &BaseClient,
+    alias: &str,
+    id: i64,
+    enabled: bool,
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/guestbooks/{}/{}/enabled", alias, id);
+
+    // Send request
+    let context = RequestType::JSON {
+        body: enabled.to_string(),
+    };
+    let response = client.put(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Downloads the guestbook responses of a collection as CSV and streams them to disk.
+///
+/// This asynchronous function sends a GET request to the guestbook responses endpoint of a collection, which
+/// covers the downloads of all datasets within it. The responses may be limited to a single guestbook. As the
+/// number of responses grows with every download, the CSV is written chunk by chunk instead of being held in
+/// memory.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection.
+/// * `guestbook` - An optional ID of the guestbook to limit the responses to.
+/// * `destination` - A `PathBuf` of the file or directory to download to.
+/// * `callback` - An optional `CallbackFun` instance receiving the number of bytes written.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `PathBuf` the CSV has been written to, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let path = collection::download_guestbook_responses(
+///     &client, "my_collection", Some(1), PathBuf::from("responses.csv"), None,
+/// ).await?;
+///
+/// println!("Responses written to {}", path.display());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "fs")]
+pub async fn download_guestbook_responses(
+    client: &BaseClient,
+    alias: &str,
+    guestbook: Option<i64>,
+    destination: PathBuf,
+    callback: Option<CallbackFun>,
+) -> Result<PathBuf, String> {
+    // Endpoint metadata
+    let url = format!("api/dataverses/{}/guestbookResponses", alias);
+
+    // Build Parameters
+    let parameters = guestbook.map(|id| HashMap::from([("guestbookId".to_string(), id.to_string())]));
+
+    download(client, &url, parameters, destination, "GuestbookResponses.csv", callback).await
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "fs")]
+    use httpmock::prelude::*;
+
+    #[cfg(feature = "fs")]
+    use crate::prelude::*;
+
+    use super::Guestbook;
+
+    /// Tests that the responses of a single guestbook are streamed to a file.
+    #[cfg(feature = "fs")]
+    #[tokio::test]
+    async fn test_download_guestbook_responses() {
+        let content = "Guestbook, Dataset, Date, Type, File Name\nDefault, Data, 2024-05-01, Download, a.csv\n";
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/dataverses/my_collection/guestbookResponses")
+                .query_param("guestbookId", "1");
+            then.status(200).body(content);
+        });
+
+        let path = std::env::temp_dir().join(format!("dvcli_guestbook_{}.csv", rand::random::<u32>()));
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let written = collection::download_guestbook_responses(&client, "my_collection", Some(1), path.clone(), None)
+            .await
+            .expect("Failed to download guestbook responses");
+
+        mock.assert();
+        assert_eq!(written, path);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), content);
+
+        std::fs::remove_file(path).unwrap();
+    }
+
+    /// Tests parsing a guestbook with custom questions.
+    #[test]
+    fn test_parse_guestbook() {
+        let guestbook: Guestbook = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "name": "Default",
+            "enabled": true,
+            "emailRequired": true,
+            "customQuestions": [
+                {"question": "Purpose of use?", "required": true, "type": "options", "optionValues": ["Research", "Teaching"]}
+            ]
+        }))
+            .expect("Failed to parse guestbook");
+
+        assert_eq!(guestbook.name.as_deref(), Some("Default"));
+        assert_eq!(guestbook.custom_questions[0].option_values, vec!["Research", "Teaching"]);
+    }
+}
@@ -0,0 +1,105 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    request::RequestType,
+    response::Response,
+};
+
+/// A metadata block enabled for a collection, along with the definitions of its fields.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct MetadataBlock {
+    pub name: String,
+    #[serde(default)]
+    pub display_name: String,
+    #[serde(default)]
+    pub fields: BTreeMap<String, FieldDefinition>,
+}
+
+/// The definition of a dataset field, as configured on the instance.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldDefinition {
+    pub name: String,
+    /// The type of the values, e.g. `TEXT`, `DATE`, `EMAIL`, `URL`, `INT`, `FLOAT`, or `NONE` for compound
+    /// fields.
+    #[serde(rename = "type", default)]
+    pub field_type: String,
+    #[serde(default)]
+    pub multiple: bool,
+    #[serde(default)]
+    pub is_controlled_vocabulary: bool,
+    #[serde(default)]
+    pub controlled_vocabulary_values: Vec<String>,
+    /// Whether the field is required, either by the block or by the collection.
+    #[serde(default)]
+    pub is_required: bool,
+    #[serde(default)]
+    pub child_fields: BTreeMap<String, FieldDefinition>,
+}
+
+impl FieldDefinition {
+    /// Returns the `typeClass` of the field in a dataset body.
+    pub fn type_class(&self) -> &str {
+        match (self.child_fields.is_empty(), self.is_controlled_vocabulary) {
+            (false, _) => "compound",
+            (true, true) => "controlledVocabulary",
+            (true, false) => "primitive",
+        }
+    }
+}
+
+/// Retrieves the metadata blocks of a collection, including the definitions of their fields.
+///
+/// This asynchronous function sends a GET request to the metadata blocks endpoint of a collection. Whether a
+/// field is required reflects the settings of the collection, which may require fields beyond those required
+/// by the block itself. Collections inheriting their metadata blocks report the blocks of their parent.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<MetadataBlock>>`, which contains the HTTP response status and the
+/// metadata blocks, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let response = collection::get_metadata_blocks(&client, "my_collection").await?;
+///
+/// for block in response.data.unwrap_or_default() {
+///     println!("{}: {} fields", block.name, block.fields.len());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_metadata_blocks(client: &BaseClient, alias: &str) -> Result<Response<Vec<MetadataBlock>>, String> {
+    // Endpoint metadata
+    let url = format!("api/dataverses/{}/metadatablocks", alias);
+
+    // Build request parameters
+    let parameters = Some(HashMap::from([(
+        "returnDatasetFieldTypes".to_string(),
+        "true".to_string(),
+    )]));
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<Vec<MetadataBlock>>(response).await
+}
@@ -0,0 +1,95 @@
+use std::collections::HashMap;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+/// Moves a collection, including its datasets and sub-collections, into another collection.
+///
+/// This asynchronous function sends a POST request to the move endpoint of a collection. The instance refuses
+/// the move if the collection uses metadata blocks, facets or guestbooks inherited from its current parent that
+/// the target does not provide, unless `force` is set, in which case these links are removed. Moving collections
+/// requires superuser privileges.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection to move.
+/// * `target_alias` - A string slice that holds the alias or ID of the new parent collection.
+/// * `force` - Whether to move the collection even if links to its current parent would be broken.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let response = collection::move_collection(&client, "my_collection", "new_parent", false).await?;
+///
+/// println!("Move result: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn move_collection(
+    client: &BaseClient,
+    alias: &str,
+    target_alias: &str,
+    force: bool,
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/dataverses/{}/move/{}", alias, target_alias);
+
+    // Build Parameters
+    let parameters = match force {
+        true => Some(HashMap::from([("forceMove".to_string(), "true".to_string())])),
+        false => None,
+    };
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.post(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    /// Tests that the collection is moved to the target, passing the force flag as query parameter.
+    #[tokio::test]
+    async fn test_move_collection() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/dataverses/child/move/parent")
+                .query_param("forceMove", "true");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"message": "Dataverse moved successfully"}
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let response = collection::move_collection(&client, "child", "parent", true)
+            .await
+            .unwrap();
+
+        assert!(response.status.is_ok());
+        mock.assert();
+    }
+}
@@ -0,0 +1,197 @@
+use std::collections::HashMap;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+// The storage endpoints answer with human-readable messages such as "Total size
+// of the files stored in this collection: 1,024 bytes" rather than plain numbers.
+// Use `bytes_in_message` to read the number of bytes from them.
+
+/// Retrieves the storage quota of a collection.
+///
+/// The quota limits the total size of the files that can be stored in the collection and its
+/// sub-collections. Quotas are only enforced if the instance has enabled them via the
+/// `:UseStorageQuotas` setting.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and a message
+/// stating the quota in bytes, or that no quota is defined, if the request is successful, or a `String` error
+/// message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::collection::storage::bytes_in_message;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let response = collection::get_storage_quota(&client, "physics").await?;
+/// let quota = response.data.and_then(|data| data.message).and_then(|m| bytes_in_message(&m));
+///
+/// println!("Quota: {:?} bytes", quota);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_storage_quota(client: &BaseClient, alias: &str) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/dataverses/{}/storage/quota", alias);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Sets the storage quota of a collection, which requires a superuser token.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection.
+/// * `bytes` - The quota in bytes.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+pub async fn set_storage_quota(
+    client: &BaseClient,
+    alias: &str,
+    bytes: u64,
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/dataverses/{}/storage/quota/{}", alias, bytes);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.post(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Deletes the storage quota of a collection, which requires a superuser token.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+pub async fn delete_storage_quota(client: &BaseClient, alias: &str) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/dataverses/{}/storage/quota", alias);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Retrieves the total size of the files stored in a collection and its sub-collections.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - A string slice that holds the alias or ID of the collection.
+/// * `include_cached` - Whether to include the cached derivatives of files, such as thumbnails and the
+///   tab-delimited versions of ingested files.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and a message
+/// stating the size in bytes, if the request is successful, or a `String` error message on failure.
+pub async fn get_storage_size(
+    client: &BaseClient,
+    alias: &str,
+    include_cached: bool,
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = format!("api/dataverses/{}/storagesize", alias);
+
+    // Build Parameters
+    let parameters = include_cached.then(|| HashMap::from([("includeCached".to_string(), "true".to_string())]));
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Reads the number of bytes from a message of the storage endpoints, e.g. `1,024` from
+/// "Total size of the files stored in this collection: 1,024 bytes".
+///
+/// # Returns
+///
+/// The first number of the message, ignoring thousands separators, or `None` if the message contains no
+/// number, e.g. because no quota is defined.
+pub fn bytes_in_message(message: &str) -> Option<u64> {
+    let start = message.find(|c: char| c.is_ascii_digit())?;
+    message[start..]
+        .chars()
+        .take_while(|c| c.is_ascii_digit() || *c == ',')
+        .filter(char::is_ascii_digit)
+        .collect::<String>()
+        .parse()
+        .ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    use super::bytes_in_message;
+
+    /// Tests that a quota is set and the storage size is read from the message.
+    #[tokio::test]
+    async fn test_quota_and_size() {
+        let server = MockServer::start();
+        let set = server.mock(|when, then| {
+            when.method(POST).path("/api/dataverses/physics/storage/quota/1073741824");
+            then.status(200).json_body(json!({"status": "OK", "data": {"message": "Storage quota successfully set"}}));
+        });
+        let size = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/dataverses/physics/storagesize")
+                .query_param("includeCached", "true");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"message": "Total size of the files stored in this collection: 52,428,800 bytes"}
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+
+        let response = collection::set_storage_quota(&client, "physics", 1 << 30).await.unwrap();
+        assert!(response.status.is_ok());
+
+        let response = collection::get_storage_size(&client, "physics", true).await.unwrap();
+        let message = response.data.unwrap().message.unwrap();
+        assert_eq!(bytes_in_message(&message), Some(52_428_800));
+        assert_eq!(bytes_in_message("No quota defined for this collection"), None);
+
+        set.assert();
+        size.assert();
+    }
+}
@@ -0,0 +1,334 @@
+use std::collections::{HashMap, HashSet};
+
+use futures::StreamExt;
+use serde::{Deserialize, Serialize};
+
+use crate::client::BaseClient;
+
+use super::content::{get_content, CollectionContent};
+use super::create::CollectionCreateResponse;
+use super::get::get_collection;
+
+/// A collection along with its datasets and, recursively, its sub-collections.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct CollectionTree {
+    pub id: Option<i64>,
+    /// The alias of the root, or the numeric ID of sub-collections, as used to retrieve their content.
+    pub identifier: String,
+    pub alias: Option<String>,
+    pub title: Option<String>,
+    /// Whether the collection has been published.
+    pub released: Option<bool>,
+    pub datasets: Vec<DatasetNode>,
+    pub collections: Vec<CollectionTree>,
+}
+
+/// A dataset found within a collection of the tree.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct DatasetNode {
+    pub id: i64,
+    pub persistent_id: Option<String>,
+    pub publication_date: Option<String>,
+}
+
+impl DatasetNode {
+    /// Returns whether a version of the dataset has been published.
+    pub fn is_published(&self) -> bool {
+        self.publication_date.is_some()
+    }
+}
+
+impl CollectionTree {
+    /// Returns the number of datasets in this collection and all of its sub-collections.
+    pub fn dataset_count(&self) -> usize {
+        self.datasets.len() + self.collections.iter().map(CollectionTree::dataset_count).sum::<usize>()
+    }
+
+    /// Returns the number of sub-collections below this collection, at any depth.
+    pub fn collection_count(&self) -> usize {
+        self.collections.len() + self.collections.iter().map(CollectionTree::collection_count).sum::<usize>()
+    }
+}
+
+// A collection reached while walking the tree, with the content reported by the instance
+pub(crate) struct CollectionNode {
+    // The alias of the root, or the numeric ID of sub-collections, as used to retrieve the content
+    pub(crate) collection: String,
+    // The title reported by the parent collection
+    pub(crate) title: Option<String>,
+    pub(crate) parent: Option<String>,
+    pub(crate) depth: u64,
+    pub(crate) datasets: Vec<CollectionContent>,
+    pub(crate) children: Vec<(i64, Option<String>)>,
+}
+
+impl CollectionNode {
+    pub(crate) fn dataset_ids(&self) -> Vec<i64> {
+        self.datasets.iter().filter_map(|dataset| dataset.id).collect()
+    }
+}
+
+// Walks the tree breadth-first and returns every collection exactly once, in the
+// order reported by the instance. Collections are recognised by their ID, such that
+// the walk terminates even if a collection is reported within its own descendants,
+// including the root, whose ID is resolved first unless it is given as a number.
+pub(crate) async fn walk_tree(
+    client: &BaseClient,
+    root: &str,
+    concurrency: usize,
+) -> Result<Vec<CollectionNode>, String> {
+    let mut visited: HashSet<i64> = root_id(client, root).await.into_iter().collect();
+    let mut frontier = vec![(root.to_string(), None, None, 0)];
+    let mut nodes = vec![];
+
+    while !frontier.is_empty() {
+        let contents: Vec<Result<CollectionNode, String>> = futures::stream::iter(frontier)
+            .map(|(collection, title, parent, depth)| fetch_node(client, collection, title, parent, depth))
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
+
+        frontier = vec![];
+        for node in contents {
+            let node = node?;
+            for (id, title) in &node.children {
+                if visited.insert(*id) {
+                    frontier.push((id.to_string(), title.clone(), Some(node.collection.clone()), node.depth + 1));
+                }
+            }
+            nodes.push(node);
+        }
+    }
+
+    Ok(nodes)
+}
+
+async fn root_id(client: &BaseClient, root: &str) -> Option<i64> {
+    if let Ok(id) = root.parse() {
+        return Some(id);
+    }
+
+    get_collection(client, root).await.ok()?.data?.id
+}
+
+// Retrieves the datasets and sub-collections of a single collection
+async fn fetch_node(
+    client: &BaseClient,
+    collection: String,
+    title: Option<String>,
+    parent: Option<String>,
+    depth: u64,
+) -> Result<CollectionNode, String> {
+    let response = get_content(client, &collection).await?;
+    if response.status.is_err() {
+        return Err(format!(
+            "Failed to retrieve the content of collection '{}': {}",
+            collection,
+            response.message.map(|m| m.to_string()).unwrap_or_default()
+        ));
+    }
+
+    let mut node = CollectionNode {
+        collection,
+        title,
+        parent,
+        depth,
+        datasets: vec![],
+        children: vec![],
+    };
+    for item in response.data.unwrap_or_default() {
+        let Some(id) = item.id else {
+            continue;
+        };
+
+        match item.type_.as_deref() {
+            Some("dataverse") => node.children.push((id, item.title)),
+            Some("dataset") => node.datasets.push(item),
+            _ => {}
+        }
+    }
+
+    Ok(node)
+}
+
+/// Retrieves the tree of sub-collections and datasets below a collection.
+///
+/// This asynchronous function walks the collection tree breadth-first using `get_content` and retrieves the
+/// details of each collection using `get_collection`. All collections of a level are requested simultaneously,
+/// with at most `concurrency` collections at a time. Each collection is visited and included only once, such
+/// that the walk terminates even if the instance reports a collection within its own descendants. The
+/// collections and datasets of each level keep the order reported by the instance.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `alias` - A string slice that holds the alias or ID of the collection at the root of the tree.
+/// * `concurrency` - The maximum number of collections requested simultaneously; zero is treated as one.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `CollectionTree`, or a `String` error message if the content or details of any
+/// collection cannot be retrieved.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let tree = collection::get_collection_tree(&client, "root", 4).await?;
+///
+/// println!("{} collections, {} datasets", tree.collection_count(), tree.dataset_count());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_collection_tree(
+    client: &BaseClient,
+    alias: &str,
+    concurrency: usize,
+) -> Result<CollectionTree, String> {
+    let nodes = walk_tree(client, alias, concurrency).await?;
+    let details: Vec<_> = futures::stream::iter(&nodes)
+        .map(|node| get_collection(client, &node.collection))
+        .buffered(concurrency.max(1))
+        .collect()
+        .await;
+
+    let mut nodes_by_collection = HashMap::new();
+    for (node, details) in nodes.into_iter().zip(details) {
+        let details = details?;
+        if details.status.is_err() {
+            return Err(format!(
+                "Failed to retrieve collection '{}': {}",
+                node.collection,
+                details.message.map(|m| m.to_string()).unwrap_or_default()
+            ));
+        }
+        nodes_by_collection.insert(node.collection.clone(), (node, details.data));
+    }
+
+    assemble(alias, &mut nodes_by_collection).ok_or_else(|| format!("Failed to retrieve collection '{}'", alias))
+}
+
+// Builds the tree below a collection from the walked nodes. Nodes are taken out of the map, such that a
+// collection reported more than once only appears at its first occurrence.
+fn assemble(
+    collection: &str,
+    nodes: &mut HashMap<String, (CollectionNode, Option<CollectionCreateResponse>)>,
+) -> Option<CollectionTree> {
+    let (node, details) = nodes.remove(collection)?;
+    let collections = node
+        .children
+        .iter()
+        .filter_map(|(id, _)| assemble(&id.to_string(), nodes))
+        .collect();
+
+    Some(CollectionTree {
+        id: details.as_ref().and_then(|details| details.id),
+        identifier: node.collection,
+        alias: details.as_ref().and_then(|details| details.alias.clone()),
+        title: details.as_ref().and_then(|details| details.name.clone()).or(node.title),
+        released: details.as_ref().and_then(|details| details.is_released),
+        datasets: node
+            .datasets
+            .into_iter()
+            .filter_map(|item| {
+                Some(DatasetNode {
+                    id: item.id?,
+                    persistent_id: persistent_id(&item),
+                    publication_date: item.publication_date,
+                })
+            })
+            .collect(),
+        collections,
+    })
+}
+
+// Composes the persistent identifier of a dataset from its parts, e.g. `doi:10.5072/FK2/ABC123`
+fn persistent_id(item: &CollectionContent) -> Option<String> {
+    match (&item.protocol, &item.authority, &item.identifier) {
+        (Some(protocol), Some(authority), Some(identifier)) => {
+            Some(format!("{}:{}/{}", protocol, authority, identifier))
+        }
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    /// Tests that the tree is assembled in order and collections reported within their descendants, including
+    /// the root, are visited and included once.
+    #[tokio::test]
+    async fn test_get_collection_tree() {
+        let server = MockServer::start();
+        let root = server.mock(|when, then| {
+            when.method(GET).path("/api/dataverses/root/contents");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": [
+                    {"type": "dataverse", "id": 2, "title": "Physics"},
+                    {"type": "dataverse", "id": 3, "title": "Chemistry"},
+                    {"type": "dataset", "id": 10, "protocol": "doi", "authority": "10.5072", "identifier": "FK2/ROOT"}
+                ]
+            }));
+        });
+        let physics = server.mock(|when, then| {
+            when.method(GET).path("/api/dataverses/2/contents");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": [
+                    {"type": "dataverse", "id": 2, "title": "Physics"},
+                    {"type": "dataset", "id": 11, "publicationDate": "2024-05-01"}
+                ]
+            }));
+        });
+        let chemistry = server.mock(|when, then| {
+            when.method(GET).path("/api/dataverses/3/contents");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": [{"type": "dataverse", "id": 1, "title": "Root"}]
+            }));
+        });
+
+        let details = [("root", 1, "root", true), ("2", 2, "physics", true), ("3", 3, "chemistry", false)];
+        for (collection, id, alias, released) in details {
+            server.mock(|when, then| {
+                when.method(GET).path(format!("/api/dataverses/{}", collection));
+                then.status(200).json_body(json!({
+                    "status": "OK",
+                    "data": {"id": id, "alias": alias, "name": alias.to_uppercase(), "isReleased": released}
+                }));
+            });
+        }
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let tree = collection::get_collection_tree(&client, "root", 2).await.unwrap();
+
+        assert_eq!(tree.identifier, "root");
+        assert_eq!(tree.id, Some(1));
+        assert_eq!(tree.title.as_deref(), Some("ROOT"));
+        assert_eq!(tree.datasets[0].persistent_id.as_deref(), Some("doi:10.5072/FK2/ROOT"));
+        assert!(!tree.datasets[0].is_published());
+        assert_eq!(tree.collections.len(), 2);
+        assert_eq!(tree.collections[0].alias.as_deref(), Some("physics"));
+        assert!(tree.collections[0].datasets[0].is_published());
+        assert!(tree.collections[0].collections.is_empty());
+        assert_eq!(tree.collections[1].released, Some(false));
+        assert!(tree.collections[1].collections.is_empty());
+        assert_eq!(tree.collection_count(), 2);
+        assert_eq!(tree.dataset_count(), 2);
+
+        root.assert();
+        physics.assert_hits(1);
+        chemistry.assert();
+    }
+}
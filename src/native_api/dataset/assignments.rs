@@ -0,0 +1,41 @@
+use std::collections::HashMap;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::collection::assignments::RoleAssignment,
+    request::RequestType,
+    response::Response,
+};
+
+/// Lists the role assignments of a dataset.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<RoleAssignment>>`, which contains the HTTP response status and the
+/// role assignments of the dataset, if the request is successful, or a `String` error message on failure.
+pub async fn list_dataset_assignments(
+    client: &BaseClient,
+    id: Identifier,
+) -> Result<Response<Vec<RoleAssignment>>, String> {
+    // Endpoint metadata
+    let (url, parameters) = match id {
+        Identifier::PersistentId(id) => (
+            "api/datasets/:persistentId/assignments".to_string(),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/datasets/{}/assignments", id), None),
+    };
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<Vec<RoleAssignment>>(response).await
+}
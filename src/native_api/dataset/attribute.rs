@@ -0,0 +1,173 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+/// Attributes of a dataset that can be changed individually, outside of its metadata blocks.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DatasetAttribute {
+    /// The type name of the date field used in the citation, e.g. `dateOfDeposit`.
+    CitationDate,
+    /// The name of a license configured for the instance, e.g. `CC BY 4.0`.
+    License,
+    /// The label of the store new files of the dataset are saved to (superuser only).
+    StorageDriver,
+}
+
+impl DatasetAttribute {
+    pub fn as_str(&self) -> &str {
+        match self {
+            DatasetAttribute::CitationDate => "citationDate",
+            DatasetAttribute::License => "license",
+            DatasetAttribute::StorageDriver => "storageDriver",
+        }
+    }
+
+    // The endpoint of the attribute below the dataset and the body carrying the value
+    fn endpoint(&self, value: &str) -> (&str, String) {
+        match self {
+            DatasetAttribute::CitationDate => ("citationdate", value.to_string()),
+            DatasetAttribute::License => ("license", serde_json::json!({ "name": value }).to_string()),
+            DatasetAttribute::StorageDriver => ("storageDriver", value.to_string()),
+        }
+    }
+}
+
+impl FromStr for DatasetAttribute {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "citationDate" => Ok(DatasetAttribute::CitationDate),
+            "license" => Ok(DatasetAttribute::License),
+            "storageDriver" => Ok(DatasetAttribute::StorageDriver),
+            _ => Err(format!("Invalid dataset attribute: {}", s)),
+        }
+    }
+}
+
+/// Changes a single attribute of a dataset.
+///
+/// This asynchronous function sends a PUT request to the endpoint of the given attribute, which updates
+/// only this attribute. Hence, small changes such as another license do not require to send the metadata
+/// of the dataset. Metadata fields are changed with `edit::set_dataset_field` instead.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `attribute` - The `DatasetAttribute` to change.
+/// * `value` - A string slice that holds the new value of the attribute.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::attribute::DatasetAttribute;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let response = dataset::set_dataset_attribute(
+///     &client, Identifier::Id(42), DatasetAttribute::License, "CC BY 4.0",
+/// ).await?;
+///
+/// println!("Updated dataset: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn set_dataset_attribute(
+    client: &BaseClient,
+    id: Identifier,
+    attribute: DatasetAttribute,
+    value: &str,
+) -> Result<Response<MessageResponse>, String> {
+    let (endpoint, body) = attribute.endpoint(value);
+
+    // Endpoint metadata
+    let (url, parameters) = match id {
+        Identifier::PersistentId(id) => (
+            format!("api/datasets/:persistentId/{}", endpoint),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/datasets/{}/{}", id, endpoint), None),
+    };
+
+    // Send request
+    let context = RequestType::JSON { body };
+    let response = client.put(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use crate::prelude::*;
+
+    use super::DatasetAttribute;
+
+    /// Tests that the license is sent by name and the citation date as the plain type name.
+    #[tokio::test]
+    async fn test_set_dataset_attribute() {
+        let server = MockServer::start();
+        let license = server.mock(|when, then| {
+            when.method(PUT)
+                .path("/api/datasets/42/license")
+                .json_body(serde_json::json!({"name": "CC BY 4.0"}));
+            then.status(200).json_body(serde_json::json!({"status": "OK", "data": {"message": "License updated"}}));
+        });
+        let citation_date = server.mock(|when, then| {
+            when.method(PUT)
+                .path("/api/datasets/:persistentId/citationdate")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123")
+                .body("dateOfDeposit");
+            then.status(200).json_body(serde_json::json!({"status": "OK", "data": {"message": "Citation date set"}}));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+
+        let response = dataset::set_dataset_attribute(&client, Identifier::Id(42), DatasetAttribute::License, "CC BY 4.0")
+            .await
+            .expect("Failed to set license");
+        assert!(response.status.is_ok());
+
+        let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+        let response = dataset::set_dataset_attribute(&client, id, DatasetAttribute::CitationDate, "dateOfDeposit")
+            .await
+            .expect("Failed to set citation date");
+        assert!(response.status.is_ok());
+
+        license.assert();
+        citation_date.assert();
+    }
+
+    /// Tests the parsing of dataset attributes from string literals.
+    #[test]
+    fn test_dataset_attribute_from_str() {
+        for attribute in [
+            DatasetAttribute::CitationDate,
+            DatasetAttribute::License,
+            DatasetAttribute::StorageDriver,
+        ] {
+            assert_eq!(attribute.as_str().parse::<DatasetAttribute>(), Ok(attribute));
+        }
+
+        assert!("invalid".parse::<DatasetAttribute>().is_err());
+    }
+}
@@ -0,0 +1,330 @@
+use serde::{Deserialize, Serialize};
+
+use crate::client::BaseClient;
+use crate::crosswalk::datacite::find_license;
+
+use super::create::{create_dataset, DatasetCreateBody};
+use super::metadata::DatasetMetadataBuilder;
+use super::publish::{publish_dataset, Version};
+
+/// Maps the columns of a CSV file onto the fields of the citation block, by the header of each column.
+///
+/// By default, each field is read from the column of the same name, e.g. `contact_email`. Cells of the
+/// `author`, `author_affiliation`, `description`, `subject` and `keyword` columns may hold multiple values
+/// joined by the `separator`, where the n-th affiliation belongs to the n-th author. The columns of the
+/// title, author, contact email, description and subject are required, all others are optional.
+///
+/// A license is set from the `license` column if it names CC0 1.0 or CC BY 4.0, or if the `license_uri`
+/// column provides the URI of another license of the instance.
+///
+/// # Examples
+///
+/// A mapping file, in YAML, for a spreadsheet with German headers:
+///
+/// ```yaml
+/// title: Titel
+/// author: Autoren
+/// contact_name: Kontakt
+/// contact_email: E-Mail
+/// description: Beschreibung
+/// subject: Fachgebiet
+/// separator: "|"
+/// ```
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CsvMapping {
+    pub title: String,
+    pub subtitle: String,
+    pub author: String,
+    pub author_affiliation: String,
+    pub contact_name: String,
+    pub contact_email: String,
+    pub description: String,
+    pub subject: String,
+    pub keyword: String,
+    pub license: String,
+    pub license_uri: String,
+    pub separator: String,
+}
+
+impl Default for CsvMapping {
+    fn default() -> Self {
+        CsvMapping {
+            title: "title".to_string(),
+            subtitle: "subtitle".to_string(),
+            author: "author".to_string(),
+            author_affiliation: "author_affiliation".to_string(),
+            contact_name: "contact_name".to_string(),
+            contact_email: "contact_email".to_string(),
+            description: "description".to_string(),
+            subject: "subject".to_string(),
+            keyword: "keyword".to_string(),
+            license: "license".to_string(),
+            license_uri: "license_uri".to_string(),
+            separator: ";".to_string(),
+        }
+    }
+}
+
+/// The outcome of creating a dataset per row of a CSV file.
+#[derive(Debug, Default)]
+pub struct BatchCreation {
+    /// Datasets that have been created, by the line of their row, along with their persistent identifier.
+    pub created: Vec<(u64, String)>,
+    /// Rows whose dataset could not be created or published, by their line, along with the error message.
+    pub failed: Vec<(u64, String)>,
+}
+
+impl BatchCreation {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty()
+    }
+}
+
+/// Creates a dataset in a collection for each row of a CSV file.
+///
+/// This asynchronous function reads the citation metadata of each row using the column `mapping` and creates
+/// the datasets one after another. Errors are collected per row rather than aborting the batch, such that the
+/// failed rows can be fixed and submitted again. If `publish` is set, each dataset is published as a major
+/// version right after its creation; datasets that fail to publish remain as drafts and are reported both as
+/// created and failed.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `collection` - The alias of the collection to create the datasets in.
+/// * `csv` - The content of the CSV file, with a header row.
+/// * `mapping` - The `CsvMapping` naming the column of each citation field.
+/// * `publish` - Whether to publish each dataset after its creation.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `BatchCreation` listing the created and failed rows, including malformed ones, or a
+/// `String` error message if the CSV cannot be read or lacks a required column.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::batch::CsvMapping;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let csv = std::fs::read_to_string("datasets.csv").map_err(|e| e.to_string())?;
+/// let batch = dataset::create_datasets_from_csv(&client, "root", &csv, &CsvMapping::default(), false).await?;
+///
+/// for (line, error) in &batch.failed {
+///     eprintln!("Line {}: {}", line, error);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn create_datasets_from_csv(
+    client: &BaseClient,
+    collection: &str,
+    csv: &str,
+    mapping: &CsvMapping,
+    publish: bool,
+) -> Result<BatchCreation, String> {
+    let mut reader = csv::Reader::from_reader(csv.as_bytes());
+    let headers = reader.headers().map_err(|e| e.to_string())?.clone();
+
+    let required = [
+        &mapping.title,
+        &mapping.author,
+        &mapping.contact_email,
+        &mapping.description,
+        &mapping.subject,
+    ];
+    let missing: Vec<&str> = required
+        .into_iter()
+        .filter(|column| !headers.iter().any(|header| header == column.as_str()))
+        .map(String::as_str)
+        .collect();
+    if !missing.is_empty() {
+        return Err(format!("Missing CSV column(s): {}", missing.join(", ")));
+    }
+
+    let mut batch = BatchCreation::default();
+    for record in reader.records() {
+        // Malformed rows, e.g. with a missing cell, are reported like rows the instance rejected
+        let record = match record {
+            Ok(record) => record,
+            Err(error) if error.is_io_error() => return Err(error.to_string()),
+            Err(error) => {
+                batch.failed.push((error_line(&error), error.to_string()));
+                continue;
+            }
+        };
+        let line = record.position().map(|position| position.line()).unwrap_or_default();
+        let cell = |column: &str| {
+            headers
+                .iter()
+                .position(|header| header == column)
+                .and_then(|index| record.get(index))
+                .map(str::trim)
+                .unwrap_or_default()
+        };
+
+        let body = match row_to_body(&cell, mapping) {
+            Ok(body) => body,
+            Err(error) => {
+                batch.failed.push((line, error));
+                continue;
+            }
+        };
+
+        let pid = match create_dataset(client, collection, body).await {
+            Ok(response) if response.status.is_ok() => {
+                response.data.and_then(|data| data.persistent_id).unwrap_or_default()
+            }
+            Ok(response) => {
+                let error = response.message.map(|m| m.to_string()).unwrap_or_default();
+                batch.failed.push((line, error));
+                continue;
+            }
+            Err(error) => {
+                batch.failed.push((line, error));
+                continue;
+            }
+        };
+        batch.created.push((line, pid.clone()));
+
+        if publish {
+            let error = match publish_dataset(client, &pid, Version::Major).await {
+                Ok(response) if response.status.is_ok() => continue,
+                Ok(response) => response.message.map(|m| m.to_string()).unwrap_or_default(),
+                Err(error) => error,
+            };
+            batch.failed.push((line, format!("Failed to publish {}: {}", pid, error)));
+        }
+    }
+
+    Ok(batch)
+}
+
+fn error_line(error: &csv::Error) -> u64 {
+    let position = match error.kind() {
+        csv::ErrorKind::Utf8 { pos, .. }
+        | csv::ErrorKind::UnequalLengths { pos, .. }
+        | csv::ErrorKind::Deserialize { pos, .. } => pos.as_ref(),
+        _ => None,
+    };
+
+    position.map(|position| position.line()).unwrap_or_default()
+}
+
+// Builds the body of a row, reading the cells through the given lookup by column name
+fn row_to_body<'a>(cell: &impl Fn(&str) -> &'a str, mapping: &CsvMapping) -> Result<DatasetCreateBody, String> {
+    let values = |column: &str| -> Vec<&'a str> {
+        cell(column)
+            .split(mapping.separator.as_str())
+            .map(str::trim)
+            .filter(|value| !value.is_empty())
+            .collect()
+    };
+
+    let mut builder = DatasetMetadataBuilder::new();
+    if !cell(&mapping.title).is_empty() {
+        builder = builder.title(cell(&mapping.title));
+    }
+    if !cell(&mapping.subtitle).is_empty() {
+        builder = builder.subtitle(cell(&mapping.subtitle));
+    }
+
+    let affiliations = values(&mapping.author_affiliation);
+    for (index, author) in values(&mapping.author).into_iter().enumerate() {
+        builder = builder.add_author(author, affiliations.get(index).copied(), None);
+    }
+
+    if !cell(&mapping.contact_email).is_empty() {
+        builder = builder.add_contact(cell(&mapping.contact_name), cell(&mapping.contact_email));
+    }
+    for description in values(&mapping.description) {
+        builder = builder.description(description);
+    }
+    for subject in values(&mapping.subject) {
+        builder = builder.subject(subject);
+    }
+    for keyword in values(&mapping.keyword) {
+        builder = builder.add_keyword(keyword);
+    }
+
+    let (license, uri) = (cell(&mapping.license), cell(&mapping.license_uri));
+    match (license.is_empty(), uri.is_empty()) {
+        (true, true) => {}
+        (false, false) => builder = builder.license(license, uri),
+        _ => {
+            let (name, uri) = find_license(license)
+                .or_else(|| find_license(uri))
+                .ok_or_else(|| format!("Unknown license '{}', please provide its name and URI", license))?;
+            builder = builder.license(name, uri);
+        }
+    }
+
+    builder.build()
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    use super::{create_datasets_from_csv, CsvMapping};
+
+    /// Tests that a dataset is created per valid row and invalid rows are reported by their line.
+    #[tokio::test]
+    async fn test_create_datasets_from_csv() {
+        let server = MockServer::start();
+        let create = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/dataverses/root/datasets")
+                .body_contains("Hourly measurements")
+                .body_contains("Roe, Richard");
+            then.status(201)
+                .json_body(json!({"status": "OK", "data": {"id": 1, "persistentId": "doi:10.5072/FK2/ONE"}}));
+        });
+        let publish = server.mock(|when, then| {
+            when.method(POST).path("/api/datasets/:persistentId/actions/:publish");
+            then.status(200).json_body(json!({"status": "OK", "data": {}}));
+        });
+
+        let csv = r#"Titel,Autoren,Institute,E-Mail,Beschreibung,subject,license
+Soil moisture,"Doe, Jane|Roe, Richard",University of Example,jane.doe@example.edu,Hourly measurements,Earth and Environmental Sciences,CC BY 4.0
+Air quality,"Doe, Jane",,jane.doe@example.edu,Daily measurements,Meteorology,
+Water levels,"Doe, Jane"
+"#;
+
+        let mapping = CsvMapping {
+            title: "Titel".to_string(),
+            author: "Autoren".to_string(),
+            author_affiliation: "Institute".to_string(),
+            contact_email: "E-Mail".to_string(),
+            description: "Beschreibung".to_string(),
+            separator: "|".to_string(),
+            ..Default::default()
+        };
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let batch = create_datasets_from_csv(&client, "root", csv, &mapping, true).await.unwrap();
+
+        assert_eq!(batch.created, vec![(2, "doi:10.5072/FK2/ONE".to_string())]);
+        assert_eq!(batch.failed.len(), 2);
+        assert_eq!(batch.failed[0].0, 3);
+        assert!(batch.failed[0].1.starts_with("Unknown subject 'Meteorology'"));
+        assert_eq!(batch.failed[1].0, 4);
+        assert!(!batch.is_success());
+
+        create.assert_hits(1);
+        publish.assert_hits(1);
+
+        let error = create_datasets_from_csv(&client, "root", "title\nSoil", &CsvMapping::default(), false)
+            .await
+            .unwrap_err();
+        assert_eq!(error, "Missing CSV column(s): author, contact_email, description, subject");
+    }
+}
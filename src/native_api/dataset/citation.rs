@@ -0,0 +1,264 @@
+use std::{collections::HashMap, str::FromStr};
+
+use crate::{
+    access::datafile::error_message,
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+/// A format of reference managers the citation of a dataset can be exported in.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CitationFormat {
+    BibTeX,
+    Ris,
+    EndNote,
+}
+
+impl CitationFormat {
+    /// Returns the name of the format as expected by the API.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CitationFormat::BibTeX => "BibTeX",
+            CitationFormat::Ris => "RIS",
+            CitationFormat::EndNote => "EndNote",
+        }
+    }
+}
+
+impl FromStr for CitationFormat {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().as_str() {
+            "bibtex" => Ok(CitationFormat::BibTeX),
+            "ris" => Ok(CitationFormat::Ris),
+            "endnote" => Ok(CitationFormat::EndNote),
+            _ => Err(format!("Unknown citation format '{}', expected bibtex, ris or endnote", s)),
+        }
+    }
+}
+
+/// Options to select the variant of a citation.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CitationOptions {
+    /// Omits the authors and other identifying parts, as shown to reviewers using an anonymized preview URL.
+    /// The client must then use the token of the preview URL as API token.
+    pub anonymized: bool,
+    /// Cites deaccessioned versions, which are hidden otherwise.
+    pub include_deaccessioned: bool,
+}
+
+impl CitationOptions {
+    // Only options deviating from the server defaults are sent
+    fn to_parameters(&self) -> HashMap<String, String> {
+        [
+            ("anonymizedAccess", self.anonymized),
+            ("includeDeaccessioned", self.include_deaccessioned),
+        ]
+            .into_iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(key, _)| (key.to_string(), "true".to_string()))
+            .collect()
+    }
+}
+
+/// Retrieves the citation of a dataset version.
+///
+/// This asynchronous function sends a GET request to the citation endpoint of a dataset version. Besides
+/// version numbers such as `1.0`, the identifiers `:draft`, `:latest` and `:latest-published` are accepted.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `version` - A string slice that holds the version to cite.
+/// * `options` - A reference to the `CitationOptions` selecting the variant of the citation.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the citation
+/// as message, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::citation::CitationOptions;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", None)
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let response = dataset::get_citation(&client, id, ":latest-published", &CitationOptions::default()).await?;
+///
+/// if let Some(citation) = response.data.and_then(|data| data.message) {
+///     println!("{}", citation);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_citation(
+    client: &BaseClient,
+    id: Identifier,
+    version: &str,
+    options: &CitationOptions,
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = match id {
+        Identifier::PersistentId(_) => format!("api/datasets/:persistentId/versions/{}/citation", version),
+        Identifier::Id(id) => format!("api/datasets/{}/versions/{}/citation", id, version),
+    };
+
+    // Build Parameters
+    let mut parameters = options.to_parameters();
+    if let Identifier::PersistentId(id) = id {
+        parameters.insert("persistentId".to_string(), id);
+    }
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), Some(parameters), &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Exports the citation of a dataset version in a format of reference managers.
+///
+/// This asynchronous function sends a GET request to the citation endpoint of a dataset version for the given
+/// format. The document is returned as is, e.g. a BibTeX entry or an EndNote XML record, such that it can be
+/// imported into reference managers directly.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `version` - A string slice that holds the version to cite.
+/// * `format` - The `CitationFormat` to export the citation in.
+/// * `options` - A reference to the `CitationOptions` selecting the variant of the citation.
+///
+/// # Returns
+///
+/// A `Result` wrapping the exported citation, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::citation::{CitationFormat, CitationOptions};
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", None)
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let bibtex = dataset::export_citation(
+///     &client, id, ":latest-published", CitationFormat::BibTeX, &CitationOptions::default(),
+/// ).await?;
+///
+/// print!("{}", bibtex);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn export_citation(
+    client: &BaseClient,
+    id: Identifier,
+    version: &str,
+    format: CitationFormat,
+    options: &CitationOptions,
+) -> Result<String, String> {
+    // Endpoint metadata
+    let url = match id {
+        Identifier::PersistentId(_) => {
+            format!("api/datasets/:persistentId/versions/{}/citation/{}", version, format.as_str())
+        }
+        Identifier::Id(id) => format!("api/datasets/{}/versions/{}/citation/{}", id, version, format.as_str()),
+    };
+
+    // Build Parameters
+    let mut parameters = options.to_parameters();
+    if let Identifier::PersistentId(id) = id {
+        parameters.insert("persistentId".to_string(), id);
+    }
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client
+        .get(url.as_str(), Some(parameters), &context)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !response.status().is_success() {
+        return Err(error_message(response).await);
+    }
+
+    response.text().await.map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    use super::{CitationFormat, CitationOptions};
+
+    /// Tests that the anonymized citation is requested with the token of the preview URL.
+    #[tokio::test]
+    async fn test_get_anonymized_citation() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/datasets/42/versions/:draft/citation")
+                .query_param("anonymizedAccess", "true")
+                .header("X-Dataverse-key", "preview-token");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"message": "Author name(s) withheld, 2024, \"Soil moisture\", DRAFT VERSION"}
+            }));
+        });
+
+        let options = CitationOptions {
+            anonymized: true,
+            ..Default::default()
+        };
+
+        let client = BaseClient::new(&server.base_url(), Some(&"preview-token".to_string())).unwrap();
+        let response = dataset::get_citation(&client, Identifier::Id(42), ":draft", &options).await.unwrap();
+
+        let citation = response.data.unwrap().message.unwrap();
+        assert!(citation.starts_with("Author name(s) withheld"));
+
+        mock.assert();
+    }
+
+    /// Tests that the citation is exported as is in the requested format.
+    #[tokio::test]
+    async fn test_export_citation() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/datasets/:persistentId/versions/1.0/citation/BibTeX")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123");
+            then.status(200).body("@data{FK2/ABC123_2024,\nauthor = {Doe, Jane},\n}\n");
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+        let format = "bibtex".parse::<CitationFormat>().unwrap();
+        let bibtex = dataset::export_citation(&client, id, "1.0", format, &CitationOptions::default())
+            .await
+            .unwrap();
+
+        assert!(bibtex.starts_with("@data{FK2/ABC123_2024,"));
+        assert_eq!("RIS".parse::<CitationFormat>(), Ok(CitationFormat::Ris));
+        assert!("apa".parse::<CitationFormat>().is_err());
+
+        mock.assert();
+    }
+}
@@ -1,13 +1,43 @@
+// The enums generated by typify implement `ToString` directly
+#![allow(clippy::to_string_trait_impl)]
+
 use serde::{Deserialize, Serialize};
 use serde_json;
 use typify::import_types;
 
 use crate::{
     client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::collection::content::get_content,
+    native_api::dataset::get::get_dataset_meta,
     request::RequestType,
-    response::Response,
+    response::{Message, Response, Status},
 };
 
+// Agency name under which idempotency tokens are stored
+// in the `otherId` field of the citation block
+pub const IDEMPOTENCY_AGENCY: &str = "dvcli-idempotency-key";
+
+// Fields of the terms of use and access, which are part of the dataset version
+const TERMS_FIELDS: [&str; 16] = [
+    "termsOfUse",
+    "confidentialityDeclaration",
+    "specialPermissions",
+    "restrictions",
+    "citationRequirements",
+    "depositorRequirements",
+    "conditions",
+    "disclaimer",
+    "termsOfAccess",
+    "fileAccessRequest",
+    "dataAccessPlace",
+    "originalArchive",
+    "availabilityStatus",
+    "contactForAccess",
+    "sizeOfCollection",
+    "studyCompletion",
+];
+
 import_types!(
     schema = "models/dataset/create.json",
     struct_builder = true,
@@ -72,10 +102,278 @@ pub async fn create_dataset(
     evaluate_response::<DatasetCreateResponse>(response).await
 }
 
+impl DatasetCreateBody {
+    /// Converts a `dataverse_json` export of a dataset into a body to create a copy of it.
+    ///
+    /// Exports, as produced by the export API, contain server-assigned fields such as identifiers, version
+    /// numbers, timestamps and the files of the version. Only the license, the terms of use and access and
+    /// the metadata blocks are kept, such that the result can be passed to `create_dataset`. The `latestVersion` of a native API dataset
+    /// response is accepted as well.
+    ///
+    /// # Arguments
+    ///
+    /// * `export` - A reference to the parsed `dataverse_json` export.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `DatasetCreateBody`, or a `String` error message if the export does not contain
+    /// a dataset version or its metadata blocks are invalid.
+    pub fn from_export(export: &serde_json::Value) -> Result<Self, String> {
+        let version = export
+            .get("datasetVersion")
+            .or_else(|| export.get("latestVersion"))
+            .ok_or("The export does not contain a dataset version")?;
+
+        let metadata_blocks = version
+            .get("metadataBlocks")
+            .and_then(|blocks| blocks.as_object())
+            .ok_or("The export does not contain any metadata blocks")?
+            .iter()
+            .map(|(name, block)| {
+                let stripped = serde_json::json!({
+                    "displayName": block.get("displayName"),
+                    "fields": block.get("fields"),
+                });
+                (name.clone(), stripped)
+            })
+            .collect::<serde_json::Map<_, _>>();
+
+        let license = version.get("license").filter(|license| !license.is_null()).map(|license| {
+            serde_json::json!({
+                "name": license.get("name"),
+                "uri": license.get("uri"),
+            })
+        });
+
+        let mut dataset_version = serde_json::json!({ "metadataBlocks": metadata_blocks });
+        if let Some(license) = license {
+            dataset_version["license"] = license;
+        }
+
+        // Custom terms of use and the terms of access are flattened into the version
+        for field in TERMS_FIELDS {
+            if let Some(value) = version.get(field).filter(|value| !value.is_null()) {
+                dataset_version[field] = value.clone();
+            }
+        }
+
+        serde_json::from_value(serde_json::json!({ "datasetVersion": dataset_version }))
+            .map_err(|e| format!("Invalid metadata in export: {}", e))
+    }
+}
+
+/// Determines how a previously created draft is recognised by `create_dataset_idempotent`.
+#[derive(Debug, Clone, PartialEq)]
+pub enum IdempotencyKey {
+    // A draft in the same collection with the same title is considered a match
+    Title,
+
+    // A draft carrying the token as an `otherId` (agency `IDEMPOTENCY_AGENCY`)
+    // is considered a match. The token is added to the body before creation.
+    Token(String),
+}
+
+/// Creates a new dataset unless a matching draft already exists in the parent dataverse.
+///
+/// Pipelines that retry `create_dataset` after a timeout frequently end up with duplicate datasets,
+/// because the first request did reach the server. This function first looks through the draft datasets
+/// of the parent dataverse for one matching the given `IdempotencyKey`. If a match is found, its identifiers
+/// are returned instead of creating a new dataset. Otherwise, the dataset is created as usual.
+///
+/// Please note, that every dataset within the parent dataverse is fetched to compare its metadata. Hence,
+/// this function is considerably slower than `create_dataset` for large collections.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `parent` - A string slice that holds the identifier of the parent dataverse under which the dataset is to be created.
+/// * `body` - The `DatasetCreateBody` struct instance containing the details of the dataset to be created.
+/// * `key` - The `IdempotencyKey` used to recognise a draft created by a previous attempt.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<DatasetCreateResponse>`, which contains the identifiers of either the existing
+/// draft or the newly created dataset, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dataverse::prelude::*;
+/// # use dataverse::native_api::dataset::create::{DatasetCreateBody, IdempotencyKey};
+/// # async fn run(body: DatasetCreateBody) -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let key = IdempotencyKey::Token("pipeline-run-42".to_string());
+/// let response = dataset::create::create_dataset_idempotent(&client, "root", body, key).await?;
+///
+/// println!("Dataset: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn create_dataset_idempotent(
+    client: &BaseClient,
+    parent: &str,
+    body: DatasetCreateBody,
+    key: IdempotencyKey,
+) -> Result<Response<DatasetCreateResponse>, String> {
+    // Tag the body with the token, such that retries can find the dataset
+    let body = match &key {
+        IdempotencyKey::Token(token) => add_idempotency_token(body, token)?,
+        IdempotencyKey::Title => body,
+    };
+
+    if let Some(existing) = find_existing_draft(client, parent, &body, &key).await? {
+        return Ok(Response {
+            status: Status::OK,
+            data: Some(existing),
+            message: Some(Message::PlainMessage(
+                "Found an existing draft matching the idempotency key".to_string(),
+            )),
+            requestUrl: None,
+            requestMethod: None,
+        });
+    }
+
+    create_dataset(client, parent, body).await
+}
+
+// Looks for a draft within the parent dataverse that matches the key
+async fn find_existing_draft(
+    client: &BaseClient,
+    parent: &str,
+    body: &DatasetCreateBody,
+    key: &IdempotencyKey,
+) -> Result<Option<DatasetCreateResponse>, String> {
+    // If the collection cannot be listed, we let the subsequent
+    // create request report the error to the user
+    let content = get_content(client, parent).await?;
+    let items = match content.data {
+        Some(items) if content.status.is_ok() => items,
+        _ => return Ok(None),
+    };
+
+    let body_blocks = metadata_blocks_of(body)?;
+    let dataset_ids = items
+        .iter()
+        .filter(|item| item.type_.as_deref() == Some("dataset"))
+        .filter_map(|item| item.id);
+
+    for id in dataset_ids {
+        let response = get_dataset_meta(client, Identifier::Id(id)).await?;
+        let Some(dataset) = response.data else {
+            continue;
+        };
+
+        let Some(version) = dataset.latest_version else {
+            continue;
+        };
+
+        if version.version_state.as_deref() != Some("DRAFT") {
+            continue;
+        }
+
+        let draft_blocks =
+            serde_json::to_value(&version.metadata_blocks).map_err(|e| e.to_string())?;
+
+        if matches_key(&body_blocks, &draft_blocks, key) {
+            return Ok(Some(DatasetCreateResponse {
+                id: dataset.id,
+                persistent_id: version.dataset_persistent_id,
+            }));
+        }
+    }
+
+    Ok(None)
+}
+
+// Adds the token as an `otherId` entry to the citation block of the body
+fn add_idempotency_token(body: DatasetCreateBody, token: &str) -> Result<DatasetCreateBody, String> {
+    let mut value = serde_json::to_value(&body).map_err(|e| e.to_string())?;
+    let entry = serde_json::json!({
+        "otherIdAgency": {
+            "typeName": "otherIdAgency",
+            "multiple": false,
+            "typeClass": "primitive",
+            "value": IDEMPOTENCY_AGENCY
+        },
+        "otherIdValue": {
+            "typeName": "otherIdValue",
+            "multiple": false,
+            "typeClass": "primitive",
+            "value": token
+        }
+    });
+
+    let fields = value
+        .pointer_mut("/datasetVersion/metadataBlocks/citation/fields")
+        .and_then(|fields| fields.as_array_mut())
+        .ok_or("The dataset body does not contain a citation block")?;
+
+    match fields.iter_mut().find(|f| f["typeName"] == "otherId") {
+        Some(field) => match field["value"].as_array_mut() {
+            Some(values) => values.push(entry),
+            None => return Err("The otherId field of the dataset body is malformed".to_string()),
+        },
+        None => fields.push(serde_json::json!({
+            "typeName": "otherId",
+            "multiple": true,
+            "typeClass": "compound",
+            "value": [entry]
+        })),
+    }
+
+    serde_json::from_value(value).map_err(|e| e.to_string())
+}
+
+fn metadata_blocks_of(body: &DatasetCreateBody) -> Result<serde_json::Value, String> {
+    let value = serde_json::to_value(body).map_err(|e| e.to_string())?;
+    Ok(value
+        .pointer("/datasetVersion/metadataBlocks")
+        .cloned()
+        .unwrap_or_default())
+}
+
+// Compares the metadata blocks of the body to be created with those of an existing draft
+fn matches_key(
+    body_blocks: &serde_json::Value,
+    draft_blocks: &serde_json::Value,
+    key: &IdempotencyKey,
+) -> bool {
+    match key {
+        IdempotencyKey::Title => {
+            let title = find_field(body_blocks, "title").map(|f| &f["value"]);
+            title.is_some() && title == find_field(draft_blocks, "title").map(|f| &f["value"])
+        }
+        IdempotencyKey::Token(token) => find_field(draft_blocks, "otherId")
+            .and_then(|f| f["value"].as_array())
+            .is_some_and(|ids| {
+                ids.iter().any(|id| {
+                    id["otherIdAgency"]["value"] == IDEMPOTENCY_AGENCY
+                        && id["otherIdValue"]["value"] == token.as_str()
+                })
+            }),
+    }
+}
+
+// Searches all metadata blocks for a field of the given type name
+fn find_field<'a>(blocks: &'a serde_json::Value, type_name: &str) -> Option<&'a serde_json::Value> {
+    blocks
+        .as_object()?
+        .values()
+        .filter_map(|block| block["fields"].as_array())
+        .flatten()
+        .find(|field| field["typeName"] == type_name)
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::{BaseClient, dataset};
-    use crate::test_utils::{extract_test_env, prepare_dataset_body};
+    use crate::test_utils::{create_test_collection, extract_test_env, prepare_dataset_body};
+
+    use super::{add_idempotency_token, matches_key, metadata_blocks_of, DatasetCreateBody, IdempotencyKey};
 
     /// Tests the successful creation of a dataset under a specified parent dataverse.
     ///
@@ -179,4 +477,116 @@ mod tests {
         // Assert the request has failed
         assert!(response.status.is_err());
     }
-}
\ No newline at end of file
+
+    /// Tests that creating a dataset twice with the same idempotency token yields a single dataset.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables,
+    /// creates a collection and then calls `create_dataset_idempotent` twice with the same token.
+    /// The test asserts that both calls return the same dataset identifier.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the Dataverse API.
+    /// - `BASE_URL`: The base URL of the Dataverse instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the second call creates
+    /// a new dataset instead of returning the existing draft.
+    #[tokio::test]
+    async fn test_create_dataset_idempotent() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+        let collection = create_test_collection(&client, "Root").await;
+
+        // Create the dataset twice
+        let key = dataset::create::IdempotencyKey::Token("test-token".to_string());
+        let body = prepare_dataset_body("./tests/fixtures/create_dataset_body.json".into());
+        let first = dataset::create::create_dataset_idempotent(
+            &client, &collection, body.clone(), key.clone(),
+        ).await.expect("Failed to create dataset");
+        let second = dataset::create::create_dataset_idempotent(
+            &client, &collection, body, key,
+        ).await.expect("Failed to create dataset");
+
+        // Assert both calls refer to the same dataset
+        assert!(first.status.is_ok());
+        assert!(second.status.is_ok());
+        assert_eq!(first.data.unwrap().id, second.data.unwrap().id);
+    }
+
+    /// Tests that an idempotency token added to a body is recognised when matching drafts.
+    ///
+    /// This test adds a token to the dataset body fixture and checks that the resulting metadata
+    /// blocks match the very same token, but neither a different token nor the untagged body.
+    #[test]
+    fn test_idempotency_token_matching() {
+        let body = prepare_dataset_body("./tests/fixtures/create_dataset_body.json".into());
+        let plain = metadata_blocks_of(&body).unwrap();
+        let tagged = metadata_blocks_of(&add_idempotency_token(body, "abc").unwrap()).unwrap();
+
+        let same = IdempotencyKey::Token("abc".to_string());
+        let other = IdempotencyKey::Token("xyz".to_string());
+
+        assert!(matches_key(&tagged, &tagged, &same));
+        assert!(!matches_key(&tagged, &tagged, &other));
+        assert!(!matches_key(&plain, &plain, &same));
+        assert!(matches_key(&plain, &tagged, &IdempotencyKey::Title));
+    }
+
+    /// Tests the conversion of a `dataverse_json` export into a dataset create body.
+    ///
+    /// This test reads an export fixture and verifies that server-assigned fields are stripped,
+    /// while the license, the terms and the metadata fields are kept.
+    ///
+    /// # Fixture Files
+    /// - `./tests/fixtures/dataverse_json_export.json`: An export of a released dataset.
+    #[test]
+    fn test_create_body_from_export() {
+        let content = std::fs::read_to_string("tests/fixtures/dataverse_json_export.json")
+            .expect("Failed to read export fixture");
+        let export: serde_json::Value = serde_json::from_str(&content).unwrap();
+
+        let body = DatasetCreateBody::from_export(&export).expect("Failed to convert export");
+        let value = serde_json::to_value(&body).unwrap();
+
+        assert_eq!(
+            value.pointer("/datasetVersion/license/name"),
+            Some(&serde_json::json!("CC0 1.0"))
+        );
+        assert_eq!(value.pointer("/datasetVersion/license/iconUri"), None);
+        assert_eq!(value.pointer("/datasetVersion/versionState"), None);
+        assert_eq!(value.pointer("/datasetVersion/files"), None);
+        assert_eq!(value.get("id"), None);
+        assert_eq!(
+            value.pointer("/datasetVersion/metadataBlocks/citation/fields/0/value"),
+            Some(&serde_json::json!("Exported Dataset"))
+        );
+
+        assert_eq!(
+            value.pointer("/datasetVersion/fileAccessRequest"),
+            Some(&serde_json::json!(true))
+        );
+
+        // Custom terms replace the license
+        let mut export = export;
+        export["datasetVersion"]["license"] = serde_json::Value::Null;
+        export["datasetVersion"]["termsOfUse"] = serde_json::json!("Only for teaching");
+        export["datasetVersion"]["termsOfAccess"] = serde_json::json!("Ask the depositor");
+
+        let body = DatasetCreateBody::from_export(&export).expect("Failed to convert export");
+        let value = serde_json::to_value(&body).unwrap();
+
+        assert_eq!(value.pointer("/datasetVersion/license"), None);
+        assert_eq!(
+            value.pointer("/datasetVersion/termsOfUse"),
+            Some(&serde_json::json!("Only for teaching"))
+        );
+        assert_eq!(
+            value.pointer("/datasetVersion/termsOfAccess"),
+            Some(&serde_json::json!("Ask the depositor"))
+        );
+
+        assert!(DatasetCreateBody::from_export(&serde_json::json!({"id": 1})).is_err());
+    }
+}
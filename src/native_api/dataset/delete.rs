@@ -1,8 +1,11 @@
 use serde::{Deserialize, Serialize};
 use typify::import_types;
 
+use std::collections::HashMap;
+
 use crate::{
     client::{BaseClient, evaluate_response},
+    identifier::Identifier,
     request::RequestType,
     response::Response,
 };
@@ -60,8 +63,66 @@ pub async fn delete_dataset(
     evaluate_response::<UnpublishedDatasetDeleteResponse>(response).await
 }
 
+/// Destroys a dataset, including published ones, identified by either a persistent identifier or a numeric ID.
+///
+/// This asynchronous function sends a DELETE request to the destroy endpoint of the API. Unlike `delete_dataset`,
+/// which only works for drafts, this removes the dataset with all its versions and files. This operation
+/// requires superuser privileges and cannot be undone.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset to be destroyed.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<UnpublishedDatasetDeleteResponse>` on success, or a `String` error message on failure.
+/// The `Response` object contains the HTTP response status and the message of the server.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let pid = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let response = dataset::destroy_dataset(&client, pid).await?;
+///
+/// println!("Dataset destroy response: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn destroy_dataset(
+    client: &BaseClient,
+    id: Identifier,
+) -> Result<Response<UnpublishedDatasetDeleteResponse>, String> {
+    // Endpoint metadata
+    let url = match id {
+        Identifier::PersistentId(_) => "api/datasets/:persistentId/destroy".to_string(),
+        Identifier::Id(id) => format!("api/datasets/{}/destroy", id),
+    };
+
+    // Build Parameters
+    let parameters = match id {
+        Identifier::PersistentId(id) => Some(HashMap::from([("persistentId".to_string(), id)])),
+        Identifier::Id(_) => None,
+    };
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<UnpublishedDatasetDeleteResponse>(response).await
+}
+
 #[cfg(test)]
 mod tests {
+    use crate::identifier::Identifier;
     use crate::prelude::{BaseClient, dataset};
     use crate::test_utils::{create_test_dataset, extract_test_env};
 
@@ -128,4 +189,62 @@ mod tests {
         // Assert the request was successful
         assert!(response.status.is_err());
     }
-}
\ No newline at end of file
+
+    /// Tests destroying a published dataset.
+    ///
+    /// This test verifies that a published dataset, which can no longer be deleted, can be destroyed.
+    /// It sets up a client using API token and base URL obtained from environment variables, creates and
+    /// publishes a dataset, and then destroys it using its persistent identifier.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token of a superuser.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the destroy request fails.
+    #[tokio::test]
+    async fn test_destroy_published_dataset() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Create and publish a dataset
+        let (_, pid) = create_test_dataset(&client, "Root").await;
+        dataset::publish::publish_dataset(&client, &pid, dataset::publish::Version::Major)
+            .await.expect("Failed to publish dataset");
+
+        // Destroy the dataset
+        let response = dataset::delete::destroy_dataset(&client, Identifier::PersistentId(pid))
+            .await.expect("Failed to destroy dataset");
+
+        // Assert the request was successful
+        assert!(response.status.is_ok());
+    }
+
+    /// Tests destroying a non-existent dataset.
+    ///
+    /// This test verifies that attempting to destroy a dataset that does not exist correctly
+    /// results in an error.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token of a superuser.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the request unexpectedly succeeds.
+    #[tokio::test]
+    async fn test_destroy_dataset_not_found() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Attempt to destroy a non-existent dataset
+        let response = dataset::delete::destroy_dataset(&client, Identifier::Id(-1))
+            .await.expect("Failed to destroy dataset");
+
+        // Assert the request has failed
+        assert!(response.status.is_err());
+    }
+}
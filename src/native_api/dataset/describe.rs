@@ -0,0 +1,211 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::BaseClient,
+    export::summary::DatasetSummary,
+    identifier::Identifier,
+    response::Response,
+};
+
+use super::edit::Dataset;
+use super::get::{get_dataset_meta, list_dataset_versions, GetDatasetOptions};
+use super::locks::{get_locks, Lock};
+
+/// An overview of a dataset, collected from its metadata, versions and locks.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct DatasetDescription {
+    pub persistent_id: Option<String>,
+    pub title: Option<String>,
+    pub authors: Vec<String>,
+    /// The state of the latest version, i.e. `DRAFT`, `RELEASED` or `DEACCESSIONED`.
+    pub state: Option<String>,
+    /// The number of the latest version, e.g. `1.2`, or `DRAFT`.
+    pub version: String,
+    pub last_update: Option<String>,
+    pub license: Option<String>,
+    /// The number of files in the latest version.
+    pub file_count: usize,
+    /// The total size of the files in the latest version in bytes.
+    pub total_size: u64,
+    /// All versions of the dataset, starting with the most recent one.
+    pub versions: Vec<VersionSummary>,
+    pub locks: Vec<Lock>,
+}
+
+/// A version of a dataset, as listed in a `DatasetDescription`.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct VersionSummary {
+    /// The number of the version, e.g. `1.2`, or `DRAFT`.
+    pub version: String,
+    pub state: Option<String>,
+    pub release_time: Option<String>,
+}
+
+impl DatasetDescription {
+    /// Returns the most recent version that has been published, if any.
+    pub fn latest_published(&self) -> Option<&VersionSummary> {
+        self.versions
+            .iter()
+            .find(|version| version.state.as_deref() == Some("RELEASED"))
+    }
+}
+
+/// Describes a dataset by its citation metadata, versions, files and locks.
+///
+/// This asynchronous function retrieves the dataset, its versions and its locks simultaneously and condenses
+/// them into a `DatasetDescription`. The files are counted in the latest version, which is the draft if one
+/// exists and is accessible with the token of the client.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `DatasetDescription`, or a `String` error message if any of the requests fails.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let description = dataset::describe_dataset(&client, id).await?;
+///
+/// println!("{} files in version {}", description.file_count, description.version);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn describe_dataset(client: &BaseClient, id: Identifier) -> Result<DatasetDescription, String> {
+    let options = GetDatasetOptions {
+        exclude_files: true,
+        ..Default::default()
+    };
+    let (dataset, versions, locks) = futures::join!(
+        get_dataset_meta(client, id.clone()),
+        list_dataset_versions(client, id.clone(), &options),
+        get_locks(client, id),
+    );
+    let (dataset, versions, locks) = (data(dataset)?, data(versions)?, data(locks)?);
+
+    let latest = dataset
+        .latest_version
+        .clone()
+        .ok_or_else(|| "The dataset does not contain a version".to_string())?;
+    let value = serde_json::to_value(&dataset).map_err(|e| e.to_string())?;
+    let summary = DatasetSummary::from_value(&value)?;
+
+    Ok(DatasetDescription {
+        persistent_id: summary.persistent_id,
+        title: summary.title,
+        authors: summary.authors.into_iter().map(|author| author.name).collect(),
+        state: latest.version_state.clone(),
+        version: version_label(&latest),
+        last_update: latest.last_update_time.clone(),
+        license: summary.license.map(|license| license.name),
+        file_count: summary.files.len(),
+        total_size: summary.files.iter().filter_map(|file| file.size).map(|size| size.max(0) as u64).sum(),
+        versions: versions
+            .iter()
+            .map(|version| VersionSummary {
+                version: version_label(version),
+                state: version.version_state.clone(),
+                release_time: version.release_time.clone(),
+            })
+            .collect(),
+        locks,
+    })
+}
+
+// Unwraps the data of a successful response, turning failed ones into their message
+fn data<T>(response: Result<Response<T>, String>) -> Result<T, String> {
+    let response = response?;
+    if response.status.is_err() {
+        return Err(response.message.map(|m| m.to_string()).unwrap_or_default());
+    }
+
+    response.data.ok_or_else(|| "The response contains no data".to_string())
+}
+
+pub(crate) fn version_label(version: &Dataset) -> String {
+    match (version.version_number, version.version_minor_number) {
+        (Some(major), Some(minor)) => format!("{}.{}", major, minor),
+        _ => "DRAFT".to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    /// Tests that the dataset, its versions and locks are condensed into a description.
+    #[tokio::test]
+    async fn test_describe_dataset() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/42");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {
+                    "id": 42,
+                    "protocol": "doi",
+                    "authority": "10.5072",
+                    "identifier": "FK2/ABC123",
+                    "latestVersion": {
+                        "versionState": "DRAFT",
+                        "lastUpdateTime": "2024-06-01T10:00:00Z",
+                        "license": {"name": "CC BY 4.0", "uri": "http://creativecommons.org/licenses/by/4.0"},
+                        "metadataBlocks": {"citation": {"fields": [
+                            {"typeName": "title", "typeClass": "primitive", "multiple": false, "value": "Soil moisture"},
+                            {"typeName": "author", "typeClass": "compound", "multiple": true, "value": [
+                                {"authorName": {"typeName": "authorName", "typeClass": "primitive", "multiple": false, "value": "Doe, Jane"}}
+                            ]}
+                        ]}},
+                        "files": [
+                            {"label": "a.csv", "dataFile": {"id": 1, "filesize": 100}},
+                            {"label": "b.csv", "dataFile": {"id": 2, "filesize": 28}}
+                        ]
+                    }
+                }
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/42/versions").query_param("excludeFiles", "true");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": [
+                    {"versionState": "DRAFT"},
+                    {"versionState": "RELEASED", "versionNumber": 1, "versionMinorNumber": 0, "releaseTime": "2024-05-01T08:00:00Z"}
+                ]
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/42/locks");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": [{"lockType": "Ingest", "date": "2024-06-01T10:00:00Z", "user": "jdoe"}]
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let description = dataset::describe_dataset(&client, Identifier::Id(42)).await.unwrap();
+
+        assert_eq!(description.persistent_id.as_deref(), Some("doi:10.5072/FK2/ABC123"));
+        assert_eq!(description.title.as_deref(), Some("Soil moisture"));
+        assert_eq!(description.authors, vec!["Doe, Jane"]);
+        assert_eq!(description.version, "DRAFT");
+        assert_eq!(description.license.as_deref(), Some("CC BY 4.0"));
+        assert_eq!((description.file_count, description.total_size), (2, 128));
+        assert_eq!(description.latest_published().unwrap().version, "1.0");
+        assert_eq!(description.locks[0].lock_type, "Ingest");
+    }
+}
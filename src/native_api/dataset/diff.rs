@@ -0,0 +1,385 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::{client::BaseClient, identifier::Identifier, response::Response};
+
+use super::describe::version_label;
+use super::edit::Dataset;
+use super::get::{get_dataset_version, GetDatasetOptions};
+
+// Fields are compared by their raw values and rendered as text for display only.
+// Files are first matched by the ID of their data file, which persists across
+// versions unless the file is replaced, and then by their path, such that moved
+// files and replaced files are not reported as a removal and an addition.
+
+/// The kind of a change between two versions.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ChangeKind {
+    Added,
+    Removed,
+    /// The metadata of a field or file changed, including moves of files.
+    Modified,
+    /// The content of a file has been replaced by another upload.
+    Replaced,
+}
+
+/// A metadata field that differs between two versions.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FieldChange {
+    /// The field prefixed by its metadata block, e.g. `citation.title`, or `license`.
+    pub field: String,
+    pub change: ChangeKind,
+    pub before: Option<String>,
+    pub after: Option<String>,
+}
+
+/// A file that differs between two versions.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct FileChange {
+    /// The path of the file in the newer version, or in the older one if it has been removed.
+    pub path: String,
+    pub change: ChangeKind,
+    /// The changed attributes, e.g. `moved from raw/a.csv` or `description`.
+    pub details: Vec<String>,
+}
+
+/// The differences between two versions of a dataset.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct VersionDiff {
+    /// The number of the older version, e.g. `1.2`, or `DRAFT`.
+    pub from: String,
+    /// The number of the newer version, e.g. `1.2`, or `DRAFT`.
+    pub to: String,
+    pub fields: Vec<FieldChange>,
+    pub files: Vec<FileChange>,
+}
+
+impl VersionDiff {
+    /// Returns true if neither the metadata nor the files differ.
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty() && self.files.is_empty()
+    }
+}
+
+/// Compares two versions of a dataset field by field and file by file.
+///
+/// Both versions are retrieved simultaneously. Besides version numbers such as `1.0`, the identifiers `:draft`,
+/// `:latest` and `:latest-published` are accepted, so comparing `:latest-published` with `:draft` lists the changes
+/// pending publication.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `from` - The older version to compare.
+/// * `to` - The newer version to compare.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `VersionDiff`, or a `String` error message if either version cannot be retrieved.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let diff = dataset::compare_versions(&client, id, ":latest-published", ":draft").await?;
+///
+/// for field in diff.fields {
+///     println!("{}: {:?} -> {:?}", field.field, field.before, field.after);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn compare_versions(
+    client: &BaseClient,
+    id: Identifier,
+    from: &str,
+    to: &str,
+) -> Result<VersionDiff, String> {
+    let options = GetDatasetOptions::default();
+    let (older, newer) = futures::join!(
+        get_dataset_version(client, id.clone(), from, &options),
+        get_dataset_version(client, id, to, &options),
+    );
+    let (older, newer) = (version(older, from)?, version(newer, to)?);
+
+    let (older_value, newer_value) = (to_value(&older)?, to_value(&newer)?);
+
+    Ok(VersionDiff {
+        from: version_label(&older),
+        to: version_label(&newer),
+        fields: diff_fields(&older_value, &newer_value),
+        files: diff_files(&older_value, &newer_value),
+    })
+}
+
+fn version(response: Result<Response<Dataset>, String>, version: &str) -> Result<Dataset, String> {
+    let response = response?;
+    if response.status.is_err() {
+        let message = response.message.map(|m| m.to_string()).unwrap_or_default();
+        return Err(format!("Failed to retrieve version {}: {}", version, message));
+    }
+
+    response
+        .data
+        .ok_or_else(|| format!("The response for version {} contains no data", version))
+}
+
+fn to_value(dataset: &Dataset) -> Result<Value, String> {
+    serde_json::to_value(dataset).map_err(|e| e.to_string())
+}
+
+fn diff_fields(older: &Value, newer: &Value) -> Vec<FieldChange> {
+    let (mut older_fields, newer_fields) = (fields(older), fields(newer));
+
+    let mut changes = Vec::new();
+    for (name, after) in newer_fields {
+        let before = older_fields.remove(&name);
+        let change = match &before {
+            None => ChangeKind::Added,
+            Some(before) if *before == after => continue,
+            Some(_) => ChangeKind::Modified,
+        };
+        changes.push(FieldChange {
+            field: name,
+            change,
+            before: before.as_ref().map(render),
+            after: Some(render(&after)),
+        });
+    }
+    changes.extend(older_fields.into_iter().map(|(name, before)| FieldChange {
+        field: name,
+        change: ChangeKind::Removed,
+        before: Some(render(&before)),
+        after: None,
+    }));
+
+    changes.sort_by(|a, b| a.field.cmp(&b.field));
+    changes
+}
+
+// Collects the values of all fields by their name prefixed with the block
+fn fields(version: &Value) -> BTreeMap<String, Value> {
+    let mut fields = BTreeMap::new();
+    if let Some(blocks) = version["metadataBlocks"].as_object() {
+        for (block, content) in blocks {
+            for field in content["fields"].as_array().into_iter().flatten() {
+                if let Some(name) = field["typeName"].as_str() {
+                    fields.insert(format!("{}.{}", block, name), field["value"].clone());
+                }
+            }
+        }
+    }
+    if let Some(license) = version["license"]["name"].as_str() {
+        fields.insert("license".to_string(), Value::String(license.to_string()));
+    }
+
+    fields
+}
+
+// Renders a field value as text, listing the sub fields of compound values
+fn render(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        Value::Array(values) => values.iter().map(render).collect::<Vec<_>>().join("; "),
+        Value::Object(children) => children
+            .iter()
+            .map(|(name, child)| format!("{}: {}", name, render(&child["value"])))
+            .collect::<Vec<_>>()
+            .join(", "),
+        Value::Null => String::new(),
+        value => value.to_string(),
+    }
+}
+
+// The attributes of a file that are compared between versions
+struct FileState {
+    id: Option<i64>,
+    path: String,
+    checksum: Option<String>,
+    attributes: [(&'static str, Value); 2],
+}
+
+impl FileState {
+    fn from_value(file: &Value) -> Option<Self> {
+        let label = file["label"].as_str()?;
+        let path = match file["directoryLabel"].as_str().filter(|directory| !directory.is_empty()) {
+            Some(directory) => format!("{}/{}", directory.trim_end_matches('/'), label),
+            None => label.to_string(),
+        };
+
+        Some(FileState {
+            id: file["dataFile"]["id"].as_i64(),
+            path,
+            checksum: file["dataFile"]["checksum"]["value"].as_str().map(str::to_string),
+            attributes: [
+                ("description", file["description"].clone()),
+                ("restricted", file["restricted"].clone()),
+            ],
+        })
+    }
+
+    // Lists the attributes that differ from those of the same file in another version
+    fn details(&self, older: &FileState) -> Vec<String> {
+        let mut details = Vec::new();
+        if self.path != older.path {
+            details.push(format!("moved from {}", older.path));
+        }
+        for ((name, after), (_, before)) in self.attributes.iter().zip(older.attributes.iter()) {
+            if after != before {
+                details.push(name.to_string());
+            }
+        }
+
+        details
+    }
+}
+
+fn diff_files(older: &Value, newer: &Value) -> Vec<FileChange> {
+    let files = |version: &Value| -> Vec<FileState> {
+        version["files"]
+            .as_array()
+            .map(|files| files.iter().filter_map(FileState::from_value).collect())
+            .unwrap_or_default()
+    };
+    let mut older_files: Vec<Option<FileState>> = files(older).into_iter().map(Some).collect();
+
+    let by_id: HashMap<i64, usize> = older_files
+        .iter()
+        .enumerate()
+        .filter_map(|(index, file)| Some((file.as_ref()?.id?, index)))
+        .collect();
+    let by_path: HashMap<String, usize> = older_files
+        .iter()
+        .enumerate()
+        .filter_map(|(index, file)| Some((file.as_ref()?.path.clone(), index)))
+        .collect();
+
+    let mut changes = Vec::new();
+    for file in files(newer) {
+        let same = file.id.and_then(|id| by_id.get(&id)).and_then(|index| older_files[*index].take());
+        if let Some(previous) = same {
+            let details = file.details(&previous);
+            if !details.is_empty() {
+                changes.push(FileChange { path: file.path, change: ChangeKind::Modified, details });
+            }
+            continue;
+        }
+
+        let replaced = by_path.get(&file.path).and_then(|index| older_files[*index].take());
+        let (change, details) = match replaced {
+            Some(previous) => {
+                let mut details = file.details(&previous);
+                if file.checksum != previous.checksum {
+                    details.insert(0, "content".to_string());
+                }
+                (ChangeKind::Replaced, details)
+            }
+            None => (ChangeKind::Added, Vec::new()),
+        };
+        changes.push(FileChange { path: file.path, change, details });
+    }
+    changes.extend(older_files.into_iter().flatten().map(|file| FileChange {
+        path: file.path,
+        change: ChangeKind::Removed,
+        details: Vec::new(),
+    }));
+
+    changes.sort_by(|a, b| a.path.cmp(&b.path));
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    use super::ChangeKind;
+
+    /// Tests that changed fields and added, moved, replaced and removed files are reported.
+    #[tokio::test]
+    async fn test_compare_versions() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/42/versions/:latest-published");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {
+                    "versionState": "RELEASED",
+                    "versionNumber": 1,
+                    "versionMinorNumber": 0,
+                    "license": {"name": "CC0 1.0"},
+                    "metadataBlocks": {"citation": {"fields": [
+                        {"typeName": "title", "typeClass": "primitive", "multiple": false, "value": "Soil moisture"},
+                        {"typeName": "subject", "typeClass": "controlledVocabulary", "multiple": true, "value": ["Earth and Environmental Sciences"]}
+                    ]}},
+                    "files": [
+                        {"label": "a.csv", "dataFile": {"id": 1, "checksum": {"type": "MD5", "value": "aaa"}}},
+                        {"label": "b.csv", "dataFile": {"id": 2, "checksum": {"type": "MD5", "value": "bbb"}}},
+                        {"label": "c.csv", "dataFile": {"id": 3, "checksum": {"type": "MD5", "value": "ccc"}}}
+                    ]
+                }
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/42/versions/:draft");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {
+                    "versionState": "DRAFT",
+                    "license": {"name": "CC0 1.0"},
+                    "metadataBlocks": {"citation": {"fields": [
+                        {"typeName": "title", "typeClass": "primitive", "multiple": false, "value": "Soil moisture 2024"},
+                        {"typeName": "subject", "typeClass": "controlledVocabulary", "multiple": true, "value": ["Earth and Environmental Sciences"]},
+                        {"typeName": "keyword", "typeClass": "compound", "multiple": true, "value": [
+                            {"keywordValue": {"typeName": "keywordValue", "typeClass": "primitive", "multiple": false, "value": "soil"}}
+                        ]}
+                    ]}},
+                    "files": [
+                        {"label": "a.csv", "directoryLabel": "data", "description": "Raw", "dataFile": {"id": 1, "checksum": {"type": "MD5", "value": "aaa"}}},
+                        {"label": "b.csv", "dataFile": {"id": 4, "checksum": {"type": "MD5", "value": "ddd"}}},
+                        {"label": "d.csv", "dataFile": {"id": 5, "checksum": {"type": "MD5", "value": "eee"}}}
+                    ]
+                }
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let diff = dataset::compare_versions(&client, Identifier::Id(42), ":latest-published", ":draft")
+            .await
+            .unwrap();
+
+        assert_eq!((diff.from.as_str(), diff.to.as_str()), ("1.0", "DRAFT"));
+
+        let fields: Vec<_> = diff.fields.iter().map(|field| (field.field.as_str(), field.change)).collect();
+        assert_eq!(
+            fields,
+            vec![("citation.keyword", ChangeKind::Added), ("citation.title", ChangeKind::Modified)]
+        );
+        assert_eq!(diff.fields[0].after.as_deref(), Some("keywordValue: soil"));
+
+        let files: Vec<_> = diff.files.iter().map(|file| (file.path.as_str(), file.change)).collect();
+        assert_eq!(
+            files,
+            vec![
+                ("b.csv", ChangeKind::Replaced),
+                ("c.csv", ChangeKind::Removed),
+                ("d.csv", ChangeKind::Added),
+                ("data/a.csv", ChangeKind::Modified),
+            ]
+        );
+        assert_eq!(diff.files[3].details, vec!["moved from a.csv", "description"]);
+    }
+}
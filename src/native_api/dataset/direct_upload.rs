@@ -0,0 +1,1279 @@
+use std::collections::HashMap;
+use std::io::{Read, SeekFrom};
+use std::path::{Path, PathBuf};
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use futures::channel::mpsc;
+use futures::{stream, StreamExt};
+use md5::{Digest, Md5};
+use serde::{Deserialize, Serialize};
+use tokio::io::{AsyncReadExt, AsyncSeekExt};
+use tokio_util::io::ReaderStream;
+use typify::import_types;
+
+use crate::{
+    access::datafile::error_message,
+    checksum::{hex, ChecksumAlgorithm},
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::file::delete::delete_file,
+    request::RequestType,
+    response::Response,
+};
+
+use super::upload::upload_bounded;
+
+import_types!(
+    schema = "models/dataset/direct_upload.json",
+    struct_builder = true,
+);
+
+// This module is only available with the `directupload` feature. Files are
+// uploaded directly to the storage of an instance using presigned URLs and are
+// then registered with their dataset via the `addFiles` endpoint.
+//
+// Every part of an upload is checked twice: the storage rejects a part whose
+// content does not match its `Content-MD5` header, and the ETag returned for
+// the part is compared with the local digest. If the completion of a multipart
+// upload reports the ETag of the assembled object, it is compared with the one
+// derived from the local parts. On any mismatch, the upload is aborted, such that
+// a corrupted file is never registered.
+//
+// The completion endpoint of Dataverse itself only answers with a message, and
+// the presigned URLs cannot be used to read the object back. Such objects are
+// therefore read back through the access API right after their registration and
+// compared with the local checksum. On a mismatch, the file is removed from the
+// dataset again, such that it is never published.
+
+/// The outcome of registering files in batches.
+#[derive(Debug, Default)]
+pub struct AddFilesBatches {
+    /// Responses of the batches that have been registered.
+    pub succeeded: Vec<AddFilesResponse>,
+    /// File names of the batches that failed, along with the error message.
+    pub failed: Vec<(Vec<String>, String)>,
+    /// Files that could not be verified once registered, along with the error message. Files whose stored
+    /// object does not match the local file are removed from the dataset again.
+    pub rejected: Vec<(String, String)>,
+}
+
+impl AddFilesBatches {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty() && self.rejected.is_empty()
+    }
+
+    /// Returns the number of files the instance reported as added.
+    pub fn added(&self) -> i64 {
+        self.succeeded
+            .iter()
+            .filter_map(|response| {
+                response
+                    .result
+                    .as_ref()?
+                    .number_of_files_successfully_added
+            })
+            .sum()
+    }
+}
+
+/// Registers files that have been uploaded directly to the storage of an instance with a dataset.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `files` - The `DirectUploadFile` entries holding the storage identifier, name, type and checksum of each file.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<AddFilesResponse>`, which contains the HTTP response status and the added
+/// files, if the request is successful, or a `String` error message on failure.
+pub async fn add_files(
+    client: &BaseClient,
+    id: Identifier,
+    files: &[DirectUploadFile],
+) -> Result<Response<AddFilesResponse>, String> {
+    // Files are registered one batch at a time, as the dataset is locked while the server adds them
+    let _queue = client.queues().acquire(&id).await;
+
+    // Endpoint metadata
+    let (url, parameters) = match id {
+        Identifier::PersistentId(id) => (
+            "api/datasets/:persistentId/addFiles".to_string(),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/datasets/{}/addFiles", id), None),
+    };
+
+    // Send request
+    let body = serde_json::to_string(files).map_err(|e| e.to_string())?;
+    let context = RequestType::Multipart {
+        bodies: Some(HashMap::from([("jsonData".to_string(), body)])),
+        files: None,
+        callbacks: None,
+    };
+    let response = client.post(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<AddFilesResponse>(response).await
+}
+
+/// Registers directly uploaded files with a dataset in batches of the given size.
+///
+/// Registering thousands of files in a single request may exceed the timeouts of an instance. This asynchronous
+/// function therefore splits the files into batches, which are registered one after another. A failing batch
+/// does not abort the remaining ones. Large batches can additionally be compressed by creating the client with
+/// `BaseClient::with_compression`.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `files` - The `DirectUploadFile` entries to register.
+/// * `batch_size` - The maximum number of files per request; zero is treated as one.
+///
+/// # Returns
+///
+/// An `AddFilesBatches` summary of the registered and failed batches.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::direct_upload::{add_files_batched, DirectUploadFile};
+///
+/// # async fn run(files: Vec<DirectUploadFile>) {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client")
+///     .with_compression(1024 * 1024);
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let batches = add_files_batched(&client, id, &files, 500).await;
+///
+/// println!("Added {} files", batches.added());
+/// # }
+/// ```
+pub async fn add_files_batched(
+    client: &BaseClient,
+    id: Identifier,
+    files: &[DirectUploadFile],
+    batch_size: usize,
+) -> AddFilesBatches {
+    let mut batches = AddFilesBatches::default();
+    for batch in files.chunks(batch_size.max(1)) {
+        register_batch(client, id.clone(), batch, &mut batches).await;
+    }
+
+    batches
+}
+
+/// Uploads multiple files directly to the storage of a dataset and registers them in batches while the
+/// remaining files are still being uploaded.
+///
+/// Up to `concurrency` files are transferred to the storage at a time. Whenever `batch_size` files have been
+/// uploaded, they are registered with the dataset, and the files that remain once all uploads have completed
+/// are registered in a final batch. As the instance rejects files added while the dataset is locked by a
+/// previous registration, the batches are registered one after another through the queue of the dataset.
+///
+/// Files whose stored object could not be verified during the upload are checked with `verify_registered`
+/// once their batch is registered. Files that do not match are removed again and reported as rejected.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `files` - The paths of the files to upload, each with the MIME type to register it with.
+/// * `algorithm` - The `ChecksumAlgorithm` to register the files with.
+/// * `concurrency` - The maximum number of simultaneous uploads; zero is treated as one.
+/// * `batch_size` - The maximum number of files per registration; zero is treated as one.
+///
+/// # Returns
+///
+/// The `DirectUploadSeries` of the uploads, of which no file is skipped, along with the `AddFilesBatches`
+/// summary of their registration.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::direct_upload::upload_and_register_direct;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let files = (0..1000)
+///     .map(|index| (PathBuf::from(format!("run_{}.csv", index)), "text/csv".to_string()))
+///     .collect();
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let algorithm = file::get_fixity_algorithm(&client).await?;
+/// let (series, batches) = upload_and_register_direct(&client, id, files, algorithm, 8, 100).await;
+///
+/// println!("Uploaded {}, registered {} files", series.succeeded.len(), batches.added());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn upload_and_register_direct(
+    client: &BaseClient,
+    id: Identifier,
+    files: Vec<(PathBuf, String)>,
+    algorithm: ChecksumAlgorithm,
+    concurrency: usize,
+    batch_size: usize,
+) -> (DirectUploadSeries, AddFilesBatches) {
+    let (sender, receiver) = mpsc::unbounded();
+
+    // Uploads pass their files on as they complete, closing the channel once all are done
+    let upload_id = id.clone();
+    let uploads = async move {
+        let mut series = DirectUploadSeries::default();
+        let mut results = stream::iter(files)
+            .map(|(path, mime_type)| {
+                let id = upload_id.clone();
+                async move {
+                    let result = upload_file_direct(client, id, &path, &mime_type, algorithm).await;
+                    (path, result)
+                }
+            })
+            .buffered(concurrency.max(1));
+
+        while let Some((path, result)) = results.next().await {
+            match result {
+                Ok(upload) => {
+                    let _ = sender.unbounded_send(upload.clone());
+                    series.succeeded.push((path, upload));
+                }
+                Err(e) => series.failed.push((path, e)),
+            }
+        }
+
+        series
+    };
+
+    let registrations = async {
+        let mut batches = AddFilesBatches::default();
+        let mut receiver = receiver.chunks(batch_size.max(1));
+        while let Some(batch) = receiver.next().await {
+            let files: Vec<DirectUploadFile> = batch.iter().map(|upload| upload.file.clone()).collect();
+            if let Some(response) = register_batch(client, id.clone(), &files, &mut batches).await {
+                verify_batch(client, &response, &batch, &mut batches).await;
+            }
+        }
+
+        batches
+    };
+
+    futures::join!(uploads, registrations)
+}
+
+// Registers a batch of files, recording its outcome and returning the response if it succeeded
+async fn register_batch(
+    client: &BaseClient,
+    id: Identifier,
+    batch: &[DirectUploadFile],
+    batches: &mut AddFilesBatches,
+) -> Option<AddFilesResponse> {
+    let result = match add_files(client, id, batch).await {
+        Ok(response) if response.status.is_ok() => response
+            .data
+            .ok_or_else(|| "The response did not contain any data".to_string()),
+        Ok(response) => Err(response.message.map(|m| m.to_string()).unwrap_or_default()),
+        Err(e) => Err(e),
+    };
+
+    match result {
+        Ok(response) => {
+            batches.succeeded.push(response.clone());
+            Some(response)
+        }
+        Err(e) => {
+            let names = batch.iter().map(|file| file.file_name.clone()).collect();
+            batches.failed.push((names, e));
+            None
+        }
+    }
+}
+
+// Verifies the files of a registered batch whose stored objects could not be checked during the upload
+async fn verify_batch(
+    client: &BaseClient,
+    response: &AddFilesResponse,
+    uploads: &[DirectUpload],
+    batches: &mut AddFilesBatches,
+) {
+    for upload in uploads.iter().filter(|upload| !upload.verified) {
+        let result = match registered_id(response, &upload.file.storage_identifier) {
+            Some(file_id) => verify_registered(client, file_id, upload).await,
+            None => Err("The instance did not report the ID of the registered file".to_string()),
+        };
+
+        if let Err(e) = result {
+            batches.rejected.push((upload.file.file_name.clone(), e));
+        }
+    }
+}
+
+// Finds the ID of a file in the response of a registration by its storage identifier
+fn registered_id(response: &AddFilesResponse, storage_identifier: &str) -> Option<i64> {
+    response
+        .files
+        .iter()
+        .find(|file| file.get("storageIdentifier").and_then(|id| id.as_str()) == Some(storage_identifier))?
+        .get("fileDetails")?
+        .pointer("/dataFile/id")?
+        .as_i64()
+}
+
+/// The checksum of a part of a direct upload, along with the ETag acknowledged by the storage.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct PartChecksum {
+    /// The number of the part, starting at one.
+    pub part: u64,
+    pub size: u64,
+    /// The hex-encoded MD5 digest of the local part.
+    pub md5: String,
+    /// The ETag returned by the storage, without quotes.
+    pub etag: String,
+}
+
+/// A file uploaded directly to the storage, which can be registered with `add_files`.
+#[derive(Debug, Clone)]
+pub struct DirectUpload {
+    /// The registration entry, carrying the checksum of the whole file.
+    pub file: DirectUploadFile,
+    /// The checksums of the uploaded parts.
+    pub parts: Vec<PartChecksum>,
+    /// Whether the stored object has been checked against the local file during the upload. Otherwise, it
+    /// has to be checked with `verify_registered` once the file is registered.
+    pub verified: bool,
+}
+
+/// Requests presigned URLs to upload a file of the given size directly to the storage of a dataset.
+///
+/// Depending on the size and the configuration of the store, the instance returns either a single `url` or
+/// one URL per part in `urls`, along with the URLs to complete or abort the multipart upload.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `size` - The size of the file in bytes.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<UploadUrls>`, which contains the HTTP response status and the upload URLs,
+/// if the request is successful, or a `String` error message on failure.
+pub async fn get_upload_urls(
+    client: &BaseClient,
+    id: Identifier,
+    size: u64,
+) -> Result<Response<UploadUrls>, String> {
+    // Endpoint metadata
+    let (url, mut parameters) = match id {
+        Identifier::PersistentId(id) => (
+            "api/datasets/:persistentId/uploadurls".to_string(),
+            HashMap::from([("persistentId".to_string(), id)]),
+        ),
+        Identifier::Id(id) => (format!("api/datasets/{}/uploadurls", id), HashMap::new()),
+    };
+
+    // Build Parameters
+    parameters.insert("size".to_string(), size.to_string());
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), Some(parameters), &context).await;
+
+    evaluate_response::<UploadUrls>(response).await
+}
+
+/// Uploads a file directly to the storage of a dataset, verifying the integrity of every part.
+///
+/// This asynchronous function requests presigned URLs for the file and uploads it in one or more parts. The
+/// MD5 digest of each part is sent along with it and compared with the ETag acknowledged by the storage. For
+/// multipart uploads, the ETag of the assembled object is checked against the local parts, if the instance
+/// reports it when completing the upload. If any check fails, a multipart upload is aborted and an error is
+/// returned, such that the file is not registered. The file still needs to be registered with `add_files` or
+/// `add_files_batched`. Uploads whose stored object could not be checked, as indicated by `verified`, have to
+/// be checked with `verify_registered` afterwards, which `upload_and_register_direct` does on its own.
+///
+/// The checksum of the whole file is computed with the given algorithm, which has to match the one the
+/// instance is configured with, as reported by `file::get_fixity_algorithm`.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `path` - The path of the file to upload.
+/// * `mime_type` - The MIME type to register the file with, e.g. `text/csv`.
+/// * `algorithm` - The `ChecksumAlgorithm` to register the file with.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `DirectUpload`, or a `String` error message if the upload failed or a checksum did
+/// not match.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::direct_upload::{add_files, upload_file_direct};
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let algorithm = file::get_fixity_algorithm(&client).await?;
+/// let upload = upload_file_direct(&client, id.clone(), Path::new("data.csv"), "text/csv", algorithm).await?;
+/// let response = add_files(&client, id, &[upload.file]).await?;
+///
+/// println!("Registered: {:?}", response.data);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn upload_file_direct(
+    client: &BaseClient,
+    id: Identifier,
+    path: &Path,
+    mime_type: &str,
+    algorithm: ChecksumAlgorithm,
+) -> Result<DirectUpload, String> {
+    let size = std::fs::metadata(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?
+        .len();
+    let file_name = path
+        .file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .ok_or_else(|| format!("Invalid file path: {}", path.display()))?;
+
+    let response = get_upload_urls(client, id, size).await?;
+    if response.status.is_err() {
+        return Err(response.message.map(|m| m.to_string()).unwrap_or_default());
+    }
+    let urls = response.data.ok_or("The response did not contain any data")?;
+    let storage_identifier = urls
+        .storage_identifier
+        .clone()
+        .ok_or("The instance did not provide a storage identifier")?;
+
+    let (parts, part_size) = match &urls.url {
+        Some(url) => (vec![url.clone()], size),
+        None => (part_urls(&urls)?, urls.part_size.ok_or("The instance did not provide a part size")? as u64),
+    };
+
+    // Hash the parts before uploading, such that their digests can be sent along
+    let digest_path = path.to_path_buf();
+    let (digests, checksum) = tokio::task::spawn_blocking(move || {
+        part_digests(&digest_path, part_size.max(1), algorithm)
+    })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| format!("Failed to hash {}: {}", path.display(), e))?;
+
+    if digests.len() != parts.len() {
+        return Err(format!(
+            "The file consists of {} parts, but the instance provided {} upload URLs",
+            digests.len(),
+            parts.len()
+        ));
+    }
+
+    // The ETag of a single part is the digest of the whole object, unless the store is encrypted
+    let multipart = urls.url.is_none();
+    let result = upload_parts(client, path, &parts, &digests, part_size, multipart).await;
+    let result = match result {
+        Ok(checksums) if multipart => match complete_upload(client, &urls, &checksums).await {
+            Ok(assembled) => check_assembled(&checksums, assembled.as_deref()).map(|verified| (checksums, verified)),
+            Err(e) => Err(e),
+        },
+        Ok(checksums) => {
+            let verified = checksums.iter().all(|checksum| is_md5(&checksum.etag));
+            Ok((checksums, verified))
+        }
+        Err(e) => Err(e),
+    };
+
+    let (checksums, verified) = match result {
+        Ok(result) => result,
+        Err(e) => {
+            if let Some(abort) = &urls.abort {
+                let _ = client.delete(abort, None, &RequestType::Plain).await;
+            }
+            return Err(e);
+        }
+    };
+
+    Ok(DirectUpload {
+        file: DirectUploadFile {
+            storage_identifier,
+            file_name,
+            mime_type: mime_type.to_string(),
+            checksum: DirectUploadChecksum {
+                type_: algorithm.name().to_string(),
+                value: checksum,
+            },
+            description: None,
+            directory_label: None,
+            categories: vec![],
+            restrict: None,
+            tab_ingest: None,
+        },
+        parts: checksums,
+        verified,
+    })
+}
+
+/// Verifies a registered file against the checksum of its direct upload.
+///
+/// This asynchronous function reads the stored object back through the data access API and computes its
+/// checksum with the algorithm the file has been registered with. This covers objects assembled from parts,
+/// which the instance cannot be asked for before their registration. If the checksum does not match the
+/// local file, the file is removed from the draft of its dataset, such that it is never published.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `file_id` - The ID of the registered file.
+/// * `upload` - A reference to the `DirectUpload` the file has been registered from.
+///
+/// # Returns
+///
+/// A `Result` that is `Ok` if the stored object matches the local file, or a `String` error message if it
+/// does not match or could not be read.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::direct_upload::{upload_file_direct, verify_registered};
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let algorithm = file::get_fixity_algorithm(&client).await?;
+/// let upload = upload_file_direct(&client, id, Path::new("data.csv"), "text/csv", algorithm).await?;
+///
+/// // Register the file, e.g. with `add_files`, which reports its ID
+/// verify_registered(&client, 42, &upload).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn verify_registered(client: &BaseClient, file_id: i64, upload: &DirectUpload) -> Result<(), String> {
+    let expected = &upload.file.checksum;
+    let algorithm = expected.type_.parse::<ChecksumAlgorithm>()?;
+    let stored = stored_checksum(client, file_id, algorithm).await?;
+
+    if stored.eq_ignore_ascii_case(&expected.value) {
+        return Ok(());
+    }
+
+    let removal = match delete_file(client, Identifier::Id(file_id)).await {
+        Ok(response) if response.status.is_ok() => "the file has been removed from the dataset".to_string(),
+        Ok(response) => format!(
+            "removing the file failed: {}",
+            response.message.map(|m| m.to_string()).unwrap_or_default()
+        ),
+        Err(e) => format!("removing the file failed: {}", e),
+    };
+
+    Err(format!(
+        "The stored object of {} does not match the local file: expected {}, but the storage holds {}; {}",
+        upload.file.file_name, expected.value, stored, removal
+    ))
+}
+
+// Streams a registered file and computes its checksum. The original is requested, as
+// tabular files may have been ingested meanwhile, without counting it as a download.
+async fn stored_checksum(client: &BaseClient, file_id: i64, algorithm: ChecksumAlgorithm) -> Result<String, String> {
+    let url = format!("api/access/datafile/{}", file_id);
+    let parameters = HashMap::from([
+        ("format".to_string(), "original".to_string()),
+        ("gbrecs".to_string(), "true".to_string()),
+    ]);
+
+    let mut response = client
+        .get(url.as_str(), Some(parameters), &RequestType::Plain)
+        .await
+        .map_err(|e| e.to_string())?;
+    if !response.status().is_success() {
+        return Err(format!("Failed to read back file {}: {}", file_id, error_message(response).await));
+    }
+
+    let mut hasher = algorithm.hasher();
+    while let Some(chunk) = response.chunk().await.map_err(|e| e.to_string())? {
+        hasher.update(&chunk);
+    }
+
+    Ok(hex(&hasher.finalize()))
+}
+
+/// The outcome of uploading multiple files directly to the storage of a dataset.
+#[derive(Debug, Default)]
+pub struct DirectUploadSeries {
+    /// Files that have been uploaded, in the order they were given.
+    pub succeeded: Vec<(PathBuf, DirectUpload)>,
+    /// Files that failed to upload, along with the error message.
+    pub failed: Vec<(PathBuf, String)>,
+    /// Files that were not attempted, because a previous upload failed.
+    pub skipped: Vec<PathBuf>,
+}
+
+impl DirectUploadSeries {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty() && self.skipped.is_empty()
+    }
+
+    /// Returns the registration entries of the uploaded files, e.g. to pass them to `add_files_batched`.
+    pub fn files(&self) -> Vec<DirectUploadFile> {
+        self.succeeded.iter().map(|(_, upload)| upload.file.clone()).collect()
+    }
+}
+
+/// Uploads multiple files directly to the storage of a dataset, running up to `concurrency` uploads at a time.
+///
+/// Each file is uploaded with `upload_file_direct`. Bounding the number of simultaneous uploads keeps large
+/// series of files from exhausting sockets or running into the rate limits of the storage. Errors are
+/// collected per file rather than aborting the whole series. If `stop_on_error` is set, no further uploads
+/// are started after the first failure and the remaining files are reported as skipped. The uploaded files
+/// still need to be registered with `add_files` or `add_files_batched`, or are registered while uploading with
+/// `upload_and_register_direct`.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `files` - The paths of the files to upload, each with the MIME type to register it with.
+/// * `algorithm` - The `ChecksumAlgorithm` to register the files with.
+/// * `concurrency` - The maximum number of simultaneous uploads; zero is treated as one.
+/// * `stop_on_error` - Whether to stop starting new uploads once an upload has failed.
+///
+/// # Returns
+///
+/// A `DirectUploadSeries` listing the succeeded, failed and skipped files.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::direct_upload::{add_files_batched, upload_files_direct};
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let files = (0..1000)
+///     .map(|index| (PathBuf::from(format!("run_{}.csv", index)), "text/csv".to_string()))
+///     .collect();
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let algorithm = file::get_fixity_algorithm(&client).await?;
+/// let series = upload_files_direct(&client, id.clone(), files, algorithm, 8, false).await;
+///
+/// let batches = add_files_batched(&client, id, &series.files(), 100).await;
+/// println!("Registered {} files", batches.added());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn upload_files_direct(
+    client: &BaseClient,
+    id: Identifier,
+    files: Vec<(PathBuf, String)>,
+    algorithm: ChecksumAlgorithm,
+    concurrency: usize,
+    stop_on_error: bool,
+) -> DirectUploadSeries {
+    let results = upload_bounded(files, concurrency, stop_on_error, |path, mime_type| {
+        let id = id.clone();
+        async move { upload_file_direct(client, id, &path, &mime_type, algorithm).await }
+    })
+    .await;
+
+    let mut series = DirectUploadSeries::default();
+    for (path, result) in results {
+        match result {
+            Some(Ok(upload)) => series.succeeded.push((path, upload)),
+            Some(Err(e)) => series.failed.push((path, e)),
+            None => series.skipped.push(path),
+        }
+    }
+
+    series
+}
+
+// Orders the part URLs by their part number
+fn part_urls(urls: &UploadUrls) -> Result<Vec<String>, String> {
+    let mut parts = urls
+        .urls
+        .iter()
+        .map(|(part, url)| Ok((part.parse::<u64>().map_err(|_| format!("Invalid part number: {}", part))?, url.clone())))
+        .collect::<Result<Vec<_>, String>>()?;
+    parts.sort();
+
+    match parts.is_empty() {
+        true => Err("The instance did not provide any upload URLs".to_string()),
+        false => Ok(parts.into_iter().map(|(_, url)| url).collect()),
+    }
+}
+
+// Computes the MD5 digest of every part and the checksum of the whole file in a single pass
+fn part_digests(
+    path: &PathBuf,
+    part_size: u64,
+    algorithm: ChecksumAlgorithm,
+) -> std::io::Result<(Vec<[u8; 16]>, String)> {
+    let mut file = std::fs::File::open(path)?;
+    let mut whole = algorithm.hasher();
+    let mut digests = Vec::new();
+    let mut buffer = vec![0; 64 * 1024];
+
+    loop {
+        let mut part = Md5::new();
+        let mut remaining = part_size;
+        while remaining > 0 {
+            let read = file.read(&mut buffer[..remaining.min(64 * 1024) as usize])?;
+            if read == 0 {
+                break;
+            }
+            part.update(&buffer[..read]);
+            whole.update(&buffer[..read]);
+            remaining -= read as u64;
+        }
+
+        // Empty files are uploaded as a single empty part
+        if remaining == part_size && !digests.is_empty() {
+            break;
+        }
+        digests.push(part.finalize().into());
+        if remaining > 0 {
+            break;
+        }
+    }
+
+    Ok((digests, hex(&whole.finalize())))
+}
+
+async fn upload_parts(
+    client: &BaseClient,
+    path: &Path,
+    urls: &[String],
+    digests: &[[u8; 16]],
+    part_size: u64,
+    multipart: bool,
+) -> Result<Vec<PartChecksum>, String> {
+    let size = std::fs::metadata(path).map_err(|e| e.to_string())?.len();
+    let mut checksums = Vec::new();
+
+    for (index, (url, digest)) in urls.iter().zip(digests).enumerate() {
+        let offset = index as u64 * part_size;
+        let length = part_size.min(size.saturating_sub(offset));
+
+        let mut file = tokio::fs::File::open(path).await.map_err(|e| e.to_string())?;
+        file.seek(SeekFrom::Start(offset)).await.map_err(|e| e.to_string())?;
+        let body = reqwest::Body::wrap_stream(ReaderStream::new(file.take(length)));
+
+        let request = client
+            .http_client()
+            .put(url)
+            .header("Content-Length", length)
+            .header("Content-MD5", STANDARD.encode(digest))
+            .body(body);
+
+        // Single uploads are tagged as temporary until they are registered
+        let request = match multipart {
+            true => request,
+            false => request.header("x-amz-tagging", "dv-state=temp"),
+        };
+
+        let part = index as u64 + 1;
+        let response = request.send().await.map_err(|e| e.to_string())?;
+        if !response.status().is_success() {
+            return Err(format!("Failed to upload part {}: {}", part, error_message(response).await));
+        }
+
+        let etag = response
+            .headers()
+            .get(reqwest::header::ETAG)
+            .and_then(|etag| etag.to_str().ok())
+            .map(|etag| etag.trim_matches('"').to_string())
+            .ok_or_else(|| format!("The storage did not acknowledge part {}", part))?;
+
+        let md5 = hex(digest);
+        if is_md5(&etag) && etag != md5 {
+            return Err(format!(
+                "Part {} was corrupted during the upload: expected {}, but the storage received {}",
+                part, md5, etag
+            ));
+        }
+
+        checksums.push(PartChecksum { part, size: length, md5, etag });
+    }
+
+    Ok(checksums)
+}
+
+// Completes a multipart upload, returning the ETag of the assembled object if the instance reports it
+async fn complete_upload(
+    client: &BaseClient,
+    urls: &UploadUrls,
+    checksums: &[PartChecksum],
+) -> Result<Option<String>, String> {
+    let complete = urls.complete.as_ref().ok_or("The instance did not provide a URL to complete the upload")?;
+    let etags: HashMap<String, &str> = checksums
+        .iter()
+        .map(|checksum| (checksum.part.to_string(), checksum.etag.as_str()))
+        .collect();
+
+    // Send request
+    let context = RequestType::JSON {
+        body: serde_json::to_string(&etags).map_err(|e| e.to_string())?,
+    };
+    let response = client.put(complete, None, &context).await.map_err(|e| e.to_string())?;
+
+    if !response.status().is_success() {
+        return Err(format!("Failed to complete the upload: {}", error_message(response).await));
+    }
+
+    // The ETag is either passed on as header or within the result of the storage
+    let header = response
+        .headers()
+        .get(reqwest::header::ETAG)
+        .and_then(|etag| etag.to_str().ok())
+        .map(str::to_string);
+    let assembled = match header {
+        Some(etag) => Some(etag),
+        None => {
+            let body = response.text().await.unwrap_or_default();
+            body.split_once("<ETag>")
+                .and_then(|(_, rest)| rest.split_once("</ETag>"))
+                .map(|(etag, _)| etag.replace("&quot;", "\""))
+        }
+    };
+
+    Ok(assembled.map(|etag| etag.trim().trim_matches('"').to_string()))
+}
+
+// The ETag of an object assembled from parts is the MD5 digest of the concatenated part
+// digests, followed by the number of parts. Returns whether the object could be checked.
+fn check_assembled(checksums: &[PartChecksum], assembled: Option<&str>) -> Result<bool, String> {
+    let Some(assembled) = assembled else {
+        return Ok(false);
+    };
+
+    // ETags of encrypted stores are no digests, which leaves the check to the registration
+    let is_digest = assembled
+        .split_once('-')
+        .is_some_and(|(digest, parts)| is_md5(digest) && parts.parse::<usize>().is_ok());
+    if !is_digest {
+        return Ok(false);
+    }
+
+    let mut hasher = Md5::new();
+    for checksum in checksums {
+        hasher.update(unhex(&checksum.md5).ok_or_else(|| format!("Invalid digest of part {}", checksum.part))?);
+    }
+    let expected = format!("{}-{}", hex(&hasher.finalize()), checksums.len());
+
+    match expected.eq_ignore_ascii_case(assembled) {
+        true => Ok(true),
+        false => Err(format!(
+            "The assembled object does not match the local file: expected {}, but the storage assembled {}",
+            expected, assembled
+        )),
+    }
+}
+
+fn is_md5(value: &str) -> bool {
+    value.len() == 32 && value.chars().all(|c| c.is_ascii_hexdigit())
+}
+
+fn unhex(value: &str) -> Option<Vec<u8>> {
+    (0..value.len())
+        .step_by(2)
+        .map(|index| u8::from_str_radix(value.get(index..index + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use md5::{Digest, Md5};
+
+    use crate::prelude::*;
+
+    use std::path::{Path, PathBuf};
+
+    use super::{
+        add_files_batched, check_assembled, hex, part_digests, upload_and_register_direct, upload_file_direct,
+        upload_files_direct, ChecksumAlgorithm,
+        DirectUploadChecksum, DirectUploadFile, PartChecksum,
+    };
+
+    const FIXTURE_MD5: &str = "a28bca1b906f539ba70ca3a0b1f2e773";
+    const FIXTURE_SHA256: &str = "c21f3ac6b5f6e45b1c0b292bcd5cc806298ecb033bc7030a6071e3c894d73054";
+
+    fn direct_upload_file(index: usize) -> DirectUploadFile {
+        DirectUploadFile {
+            storage_identifier: format!("s3://bucket:{:x}", index),
+            file_name: format!("file_{}.csv", index),
+            mime_type: "text/csv".to_string(),
+            checksum: DirectUploadChecksum {
+                type_: "SHA-1".to_string(),
+                value: format!("{:040x}", index),
+            },
+            description: None,
+            directory_label: None,
+            categories: vec![],
+            restrict: None,
+            tab_ingest: None,
+        }
+    }
+
+    /// Tests that files are registered in batches and that batches are sent compressed.
+    ///
+    /// This test registers five files in batches of two against a mock server, which expects
+    /// gzip encoded requests, and asserts that three requests were sent and all files were added.
+    #[tokio::test]
+    async fn test_add_files_batched() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/datasets/:persistentId/addFiles")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123")
+                .header("Content-Encoding", "gzip");
+            then.status(200).json_body(serde_json::json!({
+                "status": "OK",
+                "data": {
+                    "Files": [],
+                    "Result": {"Total number of files": 2, "Number of files successfully added": 2}
+                }
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None)
+            .unwrap()
+            .with_compression(0);
+        let files: Vec<DirectUploadFile> = (0..5).map(direct_upload_file).collect();
+        let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+
+        let batches = add_files_batched(&client, id, &files, 2).await;
+
+        mock.assert_hits(3);
+        assert!(batches.is_success());
+        assert_eq!(batches.succeeded.len(), 3);
+        assert_eq!(batches.added(), 6);
+    }
+
+    // Serves the upload URLs of a file in two parts, which the storage
+    // acknowledges with the given ETags, reporting the assembled ETag if given
+    fn mock_multipart<'a>(
+        server: &'a MockServer,
+        etags: [String; 2],
+        assembled: Option<&str>,
+    ) -> (httpmock::Mock<'a>, httpmock::Mock<'a>) {
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/datasets/1/uploadurls")
+                .query_param("size", "16");
+            then.status(200).json_body(serde_json::json!({
+                "status": "OK",
+                "data": {
+                    "urls": {"2": server.url("/storage/part2"), "1": server.url("/storage/part1")},
+                    "partSize": 10,
+                    "abort": "/api/datasets/mpupload?uploadid=abc",
+                    "complete": "/api/datasets/mpupload?uploadid=abc",
+                    "storageIdentifier": "s3://bucket:18f0"
+                }
+            }));
+        });
+
+        for (part, etag) in etags.into_iter().enumerate() {
+            server.mock(|when, then| {
+                when.method(PUT)
+                    .path(format!("/storage/part{}", part + 1))
+                    .header_exists("Content-MD5");
+                then.status(200).header("ETag", format!("\"{}\"", etag));
+            });
+        }
+
+        let complete = server.mock(|when, then| {
+            when.method(PUT).path("/api/datasets/mpupload").query_param("uploadid", "abc");
+            let then = then.status(200).json_body(serde_json::json!({"status": "OK", "data": {}}));
+            if let Some(assembled) = assembled {
+                then.header("ETag", format!("\"{}\"", assembled));
+            }
+        });
+        let abort = server.mock(|when, then| {
+            when.method(DELETE).path("/api/datasets/mpupload").query_param("uploadid", "abc");
+            then.status(204);
+        });
+
+        (complete, abort)
+    }
+
+    fn fixture_part_digests() -> [String; 2] {
+        let (parts, _) = part_digests(&PathBuf::from("tests/fixtures/file.txt"), 10, ChecksumAlgorithm::Md5).unwrap();
+        [hex(&parts[0]), hex(&parts[1])]
+    }
+
+    /// Tests that a multipart upload is completed if all parts are acknowledged with their digests.
+    #[tokio::test]
+    async fn test_upload_file_direct() {
+        let server = MockServer::start();
+        let (complete, abort) = mock_multipart(&server, fixture_part_digests(), None);
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let path = Path::new("tests/fixtures/file.txt");
+        let upload = upload_file_direct(&client, Identifier::Id(1), path, "text/plain", ChecksumAlgorithm::Sha256)
+            .await
+            .expect("Failed to upload file");
+
+        complete.assert();
+        abort.assert_hits(0);
+        assert_eq!(upload.file.storage_identifier, "s3://bucket:18f0");
+        assert_eq!(upload.file.checksum.type_, "SHA-256");
+        assert_eq!(upload.file.checksum.value, FIXTURE_SHA256);
+        assert_eq!(upload.parts.len(), 2);
+        assert_eq!(upload.parts[1].size, 6);
+        assert!(!upload.verified);
+    }
+
+    /// Tests that a series of direct uploads stops starting new uploads after a failure.
+    ///
+    /// This test uploads three files one at a time, of which the second does not exist, and asserts
+    /// that the third is skipped while the first is uploaded in a single part.
+    #[tokio::test]
+    async fn test_upload_files_direct() {
+        let server = MockServer::start();
+        let urls = server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/1/uploadurls");
+            then.status(200).json_body(serde_json::json!({
+                "status": "OK",
+                "data": {"url": server.url("/storage/file"), "storageIdentifier": "s3://bucket:18f1"}
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(PUT).path("/storage/file").header("x-amz-tagging", "dv-state=temp");
+            then.status(200).header("ETag", format!("\"{}\"", FIXTURE_MD5));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let files = ["tests/fixtures/file.txt", "tests/fixtures/missing.txt", "tests/fixtures/file.txt"]
+            .iter()
+            .map(|path| (PathBuf::from(path), "text/plain".to_string()))
+            .collect();
+
+        let series = upload_files_direct(&client, Identifier::Id(1), files, ChecksumAlgorithm::Md5, 1, true).await;
+
+        urls.assert_hits(1);
+        assert!(!series.is_success());
+        assert_eq!(series.files().len(), 1);
+        assert_eq!(series.files()[0].checksum.value, FIXTURE_MD5);
+        assert_eq!(series.failed[0].0, PathBuf::from("tests/fixtures/missing.txt"));
+        assert_eq!(series.skipped.len(), 1);
+    }
+
+    /// Tests that files uploaded in parallel are registered in full batches and a final partial one.
+    #[tokio::test]
+    async fn test_upload_and_register_direct() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/1/uploadurls");
+            then.status(200).json_body(serde_json::json!({
+                "status": "OK",
+                "data": {"url": server.url("/storage/file"), "storageIdentifier": "s3://bucket:18f1"}
+            }));
+        });
+        server.mock(|when, then| {
+            when.method(PUT).path("/storage/file");
+            then.status(200).header("ETag", format!("\"{}\"", FIXTURE_MD5));
+        });
+        let add = server.mock(|when, then| {
+            when.method(POST).path("/api/datasets/1/addFiles");
+            then.status(200).json_body(serde_json::json!({
+                "status": "OK",
+                "data": {"Files": [], "Result": {"Total number of files": 2, "Number of files successfully added": 2}}
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let files = (0..3)
+            .map(|_| (PathBuf::from("tests/fixtures/file.txt"), "text/plain".to_string()))
+            .collect();
+
+        let (series, batches) =
+            upload_and_register_direct(&client, Identifier::Id(1), files, ChecksumAlgorithm::Md5, 2, 2).await;
+
+        add.assert_hits(2);
+        assert!(series.is_success());
+        assert_eq!(series.succeeded.len(), 3);
+        assert!(series.succeeded.iter().all(|(_, upload)| upload.verified));
+        assert!(batches.is_success());
+    }
+
+    /// Tests that assembled objects are read back once registered and removed again if they do not match.
+    ///
+    /// This test uploads the fixture in two parts without the instance reporting the assembled ETag. The
+    /// registered file is first served with the content of the fixture and then with a truncated copy,
+    /// which is removed from the dataset and reported as rejected.
+    #[tokio::test]
+    async fn test_upload_and_register_direct_verifies_assembled() {
+        let server = MockServer::start();
+        mock_multipart(&server, fixture_part_digests(), None);
+        server.mock(|when, then| {
+            when.method(POST).path("/api/datasets/1/addFiles");
+            then.status(200).json_body(serde_json::json!({
+                "status": "OK",
+                "data": {
+                    "Files": [{"storageIdentifier": "s3://bucket:18f0", "fileDetails": {"dataFile": {"id": 7}}}],
+                    "Result": {"Total number of files": 1, "Number of files successfully added": 1}
+                }
+            }));
+        });
+        let delete = server.mock(|when, then| {
+            when.method(DELETE).path("/api/files/7");
+            then.status(200).json_body(serde_json::json!({"status": "OK", "data": {"message": "Deleted"}}));
+        });
+
+        let content = std::fs::read("tests/fixtures/file.txt").unwrap();
+        let mut stored = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/access/datafile/7")
+                .query_param("format", "original")
+                .query_param("gbrecs", "true");
+            then.status(200).body(&content);
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let files = || vec![(PathBuf::from("tests/fixtures/file.txt"), "text/plain".to_string())];
+
+        let (_, batches) =
+            upload_and_register_direct(&client, Identifier::Id(1), files(), ChecksumAlgorithm::Md5, 1, 1).await;
+
+        stored.assert();
+        delete.assert_hits(0);
+        assert!(batches.is_success());
+
+        stored.delete();
+        server.mock(|when, then| {
+            when.method(GET).path("/api/access/datafile/7");
+            then.status(200).body(&content[..10]);
+        });
+
+        let (_, batches) =
+            upload_and_register_direct(&client, Identifier::Id(1), files(), ChecksumAlgorithm::Md5, 1, 1).await;
+
+        delete.assert();
+        assert!(!batches.is_success());
+        assert_eq!(batches.rejected.len(), 1);
+        assert!(batches.rejected[0].1.contains("does not match the local file"));
+    }
+
+    /// Tests that a multipart upload is aborted if the storage received a corrupted part.
+    #[tokio::test]
+    async fn test_upload_file_direct_corrupted() {
+        let server = MockServer::start();
+        let [first, _] = fixture_part_digests();
+        let (complete, abort) = mock_multipart(&server, [first, "0".repeat(32)], None);
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let path = Path::new("tests/fixtures/file.txt");
+        let result = upload_file_direct(&client, Identifier::Id(1), path, "text/plain", ChecksumAlgorithm::Md5).await;
+
+        assert!(result.unwrap_err().contains("Part 2 was corrupted"));
+        complete.assert_hits(0);
+        abort.assert();
+    }
+
+    /// Tests that a multipart upload is refused if the storage reports a different assembled object.
+    #[tokio::test]
+    async fn test_upload_file_direct_assembled_mismatch() {
+        let server = MockServer::start();
+        let assembled = format!("{}-2", "0".repeat(32));
+        let (complete, abort) = mock_multipart(&server, fixture_part_digests(), Some(&assembled));
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let path = Path::new("tests/fixtures/file.txt");
+        let result = upload_file_direct(&client, Identifier::Id(1), path, "text/plain", ChecksumAlgorithm::Md5).await;
+
+        assert!(result.unwrap_err().contains("The assembled object does not match"));
+        complete.assert();
+        abort.assert();
+    }
+
+    /// Tests that the reported ETag of the assembled object is checked against the local parts.
+    #[test]
+    fn test_check_assembled() {
+        let [first, second] = fixture_part_digests();
+        let checksum = |part: u64, md5: &str| PartChecksum {
+            part,
+            size: 0,
+            md5: md5.to_string(),
+            etag: md5.to_string(),
+        };
+        let parts = [checksum(1, &first), checksum(2, &second)];
+        let swapped = [checksum(1, &second), checksum(2, &first)];
+
+        // The storage assembled the parts in the order they were uploaded
+        let mut hasher = Md5::new();
+        hasher.update(super::unhex(&first).unwrap());
+        hasher.update(super::unhex(&second).unwrap());
+        let assembled = format!("{}-2", hex(&hasher.finalize()));
+
+        assert_eq!(check_assembled(&parts, Some(&assembled)), Ok(true));
+        assert!(check_assembled(&swapped, Some(&assembled)).is_err());
+        assert!(check_assembled(&parts, Some(&format!("{}-3", &assembled[..32]))).is_err());
+
+        // Unreported ETags and those of encrypted stores are left to the registration
+        assert_eq!(check_assembled(&parts, None), Ok(false));
+        assert_eq!(check_assembled(&parts, Some("kms-assembled")), Ok(false));
+    }
+
+    /// Tests that the parts are hashed along with the whole file.
+    #[test]
+    fn test_part_digests() {
+        let path = PathBuf::from("tests/fixtures/file.txt");
+
+        let (parts, md5) = part_digests(&path, 10, ChecksumAlgorithm::Md5).unwrap();
+        assert_eq!(parts.len(), 2);
+        assert_eq!(md5, FIXTURE_MD5);
+
+        let (parts, md5) = part_digests(&path, 16, ChecksumAlgorithm::Md5).unwrap();
+        assert_eq!(hex(&parts[0]), FIXTURE_MD5);
+        assert_eq!(parts.len(), 1);
+        assert_eq!(md5, FIXTURE_MD5);
+
+        // Parts are always hashed with MD5, as required by the storage
+        let (parts, sha256) = part_digests(&path, 16, ChecksumAlgorithm::Sha256).unwrap();
+        assert_eq!(hex(&parts[0]), FIXTURE_MD5);
+        assert_eq!(sha256, FIXTURE_SHA256);
+    }
+
+    /// Tests that the checksum is serialized with the JSON-LD keys expected by the instance.
+    #[test]
+    fn test_serialize_direct_upload_file() {
+        let value = serde_json::to_value(direct_upload_file(1)).unwrap();
+
+        assert_eq!(value["storageIdentifier"], "s3://bucket:1");
+        assert_eq!(value["checksum"]["@type"], "SHA-1");
+        assert!(value.get("description").is_none());
+    }
+}
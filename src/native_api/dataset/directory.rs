@@ -0,0 +1,267 @@
+use std::path::{Component, Path, PathBuf};
+
+use glob::{MatchOptions, Pattern};
+
+use crate::{client::BaseClient, identifier::Identifier};
+
+use super::upload::{upload_files_to_dataset, UploadBody, UploadSeries};
+
+/// Options to select the files of a directory tree and to control their upload.
+#[derive(Debug, Clone, Default)]
+pub struct DirectoryUploadOptions {
+    /// Glob patterns of the paths relative to the root to upload, e.g. `data/**/*.csv`. All files are
+    /// uploaded if empty.
+    pub include: Vec<String>,
+    /// Glob patterns of the paths relative to the root to skip, e.g. `**/.DS_Store`. Exclusions take
+    /// precedence over inclusions.
+    pub exclude: Vec<String>,
+    /// Whether to upload hidden files and the contents of hidden directories, such as `.git`.
+    pub hidden: bool,
+    /// Metadata applied to every file, whose directory label is replaced by the relative path of the file.
+    pub body: Option<UploadBody>,
+    /// The maximum number of simultaneous uploads; zero is treated as one.
+    pub concurrency: usize,
+    /// Whether to stop starting new uploads once an upload has failed.
+    pub stop_on_error: bool,
+}
+
+/// Uploads the files of a directory tree to a dataset, preserving the folder structure.
+///
+/// This asynchronous function walks the directory tree below `root` and uploads every file matching the
+/// include and exclude patterns of the options. The directory of each file relative to `root` becomes its
+/// directory label, such that the dataset mirrors the local structure. The uploads are managed by
+/// `upload_files_to_dataset`, which runs them with the configured concurrency and collects the errors per file.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset to which the files will be uploaded.
+/// * `root` - The directory to upload the contents of.
+/// * `options` - The `DirectoryUploadOptions` selecting the files and controlling the upload.
+///
+/// # Returns
+///
+/// A `Result` wrapping an `UploadSeries` listing the succeeded, failed and skipped files, or a `String` error
+/// message if the directory cannot be read or a pattern is invalid.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::directory::DirectoryUploadOptions;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let options = DirectoryUploadOptions {
+///     exclude: vec!["**/*.tmp".to_string()],
+///     concurrency: 4,
+///     ..Default::default()
+/// };
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let series = dataset::upload_directory(&client, id, Path::new("results"), &options).await?;
+///
+/// println!("Uploaded {} files", series.succeeded.len());
+/// # Ok(())
+/// # }
+/// ```
+pub async fn upload_directory(
+    client: &BaseClient,
+    id: Identifier,
+    root: &Path,
+    options: &DirectoryUploadOptions,
+) -> Result<UploadSeries, String> {
+    let files = collect_directory_files(root, options)?;
+
+    Ok(upload_files_to_dataset(client, id, files, options.concurrency, options.stop_on_error).await)
+}
+
+/// Lists the files of a directory tree that `upload_directory` would upload, along with their upload body.
+///
+/// The files are sorted by their path, and the directory label of each body is set to the directory of the
+/// file relative to `root`, using forward slashes on every platform.
+pub fn collect_directory_files(
+    root: &Path,
+    options: &DirectoryUploadOptions,
+) -> Result<Vec<(PathBuf, Option<UploadBody>)>, String> {
+    if !root.is_dir() {
+        return Err(format!("Not a directory: {}", root.display()));
+    }
+
+    let include = compile_patterns(&options.include)?;
+    let exclude = compile_patterns(&options.exclude)?;
+    let template = match &options.body {
+        Some(body) => body.clone(),
+        None => UploadBody::builder().try_into().map_err(|e| format!("Invalid upload body: {}", e))?,
+    };
+
+    let mut paths = Vec::new();
+    walk(root, options.hidden, &mut paths)?;
+    paths.sort();
+
+    let files = paths
+        .into_iter()
+        .filter_map(|path| {
+            let relative = relative_components(root, &path)?;
+            let name = relative.join("/");
+            let included = include.is_empty() || include.iter().any(|pattern| matches(pattern, &name));
+            let excluded = exclude.iter().any(|pattern| matches(pattern, &name));
+
+            if !included || excluded {
+                return None;
+            }
+
+            let directory = &relative[..relative.len() - 1];
+            let mut body = template.clone();
+            body.directory_label = Some(directory.join("/")).filter(|label| !label.is_empty());
+
+            Some((path, Some(body)))
+        })
+        .collect();
+
+    Ok(files)
+}
+
+// Collects the files below the directory, descending into subdirectories. Symbolic
+// links to directories are not followed to avoid cycles.
+fn walk(dir: &Path, hidden: bool, paths: &mut Vec<PathBuf>) -> Result<(), String> {
+    let entries = std::fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+
+    for entry in entries {
+        let entry = entry.map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?;
+        let path = entry.path();
+        let file_type = entry.file_type().map_err(|e| e.to_string())?;
+
+        if !hidden && entry.file_name().to_string_lossy().starts_with('.') {
+            continue;
+        }
+
+        if file_type.is_dir() {
+            walk(&path, hidden, paths)?;
+        } else if path.is_file() {
+            paths.push(path);
+        }
+    }
+
+    Ok(())
+}
+
+fn relative_components(root: &Path, path: &Path) -> Option<Vec<String>> {
+    let relative = path.strip_prefix(root).ok()?;
+    relative
+        .components()
+        .map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy().to_string()),
+            _ => None,
+        })
+        .collect()
+}
+
+fn compile_patterns(patterns: &[String]) -> Result<Vec<Pattern>, String> {
+    patterns
+        .iter()
+        .map(|pattern| Pattern::new(pattern).map_err(|e| format!("Invalid pattern '{}': {}", pattern, e)))
+        .collect()
+}
+
+// Wildcards do not cross directories, such that `*.csv` only matches files at the root
+// and `**/*.csv` matches them at any depth
+fn matches(pattern: &Pattern, name: &str) -> bool {
+    let options = MatchOptions {
+        case_sensitive: true,
+        require_literal_separator: true,
+        require_literal_leading_dot: false,
+    };
+
+    pattern.matches_with(name, options)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::{collect_directory_files, DirectoryUploadOptions};
+
+    // Creates a directory tree with files at several depths
+    fn create_tree() -> PathBuf {
+        let root = std::env::temp_dir().join(format!("dvcli_upload_dir_{}", rand::random::<u32>()));
+        for path in ["readme.md", "data/a.csv", "data/raw/b.csv", "data/raw/b.tmp", ".git/config"] {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, "content").unwrap();
+        }
+        root
+    }
+
+    fn labels(files: &[(PathBuf, Option<super::UploadBody>)]) -> Vec<(String, Option<String>)> {
+        files
+            .iter()
+            .map(|(path, body)| {
+                let name = path.file_name().unwrap().to_string_lossy().to_string();
+                (name, body.as_ref().and_then(|body| body.directory_label.clone()))
+            })
+            .collect()
+    }
+
+    /// Tests that relative directories become directory labels and hidden files are skipped.
+    #[test]
+    fn test_collect_directory_files() {
+        let root = create_tree();
+
+        let files = collect_directory_files(&root, &DirectoryUploadOptions::default()).unwrap();
+
+        assert_eq!(
+            labels(&files),
+            vec![
+                ("a.csv".to_string(), Some("data".to_string())),
+                ("b.csv".to_string(), Some("data/raw".to_string())),
+                ("b.tmp".to_string(), Some("data/raw".to_string())),
+                ("readme.md".to_string(), None),
+            ]
+        );
+    }
+
+    /// Tests that exclusions take precedence over inclusions.
+    #[test]
+    fn test_collect_directory_files_with_patterns() {
+        let root = create_tree();
+        let options = DirectoryUploadOptions {
+            include: vec!["data/**/*".to_string()],
+            exclude: vec!["**/*.tmp".to_string()],
+            ..Default::default()
+        };
+
+        let files = collect_directory_files(&root, &options).unwrap();
+        assert_eq!(files.len(), 2);
+
+        let options = DirectoryUploadOptions {
+            include: vec!["*.csv".to_string()],
+            ..Default::default()
+        };
+        assert!(collect_directory_files(&root, &options).unwrap().is_empty());
+
+        let options = DirectoryUploadOptions {
+            hidden: true,
+            ..Default::default()
+        };
+        assert_eq!(collect_directory_files(&root, &options).unwrap().len(), 5);
+    }
+
+    /// Tests that invalid patterns and missing directories are reported.
+    #[test]
+    fn test_collect_directory_files_errors() {
+        let options = DirectoryUploadOptions {
+            include: vec!["[".to_string()],
+            ..Default::default()
+        };
+
+        assert!(collect_directory_files(&create_tree(), &options).is_err());
+        assert!(collect_directory_files(&PathBuf::from("missing"), &Default::default()).is_err());
+    }
+}
@@ -1,3 +1,6 @@
+// The enums generated by typify implement `ToString` directly
+#![allow(clippy::to_string_trait_impl)]
+
 use std::collections::HashMap;
 
 use serde::{Deserialize, Serialize};
@@ -80,11 +83,67 @@ pub async fn edit_dataset_metadata(
 
     // Send request
     let context = RequestType::JSON { body: body.clone() };
-    let response = client.put(&url, parameters, &context).await;
+    let response = client.put(url, parameters, &context).await;
 
     evaluate_response::<Dataset>(response).await
 }
 
+impl Field {
+    /// Creates a single-valued primitive field, such as a title or a description.
+    pub fn primitive(type_name: &str, value: &str) -> Self {
+        Field {
+            multiple: Some(false),
+            type_class: Some(FieldTypeClass::Primitive),
+            type_name: Some(type_name.to_string()),
+            value: Some(FieldValue::Variant0(value.to_string())),
+        }
+    }
+}
+
+/// Sets a single metadata field of a dataset identified by a persistent identifier (PID).
+///
+/// This is a convenience wrapper around `edit_dataset_metadata`, which replaces the value of the
+/// given field without requiring to assemble a complete `EditMetadataBody`. All other fields of the
+/// dataset remain untouched.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `pid` - A string slice that holds the persistent identifier of the dataset to edit.
+/// * `field` - The `Field` to set, e.g. created by `Field::primitive`.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Dataset>`, which contains the HTTP response status and the deserialized
+/// response data of the dataset after the edit, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// # use dataverse::prelude::*;
+/// # use dataverse::native_api::dataset::edit::Field;
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let field = Field::primitive("title", "A better title");
+/// let response = dataset::edit::set_dataset_field(&client, "doi:10.5072/FK2/ABC123", field).await?;
+///
+/// println!("Dataset metadata updated: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn set_dataset_field(
+    client: &BaseClient,
+    pid: &str,
+    field: Field,
+) -> Result<Response<Dataset>, String> {
+    let body = EditMetadataBody { fields: vec![field] };
+    edit_dataset_metadata(client, pid, &true, body).await
+}
+
 #[cfg(test)]
 mod tests {
     use crate::prelude::{BaseClient, dataset};
@@ -190,4 +249,36 @@ mod tests {
         // Assert the request was successful
         assert!(response.status.is_err());
     }
-}
\ No newline at end of file
+
+    /// Tests setting a single primitive field of a dataset.
+    ///
+    /// This test verifies that the title of a dataset can be replaced using `set_dataset_field`.
+    /// It sets up a client using API token and base URL obtained from environment variables, creates
+    /// a dataset under the root collection and replaces its title.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the edit request fails.
+    #[tokio::test]
+    async fn test_set_dataset_field() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Create a dataset
+        let (_, pid) = create_test_dataset(&client, "Root").await;
+
+        // Replace the title
+        let field = dataset::edit::Field::primitive("title", "Updated title");
+        let response = dataset::edit::set_dataset_field(&client, &pid, field)
+            .await.expect("Failed to set dataset field");
+
+        // Assert the request was successful
+        let message = serde_json::to_string(&response).unwrap();
+        assert!(response.status.is_ok(), "Response: {}", message);
+    }
+}
@@ -0,0 +1,136 @@
+use std::collections::HashMap;
+
+use crate::{
+    access::datafile::error_message,
+    client::BaseClient,
+    models::schema_org::SchemaOrgDataset,
+    request::RequestType,
+};
+
+/// Exports the metadata of a published dataset using an exporter of the instance.
+///
+/// This asynchronous function sends a GET request to the export endpoint, which renders the latest
+/// published version of a dataset in formats such as `dataverse_json`, `ddi`, `oai_dc` or `schema.org`.
+/// The available exporters depend on the instance. As the formats are not limited to JSON, the document is
+/// returned as is.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `pid` - A string slice that holds the persistent identifier of the dataset.
+/// * `exporter` - A string slice that holds the name of the exporter, e.g. `dataverse_json`.
+///
+/// # Returns
+///
+/// A `Result` wrapping the exported document, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, None)
+///     .expect("Failed to create client");
+///
+/// let document = dataset::export_dataset(&client, "doi:10.5072/FK2/ABC123", "ddi").await?;
+///
+/// println!("{}", String::from_utf8_lossy(&document));
+/// # Ok(())
+/// # }
+/// ```
+pub async fn export_dataset(client: &BaseClient, pid: &str, exporter: &str) -> Result<Vec<u8>, String> {
+    // Endpoint metadata
+    let url = "api/datasets/export";
+
+    // Build Parameters
+    let parameters = HashMap::from([
+        ("exporter".to_string(), exporter.to_string()),
+        ("persistentId".to_string(), pid.to_string()),
+    ]);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client
+        .get(url, Some(parameters), &context)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !response.status().is_success() {
+        return Err(error_message(response).await);
+    }
+
+    response.bytes().await.map(|bytes| bytes.to_vec()).map_err(|e| e.to_string())
+}
+
+/// Exports the metadata of a published dataset as schema.org JSON-LD and parses it.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `pid` - A string slice that holds the persistent identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `SchemaOrgDataset`, or a `String` error message on failure.
+pub async fn export_schema_org(client: &BaseClient, pid: &str) -> Result<SchemaOrgDataset, String> {
+    let document = export_dataset(client, pid, "schema.org").await?;
+    SchemaOrgDataset::from_slice(&document)
+}
+
+/// Exports the metadata of a published dataset as Croissant JSON-LD and parses it.
+///
+/// The `croissant` exporter is only available on instances that have it installed. In addition to the
+/// schema.org export, it describes the columns of ingested tabular files as record sets.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `pid` - A string slice that holds the persistent identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `SchemaOrgDataset`, or a `String` error message on failure.
+pub async fn export_croissant(client: &BaseClient, pid: &str) -> Result<SchemaOrgDataset, String> {
+    let document = export_dataset(client, pid, "croissant").await?;
+    SchemaOrgDataset::from_slice(&document)
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use crate::prelude::*;
+
+    /// Tests that the document is returned as is and that errors carry the message of the server.
+    #[tokio::test]
+    async fn test_export_dataset() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/datasets/export")
+                .query_param("exporter", "oai_dc")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123");
+            then.status(200).body("<oai_dc:dc/>");
+        });
+        server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/export").query_param("exporter", "unknown");
+            then.status(404).json_body(serde_json::json!({
+                "status": "ERROR",
+                "message": "Export Failed"
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let document = dataset::export_dataset(&client, "doi:10.5072/FK2/ABC123", "oai_dc")
+            .await
+            .expect("Failed to export dataset");
+
+        mock.assert();
+        assert_eq!(document, b"<oai_dc:dc/>");
+
+        let error = dataset::export_dataset(&client, "doi:10.5072/FK2/ABC123", "unknown").await;
+        assert_eq!(error, Err("Export Failed".to_string()));
+    }
+}
@@ -0,0 +1,212 @@
+use std::{collections::HashMap, str::FromStr};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::edit::File,
+    request::RequestType,
+    response::Response,
+};
+
+/// The access status of a file, as used to filter the file listing.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub enum AccessStatus {
+    Public,
+    Restricted,
+    EmbargoedThenRestricted,
+    EmbargoedThenPublic,
+}
+
+impl AccessStatus {
+    fn as_str(&self) -> &'static str {
+        match self {
+            AccessStatus::Public => "Public",
+            AccessStatus::Restricted => "Restricted",
+            AccessStatus::EmbargoedThenRestricted => "EmbargoedThenRestricted",
+            AccessStatus::EmbargoedThenPublic => "EmbargoedThenPublic",
+        }
+    }
+}
+
+impl FromStr for AccessStatus {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_lowercase().replace(['-', '_'], "").as_str() {
+            "public" => Ok(AccessStatus::Public),
+            "restricted" => Ok(AccessStatus::Restricted),
+            "embargoedthenrestricted" => Ok(AccessStatus::EmbargoedThenRestricted),
+            "embargoedthenpublic" => Ok(AccessStatus::EmbargoedThenPublic),
+            _ => Err(format!("Invalid access status: {}", s)),
+        }
+    }
+}
+
+/// Criteria to narrow down the files of a dataset version on the server.
+///
+/// All criteria are optional and combined, such that only files matching every given criterion are listed.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct FileSearch {
+    /// Text matched against the name, directory and description of the files.
+    pub search_text: Option<String>,
+    /// The name of a category the files are tagged with, e.g. `Data`.
+    pub category: Option<String>,
+    /// The content type of the files, e.g. `text/csv`.
+    pub content_type: Option<String>,
+    pub access_status: Option<AccessStatus>,
+    /// The maximum number of files to list.
+    pub limit: Option<u64>,
+    /// The number of matching files to skip, for paging through large datasets.
+    pub offset: Option<u64>,
+    /// Lists the files of deaccessioned versions, which are hidden otherwise.
+    pub include_deaccessioned: bool,
+}
+
+impl FileSearch {
+    // Only given criteria are sent, the server lists all files otherwise
+    fn to_parameters(&self) -> HashMap<String, String> {
+        let mut parameters = HashMap::new();
+        let criteria = [
+            ("searchText", self.search_text.clone()),
+            ("categoryName", self.category.clone()),
+            ("contentType", self.content_type.clone()),
+            ("accessStatus", self.access_status.as_ref().map(|s| s.as_str().to_string())),
+            ("limit", self.limit.map(|limit| limit.to_string())),
+            ("offset", self.offset.map(|offset| offset.to_string())),
+        ];
+        for (key, value) in criteria {
+            if let Some(value) = value {
+                parameters.insert(key.to_string(), value);
+            }
+        }
+        if self.include_deaccessioned {
+            parameters.insert("includeDeaccessioned".to_string(), "true".to_string());
+        }
+
+        parameters
+    }
+}
+
+/// Lists the files of a dataset version that match the given `FileSearch`.
+///
+/// This asynchronous function sends a GET request to the files endpoint of a dataset version. The criteria are
+/// evaluated by the server, such that only the matching files are transferred, which keeps the listing of
+/// datasets with many files fast. Besides version numbers such as `1.0`, the identifiers `:draft`, `:latest`
+/// and `:latest-published` are accepted.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `version` - A string slice that holds the version whose files to list.
+/// * `search` - A reference to the `FileSearch` holding the criteria.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<File>>`, which contains the HTTP response status and the metadata of the
+/// matching files, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::files::FileSearch;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let search = FileSearch {
+///     search_text: Some("csv".to_string()),
+///     limit: Some(100),
+///     ..Default::default()
+/// };
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let response = dataset::list_dataset_files(&client, id, ":latest", &search).await?;
+///
+/// for file in response.data.unwrap_or_default() {
+///     println!("{}", file.label.unwrap_or_default());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn list_dataset_files(
+    client: &BaseClient,
+    id: Identifier,
+    version: &str,
+    search: &FileSearch,
+) -> Result<Response<Vec<File>>, String> {
+    // Endpoint metadata
+    let url = match id {
+        Identifier::PersistentId(_) => format!("api/datasets/:persistentId/versions/{}/files", version),
+        Identifier::Id(id) => format!("api/datasets/{}/versions/{}/files", id, version),
+    };
+
+    // Build Parameters
+    let mut parameters = search.to_parameters();
+    if let Identifier::PersistentId(id) = id {
+        parameters.insert("persistentId".to_string(), id);
+    }
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), Some(parameters), &context).await;
+
+    evaluate_response::<Vec<File>>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    use super::{AccessStatus, FileSearch};
+
+    /// Tests that the criteria are sent as query parameters and the matching files are parsed.
+    #[tokio::test]
+    async fn test_list_dataset_files() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/datasets/:persistentId/versions/:latest/files")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123")
+                .query_param("searchText", "csv")
+                .query_param("accessStatus", "EmbargoedThenPublic")
+                .query_param("limit", "10");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": [{
+                    "label": "results.csv",
+                    "directoryLabel": "tables",
+                    "restricted": false,
+                    "dataFile": {"id": 7, "contentType": "text/csv", "filesize": 120}
+                }]
+            }));
+        });
+
+        let search = FileSearch {
+            search_text: Some("csv".to_string()),
+            access_status: Some("embargoed-then-public".parse().unwrap()),
+            limit: Some(10),
+            ..Default::default()
+        };
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+        let response = dataset::list_dataset_files(&client, id, ":latest", &search).await.unwrap();
+
+        let files = response.data.unwrap();
+        assert_eq!(files.len(), 1);
+        assert_eq!(files[0].label.as_deref(), Some("results.csv"));
+        assert_eq!(files[0].directory_label.as_deref(), Some("tables"));
+        assert_eq!("Restricted".parse::<AccessStatus>(), Ok(AccessStatus::Restricted));
+        assert!("hidden".parse::<AccessStatus>().is_err());
+
+        mock.assert();
+    }
+}
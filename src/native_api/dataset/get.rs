@@ -3,7 +3,7 @@ use std::collections::HashMap;
 use crate::{
     client::{BaseClient, evaluate_response},
     identifier::Identifier,
-    native_api::dataset::edit::GetDatasetResponse,
+    native_api::dataset::edit::{Dataset, GetDatasetResponse},
     request::RequestType,
     response::Response,
 };
@@ -18,7 +18,7 @@ use crate::{
 ///
 /// * `client` - A reference to the `BaseClient` instance used to send the request.
 /// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
-///          representing the unique identifier of the dataset whose metadata is to be retrieved.
+///   representing the unique identifier of the dataset whose metadata is to be retrieved.
 ///
 /// # Returns
 ///
@@ -66,6 +66,79 @@ use crate::{
 pub async fn get_dataset_meta(
     client: &BaseClient,
     id: Identifier,
+) -> Result<Response<GetDatasetResponse>, String> {
+    get_dataset_meta_with_options(client, id, &GetDatasetOptions::default()).await
+}
+
+/// Options to narrow down or extend the dataset returned by the API.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct GetDatasetOptions {
+    /// Omits the file metadata, which drastically reduces the response size of datasets with many files.
+    pub exclude_files: bool,
+    /// Includes deaccessioned versions, which are hidden otherwise.
+    pub include_deaccessioned: bool,
+    /// Adds the collections the dataset belongs to as `isPartOf`.
+    pub return_owners: bool,
+}
+
+impl GetDatasetOptions {
+    // Only options deviating from the server defaults are sent
+    fn to_parameters(&self) -> HashMap<String, String> {
+        [
+            ("excludeFiles", self.exclude_files),
+            ("includeDeaccessioned", self.include_deaccessioned),
+            ("returnOwners", self.return_owners),
+        ]
+            .into_iter()
+            .filter(|(_, enabled)| *enabled)
+            .map(|(key, _)| (key.to_string(), "true".to_string()))
+            .collect()
+    }
+}
+
+/// Retrieves the metadata of a dataset with the given `GetDatasetOptions`.
+///
+/// This asynchronous function behaves like `get_dataset_meta`, but passes the options as query parameters.
+/// Options that are not supported by the endpoint of the instance are ignored by the server.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset whose metadata is to be retrieved.
+/// * `options` - A reference to the `GetDatasetOptions` to apply.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<GetDatasetResponse>`, which contains the HTTP response status and the deserialized
+/// response data of the dataset metadata, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::get::GetDatasetOptions;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let options = GetDatasetOptions {
+///     exclude_files: true,
+///     ..Default::default()
+/// };
+///
+/// let response = dataset::get::get_dataset_meta_with_options(&client, Identifier::Id(123), &options).await?;
+/// println!("Dataset metadata: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_dataset_meta_with_options(
+    client: &BaseClient,
+    id: Identifier,
+    options: &GetDatasetOptions,
 ) -> Result<Response<GetDatasetResponse>, String> {
     // Endpoint metadata
     let url = match id {
@@ -74,26 +147,108 @@ pub async fn get_dataset_meta(
     };
 
     // Build Parameters
-    let parameters = match id {
-        Identifier::PersistentId(id) => {
-            Some(HashMap::from([("persistentId".to_string(), id.clone())]))
-        }
-        Identifier::Id(_) => None,
-    };
+    let mut parameters = options.to_parameters();
+    if let Identifier::PersistentId(id) = id {
+        parameters.insert("persistentId".to_string(), id);
+    }
 
     // Send request
     let context = RequestType::Plain;
-    let response = client.get(url.as_str(), parameters, &context).await;
+    let response = client.get(url.as_str(), Some(parameters), &context).await;
 
     evaluate_response::<GetDatasetResponse>(response).await
 }
 
+/// Retrieves a specific version of a dataset.
+///
+/// This asynchronous function sends a GET request to the versions endpoint of a dataset. Besides version
+/// numbers such as `1.0`, the identifiers `:draft`, `:latest` and `:latest-published` are accepted.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `version` - A string slice that holds the version to retrieve.
+/// * `options` - A reference to the `GetDatasetOptions` to apply.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Dataset>`, which contains the HTTP response status and the deserialized
+/// version of the dataset, if the request is successful, or a `String` error message on failure.
+pub async fn get_dataset_version(
+    client: &BaseClient,
+    id: Identifier,
+    version: &str,
+    options: &GetDatasetOptions,
+) -> Result<Response<Dataset>, String> {
+    // Endpoint metadata
+    let url = match id {
+        Identifier::PersistentId(_) => format!("api/datasets/:persistentId/versions/{}", version),
+        Identifier::Id(id) => format!("api/datasets/{}/versions/{}", id, version),
+    };
+
+    // Build Parameters
+    let mut parameters = options.to_parameters();
+    if let Identifier::PersistentId(id) = id {
+        parameters.insert("persistentId".to_string(), id);
+    }
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), Some(parameters), &context).await;
+
+    evaluate_response::<Dataset>(response).await
+}
+
+/// Retrieves all versions of a dataset, starting with the most recent one.
+///
+/// This asynchronous function sends a GET request to the versions endpoint of a dataset. Use `exclude_files`
+/// of the options to keep the response small for datasets with many files and versions.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `options` - A reference to the `GetDatasetOptions` to apply.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<Dataset>>`, which contains the HTTP response status and the deserialized
+/// versions of the dataset, if the request is successful, or a `String` error message on failure.
+pub async fn list_dataset_versions(
+    client: &BaseClient,
+    id: Identifier,
+    options: &GetDatasetOptions,
+) -> Result<Response<Vec<Dataset>>, String> {
+    // Endpoint metadata
+    let url = match id {
+        Identifier::PersistentId(_) => "api/datasets/:persistentId/versions".to_string(),
+        Identifier::Id(id) => format!("api/datasets/{}/versions", id),
+    };
+
+    // Build Parameters
+    let mut parameters = options.to_parameters();
+    if let Identifier::PersistentId(id) = id {
+        parameters.insert("persistentId".to_string(), id);
+    }
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), Some(parameters), &context).await;
+
+    evaluate_response::<Vec<Dataset>>(response).await
+}
+
 #[cfg(test)]
 mod tests {
     use crate::identifier::Identifier;
     use crate::prelude::{BaseClient, dataset};
     use crate::test_utils::{create_test_dataset, extract_test_env};
 
+    use super::GetDatasetOptions;
+
     /// Tests retrieval of dataset metadata by dataset ID.
     ///
     /// This test verifies that the metadata for an existing dataset can be successfully retrieved using its dataset ID.
@@ -213,4 +368,56 @@ mod tests {
 
         assert!(response.status.is_err())
     }
-}
\ No newline at end of file
+
+    /// Tests retrieval of the draft version of a dataset without its files.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables,
+    /// creates a dataset and retrieves its draft version with files excluded and owners included.
+    /// The test asserts that no files have been returned and that the owning collection is present.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the retrieval fails.
+    #[tokio::test]
+    async fn test_get_dataset_version_with_options() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Create a dataset
+        let (id, _) = create_test_dataset(&client, "Root").await;
+
+        // Get the draft version
+        let options = GetDatasetOptions {
+            exclude_files: true,
+            return_owners: true,
+            ..Default::default()
+        };
+        let response = dataset::get::get_dataset_version(&client, Identifier::Id(id), ":draft", &options)
+            .await.expect("Failed to get dataset version");
+
+        assert!(response.status.is_ok());
+        assert!(response.data.unwrap().files.is_empty());
+    }
+
+    /// Tests that only enabled options are sent as query parameters.
+    #[test]
+    fn test_options_to_parameters() {
+        assert!(GetDatasetOptions::default().to_parameters().is_empty());
+
+        let parameters = GetDatasetOptions {
+            exclude_files: true,
+            return_owners: true,
+            ..Default::default()
+        }
+            .to_parameters();
+
+        assert_eq!(parameters.len(), 2);
+        assert_eq!(parameters["excludeFiles"], "true");
+        assert_eq!(parameters["returnOwners"], "true");
+    }
+}
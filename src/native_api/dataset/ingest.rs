@@ -0,0 +1,245 @@
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{client::BaseClient, identifier::Identifier};
+
+use super::files::{list_dataset_files, FileSearch};
+use super::locks::get_locks;
+
+// Tabular files are ingested asynchronously after the upload, during which the
+// dataset is locked and edits or the publication are rejected with a 409 error.
+// Files that failed to ingest keep their original content type and are stored as is.
+
+/// The content types of tabular files the instance attempts to ingest.
+pub const INGESTABLE_TYPES: [&str; 7] = [
+    "text/csv",
+    "text/tab-separated-values",
+    "application/x-spss-sav",
+    "application/x-spss-por",
+    "application/x-stata",
+    "application/x-rlang-transport",
+    "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+];
+
+/// Options of waiting for the ingest of tabular files.
+#[derive(Debug, Clone, PartialEq)]
+pub struct IngestWait {
+    /// The time between two checks of the locks of the dataset.
+    pub interval: Duration,
+    /// The time after which waiting is given up.
+    pub timeout: Duration,
+}
+
+impl Default for IngestWait {
+    fn default() -> Self {
+        IngestWait {
+            interval: Duration::from_secs(2),
+            timeout: Duration::from_secs(600),
+        }
+    }
+}
+
+/// A file of the dataset after the ingest has completed.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct IngestedFile {
+    pub id: Option<i64>,
+    pub label: Option<String>,
+    /// The content type of the file, which is `text/tab-separated-values` for ingested files.
+    pub content_type: Option<String>,
+    /// The name of the uploaded file, if it has been converted by the ingest.
+    pub original_file_name: Option<String>,
+    /// Whether the file has been ingested as tabular data.
+    pub ingested: bool,
+}
+
+impl IngestedFile {
+    /// Returns true if the file is of a tabular type, but has not been ingested.
+    pub fn failed(&self) -> bool {
+        !self.ingested
+            && self
+                .content_type
+                .as_deref()
+                .is_some_and(|content_type| INGESTABLE_TYPES.contains(&content_type))
+    }
+}
+
+/// The files of a dataset once no ingest is in progress anymore.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct IngestReport {
+    pub files: Vec<IngestedFile>,
+    /// The time spent waiting for the ingest to complete.
+    pub waited: Duration,
+}
+
+impl IngestReport {
+    /// Returns the files that have been ingested as tabular data.
+    pub fn ingested(&self) -> Vec<&IngestedFile> {
+        self.files.iter().filter(|file| file.ingested).collect()
+    }
+
+    /// Returns the tabular files that failed to ingest.
+    pub fn failed(&self) -> Vec<&IngestedFile> {
+        self.files.iter().filter(|file| file.failed()).collect()
+    }
+}
+
+/// Waits until the ingest of the tabular files of a dataset has completed.
+///
+/// This asynchronous function polls the locks of the dataset until it is no longer locked for ingest and then
+/// lists the files of the latest version, reporting which of them have been ingested. Awaiting it after an upload
+/// prevents follow-up requests, such as publishing the dataset, from failing because the dataset is locked.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `options` - The `IngestWait` holding the polling interval and the timeout.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `IngestReport` of the files, or a `String` error message if a request fails or the
+/// ingest has not completed within the timeout.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::ingest::IngestWait;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let report = dataset::wait_for_ingest(&client, id, &IngestWait::default()).await?;
+///
+/// for file in report.failed() {
+///     println!("{} was not ingested", file.label.clone().unwrap_or_default());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn wait_for_ingest(
+    client: &BaseClient,
+    id: Identifier,
+    options: &IngestWait,
+) -> Result<IngestReport, String> {
+    let started = Instant::now();
+
+    loop {
+        let response = get_locks(client, id.clone()).await?;
+        if response.status.is_err() {
+            return Err(response.message.map(|m| m.to_string()).unwrap_or_default());
+        }
+
+        let ingesting = response
+            .data
+            .unwrap_or_default()
+            .iter()
+            .any(|lock| lock.lock_type == "Ingest");
+        if !ingesting {
+            break;
+        }
+
+        if started.elapsed() >= options.timeout {
+            return Err(format!(
+                "The ingest has not completed within {} seconds",
+                options.timeout.as_secs()
+            ));
+        }
+        tokio::time::sleep(options.interval).await;
+    }
+    let waited = started.elapsed();
+
+    let response = list_dataset_files(client, id, ":latest", &FileSearch::default()).await?;
+    if response.status.is_err() {
+        return Err(response.message.map(|m| m.to_string()).unwrap_or_default());
+    }
+
+    let files = response
+        .data
+        .unwrap_or_default()
+        .into_iter()
+        .map(|file| {
+            let data_file = file.data_file.as_ref();
+            IngestedFile {
+                id: data_file.and_then(|data_file| data_file.id),
+                label: file.label.clone(),
+                content_type: data_file.and_then(|data_file| data_file.content_type.clone()),
+                original_file_name: data_file.and_then(|data_file| data_file.original_file_name.clone()),
+                ingested: data_file.and_then(|data_file| data_file.tabular_data).unwrap_or(false),
+            }
+        })
+        .collect();
+
+    Ok(IngestReport { files, waited })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    use super::IngestWait;
+
+    /// Tests that ingested and failed files are reported once the dataset is unlocked.
+    #[tokio::test]
+    async fn test_wait_for_ingest() {
+        let server = MockServer::start();
+        let locks = server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/42/locks");
+            then.status(200).json_body(json!({"status": "OK", "data": []}));
+        });
+        let files = server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/42/versions/:latest/files");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": [
+                    {"label": "survey.tab", "dataFile": {
+                        "id": 1, "contentType": "text/tab-separated-values",
+                        "originalFileName": "survey.csv", "tabularData": true
+                    }},
+                    {"label": "broken.csv", "dataFile": {"id": 2, "contentType": "text/csv", "tabularData": false}},
+                    {"label": "readme.txt", "dataFile": {"id": 3, "contentType": "text/plain"}}
+                ]
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let report = dataset::wait_for_ingest(&client, Identifier::Id(42), &IngestWait::default())
+            .await
+            .unwrap();
+
+        assert_eq!(report.ingested()[0].original_file_name.as_deref(), Some("survey.csv"));
+        assert_eq!(report.failed().len(), 1);
+        assert_eq!(report.failed()[0].id, Some(2));
+
+        locks.assert();
+        files.assert();
+    }
+
+    /// Tests that waiting is given up if the dataset remains locked for ingest.
+    #[tokio::test]
+    async fn test_wait_for_ingest_timeout() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/42/locks");
+            then.status(200).json_body(json!({"status": "OK", "data": [{"lockType": "Ingest"}]}));
+        });
+
+        let options = IngestWait {
+            interval: Duration::from_millis(10),
+            timeout: Duration::from_millis(50),
+        };
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let result = dataset::wait_for_ingest(&client, Identifier::Id(42), &options).await;
+
+        assert!(result.is_err());
+    }
+}
@@ -24,7 +24,7 @@ import_types!(
 ///
 /// * `client` - A reference to the `BaseClient` instance used to send the request.
 /// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
-///          representing the unique identifier of the dataset to be linked.
+///   representing the unique identifier of the dataset to be linked.
 /// * `collection_id` - A string slice that holds the ID of the collection to which the dataset will be linked.
 ///
 /// # Returns
@@ -61,7 +61,7 @@ pub async fn link_dataset(
     // Determine dataset id
     let dataset_id = match id {
         Identifier::PersistentId(_) => get_dataset_id(client, id).await?,
-        Identifier::Id(id) => id.clone(),
+        Identifier::Id(id) => id,
     };
 
     // Endpoint metadata
@@ -0,0 +1,76 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    request::RequestType,
+    response::Response,
+};
+
+/// A lock preventing changes to a dataset, e.g. while files are ingested or the dataset is in review.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Lock {
+    /// The reason of the lock, e.g. `Ingest`, `InReview`, `Workflow`, `EditInProgress` or `finalizePublication`.
+    pub lock_type: String,
+    /// The time the lock was set.
+    #[serde(default)]
+    pub date: Option<String>,
+    /// The user who caused the lock.
+    #[serde(default)]
+    pub user: Option<String>,
+    #[serde(default)]
+    pub message: Option<String>,
+    /// The persistent identifier of the locked dataset, only present in instance-wide listings.
+    #[serde(default)]
+    pub dataset: Option<String>,
+}
+
+/// Retrieves the locks of a dataset.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<Lock>>`, which contains the HTTP response status and the locks of the
+/// dataset, which are empty if it is not locked, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let response = dataset::get_locks(&client, Identifier::Id(42)).await?;
+///
+/// for lock in response.data.unwrap_or_default() {
+///     println!("{} since {}", lock.lock_type, lock.date.unwrap_or_default());
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_locks(client: &BaseClient, id: Identifier) -> Result<Response<Vec<Lock>>, String> {
+    // Endpoint metadata
+    let (url, parameters) = match id {
+        Identifier::PersistentId(id) => (
+            "api/datasets/:persistentId/locks".to_string(),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/datasets/{}/locks", id), None),
+    };
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<Vec<Lock>>(response).await
+}
@@ -0,0 +1,306 @@
+use serde_json::{json, Map, Value};
+
+use super::create::DatasetCreateBody;
+
+/// The values of the controlled vocabulary of the `subject` field of the citation block.
+pub const SUBJECTS: [&str; 14] = [
+    "Agricultural Sciences",
+    "Arts and Humanities",
+    "Astronomy and Astrophysics",
+    "Business and Management",
+    "Chemistry",
+    "Computer and Information Science",
+    "Earth and Environmental Sciences",
+    "Engineering",
+    "Law",
+    "Mathematical Sciences",
+    "Medicine, Health and Life Sciences",
+    "Physics",
+    "Social Sciences",
+    "Other",
+];
+
+/// Assembles the citation metadata of a dataset without writing the nested field structure by hand.
+///
+/// The builder produces the `typeName`, `multiple`, `typeClass` and `value` entries of the citation block
+/// expected by the native API. Title, author, contact, description and subject are required by Dataverse,
+/// and `build` fails if any of them is missing.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::metadata::DatasetMetadataBuilder;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let body = DatasetMetadataBuilder::new()
+///     .title("Soil moisture measurements 2024")
+///     .add_author("Doe, Jane", Some("University of Example"), Some("0000-0002-1825-0097"))
+///     .add_contact("Doe, Jane", "jane.doe@example.edu")
+///     .description("Hourly soil moisture at ten sites.")
+///     .subject("Earth and Environmental Sciences")
+///     .add_keyword("soil moisture")
+///     .license("CC0 1.0", "http://creativecommons.org/publicdomain/zero/1.0")
+///     .build()?;
+///
+/// let response = dataset::create_dataset(&client, "root", body).await?;
+/// # Ok(())
+/// # }
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct DatasetMetadataBuilder {
+    title: Option<String>,
+    subtitle: Option<String>,
+    authors: Vec<Map<String, Value>>,
+    contacts: Vec<Map<String, Value>>,
+    descriptions: Vec<String>,
+    subjects: Vec<String>,
+    keywords: Vec<String>,
+    other_ids: Vec<Map<String, Value>>,
+    license: Option<(String, String)>,
+}
+
+impl DatasetMetadataBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn title(mut self, title: &str) -> Self {
+        self.title = Some(title.to_string());
+        self
+    }
+
+    pub fn subtitle(mut self, subtitle: &str) -> Self {
+        self.subtitle = Some(subtitle.to_string());
+        self
+    }
+
+    /// Adds an author, e.g. `Doe, Jane`, along with the affiliation and ORCID iD, if known.
+    pub fn add_author(mut self, name: &str, affiliation: Option<&str>, orcid: Option<&str>) -> Self {
+        let mut author = Map::new();
+        author.insert("authorName".into(), primitive("authorName", name));
+        if let Some(affiliation) = affiliation {
+            author.insert("authorAffiliation".into(), primitive("authorAffiliation", affiliation));
+        }
+        if let Some(orcid) = orcid {
+            author.insert(
+                "authorIdentifierScheme".into(),
+                vocabulary("authorIdentifierScheme", "ORCID"),
+            );
+            author.insert("authorIdentifier".into(), primitive("authorIdentifier", orcid));
+        }
+
+        self.authors.push(author);
+        self
+    }
+
+    /// Adds a contact, to whom requests about the dataset are sent.
+    pub fn add_contact(mut self, name: &str, email: &str) -> Self {
+        let mut contact = Map::new();
+        contact.insert("datasetContactName".into(), primitive("datasetContactName", name));
+        contact.insert("datasetContactEmail".into(), primitive("datasetContactEmail", email));
+
+        self.contacts.push(contact);
+        self
+    }
+
+    /// Adds a description. Calling this multiple times adds multiple descriptions.
+    pub fn description(mut self, description: &str) -> Self {
+        self.descriptions.push(description.to_string());
+        self
+    }
+
+    /// Adds a subject, which has to be one of `SUBJECTS`. Calling this multiple times adds multiple subjects.
+    pub fn subject(mut self, subject: &str) -> Self {
+        self.subjects.push(subject.to_string());
+        self
+    }
+
+    pub fn add_keyword(mut self, keyword: &str) -> Self {
+        self.keywords.push(keyword.to_string());
+        self
+    }
+
+    /// Adds an identifier the dataset is known by elsewhere, e.g. a DOI minted by another repository.
+    pub fn add_other_id(mut self, agency: &str, value: &str) -> Self {
+        let mut other_id = Map::new();
+        other_id.insert("otherIdAgency".into(), primitive("otherIdAgency", agency));
+        other_id.insert("otherIdValue".into(), primitive("otherIdValue", value));
+
+        self.other_ids.push(other_id);
+        self
+    }
+
+    /// Sets the license by its name and URI, which have to match a license configured for the instance.
+    pub fn license(mut self, name: &str, uri: &str) -> Self {
+        self.license = Some((name.to_string(), uri.to_string()));
+        self
+    }
+
+    /// Builds the body to pass to `create_dataset`.
+    ///
+    /// # Returns
+    ///
+    /// A `Result` wrapping the `DatasetCreateBody`, or a `String` error message naming the missing required
+    /// fields or unknown subjects.
+    pub fn build(self) -> Result<DatasetCreateBody, String> {
+        let missing: Vec<&str> = [
+            ("title", self.title.is_none()),
+            ("author", self.authors.is_empty()),
+            ("contact", self.contacts.is_empty()),
+            ("description", self.descriptions.is_empty()),
+            ("subject", self.subjects.is_empty()),
+        ]
+        .into_iter()
+        .filter_map(|(name, missing)| missing.then_some(name))
+        .collect();
+
+        if !missing.is_empty() {
+            return Err(format!("Missing required metadata: {}", missing.join(", ")));
+        }
+
+        if let Some(subject) = self.subjects.iter().find(|s| !SUBJECTS.contains(&s.as_str())) {
+            return Err(format!("Unknown subject '{}', expected one of: {}", subject, SUBJECTS.join("; ")));
+        }
+
+        let mut fields = vec![primitive("title", self.title.as_deref().unwrap_or_default())];
+        if let Some(subtitle) = &self.subtitle {
+            fields.push(primitive("subtitle", subtitle));
+        }
+        fields.push(compound("author", self.authors));
+        fields.push(compound("datasetContact", self.contacts));
+
+        let descriptions = self
+            .descriptions
+            .iter()
+            .map(|text| single("dsDescriptionValue", primitive("dsDescriptionValue", text)))
+            .collect();
+        fields.push(compound("dsDescription", descriptions));
+
+        fields.push(json!({
+            "typeName": "subject",
+            "multiple": true,
+            "typeClass": "controlledVocabulary",
+            "value": self.subjects,
+        }));
+
+        if !self.other_ids.is_empty() {
+            fields.push(compound("otherId", self.other_ids));
+        }
+
+        if !self.keywords.is_empty() {
+            let keywords = self
+                .keywords
+                .iter()
+                .map(|term| single("keywordValue", primitive("keywordValue", term)))
+                .collect();
+            fields.push(compound("keyword", keywords));
+        }
+
+        let mut dataset_version = json!({
+            "metadataBlocks": {
+                "citation": {
+                    "displayName": "Citation Metadata",
+                    "fields": fields,
+                }
+            }
+        });
+        if let Some((name, uri)) = self.license {
+            dataset_version["license"] = json!({ "name": name, "uri": uri });
+        }
+
+        serde_json::from_value(json!({ "datasetVersion": dataset_version }))
+            .map_err(|e| format!("Invalid metadata: {}", e))
+    }
+}
+
+fn primitive(type_name: &str, value: &str) -> Value {
+    json!({
+        "typeName": type_name,
+        "multiple": false,
+        "typeClass": "primitive",
+        "value": value,
+    })
+}
+
+fn vocabulary(type_name: &str, value: &str) -> Value {
+    json!({
+        "typeName": type_name,
+        "multiple": false,
+        "typeClass": "controlledVocabulary",
+        "value": value,
+    })
+}
+
+fn compound(type_name: &str, values: Vec<Map<String, Value>>) -> Value {
+    json!({
+        "typeName": type_name,
+        "multiple": true,
+        "typeClass": "compound",
+        "value": values,
+    })
+}
+
+fn single(name: &str, field: Value) -> Map<String, Value> {
+    Map::from_iter([(name.to_string(), field)])
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::DatasetMetadataBuilder;
+
+    /// Tests that the builder produces the citation block structure of the native API.
+    #[test]
+    fn test_build_citation_metadata() {
+        let body = DatasetMetadataBuilder::new()
+            .title("Soil moisture")
+            .add_author("Doe, Jane", Some("University of Example"), Some("0000-0002-1825-0097"))
+            .add_author("Roe, Richard", None, None)
+            .add_contact("Doe, Jane", "jane.doe@example.edu")
+            .description("Hourly measurements.")
+            .subject("Earth and Environmental Sciences")
+            .add_keyword("soil")
+            .license("CC0 1.0", "http://creativecommons.org/publicdomain/zero/1.0")
+            .build()
+            .expect("Failed to build metadata");
+
+        let value = serde_json::to_value(&body).unwrap();
+        let fields = value
+            .pointer("/datasetVersion/metadataBlocks/citation/fields")
+            .and_then(|fields| fields.as_array())
+            .unwrap();
+
+        let names: Vec<&str> = fields.iter().map(|f| f["typeName"].as_str().unwrap()).collect();
+        assert_eq!(names, ["title", "author", "datasetContact", "dsDescription", "subject", "keyword"]);
+
+        assert_eq!(fields[0]["value"], json!("Soil moisture"));
+        assert_eq!(fields[1]["value"][0]["authorIdentifierScheme"]["value"], json!("ORCID"));
+        assert_eq!(fields[1]["value"][0]["authorIdentifier"]["value"], json!("0000-0002-1825-0097"));
+        assert_eq!(fields[1]["value"][1].as_object().unwrap().len(), 1);
+        assert_eq!(fields[3]["value"][0]["dsDescriptionValue"]["typeClass"], json!("primitive"));
+        assert_eq!(fields[4]["value"], json!(["Earth and Environmental Sciences"]));
+        assert_eq!(value.pointer("/datasetVersion/license/name"), Some(&json!("CC0 1.0")));
+    }
+
+    /// Tests that missing required fields and unknown subjects are reported.
+    #[test]
+    fn test_build_incomplete_metadata() {
+        let error = DatasetMetadataBuilder::new().title("Soil moisture").build().unwrap_err();
+        assert_eq!(error, "Missing required metadata: author, contact, description, subject");
+
+        let error = DatasetMetadataBuilder::new()
+            .title("Soil moisture")
+            .add_author("Doe, Jane", None, None)
+            .add_contact("Doe, Jane", "jane.doe@example.edu")
+            .description("Hourly measurements.")
+            .subject("Soil Science")
+            .build()
+            .unwrap_err();
+        assert!(error.starts_with("Unknown subject 'Soil Science'"));
+    }
+}
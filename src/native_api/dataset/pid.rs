@@ -0,0 +1,164 @@
+use std::collections::HashMap;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+/// Reserves the persistent identifier of a draft dataset at the PID provider, e.g. DataCite.
+///
+/// Reserved DOIs resolve only once the dataset is published, but they can already be cited, e.g. in a
+/// manuscript that is submitted before the data is made public.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let response = dataset::reserve_pid(&client, id).await?;
+///
+/// println!("{:?}", response.data.and_then(|data| data.message));
+/// # Ok(())
+/// # }
+/// ```
+pub async fn reserve_pid(client: &BaseClient, id: Identifier) -> Result<Response<MessageResponse>, String> {
+    let (url, parameters) = pid_endpoint(id, "reserve");
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.post(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Deletes the reservation of the persistent identifier of a dataset that has never been published.
+///
+/// This requires a superuser token. The dataset keeps its identifier, which can be reserved again later.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+pub async fn delete_pid(client: &BaseClient, id: Identifier) -> Result<Response<MessageResponse>, String> {
+    let (url, parameters) = pid_endpoint(id, "delete");
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Updates the metadata registered for the persistent identifier of a published dataset at the PID provider.
+///
+/// This requires a superuser token and is only needed if the registration is out of date, e.g. after
+/// the URL of the instance changed.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the instance, if the request is successful, or a `String` error message on failure.
+pub async fn modify_registration(
+    client: &BaseClient,
+    id: Identifier,
+) -> Result<Response<MessageResponse>, String> {
+    let (url, parameters) = dataset_endpoint(id, "modifyRegistration");
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.post(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+fn pid_endpoint(id: Identifier, action: &str) -> (String, Option<HashMap<String, String>>) {
+    match id {
+        Identifier::PersistentId(id) => (
+            format!("api/pids/:persistentId/{}", action),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/pids/{}/{}", id, action), None),
+    }
+}
+
+fn dataset_endpoint(id: Identifier, action: &str) -> (String, Option<HashMap<String, String>>) {
+    match id {
+        Identifier::PersistentId(id) => (
+            format!("api/datasets/:persistentId/{}", action),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/datasets/{}/{}", id, action), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    /// Tests that the PID of a draft is reserved and its reservation deleted again.
+    #[tokio::test]
+    async fn test_reserve_and_delete_pid() {
+        let server = MockServer::start();
+        let reserve = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/pids/:persistentId/reserve")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"message": "PID reserved for doi:10.5072/FK2/ABC123"}
+            }));
+        });
+        let delete = server.mock(|when, then| {
+            when.method(DELETE).path("/api/pids/42/delete");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"message": "PID deleted for doi:10.5072/FK2/ABC123"}
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+
+        let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+        let response = dataset::reserve_pid(&client, id).await.unwrap();
+        assert!(response.status.is_ok());
+
+        let response = dataset::delete_pid(&client, Identifier::Id(42)).await.unwrap();
+        assert!(response.status.is_ok());
+
+        reserve.assert();
+        delete.assert();
+    }
+}
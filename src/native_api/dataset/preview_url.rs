@@ -0,0 +1,199 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use typify::import_types;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+import_types!(
+    schema = "models/dataset/preview_url.json",
+    struct_builder = true,
+);
+
+/// Creates a preview (private) URL for a draft dataset.
+///
+/// This asynchronous function sends a POST request to the API endpoint designated for preview URLs. The
+/// resulting link grants read access to the draft version of the dataset, which allows authors to share
+/// drafts with reviewers who do not have an account. If `anonymized` is set, the names of the authors and
+/// other identifying metadata are withheld from the reviewers.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `anonymized` - A boolean flag indicating whether the preview shall withhold identifying metadata.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<PreviewUrlResponse>`, which contains the HTTP response status and the token
+/// and link of the preview URL, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let response = dataset::create_preview_url(&client, id, false).await?;
+///
+/// println!("Preview URL: {:?}", response.data.and_then(|d| d.link));
+/// # Ok(())
+/// # }
+/// ```
+pub async fn create_preview_url(
+    client: &BaseClient,
+    id: Identifier,
+    anonymized: bool,
+) -> Result<Response<PreviewUrlResponse>, String> {
+    let (url, mut parameters) = preview_url_endpoint(id);
+
+    if anonymized {
+        parameters.insert("anonymizedAccess".to_string(), "true".to_string());
+    }
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.post(url.as_str(), Some(parameters), &context).await;
+
+    evaluate_response::<PreviewUrlResponse>(response).await
+}
+
+/// Retrieves the preview (private) URL of a draft dataset.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<PreviewUrlResponse>`, which contains the HTTP response status and the token
+/// and link of the preview URL, if the request is successful, or a `String` error message on failure.
+pub async fn get_preview_url(
+    client: &BaseClient,
+    id: Identifier,
+) -> Result<Response<PreviewUrlResponse>, String> {
+    let (url, parameters) = preview_url_endpoint(id);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), Some(parameters), &context).await;
+
+    evaluate_response::<PreviewUrlResponse>(response).await
+}
+
+/// Deletes the preview (private) URL of a draft dataset, revoking the access of reviewers.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn delete_preview_url(
+    client: &BaseClient,
+    id: Identifier,
+) -> Result<Response<MessageResponse>, String> {
+    let (url, parameters) = preview_url_endpoint(id);
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), Some(parameters), &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+// Builds the endpoint and parameters of the preview URL API
+fn preview_url_endpoint(id: Identifier) -> (String, HashMap<String, String>) {
+    match id {
+        Identifier::PersistentId(id) => (
+            "api/datasets/:persistentId/privateUrl".to_string(),
+            HashMap::from([("persistentId".to_string(), id)]),
+        ),
+        Identifier::Id(id) => (format!("api/datasets/{}/privateUrl", id), HashMap::new()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::identifier::Identifier;
+    use crate::prelude::{BaseClient, dataset};
+    use crate::test_utils::{create_test_dataset, extract_test_env};
+
+    /// Tests the lifecycle of a preview URL.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables,
+    /// creates a draft dataset, and then creates, retrieves and deletes a preview URL for it. The test
+    /// asserts that every step was successful and that the retrieved token matches the created one.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if any of the requests fail.
+    #[tokio::test]
+    async fn test_preview_url_lifecycle() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Create a dataset
+        let (id, _) = create_test_dataset(&client, "Root").await;
+
+        // Create the preview URL
+        let created = dataset::preview_url::create_preview_url(&client, Identifier::Id(id), false)
+            .await.expect("Failed to create preview URL");
+        assert!(created.status.is_ok());
+
+        // Retrieve the preview URL
+        let retrieved = dataset::preview_url::get_preview_url(&client, Identifier::Id(id))
+            .await.expect("Failed to get preview URL");
+        assert!(retrieved.status.is_ok());
+        assert_eq!(created.data.unwrap().token, retrieved.data.unwrap().token);
+
+        // Delete the preview URL
+        let deleted = dataset::preview_url::delete_preview_url(&client, Identifier::Id(id))
+            .await.expect("Failed to delete preview URL");
+        assert!(deleted.status.is_ok());
+    }
+
+    /// Tests retrieving the preview URL of a non-existent dataset.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the request unexpectedly succeeds.
+    #[tokio::test]
+    async fn test_get_preview_url_non_existent_dataset() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        let response = dataset::preview_url::get_preview_url(&client, Identifier::Id(-1))
+            .await.expect("Failed to get preview URL");
+
+        assert!(response.status.is_err());
+    }
+}
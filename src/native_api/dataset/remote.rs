@@ -0,0 +1,160 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{client::BaseClient, identifier::Identifier};
+
+use super::direct_upload::{add_files_batched, AddFilesBatches, DirectUploadChecksum, DirectUploadFile};
+
+// This module is only available with the `directupload` feature. Files in remote
+// stores, such as the `RemoteOverlay` stores of an instance, are registered by
+// reference via the `addFiles` endpoint. No bytes are transferred; the instance
+// resolves the files relative to the base URL configured for the store.
+
+/// A file in a remote store, to be added to a dataset by reference.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct RemoteFile {
+    /// The path of the file relative to the base URL of the store, e.g. `surveys/2024/wave1.csv`.
+    pub path: String,
+    /// The name of the file in the dataset, defaulting to the last segment of the path.
+    #[serde(default)]
+    pub file_name: Option<String>,
+    /// The MIME type of the file, defaulting to `application/octet-stream`.
+    #[serde(default)]
+    pub mime_type: Option<String>,
+    /// The algorithm of the checksum, e.g. `MD5` or `SHA-256`.
+    pub checksum_type: String,
+    pub checksum: String,
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default)]
+    pub directory_label: Option<String>,
+    #[serde(default)]
+    pub categories: Vec<String>,
+}
+
+impl RemoteFile {
+    /// Builds the registration entry of the file in the given store.
+    pub fn to_upload_file(&self, store: &str) -> DirectUploadFile {
+        let file_name = self
+            .file_name
+            .clone()
+            .unwrap_or_else(|| self.path.trim_end_matches('/').rsplit('/').next().unwrap_or_default().to_string());
+
+        DirectUploadFile {
+            storage_identifier: storage_identifier(store, &self.path),
+            file_name,
+            mime_type: self
+                .mime_type
+                .clone()
+                .unwrap_or_else(|| "application/octet-stream".to_string()),
+            checksum: DirectUploadChecksum {
+                type_: self.checksum_type.clone(),
+                value: self.checksum.clone(),
+            },
+            description: self.description.clone(),
+            directory_label: self.directory_label.clone(),
+            categories: self.categories.clone(),
+            restrict: None,
+            tab_ingest: None,
+        }
+    }
+}
+
+/// Returns the storage identifier of a file in a remote store, e.g. `trsa://surveys/wave1.csv` for the
+/// path `surveys/wave1.csv` in the store `trsa`.
+pub fn storage_identifier(store: &str, path: &str) -> String {
+    format!("{}://{}", store, path.trim_start_matches('/'))
+}
+
+/// Adds files in a remote store to a dataset by reference, in batches of the given size.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `store` - A string slice that holds the ID of the remote store, as configured on the instance.
+/// * `files` - The `RemoteFile` entries to add.
+/// * `batch_size` - The maximum number of files per request; zero is treated as one.
+///
+/// # Returns
+///
+/// An `AddFilesBatches` summary of the registered and failed batches.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::remote::{add_remote_files, RemoteFile};
+///
+/// # async fn run() {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let file = RemoteFile {
+///     path: "surveys/2024/wave1.csv".to_string(),
+///     file_name: None,
+///     mime_type: Some("text/csv".to_string()),
+///     checksum_type: "MD5".to_string(),
+///     checksum: "509ef88afa907eaf2c17c1c8d8fde77e".to_string(),
+///     description: None,
+///     directory_label: None,
+///     categories: vec![],
+/// };
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let batches = add_remote_files(&client, id, "trsa", &[file], 500).await;
+///
+/// println!("Added {} files", batches.added());
+/// # }
+/// ```
+pub async fn add_remote_files(
+    client: &BaseClient,
+    id: Identifier,
+    store: &str,
+    files: &[RemoteFile],
+    batch_size: usize,
+) -> AddFilesBatches {
+    let files: Vec<DirectUploadFile> = files.iter().map(|file| file.to_upload_file(store)).collect();
+    add_files_batched(client, id, &files, batch_size).await
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    use super::{add_remote_files, RemoteFile};
+
+    /// Tests that remote files are registered by their storage identifier without uploading bytes.
+    #[tokio::test]
+    async fn test_add_remote_files() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/datasets/42/addFiles")
+                .body_contains(r#""storageIdentifier":"trsa://surveys/2024/wave1.csv""#)
+                .body_contains(r#""fileName":"wave1.csv""#)
+                .body_contains(r#""mimeType":"application/octet-stream""#);
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"Files": [{}], "Result": {"Total number of files": 1, "Number of files successfully added": 1}}
+            }));
+        });
+
+        let files: Vec<RemoteFile> = serde_json::from_value(json!([
+            {"path": "/surveys/2024/wave1.csv", "checksumType": "MD5", "checksum": "509ef88afa907eaf2c17c1c8d8fde77e"}
+        ]))
+        .unwrap();
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+        let batches = add_remote_files(&client, Identifier::Id(42), "trsa", &files, 100).await;
+
+        assert!(batches.is_success());
+        assert_eq!(batches.added(), 1);
+
+        mock.assert();
+    }
+}
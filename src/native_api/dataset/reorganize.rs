@@ -0,0 +1,300 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    access::mirror::relative_path,
+    client::BaseClient,
+    identifier::Identifier,
+    plan::{Action, Plan},
+};
+
+use super::files::{list_dataset_files, FileSearch};
+
+// Rules map the path of a file, i.e. its directory label joined with its label,
+// to a new path. The first matching rule is applied, and files not matched by
+// any rule stay where they are. Renamed files keep their content, as only the
+// metadata of the files is updated.
+
+/// A rule mapping the paths of files to new paths.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum PathRule {
+    /// Moves files below the directory `prefix` to the directory `to`, keeping their subdirectories.
+    Prefix { prefix: String, to: String },
+    /// Replaces the first match of the regular expression `pattern` with `replacement`, in which `$1` refers
+    /// to the first capture group, `$&` to the whole match and `$$` to a literal dollar sign.
+    Regex { pattern: String, replacement: String },
+}
+
+impl FromStr for PathRule {
+    type Err = String;
+
+    // Parses a prefix rule of the form `from=to`
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.split_once('=') {
+            Some((prefix, to)) => Ok(PathRule::Prefix {
+                prefix: prefix.to_string(),
+                to: to.to_string(),
+            }),
+            None => Err(format!("Expected a rule of the form 'from=to', got '{}'", s)),
+        }
+    }
+}
+
+// A rule whose regular expression has been compiled
+enum CompiledRule<'a> {
+    Prefix { prefix: String, to: &'a str },
+    Regex { regex: regress::Regex, replacement: &'a str },
+}
+
+impl<'a> CompiledRule<'a> {
+    fn compile(rule: &'a PathRule) -> Result<Self, String> {
+        match rule {
+            PathRule::Prefix { prefix, to } => Ok(CompiledRule::Prefix {
+                prefix: normalize_path(prefix),
+                to,
+            }),
+            PathRule::Regex { pattern, replacement } => {
+                let regex = regress::Regex::new(pattern)
+                    .map_err(|e| format!("Invalid pattern '{}': {}", pattern, e))?;
+                Ok(CompiledRule::Regex { regex, replacement })
+            }
+        }
+    }
+
+    // Returns the new path of a file, if the rule matches it
+    fn apply(&self, path: &str) -> Option<String> {
+        match self {
+            CompiledRule::Prefix { prefix, to } => {
+                let rest = match prefix.is_empty() {
+                    true => path,
+                    false => path.strip_prefix(prefix.as_str())?.strip_prefix('/')?,
+                };
+                Some(normalize_path(&format!("{}/{}", to, rest)))
+            }
+            CompiledRule::Regex { regex, replacement } => {
+                let found = regex.find(path)?;
+                let expanded = expand(replacement, path, &found);
+                Some(normalize_path(&format!(
+                    "{}{}{}",
+                    &path[..found.start()],
+                    expanded,
+                    &path[found.end()..]
+                )))
+            }
+        }
+    }
+}
+
+/// Applies the first matching rule to a path.
+///
+/// # Arguments
+///
+/// * `rules` - The `PathRule` items, in the order they are tried.
+/// * `path` - The path of a file, e.g. `raw/wave1_survey.csv`.
+///
+/// # Returns
+///
+/// A `Result` wrapping the new path, which is `None` if no rule matches, or a `String` error message if a
+/// pattern is invalid.
+pub fn apply_rules(rules: &[PathRule], path: &str) -> Result<Option<String>, String> {
+    let rules = rules.iter().map(CompiledRule::compile).collect::<Result<Vec<_>, _>>()?;
+    Ok(rules.iter().find_map(|rule| rule.apply(path)))
+}
+
+/// Plans moving the files of a dataset to the directories given by path rules.
+///
+/// The files of the latest version, which is the draft if one exists, are matched against the rules. Files
+/// whose path changes are moved and, if a rule changes their name, renamed. The plan is rejected as a whole if
+/// two files would end up at the same path or a rule leaves a file without a name.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `rules` - The `PathRule` items, of which the first matching one is applied to each file.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `Plan` of the moves, or a `String` error message if the files cannot be listed or
+/// the rules are invalid.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::reorganize::PathRule;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// // Sorts a flat dump of files named like `wave1_survey.csv` into a folder per wave
+/// let rules = vec![PathRule::Regex {
+///     pattern: r"^(wave\d+)_".to_string(),
+///     replacement: "data/$1/".to_string(),
+/// }];
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let plan = dataset::plan_reorganize(&client, id, &rules).await?;
+///
+/// println!("{}", plan);
+/// plan.execute(&client).await;
+/// # Ok(())
+/// # }
+/// ```
+pub async fn plan_reorganize(client: &BaseClient, id: Identifier, rules: &[PathRule]) -> Result<Plan, String> {
+    let rules = rules.iter().map(CompiledRule::compile).collect::<Result<Vec<_>, _>>()?;
+
+    let response = list_dataset_files(client, id, ":latest", &FileSearch::default()).await?;
+    if response.status.is_err() {
+        return Err(response.message.map(|m| m.to_string()).unwrap_or_default());
+    }
+
+    let mut plan = Plan::new();
+    let mut targets: HashMap<String, String> = HashMap::new();
+    for file in response.data.unwrap_or_default() {
+        let (Some(path), Some(file_id)) = (relative_path(&file), file.data_file.as_ref().and_then(|f| f.id)) else {
+            continue;
+        };
+        let path = path.to_string_lossy().replace('\\', "/");
+        let target = rules
+            .iter()
+            .find_map(|rule| rule.apply(&path))
+            .unwrap_or_else(|| path.clone());
+
+        if let Some(other) = targets.insert(target.clone(), path.clone()) {
+            return Err(format!("{} and {} would both be moved to {}", other, path, target));
+        }
+        if target == path {
+            continue;
+        }
+
+        let (directory, label) = match target.rsplit_once('/') {
+            Some((directory, label)) => (directory.to_string(), label.to_string()),
+            None => (String::new(), target.clone()),
+        };
+        if label.is_empty() {
+            return Err(format!("The rules leave {} without a file name", path));
+        }
+
+        let action = Action::Move { file: file_id, directory, label };
+        plan.push(action, &format!("Was {}", path));
+    }
+
+    Ok(plan)
+}
+
+// Expands the references to groups of a match in a replacement
+fn expand(replacement: &str, text: &str, found: &regress::Match) -> String {
+    let mut expanded = String::new();
+    let mut chars = replacement.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '$' {
+            expanded.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            Some('$') => {
+                chars.next();
+                expanded.push('$');
+            }
+            Some('&') => {
+                chars.next();
+                expanded.push_str(&text[found.range()]);
+            }
+            Some(d) if d.is_ascii_digit() => {
+                let mut index = 0;
+                while let Some(digit) = chars.peek().and_then(|d| d.to_digit(10)) {
+                    index = index * 10 + digit as usize;
+                    chars.next();
+                }
+                if let Some(range) = found.captures.get(index.wrapping_sub(1)).cloned().flatten() {
+                    expanded.push_str(&text[range]);
+                }
+            }
+            _ => expanded.push('$'),
+        }
+    }
+
+    expanded
+}
+
+// Removes empty segments, such that rules may add or omit slashes
+fn normalize_path(path: &str) -> String {
+    path.split(['/', '\\'])
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    use super::{apply_rules, PathRule};
+
+    /// Tests that prefix and regular expression rules map paths, with the first matching rule applied.
+    #[test]
+    fn test_apply_rules() {
+        let rules = vec![
+            "raw=data/raw".parse::<PathRule>().unwrap(),
+            PathRule::Regex {
+                pattern: r"^(wave\d+)_(.*)$".to_string(),
+                replacement: "data/$1/$2".to_string(),
+            },
+        ];
+
+        let apply = |path| apply_rules(&rules, path).unwrap();
+        assert_eq!(apply("raw/2024/a.csv"), Some("data/raw/2024/a.csv".to_string()));
+        assert_eq!(apply("rawdata/a.csv"), None);
+        assert_eq!(apply("wave1_survey.csv"), Some("data/wave1/survey.csv".to_string()));
+        assert_eq!(apply("readme.txt"), None);
+
+        let invalid = vec![PathRule::Regex { pattern: "(".to_string(), replacement: String::new() }];
+        assert!(apply_rules(&invalid, "a.csv").is_err());
+    }
+
+    /// Tests that only files whose path changes are planned to be moved, and that collisions are rejected.
+    #[tokio::test]
+    async fn test_plan_reorganize() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/42/versions/:latest/files");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": [
+                    {"label": "wave1_survey.csv", "dataFile": {"id": 1}},
+                    {"label": "wave2_survey.csv", "dataFile": {"id": 2}},
+                    {"label": "readme.txt", "dataFile": {"id": 3}}
+                ]
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let rules = vec![PathRule::Regex {
+            pattern: r"^(wave\d+)_".to_string(),
+            replacement: "data/$1/".to_string(),
+        }];
+        let plan = dataset::plan_reorganize(&client, Identifier::Id(42), &rules).await.unwrap();
+        let output = plan.to_string();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(lines[0], "move     file 1 to data/wave1/survey.csv (Was wave1_survey.csv)");
+        assert_eq!(lines[2], "Plan: 2 move");
+
+        let rules = vec![PathRule::Regex {
+            pattern: r"^wave\d+_".to_string(),
+            replacement: String::new(),
+        }];
+        let result = dataset::plan_reorganize(&client, Identifier::Id(42), &rules).await;
+        assert!(result.unwrap_err().contains("would both be moved to survey.csv"));
+    }
+}
@@ -0,0 +1,151 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use typify::import_types;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    request::RequestType,
+    response::Response,
+};
+
+import_types!(
+    schema = "models/dataset/review.json",
+    struct_builder = true,
+);
+
+/// Submits a draft dataset for review by the curators of its collection.
+///
+/// This asynchronous function sends a POST request to the review endpoint of a dataset. Once submitted,
+/// the dataset is locked for editing by its authors until it is either published or returned to them.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<ReviewResponse>`, which contains the HTTP response status and whether the
+/// dataset is in review, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let response = dataset::submit_for_review(&client, id).await?;
+///
+/// println!("In review: {:?}", response.data.and_then(|d| d.in_review));
+/// # Ok(())
+/// # }
+/// ```
+pub async fn submit_for_review(
+    client: &BaseClient,
+    id: Identifier,
+) -> Result<Response<ReviewResponse>, String> {
+    let (url, parameters) = review_endpoint(id, "submitForReview");
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.post(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<ReviewResponse>(response).await
+}
+
+/// Returns a dataset in review to its authors, along with the reason for doing so.
+///
+/// This asynchronous function sends a POST request to the return endpoint of a dataset, which lifts the
+/// review lock such that the authors can address the remarks of the curators. The reason is sent to the
+/// authors by email.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `body` - A `ReturnToAuthorBody` holding the reason for the return.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<ReviewResponse>`, which contains the HTTP response status and whether the
+/// dataset is still in review, if the request is successful, or a `String` error message on failure.
+pub async fn return_to_author(
+    client: &BaseClient,
+    id: Identifier,
+    body: ReturnToAuthorBody,
+) -> Result<Response<ReviewResponse>, String> {
+    let (url, parameters) = review_endpoint(id, "returnToAuthor");
+
+    // Send request
+    let context = RequestType::JSON {
+        body: serde_json::to_string(&body).unwrap(),
+    };
+    let response = client.post(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<ReviewResponse>(response).await
+}
+
+// Builds the endpoint and parameters of the review actions
+fn review_endpoint(id: Identifier, action: &str) -> (String, Option<HashMap<String, String>>) {
+    match id {
+        Identifier::PersistentId(id) => (
+            format!("api/datasets/:persistentId/{}", action),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/datasets/{}/{}", id, action), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_utils::{create_test_dataset, extract_test_env};
+
+    use super::ReturnToAuthorBody;
+
+    /// Tests submitting a dataset for review and returning it to its author.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables,
+    /// creates a draft dataset, submits it for review and returns it with a reason. The test asserts
+    /// that the dataset is in review after the submission and no longer after the return.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if any of the requests fail.
+    #[tokio::test]
+    async fn test_review_workflow() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        let (id, _) = create_test_dataset(&client, "Root").await;
+
+        // Submit the dataset for review
+        let response = dataset::submit_for_review(&client, Identifier::Id(id))
+            .await
+            .expect("Failed to submit dataset for review");
+        assert_eq!(response.data.unwrap().in_review, Some(true));
+
+        // Return it to the author
+        let body = ReturnToAuthorBody {
+            reason_for_return: "Please add a description".to_string(),
+        };
+        let response = dataset::return_to_author(&client, Identifier::Id(id), body)
+            .await
+            .expect("Failed to return dataset to author");
+        assert_eq!(response.data.unwrap().in_review, Some(false));
+    }
+}
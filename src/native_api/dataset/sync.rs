@@ -0,0 +1,326 @@
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::{
+    access::mirror::relative_path,
+    checksum::{file_checksums, hashing_threads},
+    client::BaseClient,
+    identifier::Identifier,
+    plan::{Action, ExecutedAction, Plan},
+};
+
+use super::directory::{collect_directory_files, DirectoryUploadOptions};
+use super::edit::File;
+use super::get::{get_dataset_version, GetDatasetOptions};
+use super::upload::UploadBody;
+
+/// Options to select the local files to synchronise and whether to remove files from the dataset.
+#[derive(Debug, Clone, Default)]
+pub struct SyncOptions {
+    /// Glob patterns of the paths relative to the root to synchronise, e.g. `data/**/*.csv`. All files are
+    /// synchronised if empty.
+    pub include: Vec<String>,
+    /// Glob patterns of the paths relative to the root to skip. Exclusions take precedence over inclusions.
+    pub exclude: Vec<String>,
+    /// Whether to synchronise hidden files and the contents of hidden directories.
+    pub hidden: bool,
+    /// Whether to delete files of the dataset that are not present in the local directory.
+    pub delete: bool,
+}
+
+/// Plans the synchronisation of a local directory with the latest version of a dataset.
+///
+/// This asynchronous function compares the files below `root` with the files of the dataset by their path,
+/// i.e. the directory label joined with the file name. Local files without a counterpart are planned for
+/// upload, and files present on both sides are hashed with the algorithm reported by the instance to plan
+/// the replacement of those whose checksum differs. Ingested tabular files are matched by the name of their
+/// original upload, to which their checksum refers. Files only present in the dataset are planned for
+/// deletion if `delete` is set and skipped otherwise.
+///
+/// Nothing is changed by this function; the returned plan can be printed for a dry run or executed.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `root` - The directory to synchronise the dataset with.
+/// * `options` - The `SyncOptions` selecting the files and whether to delete remote files.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `Plan` of the synchronisation, or a `String` error message if the dataset cannot
+/// be retrieved or a local file cannot be read.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::Path;
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::sync::SyncOptions;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let plan = dataset::plan_sync(&client, id, Path::new("results"), &SyncOptions::default()).await?;
+///
+/// println!("{}", plan);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn plan_sync(
+    client: &BaseClient,
+    id: Identifier,
+    root: &Path,
+    options: &SyncOptions,
+) -> Result<Plan, String> {
+    let directory_options = DirectoryUploadOptions {
+        include: options.include.clone(),
+        exclude: options.exclude.clone(),
+        hidden: options.hidden,
+        ..Default::default()
+    };
+    let local = collect_directory_files(root, &directory_options)?;
+
+    let response = get_dataset_version(client, id.clone(), ":latest", &GetDatasetOptions::default()).await?;
+    if response.status.is_err() {
+        return Err(response.message.map(|m| m.to_string()).unwrap_or_default());
+    }
+    let files = response.data.ok_or("The response did not contain any data")?.files;
+
+    let mut remote: HashMap<String, &File> = HashMap::new();
+    for file in &files {
+        if let Some(path) = remote_path(file) {
+            remote.entry(path).or_insert(file);
+        }
+    }
+
+    // Only files present on both sides need to be hashed
+    let jobs: Vec<(PathBuf, String)> = local
+        .iter()
+        .filter_map(|(path, body)| {
+            let file = remote.get(&local_path(path, body))?;
+            let algorithm = file.data_file.as_ref()?.checksum.as_ref()?.type_.clone()?;
+            Some((path.clone(), algorithm))
+        })
+        .collect();
+    let results = file_checksums(jobs.clone(), hashing_threads()).await;
+
+    let mut checksums = HashMap::new();
+    for ((path, _), result) in jobs.into_iter().zip(results) {
+        checksums.insert(path, result?);
+    }
+
+    let mut plan = Plan::new();
+    for (path, body) in local {
+        let name = local_path(&path, &body);
+        let Some(file) = remote.remove(&name) else {
+            plan.push(Action::Upload { dataset: id.clone(), path, body }, "Not present in the dataset");
+            continue;
+        };
+
+        let data_file = file.data_file.as_ref();
+        let remote_checksum = data_file.and_then(|f| f.checksum.as_ref()).and_then(|c| c.value.as_ref());
+
+        match (data_file.and_then(|f| f.id), remote_checksum, checksums.get(&path)) {
+            (Some(file), Some(expected), Some(actual)) if !actual.eq_ignore_ascii_case(expected) => {
+                // The content type may change along with the content
+                let body = body.map(|body| UploadBody { force_replace: Some(true), ..body });
+                plan.push(Action::Replace { file, path, body }, "Checksum differs");
+            }
+            (Some(_), Some(_), Some(_)) => plan.push(Action::Skip { item: name }, "Unchanged"),
+            _ => plan.push(Action::Skip { item: name }, "No checksum to compare with"),
+        }
+    }
+
+    // Deletions come last, such that a failing upload leaves the remote files in place
+    let mut vanished: Vec<(String, &File)> = remote.into_iter().collect();
+    vanished.sort_by(|(a, _), (b, _)| a.cmp(b));
+    for (name, file) in vanished {
+        match file.data_file.as_ref().and_then(|f| f.id) {
+            Some(file) if options.delete => {
+                plan.push(Action::Delete { file: Identifier::Id(file) }, "Not present locally")
+            }
+            _ => plan.push(Action::Skip { item: name }, "Only present in the dataset"),
+        }
+    }
+
+    Ok(plan)
+}
+
+/// Synchronises a local directory with the latest version of a dataset.
+///
+/// This asynchronous function plans the synchronisation using `plan_sync` and executes the plan right away.
+/// As with any plan, the execution stops at the first failing action.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `root` - The directory to synchronise the dataset with.
+/// * `options` - The `SyncOptions` selecting the files and whether to delete remote files.
+///
+/// # Returns
+///
+/// A `Result` wrapping the outcomes of the executed actions, or a `String` error message if the plan could
+/// not be created.
+pub async fn sync_directory(
+    client: &BaseClient,
+    id: Identifier,
+    root: &Path,
+    options: &SyncOptions,
+) -> Result<Vec<ExecutedAction>, String> {
+    let plan = plan_sync(client, id, root, options).await?;
+    Ok(plan.execute(client).await)
+}
+
+// The path of a dataset file relative to the root of the dataset. Ingested files
+// are addressed by their original name, as their label refers to the archival format.
+fn remote_path(file: &File) -> Option<String> {
+    let original = file
+        .data_file
+        .as_ref()
+        .filter(|data_file| data_file.tabular_data == Some(true))
+        .and_then(|data_file| data_file.original_file_name.clone());
+
+    let path = match original {
+        Some(name) => {
+            let mut file = file.clone();
+            file.label = Some(name);
+            relative_path(&file)?
+        }
+        None => relative_path(file)?,
+    };
+
+    let components: Vec<String> = path.iter().map(|c| c.to_string_lossy().to_string()).collect();
+    Some(components.join("/"))
+}
+
+fn local_path(path: &Path, body: &Option<UploadBody>) -> String {
+    let name = path.file_name().map(|name| name.to_string_lossy().to_string()).unwrap_or_default();
+    match body.as_ref().and_then(|body| body.directory_label.as_ref()) {
+        Some(directory) => format!("{}/{}", directory, name),
+        None => name,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::plan::Action;
+    use crate::prelude::*;
+
+    use super::SyncOptions;
+
+    // Serves a dataset with an unchanged, a modified, an ingested and a vanished file
+    fn mock_dataset(server: &MockServer) {
+        server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/7/versions/:latest");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {
+                    "versionState": "DRAFT",
+                    "files": [
+                        {
+                            "label": "file.txt",
+                            "directoryLabel": "docs",
+                            "dataFile": {"id": 1, "checksum": {"type": "MD5", "value": "a28bca1b906f539ba70ca3a0b1f2e773"}}
+                        },
+                        {
+                            "label": "changed.txt",
+                            "dataFile": {"id": 2, "checksum": {"type": "MD5", "value": "00000000000000000000000000000000"}}
+                        },
+                        {
+                            "label": "survey.tab",
+                            "dataFile": {
+                                "id": 3,
+                                "tabularData": true,
+                                "originalFileName": "survey.csv",
+                                "checksum": {"type": "MD5", "value": "a28bca1b906f539ba70ca3a0b1f2e773"}
+                            }
+                        },
+                        {
+                            "label": "gone.txt",
+                            "dataFile": {"id": 4, "checksum": {"type": "MD5", "value": "0"}}
+                        }
+                    ]
+                }
+            }));
+        });
+    }
+
+    fn create_tree() -> std::path::PathBuf {
+        let root = std::env::temp_dir().join(format!("dvcli_sync_{}", rand::random::<u32>()));
+        let content = fs::read("tests/fixtures/file.txt").unwrap();
+        for path in ["docs/file.txt", "changed.txt", "survey.csv", "new/added.txt"] {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, &content).unwrap();
+        }
+        root
+    }
+
+    /// Tests that new, changed, unchanged and vanished files are planned accordingly.
+    #[tokio::test]
+    async fn test_plan_sync() {
+        let server = MockServer::start();
+        mock_dataset(&server);
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let root = create_tree();
+
+        let plan = dataset::plan_sync(&client, Identifier::Id(7), &root, &SyncOptions::default())
+            .await
+            .expect("Failed to plan sync");
+
+        let lines: Vec<String> = plan.to_string().lines().map(String::from).collect();
+        assert_eq!(lines.len(), 6);
+        assert!(lines[0].starts_with("replace  file 2 with"));
+        assert_eq!(lines[1], "skip     docs/file.txt (Unchanged)");
+        assert!(lines[2].starts_with("upload") && lines[2].ends_with("(Not present in the dataset)"));
+        assert_eq!(lines[3], "skip     survey.csv (Unchanged)");
+        assert_eq!(lines[4], "skip     gone.txt (Only present in the dataset)");
+
+        let Action::Upload { body, .. } = &plan.actions[2].action else {
+            panic!("Expected an upload");
+        };
+        assert_eq!(body.as_ref().unwrap().directory_label.as_deref(), Some("new"));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+
+    /// Tests that vanished files are only deleted if requested.
+    #[tokio::test]
+    async fn test_plan_sync_with_delete() {
+        let server = MockServer::start();
+        mock_dataset(&server);
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let root = create_tree();
+        let options = SyncOptions {
+            delete: true,
+            ..Default::default()
+        };
+
+        let plan = dataset::plan_sync(&client, Identifier::Id(7), &root, &options)
+            .await
+            .expect("Failed to plan sync");
+
+        assert_eq!(plan.to_string().lines().last(), Some("Plan: 1 replace, 2 skip, 1 upload, 1 delete"));
+        assert!(matches!(
+            plan.actions.last().unwrap().action,
+            Action::Delete { file: Identifier::Id(4) }
+        ));
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+#[cfg(feature = "fs")]
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use typify::import_types;
+
+#[cfg(feature = "fs")]
+use crate::access::datafile::download;
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+import_types!(schema = "models/dataset/thumbnail.json");
+
+/// Downloads the thumbnail of a dataset.
+///
+/// The thumbnail is either an image uploaded for the dataset or derived from one of its image files. It is
+/// returned as PNG by the instance and written to the destination.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `destination` - A `PathBuf` of the file or directory to download to. Within a directory, the thumbnail
+///   is written to `thumbnail.png`.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `PathBuf` the thumbnail has been written to, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, None)
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let path = dataset::get_thumbnail(&client, id, PathBuf::from("thumbnail.png")).await?;
+///
+/// println!("Thumbnail written to {}", path.display());
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "fs")]
+pub async fn get_thumbnail(
+    client: &BaseClient,
+    id: Identifier,
+    destination: PathBuf,
+) -> Result<PathBuf, String> {
+    let (url, parameters) = thumbnail_endpoint(id, "");
+
+    download(client, &url, parameters, destination, "thumbnail.png", None).await
+}
+
+/// Lists the image files of a dataset that can be used as its thumbnail.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<ThumbnailCandidate>>`, which contains the HTTP response status and the
+/// file IDs along with a Base64 encoded preview, if the request is successful, or a `String` error message on
+/// failure.
+pub async fn list_thumbnail_candidates(
+    client: &BaseClient,
+    id: Identifier,
+) -> Result<Response<Vec<ThumbnailCandidate>>, String> {
+    let (url, parameters) = thumbnail_endpoint(id, "/candidates");
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<Vec<ThumbnailCandidate>>(response).await
+}
+
+/// Uploads an image file as the thumbnail of a dataset.
+///
+/// The image is stored alongside the dataset and is not added to its files. It replaces any thumbnail that
+/// has been set before.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `path` - A `PathBuf` of the image to upload.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let response = dataset::set_thumbnail_from_file(&client, id, PathBuf::from("logo.png")).await?;
+///
+/// println!("{:?}", response.data);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "fs")]
+pub async fn set_thumbnail_from_file(
+    client: &BaseClient,
+    id: Identifier,
+    path: PathBuf,
+) -> Result<Response<MessageResponse>, String> {
+    let (url, parameters) = thumbnail_endpoint(id, "");
+
+    // Send request
+    let context = RequestType::Multipart {
+        bodies: None,
+        files: Some(HashMap::from([("file".to_string(), path)])),
+        callbacks: None,
+    };
+    let response = client.post(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Uses an image file of a dataset as its thumbnail.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `file_id` - The ID of the image file within the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn set_thumbnail_from_datafile(
+    client: &BaseClient,
+    id: Identifier,
+    file_id: i64,
+) -> Result<Response<MessageResponse>, String> {
+    let (url, parameters) = thumbnail_endpoint(id, &format!("/{}", file_id));
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.post(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Removes the thumbnail of a dataset.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn remove_thumbnail(
+    client: &BaseClient,
+    id: Identifier,
+) -> Result<Response<MessageResponse>, String> {
+    let (url, parameters) = thumbnail_endpoint(id, "");
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+// Builds the endpoint and parameters of the thumbnail API, with the suffix
+// appended to the thumbnail path
+fn thumbnail_endpoint(id: Identifier, suffix: &str) -> (String, Option<HashMap<String, String>>) {
+    match id {
+        Identifier::PersistentId(id) => (
+            format!("api/datasets/:persistentId/thumbnail{}", suffix),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/datasets/{}/thumbnail{}", id, suffix), None),
+    }
+}
+
+#[cfg(all(test, feature = "fs"))]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    /// Tests setting the thumbnail from a file of the dataset and removing it again.
+    #[tokio::test]
+    async fn test_set_and_remove_thumbnail() {
+        let server = MockServer::start();
+        let set = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/datasets/:persistentId/thumbnail/42")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"message": "Thumbnail set to 42"}
+            }));
+        });
+        let remove = server.mock(|when, then| {
+            when.method(DELETE).path("/api/datasets/1/thumbnail");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"message": "Dataset thumbnail removed."}
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+
+        let response = dataset::set_thumbnail_from_datafile(&client, id, 42)
+            .await
+            .expect("Failed to set thumbnail");
+        assert!(response.status.is_ok());
+
+        let response = dataset::remove_thumbnail(&client, Identifier::Id(1))
+            .await
+            .expect("Failed to remove thumbnail");
+        assert!(response.status.is_ok());
+
+        set.assert();
+        remove.assert();
+    }
+
+    /// Tests that the thumbnail is written to `thumbnail.png` within a directory.
+    #[tokio::test]
+    async fn test_get_thumbnail() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/1/thumbnail");
+            then.status(200).header("Content-Type", "image/png").body("PNG");
+        });
+
+        let dir = std::env::temp_dir().join(format!("dvcli_thumbnail_{}", rand::random::<u32>()));
+        std::fs::create_dir_all(&dir).unwrap();
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let path = dataset::get_thumbnail(&client, Identifier::Id(1), dir.clone())
+            .await
+            .expect("Failed to download thumbnail");
+
+        mock.assert();
+        assert_eq!(path, dir.join("thumbnail.png"));
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "PNG");
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -1,6 +1,9 @@
 use std::collections::HashMap;
+use std::future::Future;
 use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
 
+use futures::stream::{self, StreamExt};
 use serde::{Deserialize, Serialize};
 use serde_json;
 use typify::import_types;
@@ -28,7 +31,7 @@ import_types!(
 ///
 /// * `client` - A reference to the `BaseClient` instance used to send the request.
 /// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
-///          representing the unique identifier of the dataset to which the file will be uploaded.
+///   representing the unique identifier of the dataset to which the file will be uploaded.
 /// * `fpath` - A `PathBuf` instance representing the file path of the file to be uploaded.
 /// * `body` - An optional `UploadBody` struct instance containing additional metadata for the upload.
 /// * `callback` - An optional `CallbackFun` instance for handling callbacks during the upload process.
@@ -64,6 +67,8 @@ pub async fn upload_file_to_dataset(
         callbacks,
     };
 
+    // Files are added one at a time, as the dataset is locked while the server processes one
+    let _queue = client.queues().acquire(&id).await;
     let response = match id {
         Identifier::PersistentId(id) => client.post(
             path.as_str(),
@@ -76,13 +81,198 @@ pub async fn upload_file_to_dataset(
     evaluate_response::<UploadResponse>(response).await
 }
 
+/// Identifiers of a file that has been created by an upload.
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadedFile {
+    pub id: i64,
+    pub persistent_id: Option<String>,
+    pub storage_identifier: Option<String>,
+    pub label: Option<String>,
+}
+
+impl UploadedFile {
+    /// Returns the persistent identifier of the file if one has been registered, or its numeric ID otherwise.
+    pub fn identifier(&self) -> Identifier {
+        match &self.persistent_id {
+            Some(pid) => Identifier::PersistentId(pid.clone()),
+            None => Identifier::Id(self.id),
+        }
+    }
+}
+
+impl UploadResponse {
+    /// Returns the identifiers of all files created by the upload.
+    ///
+    /// Follow-up operations such as restricting, tagging or replacing a file require its identifier, which
+    /// is otherwise nested within the file metadata of the response. Entries without a file ID are skipped
+    /// and empty persistent identifiers, as returned by instances without file PIDs, are omitted.
+    pub fn uploaded_files(&self) -> Vec<UploadedFile> {
+        self.files
+            .iter()
+            .filter_map(|info| {
+                let data_file = info.data_file.as_ref()?;
+                Some(UploadedFile {
+                    id: data_file.id?,
+                    persistent_id: data_file.persistent_id.clone().filter(|pid| !pid.is_empty()),
+                    storage_identifier: data_file.storage_identifier.clone(),
+                    label: info.label.clone().or_else(|| data_file.filename.clone()),
+                })
+            })
+            .collect()
+    }
+
+    /// Returns the numeric IDs of all files created by the upload.
+    pub fn file_ids(&self) -> Vec<i64> {
+        self.uploaded_files().iter().map(|file| file.id).collect()
+    }
+}
+
+/// The outcome of uploading multiple files to a dataset.
+#[derive(Debug, Default)]
+pub struct UploadSeries {
+    /// Files that have been uploaded, in the order they were given.
+    pub succeeded: Vec<(PathBuf, UploadResponse)>,
+    /// Files that failed to upload, along with the error message.
+    pub failed: Vec<(PathBuf, String)>,
+    /// Files that were not attempted, because a previous upload failed.
+    pub skipped: Vec<PathBuf>,
+}
+
+impl UploadSeries {
+    pub fn is_success(&self) -> bool {
+        self.failed.is_empty() && self.skipped.is_empty()
+    }
+}
+
+/// Uploads multiple files to a dataset identified by either a persistent identifier (PID) or a numeric ID.
+///
+/// The native API accepts a single file per request. This asynchronous function manages the series of requests,
+/// running up to `concurrency` uploads at a time, each with its own progress bar. Errors are collected per file
+/// rather than aborting the whole series. If `stop_on_error` is set, no further uploads are started after the
+/// first failure and the remaining files are reported as skipped, while uploads already in flight complete.
+///
+/// Note that a dataset is locked while the server processes an added file, so uploads to the same dataset are
+/// queued by the client and sent one at a time. Use direct uploads to transfer files in parallel.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset to which the files will be uploaded.
+/// * `files` - The paths of the files to upload, each with optional `UploadBody` metadata.
+/// * `concurrency` - The maximum number of simultaneous uploads; zero is treated as one.
+/// * `stop_on_error` - Whether to stop starting new uploads once an upload has failed.
+///
+/// # Returns
+///
+/// An `UploadSeries` listing the succeeded, failed and skipped files.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+/// use dataverse::prelude::*;
+///
+/// # async fn run() {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let files = vec![(PathBuf::from("a.csv"), None), (PathBuf::from("b.csv"), None)];
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let series = dataset::upload_files_to_dataset(&client, id, files, 2, true).await;
+///
+/// for (path, error) in &series.failed {
+///     println!("Failed to upload {}: {}", path.display(), error);
+/// }
+/// # }
+/// ```
+pub async fn upload_files_to_dataset(
+    client: &BaseClient,
+    id: Identifier,
+    files: Vec<(PathBuf, Option<UploadBody>)>,
+    concurrency: usize,
+    stop_on_error: bool,
+) -> UploadSeries {
+    let results = upload_bounded(files, concurrency, stop_on_error, |fpath, body| {
+        let id = id.clone();
+        async move {
+            // Missing files are reported instead of failing the whole series
+            let result = match fpath.is_file() {
+                false => Err(format!("File not found: {}", fpath.display())),
+                true => upload_file_to_dataset(client, id, fpath, body, None).await,
+            };
+
+            match result {
+                Ok(response) if response.status.is_ok() => response
+                    .data
+                    .ok_or_else(|| "The response did not contain any data".to_string()),
+                Ok(response) => Err(response.message.map(|m| m.to_string()).unwrap_or_default()),
+                Err(e) => Err(e),
+            }
+        }
+    })
+    .await;
+
+    let mut series = UploadSeries::default();
+    for (fpath, result) in results {
+        match result {
+            Some(Ok(response)) => series.succeeded.push((fpath, response)),
+            Some(Err(e)) => series.failed.push((fpath, e)),
+            None => series.skipped.push(fpath),
+        }
+    }
+
+    series
+}
+
+/// Runs an upload per file with at most `concurrency` uploads in flight.
+///
+/// Uploads are started in the order of the files as earlier ones complete, such that thousands of files can
+/// be uploaded without opening a connection for each of them at once. If `stop_on_error` is set, files whose
+/// upload has not started by the time an upload fails are returned without a result. The results are
+/// returned in the order of the files.
+pub(crate) async fn upload_bounded<B, T, F, Fut>(
+    files: Vec<(PathBuf, B)>,
+    concurrency: usize,
+    stop_on_error: bool,
+    upload: F,
+) -> Vec<(PathBuf, Option<Result<T, String>>)>
+where
+    F: Fn(PathBuf, B) -> Fut,
+    Fut: Future<Output = Result<T, String>>,
+{
+    let failed = AtomicBool::new(false);
+
+    stream::iter(files)
+        .map(|(fpath, body)| {
+            let (failed, upload) = (&failed, &upload);
+            async move {
+                if stop_on_error && failed.load(Ordering::SeqCst) {
+                    return (fpath, None);
+                }
+
+                let result = upload(fpath.clone(), body).await;
+                if result.is_err() {
+                    failed.store(true, Ordering::SeqCst);
+                }
+
+                (fpath, Some(result))
+            }
+        })
+        .buffered(concurrency.max(1))
+        .collect()
+        .await
+}
+
 #[cfg(test)]
 mod tests {
     use std::path::PathBuf;
 
     use crate::identifier::Identifier;
     use crate::prelude::BaseClient;
-    use crate::prelude::dataset::upload::upload_file_to_dataset;
+    use crate::prelude::dataset::upload::{upload_file_to_dataset, upload_files_to_dataset, UploadResponse};
     use crate::test_utils::{create_test_dataset, extract_test_env, prepare_upload_body};
 
     /// Tests the file upload functionality to a dataset using a persistent identifier (PID).
@@ -248,4 +438,135 @@ mod tests {
             .await
             .expect("Failed to upload file to dataset");
     }
+
+    /// Tests uploading multiple files to a dataset in a single series.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables,
+    /// creates a test dataset and uploads two files with a concurrency of one. The test asserts that
+    /// both files have been uploaded and are reported in their original order.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if any of the uploads fail.
+    #[tokio::test]
+    async fn test_upload_files_to_dataset() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token)).unwrap();
+
+        // Create a test dataset
+        let (id, _) = create_test_dataset(&client, "Root").await;
+
+        // Upload two files, the second one with a directory to avoid duplicates
+        let mut body = prepare_upload_body();
+        body.directory_label = Some("second".to_string());
+
+        let files = vec![
+            (PathBuf::from("tests/fixtures/file.txt"), None),
+            (PathBuf::from("tests/fixtures/file.txt"), Some(body)),
+        ];
+
+        let series = upload_files_to_dataset(&client, Identifier::Id(id), files, 1, false).await;
+
+        // Assert that both uploads were successful
+        assert!(series.is_success(), "Failed uploads: {:?}", series.failed);
+        assert_eq!(series.succeeded.len(), 2);
+        assert!(series.succeeded.iter().all(|(_, r)| r.file_ids().len() == 1));
+    }
+
+    /// Tests that no further uploads are started after the first failure.
+    ///
+    /// This test serves an error for every upload from a mock server and asserts that, with a concurrency
+    /// of one, only the first file has been attempted while the remaining files are reported as skipped.
+    #[tokio::test]
+    async fn test_upload_files_stop_on_error() {
+        let server = httpmock::MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST).path("/api/datasets/1/add");
+            then.status(400)
+                .json_body(serde_json::json!({"status": "ERROR", "message": "Dataset is locked"}));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let files = vec![
+            (PathBuf::from("tests/fixtures/file.txt"), None),
+            (PathBuf::from("tests/fixtures/file.txt"), None),
+            (PathBuf::from("tests/fixtures/file.txt"), None),
+        ];
+
+        let series = upload_files_to_dataset(&client, Identifier::Id(1), files, 1, true).await;
+
+        mock.assert_hits(1);
+        assert_eq!(series.failed.len(), 1);
+        assert_eq!(series.failed[0].1, "Dataset is locked");
+        assert_eq!(series.skipped.len(), 2);
+        assert!(!series.is_success());
+    }
+
+    /// Tests that the ingest of tabular files is skipped if requested in the body.
+    #[tokio::test]
+    async fn test_upload_without_ingest() {
+        let server = httpmock::MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(httpmock::Method::POST)
+                .path("/api/datasets/1/add")
+                .body_contains("\"tabIngest\":false");
+            then.status(200).json_body(serde_json::json!({"status": "OK", "data": {"files": []}}));
+        });
+
+        let mut body = prepare_upload_body();
+        body.tab_ingest = Some(false);
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let response = upload_file_to_dataset(
+            &client,
+            Identifier::Id(1),
+            PathBuf::from("tests/fixtures/file.txt"),
+            Some(body),
+            None,
+        )
+            .await
+            .expect("Failed to upload file");
+
+        mock.assert();
+        assert!(response.status.is_ok());
+    }
+
+    /// Tests the extraction of the identifiers of uploaded files from an upload response.
+    #[test]
+    fn test_uploaded_files() {
+        let response: UploadResponse = serde_json::from_value(serde_json::json!({
+            "files": [
+                {
+                    "label": "file.txt",
+                    "dataFile": {
+                        "id": 42,
+                        "persistentId": "",
+                        "storageIdentifier": "local://18f0f4f1e2b-6c8e7a9d3b21",
+                        "filename": "file.txt"
+                    }
+                },
+                {
+                    "label": "data.csv",
+                    "dataFile": {"id": 43, "persistentId": "doi:10.5072/FK2/ABC123/XYZ"}
+                },
+                {"label": "missing.txt"}
+            ]
+        }))
+        .expect("Failed to deserialize upload response");
+
+        let files = response.uploaded_files();
+
+        assert_eq!(response.file_ids(), vec![42, 43]);
+        assert_eq!(files[0].persistent_id, None);
+        assert_eq!(files[0].storage_identifier.as_deref(), Some("local://18f0f4f1e2b-6c8e7a9d3b21"));
+        assert!(matches!(files[0].identifier(), Identifier::Id(42)));
+        assert!(matches!(
+            files[1].identifier(),
+            Identifier::PersistentId(ref pid) if pid == "doi:10.5072/FK2/ABC123/XYZ"
+        ));
+    }
 }
@@ -0,0 +1,470 @@
+use std::fmt;
+
+use serde_json::{Map, Value};
+
+use crate::client::BaseClient;
+use crate::native_api::collection::metadatablocks::{get_metadata_blocks, FieldDefinition, MetadataBlock};
+
+use super::create::DatasetCreateBody;
+
+/// A mistake in a dataset body, along with the path of the field it was found in.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ValidationError {
+    /// The path of the offending field, e.g. `citation.author[0].authorName`.
+    pub path: String,
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path, self.message)
+    }
+}
+
+/// Validates a dataset body against the metadata blocks of the collection it is to be created in.
+///
+/// This asynchronous function fetches the metadata blocks of the collection and checks the body with
+/// `validate_against_blocks`, such that mistakes are reported per field before anything is created, instead
+/// of by a single error response of the server.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `collection` - The alias of the collection the dataset is to be created in.
+/// * `body` - The `DatasetCreateBody` to validate.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `ValidationError`s of the body, which is valid if there are none, or a `String`
+/// error message if the metadata blocks cannot be retrieved.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::create::DatasetCreateBody;
+///
+/// # async fn run(body: DatasetCreateBody) -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let errors = dataset::validate_dataset(&client, "root", &body).await?;
+/// for error in &errors {
+///     println!("{}", error);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn validate_dataset(
+    client: &BaseClient,
+    collection: &str,
+    body: &DatasetCreateBody,
+) -> Result<Vec<ValidationError>, String> {
+    let response = get_metadata_blocks(client, collection).await?;
+    if !response.status.is_ok() {
+        return Err(response.message.map(|m| m.to_string()).unwrap_or_default());
+    }
+
+    Ok(validate_against_blocks(body, &response.data.unwrap_or_default()))
+}
+
+/// Validates the structure of a dataset body without consulting an instance.
+///
+/// Unlike deserializing into a `DatasetCreateBody`, which stops at the first mistake, each field of each
+/// metadata block is checked for the `typeName`, `multiple`, `typeClass` and `value` entries, including the
+/// child fields of compound fields. Once the structure is sound, the body is deserialized to check the
+/// remaining parts, such as the license.
+///
+/// # Arguments
+///
+/// * `body` - The dataset body as JSON, e.g. read from a JSON or YAML file.
+///
+/// # Returns
+///
+/// The `ValidationError`s of the body, with paths relative to its root, e.g.
+/// `datasetVersion.metadataBlocks.citation.fields[1].typeClass`.
+pub fn validate_structure(body: &Value) -> Vec<ValidationError> {
+    let path = "datasetVersion.metadataBlocks";
+    let blocks = match body.pointer("/datasetVersion/metadataBlocks") {
+        Some(Value::Object(blocks)) => blocks,
+        Some(_) => return vec![error(path, "Expected an object of metadata blocks".to_string())],
+        None => return vec![error(path, "Required field is missing".to_string())],
+    };
+
+    let mut errors = Vec::new();
+    for (name, block) in blocks {
+        let path = format!("{}.{}.fields", path, name);
+        match block["fields"].as_array() {
+            Some(fields) => fields
+                .iter()
+                .enumerate()
+                .for_each(|(index, field)| check_entries(&format!("{}[{}]", path, index), field, &mut errors)),
+            None => errors.push(error(&path, "Expected a list of fields".to_string())),
+        }
+    }
+
+    if errors.is_empty() {
+        if let Err(e) = serde_json::from_value::<DatasetCreateBody>(body.clone()) {
+            errors.push(error("datasetVersion", e.to_string()));
+        }
+    }
+
+    errors
+}
+
+// Checks that a field has all entries, and those of its children if it is a compound field
+fn check_entries(path: &str, field: &Value, errors: &mut Vec<ValidationError>) {
+    let Some(entries) = field.as_object() else {
+        errors.push(error(path, "Expected an object with typeName, multiple, typeClass and value".to_string()));
+        return;
+    };
+
+    if !entries.get("typeName").is_some_and(Value::is_string) {
+        errors.push(error(&format!("{}.typeName", path), "Expected the name of the field".to_string()));
+    }
+    if !entries.get("multiple").is_some_and(Value::is_boolean) {
+        errors.push(error(&format!("{}.multiple", path), "Expected true or false".to_string()));
+    }
+
+    let type_class = entries.get("typeClass").and_then(Value::as_str);
+    if !matches!(type_class, Some("primitive" | "controlledVocabulary" | "compound")) {
+        let message = "Expected one of: primitive; controlledVocabulary; compound".to_string();
+        errors.push(error(&format!("{}.typeClass", path), message));
+    }
+
+    let path = format!("{}.value", path);
+    let values: Vec<(String, &Value)> = match entries.get("value") {
+        Some(Value::Array(values)) => values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (format!("{}[{}]", path, index), value))
+            .collect(),
+        Some(value) => vec![(path, value)],
+        None => {
+            errors.push(error(&path, "Required field is missing".to_string()));
+            return;
+        }
+    };
+
+    for (path, value) in values {
+        match (type_class, value) {
+            (Some("compound"), Value::Object(children)) => {
+                for (name, child) in children {
+                    check_entries(&format!("{}.{}", path, name), child, errors);
+                }
+            }
+            (Some("compound"), _) => errors.push(error(&path, "Expected an object of child fields".to_string())),
+            (_, Value::String(_)) => {}
+            (_, _) => errors.push(error(&path, "Expected a string".to_string())),
+        }
+    }
+}
+
+/// Validates a dataset body against the given metadata block definitions.
+///
+/// The metadata blocks of the body have to be enabled, and each field has to be defined by its block with
+/// matching `multiple` and `typeClass` entries. Values of controlled vocabularies have to be one of the
+/// allowed values, and primitive values have to match the type of the field, e.g. dates given as `YYYY`,
+/// `YYYY-MM` or `YYYY-MM-DD`. Required fields have to be present; required child fields only within their
+/// compound fields.
+///
+/// # Arguments
+///
+/// * `body` - The `DatasetCreateBody` to validate.
+/// * `blocks` - The `MetadataBlock`s, as returned by `collection::get_metadata_blocks`.
+///
+/// # Returns
+///
+/// The `ValidationError`s of the body in the order of its fields, followed by missing required fields.
+pub fn validate_against_blocks(body: &DatasetCreateBody, blocks: &[MetadataBlock]) -> Vec<ValidationError> {
+    let value = serde_json::to_value(body).unwrap_or_default();
+    let submitted = value
+        .pointer("/datasetVersion/metadataBlocks")
+        .and_then(Value::as_object)
+        .cloned()
+        .unwrap_or_default();
+
+    let mut errors = Vec::new();
+    for (name, block) in &submitted {
+        let Some(definition) = blocks.iter().find(|b| &b.name == name) else {
+            errors.push(error(name, "Metadata block is not enabled for the collection".to_string()));
+            continue;
+        };
+
+        for field in block["fields"].as_array().into_iter().flatten() {
+            let type_name = field["typeName"].as_str().unwrap_or_default();
+            let path = format!("{}.{}", name, type_name);
+            match definition.fields.get(type_name) {
+                Some(definition) => validate_field(&path, field, definition, &mut errors),
+                None => errors.push(error(&path, "Unknown field".to_string())),
+            }
+        }
+    }
+
+    for block in blocks {
+        let present: Vec<&str> = submitted
+            .get(&block.name)
+            .and_then(|block| block["fields"].as_array())
+            .into_iter()
+            .flatten()
+            .filter_map(|field| field["typeName"].as_str())
+            .collect();
+
+        for field in block.fields.values().filter(|f| f.is_required && !present.contains(&f.name.as_str())) {
+            let path = format!("{}.{}", block.name, field.name);
+            errors.push(error(&path, "Required field is missing".to_string()));
+        }
+    }
+
+    errors
+}
+
+// Checks the entries and values of a field with `typeName`, `multiple`, `typeClass` and `value`
+fn validate_field(path: &str, field: &Value, definition: &FieldDefinition, errors: &mut Vec<ValidationError>) {
+    if field["multiple"].as_bool() != Some(definition.multiple) {
+        errors.push(error(path, format!("Expected 'multiple' to be {}", definition.multiple)));
+    }
+    if field["typeClass"].as_str() != Some(definition.type_class()) {
+        errors.push(error(path, format!("Expected 'typeClass' to be '{}'", definition.type_class())));
+        return;
+    }
+
+    let values: Vec<(String, &Value)> = match (&field["value"], definition.multiple) {
+        (Value::Array(values), true) => values
+            .iter()
+            .enumerate()
+            .map(|(index, value)| (format!("{}[{}]", path, index), value))
+            .collect(),
+        (Value::Array(_), false) => {
+            errors.push(error(path, "Expected a single value".to_string()));
+            return;
+        }
+        (_, true) => {
+            errors.push(error(path, "Expected a list of values".to_string()));
+            return;
+        }
+        (value, false) => vec![(path.to_string(), value)],
+    };
+
+    for (path, value) in values {
+        match definition.type_class() {
+            "compound" => match value.as_object() {
+                Some(children) => validate_children(&path, children, definition, errors),
+                None => errors.push(error(&path, "Expected an object of child fields".to_string())),
+            },
+            _ => match value.as_str() {
+                Some(value) => {
+                    if let Err(message) = check_value(value, definition) {
+                        errors.push(error(&path, message));
+                    }
+                }
+                None => errors.push(error(&path, "Expected a string".to_string())),
+            },
+        }
+    }
+}
+
+fn validate_children(
+    path: &str,
+    children: &Map<String, Value>,
+    definition: &FieldDefinition,
+    errors: &mut Vec<ValidationError>,
+) {
+    for (name, child) in children {
+        let path = format!("{}.{}", path, name);
+        match definition.child_fields.get(name) {
+            Some(definition) => validate_field(&path, child, definition, errors),
+            None => errors.push(error(&path, "Unknown child field".to_string())),
+        }
+    }
+
+    for child in definition.child_fields.values().filter(|c| c.is_required && !children.contains_key(&c.name)) {
+        errors.push(error(&format!("{}.{}", path, child.name), "Required field is missing".to_string()));
+    }
+}
+
+fn check_value(value: &str, definition: &FieldDefinition) -> Result<(), String> {
+    if value.trim().is_empty() {
+        return Err("Value is empty".to_string());
+    }
+
+    if definition.is_controlled_vocabulary {
+        return match definition.controlled_vocabulary_values.iter().any(|allowed| allowed == value) {
+            true => Ok(()),
+            false => Err(format!(
+                "'{}' is not one of the allowed values: {}",
+                value,
+                definition.controlled_vocabulary_values.join("; ")
+            )),
+        };
+    }
+
+    let (valid, expected) = match definition.field_type.as_str() {
+        "INT" => (value.parse::<i64>().is_ok(), "an integer"),
+        "FLOAT" => (value.parse::<f64>().is_ok(), "a number"),
+        "DATE" => (is_date(value), "a date as YYYY, YYYY-MM or YYYY-MM-DD"),
+        "EMAIL" => (is_email(value), "an email address"),
+        "URL" => (reqwest::Url::parse(value).is_ok(), "a URL"),
+        _ => (true, ""),
+    };
+
+    match valid {
+        true => Ok(()),
+        false => Err(format!("Expected {}, found '{}'", expected, value)),
+    }
+}
+
+fn is_date(value: &str) -> bool {
+    let date = match value.len() {
+        4 => format!("{}-01-01", value),
+        7 => format!("{}-01", value),
+        _ => value.to_string(),
+    };
+    chrono::NaiveDate::parse_from_str(&date, "%Y-%m-%d").is_ok()
+}
+
+fn is_email(value: &str) -> bool {
+    match value.split_once('@') {
+        Some((local, domain)) => !local.is_empty() && domain.contains('.') && !domain.contains('@'),
+        None => false,
+    }
+}
+
+fn error(path: &str, message: String) -> ValidationError {
+    ValidationError { path: path.to_string(), message }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::native_api::collection::metadatablocks::MetadataBlock;
+    use crate::native_api::dataset::create::DatasetCreateBody;
+    use crate::prelude::*;
+
+    use super::{validate_against_blocks, validate_dataset, validate_structure};
+
+    fn blocks() -> serde_json::Value {
+        json!([{
+            "name": "citation",
+            "displayName": "Citation Metadata",
+            "fields": {
+                "title": {"name": "title", "type": "TEXT", "multiple": false, "isRequired": true},
+                "author": {
+                    "name": "author", "type": "NONE", "multiple": true, "isRequired": true,
+                    "childFields": {
+                        "authorName": {"name": "authorName", "type": "TEXT", "multiple": false, "isRequired": true},
+                        "authorAffiliation": {"name": "authorAffiliation", "type": "TEXT", "multiple": false}
+                    }
+                },
+                "subject": {
+                    "name": "subject", "type": "TEXT", "multiple": true, "isRequired": true,
+                    "isControlledVocabulary": true,
+                    "controlledVocabularyValues": ["Chemistry", "Other"]
+                },
+                "distributionDate": {"name": "distributionDate", "type": "DATE", "multiple": false}
+            }
+        }])
+    }
+
+    fn body(fields: serde_json::Value) -> DatasetCreateBody {
+        serde_json::from_value(json!({
+            "datasetVersion": {"metadataBlocks": {"citation": {"displayName": "Citation Metadata", "fields": fields}}}
+        }))
+        .unwrap()
+    }
+
+    /// Tests that each violation is reported along with the path of the field.
+    #[test]
+    fn test_validate_against_blocks() {
+        let blocks: Vec<MetadataBlock> = serde_json::from_value(blocks()).unwrap();
+        let body = body(json!([
+            {"typeName": "title", "multiple": false, "typeClass": "primitive", "value": "Soil moisture"},
+            {"typeName": "author", "multiple": true, "typeClass": "compound", "value": [
+                {"authorName": {"typeName": "authorName", "multiple": false, "typeClass": "primitive", "value": "Doe, Jane"}},
+                {"authorAffiliation": {"typeName": "authorAffiliation", "multiple": false, "typeClass": "primitive", "value": "Example"}}
+            ]},
+            {"typeName": "subject", "multiple": true, "typeClass": "controlledVocabulary", "value": ["Soil Science"]},
+            {"typeName": "distributionDate", "multiple": false, "typeClass": "primitive", "value": "2024-13"},
+            {"typeName": "color", "multiple": false, "typeClass": "primitive", "value": "blue"}
+        ]));
+
+        let errors: Vec<String> = validate_against_blocks(&body, &blocks).iter().map(|e| e.to_string()).collect();
+
+        assert_eq!(
+            errors,
+            vec![
+                "citation.author[1].authorName: Required field is missing",
+                "citation.subject[0]: 'Soil Science' is not one of the allowed values: Chemistry; Other",
+                "citation.distributionDate: Expected a date as YYYY, YYYY-MM or YYYY-MM-DD, found '2024-13'",
+                "citation.color: Unknown field",
+            ]
+        );
+    }
+
+    /// Tests that structural mistakes are reported per field instead of at the first one.
+    #[test]
+    fn test_validate_structure() {
+        let body = json!({"datasetVersion": {"metadataBlocks": {"citation": {"fields": [
+            {"typeName": "title", "multiple": "no", "typeClass": "primitive", "value": "Soil moisture"},
+            {"typeName": "author", "multiple": true, "typeClass": "compound", "value": [
+                {"authorName": {"typeName": "authorName", "multiple": false, "typeClass": "text", "value": 5}}
+            ]},
+            {"typeName": "subject", "multiple": true, "typeClass": "controlledVocabulary"}
+        ]}}}});
+
+        let errors: Vec<String> = validate_structure(&body).iter().map(|e| e.to_string()).collect();
+
+        assert_eq!(
+            errors,
+            vec![
+                "datasetVersion.metadataBlocks.citation.fields[0].multiple: Expected true or false",
+                "datasetVersion.metadataBlocks.citation.fields[1].value[0].authorName.typeClass: Expected one of: primitive; controlledVocabulary; compound",
+                "datasetVersion.metadataBlocks.citation.fields[1].value[0].authorName.value: Expected a string",
+                "datasetVersion.metadataBlocks.citation.fields[2].value: Required field is missing",
+            ]
+        );
+
+        let valid = json!({"datasetVersion": {"metadataBlocks": {"citation": {"fields": [
+            {"typeName": "title", "multiple": false, "typeClass": "primitive", "value": "Soil moisture"}
+        ]}}}});
+        assert!(validate_structure(&valid).is_empty());
+        assert_eq!(validate_structure(&json!({})).len(), 1);
+    }
+
+    /// Tests that the blocks of the collection are fetched and missing required fields reported.
+    #[tokio::test]
+    async fn test_validate_dataset() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET)
+                .path("/api/dataverses/root/metadatablocks")
+                .query_param("returnDatasetFieldTypes", "true");
+            then.status(200).json_body(json!({"status": "OK", "data": blocks()}));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let body = body(json!([
+            {"typeName": "title", "multiple": true, "typeClass": "primitive", "value": ["Soil moisture"]},
+            {"typeName": "subject", "multiple": true, "typeClass": "controlledVocabulary", "value": ["Other"]}
+        ]));
+
+        let errors: Vec<String> = validate_dataset(&client, "root", &body)
+            .await
+            .unwrap()
+            .iter()
+            .map(|e| e.to_string())
+            .collect();
+
+        assert_eq!(
+            errors,
+            vec![
+                "citation.title: Expected 'multiple' to be false",
+                "citation.title: Expected a single value",
+                "citation.author: Required field is missing",
+            ]
+        );
+        mock.assert();
+    }
+}
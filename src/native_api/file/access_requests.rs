@@ -0,0 +1,203 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+// Access to restricted files can be requested if the dataset allows it. Requests
+// are listed, granted and rejected by users who may manage the permissions of the
+// dataset, who are also notified of new requests by the instance.
+
+/// A user who requested access to a restricted file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct AccessRequest {
+    /// The identifier of the user, e.g. `@jdoe`, used to grant or reject the request.
+    pub identifier: String,
+    #[serde(default)]
+    pub first_name: Option<String>,
+    #[serde(default)]
+    pub last_name: Option<String>,
+    #[serde(default)]
+    pub email: Option<String>,
+    #[serde(default)]
+    pub affiliation: Option<String>,
+}
+
+/// Requests access to a restricted file on behalf of the user of the API token.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let response = file::request_access(&client, Identifier::Id(42)).await?;
+///
+/// println!("{:?}", response.data.and_then(|data| data.message));
+/// # Ok(())
+/// # }
+/// ```
+pub async fn request_access(client: &BaseClient, id: Identifier) -> Result<Response<MessageResponse>, String> {
+    let (url, parameters) = datafile_endpoint(id, "requestAccess");
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.put(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Lists the pending access requests of a restricted file.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<AccessRequest>>`, which contains the HTTP response status and the users
+/// who requested access, if the request is successful, or a `String` error message on failure.
+pub async fn list_access_requests(
+    client: &BaseClient,
+    id: Identifier,
+) -> Result<Response<Vec<AccessRequest>>, String> {
+    let (url, parameters) = datafile_endpoint(id, "listRequests");
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<Vec<AccessRequest>>(response).await
+}
+
+/// Grants a user access to a restricted file, which also resolves their pending request.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+/// * `user` - A string slice that holds the identifier of the user, e.g. `@jdoe`.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn grant_access(
+    client: &BaseClient,
+    id: Identifier,
+    user: &str,
+) -> Result<Response<MessageResponse>, String> {
+    let (url, parameters) = datafile_endpoint(id, &format!("grantAccess/{}", user));
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.put(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Rejects the access request of a user to a restricted file.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+/// * `user` - A string slice that holds the identifier of the user, e.g. `@jdoe`.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn reject_access(
+    client: &BaseClient,
+    id: Identifier,
+    user: &str,
+) -> Result<Response<MessageResponse>, String> {
+    let (url, parameters) = datafile_endpoint(id, &format!("rejectAccess/{}", user));
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.put(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+fn datafile_endpoint(id: Identifier, action: &str) -> (String, Option<HashMap<String, String>>) {
+    match id {
+        Identifier::PersistentId(id) => (
+            format!("api/access/datafile/:persistentId/{}", action),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/access/datafile/{}/{}", id, action), None),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    use crate::prelude::*;
+
+    /// Tests that pending requests are listed and a requesting user is granted access.
+    #[tokio::test]
+    async fn test_list_and_grant_access() {
+        let server = MockServer::start();
+        let list = server.mock(|when, then| {
+            when.method(GET).path("/api/access/datafile/42/listRequests");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": [{
+                    "identifier": "@jdoe",
+                    "firstName": "Jane",
+                    "lastName": "Doe",
+                    "email": "jane.doe@example.edu",
+                    "affiliation": "Example University"
+                }]
+            }));
+        });
+        let grant = server.mock(|when, then| {
+            when.method(PUT).path("/api/access/datafile/42/grantAccess/@jdoe");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"message": "Access to File survey.csv granted to @jdoe"}
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+
+        let response = file::list_access_requests(&client, Identifier::Id(42)).await.unwrap();
+        let requests = response.data.unwrap();
+        assert_eq!(requests[0].identifier, "@jdoe");
+
+        let response = file::grant_access(&client, Identifier::Id(42), &requests[0].identifier).await.unwrap();
+        assert!(response.status.is_ok());
+
+        list.assert();
+        grant.assert();
+    }
+}
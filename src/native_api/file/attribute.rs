@@ -0,0 +1,151 @@
+use std::str::FromStr;
+
+use crate::{
+    client::BaseClient,
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    response::Response,
+};
+
+use super::metadata::{update_file_metadata, FileMetadataBody};
+
+/// Attributes of a file that can be changed individually.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum FileAttribute {
+    Label,
+    DirectoryLabel,
+    Description,
+    /// Whether access to the file is restricted, given as `true` or `false`.
+    Restrict,
+    ProvFreeForm,
+}
+
+impl FileAttribute {
+    pub fn as_str(&self) -> &str {
+        match self {
+            FileAttribute::Label => "label",
+            FileAttribute::DirectoryLabel => "directoryLabel",
+            FileAttribute::Description => "description",
+            FileAttribute::Restrict => "restrict",
+            FileAttribute::ProvFreeForm => "provFreeForm",
+        }
+    }
+}
+
+impl FromStr for FileAttribute {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "label" => Ok(FileAttribute::Label),
+            "directoryLabel" => Ok(FileAttribute::DirectoryLabel),
+            "description" => Ok(FileAttribute::Description),
+            "restrict" => Ok(FileAttribute::Restrict),
+            "provFreeForm" => Ok(FileAttribute::ProvFreeForm),
+            _ => Err(format!("Invalid file attribute: {}", s)),
+        }
+    }
+}
+
+/// Changes a single attribute of a file.
+///
+/// This asynchronous function sends only the given attribute to the metadata endpoint of a file via
+/// `update_file_metadata`, leaving all other metadata of the file unchanged. Changing an attribute of a
+/// published file creates a new draft of its dataset.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+/// * `attribute` - The `FileAttribute` to change.
+/// * `value` - A string slice that holds the new value of the attribute.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure or if the value is
+/// invalid for the attribute.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::file::attribute::FileAttribute;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let response = file::set_file_attribute(
+///     &client, Identifier::Id(42), FileAttribute::Description, "Cleaned measurements",
+/// ).await?;
+///
+/// println!("Updated file: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn set_file_attribute(
+    client: &BaseClient,
+    id: Identifier,
+    attribute: FileAttribute,
+    value: &str,
+) -> Result<Response<MessageResponse>, String> {
+    let body = attribute_body(attribute, value)?;
+    update_file_metadata(client, id, &body).await
+}
+
+// Builds a metadata body holding only the given attribute
+fn attribute_body(attribute: FileAttribute, value: &str) -> Result<FileMetadataBody, String> {
+    let builder = FileMetadataBody::builder();
+    let builder = match attribute {
+        FileAttribute::Label => builder.label(Some(value.to_string())),
+        FileAttribute::DirectoryLabel => builder.directory_label(Some(value.to_string())),
+        FileAttribute::Description => builder.description(Some(value.to_string())),
+        FileAttribute::ProvFreeForm => builder.prov_free_form(Some(value.to_string())),
+        FileAttribute::Restrict => {
+            let restrict = value
+                .parse::<bool>()
+                .map_err(|_| "The restriction of a file must be 'true' or 'false'".to_string())?;
+            builder.restrict(Some(restrict))
+        }
+    };
+
+    builder
+        .try_into()
+        .map_err(|e| format!("Invalid file metadata: {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{attribute_body, FileAttribute};
+
+    /// Tests that only the given attribute is sent, with restrictions as booleans.
+    #[test]
+    fn test_attribute_body() {
+        let body = attribute_body(FileAttribute::Description, "Cleaned").unwrap();
+        assert_eq!(serde_json::to_value(&body).unwrap(), serde_json::json!({"description": "Cleaned"}));
+
+        let body = attribute_body(FileAttribute::Restrict, "true").unwrap();
+        assert_eq!(serde_json::to_value(&body).unwrap(), serde_json::json!({"restrict": true}));
+
+        assert!(attribute_body(FileAttribute::Restrict, "yes").is_err());
+    }
+
+    /// Tests the parsing of file attributes from string literals.
+    #[test]
+    fn test_file_attribute_from_str() {
+        for attribute in [
+            FileAttribute::Label,
+            FileAttribute::DirectoryLabel,
+            FileAttribute::Description,
+            FileAttribute::Restrict,
+            FileAttribute::ProvFreeForm,
+        ] {
+            assert_eq!(attribute.as_str().parse::<FileAttribute>(), Ok(attribute));
+        }
+
+        assert!("invalid".parse::<FileAttribute>().is_err());
+    }
+}
@@ -0,0 +1,314 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{access::datafile::error_message, client::BaseClient, identifier::Identifier, request::RequestType};
+
+/// Variable-level metadata of an ingested tabular file, as described by its DDI codebook.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct DataDictionary {
+    /// The variables in the order of the columns of the file
+    pub variables: Vec<Variable>,
+}
+
+/// A single variable, i.e. a column, of a tabular file.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Variable {
+    pub id: String,
+    pub name: String,
+    pub label: Option<String>,
+    pub interval: Option<Interval>,
+    pub format: Option<VariableFormat>,
+    /// Summary statistics by their DDI type, e.g. `mean`, `medn`, `stdev`, `min`, `max`, `vald` or `invd`
+    pub statistics: BTreeMap<String, f64>,
+    pub categories: Vec<Category>,
+}
+
+/// Whether the values of a variable are discrete or continuous.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Interval {
+    Discrete,
+    Continuous,
+}
+
+/// Whether the values of a variable are numbers or text.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum VariableFormat {
+    Numeric,
+    Character,
+}
+
+/// A value of a categorical variable along with its label and frequency.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Category {
+    pub value: String,
+    pub label: Option<String>,
+    pub frequency: Option<f64>,
+    /// Whether the value marks a missing observation
+    pub missing: bool,
+}
+
+impl DataDictionary {
+    /// Parses the variables of a DDI codebook as served by the data access API.
+    ///
+    /// Elements and attributes that are not part of the typed structs, such as the file description or
+    /// notes, are ignored.
+    pub fn from_xml(xml: &str) -> Result<Self, String> {
+        let codebook: CodeBook = quick_xml::de::from_str(xml).map_err(|e| e.to_string())?;
+
+        let variables = codebook
+            .data_dscr
+            .map(|data| data.vars)
+            .unwrap_or_default()
+            .into_iter()
+            .map(Variable::from)
+            .collect();
+
+        Ok(DataDictionary { variables })
+    }
+
+    /// Returns the variable of the given name, if any.
+    pub fn variable(&self, name: &str) -> Option<&Variable> {
+        self.variables.iter().find(|variable| variable.name == name)
+    }
+}
+
+/// Retrieves the data dictionary of an ingested tabular file.
+///
+/// This asynchronous function sends a GET request to the DDI metadata endpoint of the data access API and
+/// parses the variable-level metadata of the returned codebook into a `DataDictionary`, such that labels,
+/// summary statistics and categories can be inspected without handling XML. Files that have not been
+/// ingested as tabular data have no data dictionary and result in an error.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `DataDictionary` of the file, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let dictionary = file::get_data_dictionary(&client, Identifier::Id(42)).await?;
+///
+/// for variable in &dictionary.variables {
+///     println!("{}: {:?}", variable.name, variable.statistics.get("mean"));
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_data_dictionary(client: &BaseClient, id: Identifier) -> Result<DataDictionary, String> {
+    // Endpoint metadata
+    let (url, parameters) = match id {
+        Identifier::PersistentId(id) => (
+            "api/access/datafile/:persistentId/metadata/ddi".to_string(),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/access/datafile/{}/metadata/ddi", id), None),
+    };
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client
+        .get(url.as_str(), parameters, &context)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if !response.status().is_success() {
+        return Err(error_message(response).await);
+    }
+
+    let xml = response.text().await.map_err(|e| e.to_string())?;
+    DataDictionary::from_xml(&xml)
+}
+
+// Raw structure of the DDI codebook, reduced to the variable descriptions
+#[derive(Deserialize)]
+struct CodeBook {
+    #[serde(rename = "dataDscr")]
+    data_dscr: Option<DataDscr>,
+}
+
+#[derive(Deserialize)]
+struct DataDscr {
+    #[serde(rename = "var", default)]
+    vars: Vec<Var>,
+}
+
+#[derive(Deserialize)]
+struct Var {
+    #[serde(rename = "@ID")]
+    id: String,
+    #[serde(rename = "@name")]
+    name: String,
+    #[serde(rename = "@intrvl")]
+    intrvl: Option<String>,
+    labl: Option<Text>,
+    #[serde(rename = "sumStat", default)]
+    sum_stats: Vec<SumStat>,
+    #[serde(rename = "catgry", default)]
+    categories: Vec<Catgry>,
+    #[serde(rename = "varFormat")]
+    var_format: Option<VarFormat>,
+}
+
+#[derive(Deserialize)]
+struct Text {
+    #[serde(rename = "$text", default)]
+    text: String,
+}
+
+#[derive(Deserialize)]
+struct SumStat {
+    #[serde(rename = "@type")]
+    kind: String,
+    #[serde(rename = "$text", default)]
+    value: String,
+}
+
+#[derive(Deserialize)]
+struct Catgry {
+    #[serde(rename = "@missing")]
+    missing: Option<String>,
+    #[serde(rename = "catValu")]
+    value: Option<Text>,
+    labl: Option<Text>,
+    #[serde(rename = "catStat", default)]
+    stats: Vec<SumStat>,
+}
+
+#[derive(Deserialize)]
+struct VarFormat {
+    #[serde(rename = "@type")]
+    kind: Option<String>,
+}
+
+impl From<Var> for Variable {
+    fn from(var: Var) -> Self {
+        Variable {
+            id: var.id,
+            name: var.name,
+            label: var.labl.map(|labl| labl.text.trim().to_string()),
+            interval: match var.intrvl.as_deref() {
+                Some("discrete") => Some(Interval::Discrete),
+                Some("contin") => Some(Interval::Continuous),
+                _ => None,
+            },
+            format: match var.var_format.and_then(|format| format.kind).as_deref() {
+                Some("numeric") => Some(VariableFormat::Numeric),
+                Some("character") => Some(VariableFormat::Character),
+                _ => None,
+            },
+            // Statistics that are not numbers, such as NaN written as text, are dropped
+            statistics: var
+                .sum_stats
+                .into_iter()
+                .filter_map(|stat| Some((stat.kind, stat.value.trim().parse::<f64>().ok()?)))
+                .filter(|(_, value)| value.is_finite())
+                .collect(),
+            categories: var
+                .categories
+                .into_iter()
+                .map(|category| Category {
+                    value: category.value.map(|value| value.text.trim().to_string()).unwrap_or_default(),
+                    label: category.labl.map(|labl| labl.text.trim().to_string()),
+                    frequency: category
+                        .stats
+                        .iter()
+                        .find(|stat| stat.kind == "freq")
+                        .and_then(|stat| stat.value.trim().parse().ok()),
+                    missing: category.missing.as_deref() == Some("Y"),
+                })
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use crate::prelude::*;
+
+    use super::{DataDictionary, Interval, VariableFormat};
+
+    const DDI: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<codeBook xmlns="ddi:codebook:2_5" version="2.5">
+  <fileDscr ID="f42"><fileTxt><fileName>survey.tab</fileName></fileTxt></fileDscr>
+  <dataDscr>
+    <var ID="v1" name="age" intrvl="contin">
+      <location fileid="f42"/>
+      <labl level="variable">Age in years</labl>
+      <sumStat type="mean">32.5</sumStat>
+      <sumStat type="max">89.0</sumStat>
+      <sumStat type="mode">.</sumStat>
+      <varFormat type="numeric"/>
+    </var>
+    <var ID="v2" name="sex" intrvl="discrete">
+      <labl level="variable">Sex</labl>
+      <catgry><catValu>1</catValu><labl level="category">Female</labl><catStat type="freq">12</catStat></catgry>
+      <catgry missing="Y"><catValu>9</catValu><catStat type="freq">1</catStat></catgry>
+      <varFormat type="numeric"/>
+    </var>
+    <var ID="v3" name="comment">
+      <varFormat type="character"/>
+    </var>
+  </dataDscr>
+</codeBook>"#;
+
+    /// Tests the parsing of variables, statistics and categories from a DDI codebook.
+    #[test]
+    fn test_parse_data_dictionary() {
+        let dictionary = DataDictionary::from_xml(DDI).expect("Failed to parse DDI");
+
+        assert_eq!(dictionary.variables.len(), 3);
+
+        let age = dictionary.variable("age").unwrap();
+        assert_eq!(age.label.as_deref(), Some("Age in years"));
+        assert_eq!(age.interval, Some(Interval::Continuous));
+        assert_eq!(age.statistics.get("mean"), Some(&32.5));
+        assert!(!age.statistics.contains_key("mode"));
+
+        let sex = dictionary.variable("sex").unwrap();
+        assert_eq!(sex.categories.len(), 2);
+        assert_eq!(sex.categories[0].label.as_deref(), Some("Female"));
+        assert_eq!(sex.categories[0].frequency, Some(12.0));
+        assert!(sex.categories[1].missing);
+
+        let comment = dictionary.variable("comment").unwrap();
+        assert_eq!(comment.format, Some(VariableFormat::Character));
+        assert!(comment.label.is_none());
+    }
+
+    /// Tests that the codebook is requested from the data access API.
+    #[tokio::test]
+    async fn test_get_data_dictionary() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/api/access/datafile/42/metadata/ddi");
+            then.status(200).header("Content-Type", "text/xml").body(DDI);
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let dictionary = file::get_data_dictionary(&client, Identifier::Id(42))
+            .await
+            .expect("Failed to get data dictionary");
+
+        mock.assert();
+        assert_eq!(dictionary.variables[1].name, "sex");
+    }
+}
@@ -0,0 +1,88 @@
+use std::collections::HashMap;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+/// Deletes a file from the draft version of its dataset.
+///
+/// This asynchronous function sends a DELETE request to the files API. If the file is part of a published
+/// version, it is only removed from the draft and remains accessible in previous versions.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn delete_file(client: &BaseClient, id: Identifier) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let (url, parameters) = match id {
+        Identifier::PersistentId(id) => (
+            "api/files/:persistentId".to_string(),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/files/{}", id), None),
+    };
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+#[cfg(all(test, feature = "fs"))]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::prelude::*;
+    use crate::test_utils::{create_test_dataset, extract_test_env};
+
+    /// Tests deleting a file from a draft dataset.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables,
+    /// uploads a file to a new dataset and deletes it again.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if any of the requests fail.
+    #[tokio::test]
+    async fn test_delete_file() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Upload a file
+        let (id, _) = create_test_dataset(&client, "Root").await;
+        let response = dataset::upload_file_to_dataset(
+            &client,
+            Identifier::Id(id),
+            PathBuf::from("tests/fixtures/file.txt"),
+            None,
+            None,
+        )
+            .await
+            .expect("Failed to upload file");
+        let file_id = response.data.unwrap().file_ids()[0];
+
+        // Delete the file
+        let response = file::delete_file(&client, Identifier::Id(file_id))
+            .await
+            .expect("Failed to delete file");
+
+        assert!(response.status.is_ok());
+    }
+}
@@ -0,0 +1,191 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use serde_json;
+use typify::import_types;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+import_types!(
+    schema = "models/file/embargo.json",
+    struct_builder = true,
+);
+
+/// Sets an embargo on one or more files of a dataset.
+///
+/// This asynchronous function sends a POST request to the API endpoint designated for embargoing files.
+/// The files are selected by their numeric IDs within the `EmbargoBody`, which also specifies the date
+/// at which the files become available again and an optional reason for the embargo.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset containing the files.
+/// * `body` - The `EmbargoBody` struct instance containing the date, reason and file IDs of the embargo.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the deserialized
+/// response data indicating the outcome of the embargo, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::file::embargo::EmbargoBody;
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let body = EmbargoBody {
+///     date_available: "2030-01-01".to_string(),
+///     reason: Some("Pending publication".to_string()),
+///     file_ids: vec![42, 43],
+/// };
+///
+/// let response = file::set_embargo(&client, Identifier::Id(123), body).await?;
+///
+/// println!("Embargo set: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn set_embargo(
+    client: &BaseClient,
+    id: Identifier,
+    body: EmbargoBody,
+) -> Result<Response<MessageResponse>, String> {
+    let body = serde_json::to_string(&body).unwrap();
+    send_embargo_request(client, id, ":set-embargo", body).await
+}
+
+/// Removes the embargo from one or more files of a dataset.
+///
+/// This asynchronous function sends a POST request to the API endpoint designated for lifting embargoes.
+/// The files are selected by their numeric IDs within the `UnembargoBody`.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset containing the files.
+/// * `body` - The `UnembargoBody` struct instance containing the file IDs to lift the embargo from.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the deserialized
+/// response data indicating the outcome of the removal, if the request is successful, or a `String` error message on failure.
+pub async fn remove_embargo(
+    client: &BaseClient,
+    id: Identifier,
+    body: UnembargoBody,
+) -> Result<Response<MessageResponse>, String> {
+    let body = serde_json::to_string(&body).unwrap();
+    send_embargo_request(client, id, ":unset-embargo", body).await
+}
+
+async fn send_embargo_request(
+    client: &BaseClient,
+    id: Identifier,
+    action: &str,
+    body: String,
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let url = match id {
+        Identifier::PersistentId(_) => {
+            format!("api/datasets/:persistentId/files/actions/{}", action)
+        }
+        Identifier::Id(id) => format!("api/datasets/{}/files/actions/{}", id, action),
+    };
+
+    // Build Parameters
+    let parameters = match id {
+        Identifier::PersistentId(id) => Some(HashMap::from([("persistentId".to_string(), id)])),
+        Identifier::Id(_) => None,
+    };
+
+    // Send request
+    let context = RequestType::JSON { body };
+    let response = client.post(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::identifier::Identifier;
+    use crate::prelude::{BaseClient, file};
+    use crate::test_utils::extract_test_env;
+
+    use super::{EmbargoBody, UnembargoBody};
+
+    /// Tests setting an embargo on files of a non-existent dataset.
+    ///
+    /// This test verifies that attempting to embargo files of a dataset that does not exist
+    /// correctly results in an error. It sets up a client using API token and base URL obtained
+    /// from environment variables and requests an embargo for a dataset with a known non-existent ID.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the request unexpectedly succeeds.
+    #[tokio::test]
+    async fn test_set_embargo_non_existent_dataset() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Embargo files of a non-existent dataset
+        let body = EmbargoBody {
+            date_available: "2099-01-01".to_string(),
+            reason: None,
+            file_ids: vec![-1],
+        };
+        let response = file::embargo::set_embargo(&client, Identifier::Id(-1), body)
+            .await.expect("Failed to send embargo request");
+
+        // Assert the request has failed
+        assert!(response.status.is_err());
+    }
+
+    /// Tests removing an embargo from files of a non-existent dataset.
+    ///
+    /// This test verifies that attempting to lift the embargo of files of a dataset that does
+    /// not exist correctly results in an error.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the request unexpectedly succeeds.
+    #[tokio::test]
+    async fn test_remove_embargo_non_existent_dataset() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Lift the embargo of files of a non-existent dataset
+        let body = UnembargoBody { file_ids: vec![-1] };
+        let response = file::embargo::remove_embargo(
+            &client,
+            Identifier::PersistentId("doi:10.5072/FK2/NONEXISTENT".to_string()),
+            body,
+        ).await.expect("Failed to send embargo request");
+
+        // Assert the request has failed
+        assert!(response.status.is_err());
+    }
+}
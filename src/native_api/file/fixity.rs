@@ -0,0 +1,86 @@
+use crate::{
+    checksum::ChecksumAlgorithm,
+    client::{BaseClient, evaluate_response},
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+};
+
+/// Retrieves the checksum algorithm the instance is configured with.
+///
+/// This asynchronous function sends a GET request to the fixity endpoint of the files API, which reports the
+/// algorithm used to compute the checksums of new files. Files uploaded directly to the storage have to be
+/// registered with a checksum of this algorithm.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `ChecksumAlgorithm` of the instance, or a `String` error message on failure or if
+/// the algorithm is not supported.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, None)
+///     .expect("Failed to create client");
+///
+/// let algorithm = file::get_fixity_algorithm(&client).await?;
+///
+/// println!("Checksums are computed with {}", algorithm);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_fixity_algorithm(client: &BaseClient) -> Result<ChecksumAlgorithm, String> {
+    // Endpoint metadata
+    let url = "api/files/fixityAlgorithm";
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url, None, &context).await;
+    let response = evaluate_response::<MessageResponse>(response).await?;
+
+    if response.status.is_err() {
+        return Err(response.message.map(|m| m.to_string()).unwrap_or_default());
+    }
+
+    response
+        .data
+        .and_then(|data| data.message)
+        .ok_or("The instance did not report a checksum algorithm")?
+        .parse()
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use crate::checksum::ChecksumAlgorithm;
+    use crate::prelude::*;
+
+    /// Tests that the reported algorithm is parsed.
+    #[tokio::test]
+    async fn test_get_fixity_algorithm() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/api/files/fixityAlgorithm");
+            then.status(200).json_body(serde_json::json!({
+                "status": "OK",
+                "data": {"message": "SHA-512"}
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let algorithm = file::get_fixity_algorithm(&client)
+            .await
+            .expect("Failed to get fixity algorithm");
+
+        mock.assert();
+        assert_eq!(algorithm, ChecksumAlgorithm::Sha512);
+    }
+}
@@ -0,0 +1,156 @@
+use std::collections::HashMap;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+/// Starts the tabular ingest of a file again.
+///
+/// This asynchronous function sends a POST request to the reingest endpoint of a file. It is useful when the
+/// ingest of a tabular file has failed, or when an improved ingest of a newer version of Dataverse should
+/// produce better variable metadata. The ingest runs in the background and locks the dataset until it is done.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let response = file::reingest_file(&client, Identifier::Id(42)).await?;
+///
+/// println!("Reingest: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn reingest_file(
+    client: &BaseClient,
+    id: Identifier,
+) -> Result<Response<MessageResponse>, String> {
+    send_ingest_request(client, id, "reingest").await
+}
+
+/// Reverts the tabular ingest of a file.
+///
+/// This asynchronous function sends a POST request to the uningest endpoint of a file. The derived tab-delimited
+/// file and its variable metadata are removed, such that the file is served in its original format again. This
+/// is the way out when the ingest produced wrong variable metadata.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn uningest_file(
+    client: &BaseClient,
+    id: Identifier,
+) -> Result<Response<MessageResponse>, String> {
+    send_ingest_request(client, id, "uningest").await
+}
+
+async fn send_ingest_request(
+    client: &BaseClient,
+    id: Identifier,
+    action: &str,
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let (url, parameters) = match id {
+        Identifier::PersistentId(id) => (
+            format!("api/files/:persistentId/{}", action),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/files/{}/{}", id, action), None),
+    };
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.post(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_utils::extract_test_env;
+
+    /// Tests reingesting a non-existent file.
+    ///
+    /// This test verifies that attempting to reingest a file that does not exist correctly
+    /// results in an error.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the request unexpectedly succeeds.
+    #[tokio::test]
+    async fn test_reingest_non_existent_file() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Reingest a non-existent file
+        let response = file::reingest_file(&client, Identifier::Id(-1))
+            .await
+            .expect("Failed to send reingest request");
+
+        // Assert the request has failed
+        assert!(response.status.is_err());
+    }
+
+    /// Tests uningesting a non-existent file.
+    ///
+    /// This test verifies that attempting to uningest a file that does not exist correctly
+    /// results in an error.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the request unexpectedly succeeds.
+    #[tokio::test]
+    async fn test_uningest_non_existent_file() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Uningest a non-existent file
+        let response = file::uningest_file(
+            &client,
+            Identifier::PersistentId("doi:10.5072/FK2/NONEXISTENT".to_string()),
+        )
+            .await
+            .expect("Failed to send uningest request");
+
+        // Assert the request has failed
+        assert!(response.status.is_err());
+    }
+}
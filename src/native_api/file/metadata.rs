@@ -0,0 +1,393 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+use typify::import_types;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+import_types!(
+    schema = "models/file/metadata.json",
+    struct_builder = true,
+);
+
+/// Updates the metadata of a file without replacing its content.
+///
+/// This asynchronous function sends a POST request to the metadata endpoint of a file. Only the fields set in
+/// the body are changed, such that the description, directory, categories or restriction of a file can be
+/// adjusted after the upload. Changing the metadata of a published file creates a new draft of its dataset.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+/// * `body` - A reference to the `FileMetadataBody` holding the fields to change.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::file::metadata::FileMetadataBody;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let body: FileMetadataBody = FileMetadataBody::builder()
+///     .description(Some("Cleaned measurements".to_string()))
+///     .categories(vec!["Data".to_string()])
+///     .try_into()
+///     .expect("Failed to build body");
+///
+/// let response = file::update_file_metadata(&client, Identifier::Id(42), &body).await?;
+///
+/// println!("Updated file: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn update_file_metadata(
+    client: &BaseClient,
+    id: Identifier,
+    body: &FileMetadataBody,
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let (url, parameters) = match id {
+        Identifier::PersistentId(id) => (
+            "api/files/:persistentId/metadata".to_string(),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/files/{}/metadata", id), None),
+    };
+
+    // Send request
+    let body = serde_json::to_string(body).map_err(|e| e.to_string())?;
+    let context = RequestType::Multipart {
+        bodies: Some(HashMap::from([("jsonData".to_string(), body)])),
+        files: None,
+        callbacks: None,
+    };
+    let response = client.post(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Moves a file to another directory of its dataset and optionally renames it.
+///
+/// This asynchronous function uses `update_file_metadata` to change only the directory label and label of a
+/// file. The content of the file is left untouched, such that the folder layout of a
+/// draft dataset can be restructured without re-uploading any data. Leading and trailing slashes of the
+/// directory are removed, and an empty directory moves the file to the root of the dataset.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+/// * `directory` - A string slice that holds the new directory of the file, e.g. `data/cleaned/`.
+/// * `label` - An optional new name of the file.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let response = file::move_file(&client, Identifier::Id(42), "data/cleaned/", None).await?;
+///
+/// println!("Moved file: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn move_file(
+    client: &BaseClient,
+    id: Identifier,
+    directory: &str,
+    label: Option<&str>,
+) -> Result<Response<MessageResponse>, String> {
+    // Leave all other fields unchanged
+    let body: FileMetadataBody = FileMetadataBody::builder()
+        .directory_label(Some(normalize_directory(directory)))
+        .label(label.map(|label| label.to_string()))
+        .try_into()
+        .map_err(|e| format!("Invalid file metadata: {}", e))?;
+
+    update_file_metadata(client, id, &body).await
+}
+
+/// Renames a file, leaving its directory and all other metadata unchanged.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+/// * `label` - A string slice that holds the new name of the file, e.g. `survey.csv`.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn rename_file(
+    client: &BaseClient,
+    id: Identifier,
+    label: &str,
+) -> Result<Response<MessageResponse>, String> {
+    let body: FileMetadataBody = FileMetadataBody::builder()
+        .label(Some(label.to_string()))
+        .try_into()
+        .map_err(|e| format!("Invalid file metadata: {}", e))?;
+
+    update_file_metadata(client, id, &body).await
+}
+
+/// Adds categories to a file, such as `Documentation` or `Data`, or replaces its categories.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+/// * `categories` - The categories to add.
+/// * `replace` - Whether to replace the existing categories instead of adding to them.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let categories = vec!["Documentation".to_string()];
+/// let response = file::set_file_categories(&client, Identifier::Id(42), &categories, false).await?;
+///
+/// println!("Tagged file: {:?}", response);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn set_file_categories(
+    client: &BaseClient,
+    id: Identifier,
+    categories: &[String],
+    replace: bool,
+) -> Result<Response<MessageResponse>, String> {
+    let body = serde_json::json!({ "categories": categories });
+    send_tags(client, id, "categories", body, replace).await
+}
+
+/// Adds tabular tags to an ingested tabular file, such as `Survey`, `Time Series` or `Panel`, or replaces its
+/// tabular tags.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the file.
+/// * `tags` - The tabular tags to add. The instance rejects tags other than `Survey`, `Time Series`, `Panel`,
+///   `Event`, `Genomics`, `Network` and `Geospatial`.
+/// * `replace` - Whether to replace the existing tabular tags instead of adding to them.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn set_tabular_tags(
+    client: &BaseClient,
+    id: Identifier,
+    tags: &[String],
+    replace: bool,
+) -> Result<Response<MessageResponse>, String> {
+    let body = serde_json::json!({ "tabularTags": tags });
+    send_tags(client, id, "tabularTags", body, replace).await
+}
+
+async fn send_tags(
+    client: &BaseClient,
+    id: Identifier,
+    kind: &str,
+    body: serde_json::Value,
+    replace: bool,
+) -> Result<Response<MessageResponse>, String> {
+    // Endpoint metadata
+    let (url, mut parameters) = match id {
+        Identifier::PersistentId(id) => (
+            format!("api/files/:persistentId/metadata/{}", kind),
+            HashMap::from([("persistentId".to_string(), id)]),
+        ),
+        Identifier::Id(id) => (format!("api/files/{}/metadata/{}", id, kind), HashMap::new()),
+    };
+
+    // Build Parameters
+    if replace {
+        parameters.insert("replace".to_string(), "true".to_string());
+    }
+
+    // Send request
+    let context = RequestType::JSON { body: body.to_string() };
+    let response = client.post(url.as_str(), Some(parameters), &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+// Dataverse stores directory labels without leading or trailing separators
+fn normalize_directory(directory: &str) -> String {
+    directory
+        .split(['/', '\\'])
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use crate::prelude::*;
+
+    use super::{normalize_directory, FileMetadataBody};
+
+    /// Tests that the new directory and label are sent as the metadata of the file.
+    #[tokio::test]
+    async fn test_move_file() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/files/42/metadata")
+                .body_contains("\"directoryLabel\":\"data/cleaned\"")
+                .body_contains("\"label\":\"table.csv\"");
+            then.status(200).json_body(serde_json::json!({
+                "status": "OK",
+                "data": {"message": "File Metadata update has been completed"}
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let response = file::move_file(&client, Identifier::Id(42), "/data/cleaned/", Some("table.csv"))
+            .await
+            .expect("Failed to move file");
+
+        mock.assert();
+        assert!(response.status.is_ok());
+    }
+
+    /// Tests that renaming a file leaves its directory unchanged.
+    #[tokio::test]
+    async fn test_rename_file() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/files/42/metadata")
+                .body_contains("\"label\":\"survey.csv\"")
+                .matches(|request| {
+                    let body = String::from_utf8_lossy(request.body.as_deref().unwrap_or_default());
+                    !body.contains("directoryLabel")
+                });
+            then.status(200).json_body(serde_json::json!({
+                "status": "OK",
+                "data": {"message": "File Metadata update has been completed"}
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let response = file::rename_file(&client, Identifier::Id(42), "survey.csv").await.unwrap();
+
+        mock.assert();
+        assert!(response.status.is_ok());
+    }
+
+    /// Tests that only the fields set in the body are sent.
+    #[tokio::test]
+    async fn test_update_file_metadata() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/files/:persistentId/metadata")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123/XYZ")
+                .body_contains("\"restrict\":true")
+                .body_contains("\"categories\":[\"Data\"]");
+            then.status(200).json_body(serde_json::json!({
+                "status": "OK",
+                "data": {"message": "File Metadata update has been completed"}
+            }));
+        });
+
+        let body: FileMetadataBody = serde_json::from_value(serde_json::json!({
+            "restrict": true,
+            "categories": ["Data"]
+        }))
+            .unwrap();
+        assert!(!serde_json::to_string(&body).unwrap().contains("label"));
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123/XYZ".to_string());
+        let response = file::update_file_metadata(&client, id, &body)
+            .await
+            .expect("Failed to update file metadata");
+
+        mock.assert();
+        assert!(response.status.is_ok());
+    }
+
+    /// Tests that tabular tags replace the existing ones if requested.
+    #[tokio::test]
+    async fn test_set_tabular_tags() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/files/42/metadata/tabularTags")
+                .query_param("replace", "true")
+                .json_body(serde_json::json!({"tabularTags": ["Survey", "Panel"]}));
+            then.status(200).json_body(serde_json::json!({
+                "status": "OK",
+                "data": {"message": "Tabular tags updated"}
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let tags = vec!["Survey".to_string(), "Panel".to_string()];
+        let response = file::set_tabular_tags(&client, Identifier::Id(42), &tags, true)
+            .await
+            .expect("Failed to set tabular tags");
+
+        mock.assert();
+        assert!(response.status.is_ok());
+    }
+
+    /// Tests the normalization of directory labels.
+    #[test]
+    fn test_normalize_directory() {
+        assert_eq!(normalize_directory("data/cleaned/"), "data/cleaned");
+        assert_eq!(normalize_directory("/data//raw"), "data/raw");
+        assert_eq!(normalize_directory("data\\raw"), "data/raw");
+        assert_eq!(normalize_directory("/"), "");
+    }
+}
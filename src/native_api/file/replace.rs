@@ -1,16 +1,61 @@
 use std::collections::HashMap;
 use std::path::PathBuf;
 
+use serde::{Deserialize, Serialize};
 use serde_json;
 
+#[cfg(feature = "directupload")]
 use crate::{
+    checksum::ChecksumAlgorithm,
+    native_api::dataset::direct_upload::{upload_files_direct, DirectUploadFile, DirectUploadSeries},
+};
+use crate::{
+    access::mirror::relative_path,
     callback::CallbackFun,
     client::{BaseClient, evaluate_response},
+    identifier::Identifier,
+    native_api::dataset::files::{list_dataset_files, FileSearch},
     native_api::dataset::upload::{UploadBody, UploadResponse},
+    plan::{Action, Plan},
     request::RequestType,
     response::Response,
 };
 
+/// A file uploaded directly to the storage, replacing an existing file of the dataset.
+#[cfg(feature = "directupload")]
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaceFilesEntry {
+    /// The ID of the file to replace.
+    pub file_to_replace_id: i64,
+    /// Allows replacing a file by one of a different content type.
+    #[serde(default)]
+    pub force_replace: bool,
+    /// The storage identifier, name, type and checksum of the new file.
+    #[serde(flatten)]
+    pub file: DirectUploadFile,
+}
+
+/// The outcome of replacing multiple files.
+#[cfg(feature = "directupload")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ReplaceFilesResponse {
+    #[serde(rename = "Files", default)]
+    pub files: Vec<serde_json::Value>,
+    #[serde(rename = "Result", default)]
+    pub result: Option<ReplaceFilesResult>,
+}
+
+/// The number of files replaced by a request.
+#[cfg(feature = "directupload")]
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ReplaceFilesResult {
+    #[serde(rename = "Total number of files", default)]
+    pub total: i64,
+    #[serde(rename = "Number of files successfully replaced", default)]
+    pub replaced: i64,
+}
+
 /// Replaces a file in a dataset identified by a file ID.
 ///
 /// This asynchronous function sends a POST request to the API endpoint designated for replacing files in a dataset.
@@ -59,3 +104,368 @@ pub async fn replace_file(
 
     evaluate_response::<UploadResponse>(response).await
 }
+
+/// Replaces multiple files of a dataset by files uploaded directly to the storage, in a single update of the
+/// draft version.
+///
+/// This asynchronous function sends a POST request to the `replaceFiles` endpoint of a dataset. Compared to
+/// replacing files one after another, the draft is only updated once, which keeps large synchronisations fast.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the unique identifier of the dataset.
+/// * `entries` - The `ReplaceFilesEntry` items naming the file to replace and the uploaded file replacing it.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<ReplaceFilesResponse>`, which contains the HTTP response status and the number
+/// of replaced files, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::dataset::direct_upload::upload_file_direct;
+/// use dataverse::native_api::file::replace::ReplaceFilesEntry;
+/// use dataverse::checksum::ChecksumAlgorithm;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let upload = upload_file_direct(
+///     &client, id.clone(), std::path::Path::new("wave1.csv"), "text/csv", ChecksumAlgorithm::Md5,
+/// ).await?;
+///
+/// let entries = vec![ReplaceFilesEntry { file_to_replace_id: 17, force_replace: false, file: upload.file }];
+/// let response = file::replace_multiple(&client, id, &entries).await?;
+///
+/// println!("{:?}", response.data.and_then(|data| data.result).map(|result| result.replaced));
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "directupload")]
+pub async fn replace_multiple(
+    client: &BaseClient,
+    id: Identifier,
+    entries: &[ReplaceFilesEntry],
+) -> Result<Response<ReplaceFilesResponse>, String> {
+    // Endpoint metadata
+    let (url, parameters) = match id {
+        Identifier::PersistentId(id) => (
+            "api/datasets/:persistentId/replaceFiles".to_string(),
+            Some(HashMap::from([("persistentId".to_string(), id)])),
+        ),
+        Identifier::Id(id) => (format!("api/datasets/{}/replaceFiles", id), None),
+    };
+
+    // Send request
+    let body = serde_json::to_string(entries).map_err(|e| e.to_string())?;
+    let context = RequestType::Multipart {
+        bodies: Some(HashMap::from([("jsonData".to_string(), body)])),
+        files: None,
+        callbacks: None,
+    };
+    let response = client.post(url.as_str(), parameters, &context).await;
+
+    evaluate_response::<ReplaceFilesResponse>(response).await
+}
+
+/// A file of a dataset, referenced by its ID or by its label.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum FileReference {
+    Id(i64),
+    /// The label of the file, optionally prefixed with its directory, e.g. `data/survey.csv`.
+    Label(String),
+}
+
+/// An entry of a replacement manifest, naming a file of a dataset and the local file replacing it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct ManifestEntry {
+    pub file: FileReference,
+    pub path: PathBuf,
+}
+
+/// A file of a dataset resolved from a `ManifestEntry`, along with the local file replacing it.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Replacement {
+    pub file: i64,
+    /// The content type of the replaced file, if the files of the dataset have been listed.
+    pub content_type: Option<String>,
+    pub path: PathBuf,
+}
+
+/// Resolves the entries of a replacement manifest to the IDs of the files to replace.
+///
+/// Files referenced by their label are looked up in the latest version of the dataset, matching either the
+/// label including its directory or, if unique, the label alone. The whole manifest is checked before any file
+/// is replaced, such that a mistake in one entry does not leave the dataset partially updated.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `dataset` - The `Identifier` of the dataset, which is required if any file is referenced by its label.
+/// * `entries` - The `ManifestEntry` items of the manifest.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `Replacement` of every entry, or a `String` error message if a label is unknown or
+/// ambiguous, a local file does not exist or the files of the dataset cannot be listed.
+pub async fn resolve_manifest(
+    client: &BaseClient,
+    dataset: Option<Identifier>,
+    entries: &[ManifestEntry],
+) -> Result<Vec<Replacement>, String> {
+    if let Some(entry) = entries.iter().find(|entry| !entry.path.is_file()) {
+        return Err(format!("File not found: {}", entry.path.display()));
+    }
+
+    let files = match dataset {
+        Some(dataset) => {
+            let response = list_dataset_files(client, dataset, ":latest", &FileSearch::default()).await?;
+            if response.status.is_err() {
+                return Err(response.message.map(|m| m.to_string()).unwrap_or_default());
+            }
+            response.data.unwrap_or_default()
+        }
+        None if entries.iter().any(|entry| matches!(entry.file, FileReference::Label(_))) => {
+            return Err("Files referenced by their label require the dataset".to_string());
+        }
+        None => vec![],
+    };
+
+    // Pairs of the relative path, label, ID and content type of each listed file
+    let listed: Vec<(PathBuf, &str, i64, Option<String>)> = files
+        .iter()
+        .filter_map(|file| {
+            let data_file = file.data_file.as_ref()?;
+            Some((relative_path(file)?, file.label.as_deref()?, data_file.id?, data_file.content_type.clone()))
+        })
+        .collect();
+
+    entries
+        .iter()
+        .map(|entry| {
+            let (file, content_type) = match &entry.file {
+                FileReference::Id(id) => {
+                    let listed = listed.iter().find(|(_, _, listed_id, _)| listed_id == id);
+                    (*id, listed.and_then(|(_, _, _, content_type)| content_type.clone()))
+                }
+                FileReference::Label(label) => {
+                    let by_path: Vec<_> = listed.iter().filter(|(path, ..)| path == &PathBuf::from(label)).collect();
+                    let by_label: Vec<_> = listed.iter().filter(|(_, name, ..)| name == label).collect();
+                    let matches = if by_path.is_empty() { by_label } else { by_path };
+
+                    match matches.as_slice() {
+                        [(_, _, id, content_type)] => (*id, content_type.clone()),
+                        [] => return Err(format!("No file labelled '{}' in the dataset", label)),
+                        _ => return Err(format!("Several files are labelled '{}', please use their ID", label)),
+                    }
+                }
+            };
+
+            Ok(Replacement { file, content_type, path: entry.path.clone() })
+        })
+        .collect()
+}
+
+/// Plans the replacement of files, one request per file, such that it can be reviewed before it is executed.
+///
+/// # Arguments
+///
+/// * `replacements` - The `Replacement` items resolved from a manifest.
+/// * `force` - Whether files may be replaced by files of a different content type.
+///
+/// # Returns
+///
+/// A `Plan` holding a replace action per file.
+pub fn plan_replacements(replacements: &[Replacement], force: bool) -> Plan {
+    let mut plan = Plan::new();
+    for replacement in replacements {
+        let body = force.then(|| UploadBody {
+            force_replace: Some(true),
+            ..UploadBody::builder().try_into().expect("An empty upload body is valid")
+        });
+        let action = Action::Replace {
+            file: replacement.file,
+            path: replacement.path.clone(),
+            body,
+        };
+        plan.push(action, "Listed in the manifest");
+    }
+
+    plan
+}
+
+/// Uploads the replacing files directly to the storage and replaces all of them in a single request.
+///
+/// The files are registered with the content type of the file they replace, or `application/octet-stream`
+/// if it is unknown. Files that fail to upload are reported and left out of the replacement.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `dataset` - The `Identifier` of the dataset the files belong to.
+/// * `replacements` - The `Replacement` items resolved from a manifest.
+/// * `algorithm` - The `ChecksumAlgorithm` to register the files with.
+/// * `concurrency` - The maximum number of simultaneous uploads; zero is treated as one.
+/// * `force` - Whether files may be replaced by files of a different content type.
+///
+/// # Returns
+///
+/// The `DirectUploadSeries` of the uploads, along with the result of replacing the uploaded files, which is
+/// `None` if no file has been uploaded.
+#[cfg(feature = "directupload")]
+pub async fn replace_files_direct(
+    client: &BaseClient,
+    dataset: Identifier,
+    replacements: &[Replacement],
+    algorithm: ChecksumAlgorithm,
+    concurrency: usize,
+    force: bool,
+) -> (DirectUploadSeries, Option<Result<ReplaceFilesResponse, String>>) {
+    let files = replacements
+        .iter()
+        .map(|replacement| {
+            let content_type = replacement.content_type.clone();
+            (replacement.path.clone(), content_type.unwrap_or_else(|| "application/octet-stream".to_string()))
+        })
+        .collect();
+    let series = upload_files_direct(client, dataset.clone(), files, algorithm, concurrency, false).await;
+
+    // Uploads are returned in the order of the replacements, some of which may have failed
+    let mut remaining = replacements.iter();
+    let entries: Vec<ReplaceFilesEntry> = series
+        .succeeded
+        .iter()
+        .filter_map(|(path, upload)| {
+            let replacement = remaining.find(|replacement| &replacement.path == path)?;
+            Some(ReplaceFilesEntry {
+                file_to_replace_id: replacement.file,
+                force_replace: force,
+                file: upload.file.clone(),
+            })
+        })
+        .collect();
+
+    if entries.is_empty() {
+        return (series, None);
+    }
+
+    let result = match replace_multiple(client, dataset, &entries).await {
+        Ok(response) if response.status.is_ok() => {
+            response.data.ok_or_else(|| "The response did not contain any data".to_string())
+        }
+        Ok(response) => Err(response.message.map(|m| m.to_string()).unwrap_or_default()),
+        Err(e) => Err(e),
+    };
+
+    (series, Some(result))
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use httpmock::prelude::*;
+    use serde_json::json;
+
+    #[cfg(feature = "directupload")]
+    use crate::native_api::dataset::direct_upload::{DirectUploadChecksum, DirectUploadFile};
+    use crate::prelude::*;
+
+    #[cfg(feature = "directupload")]
+    use super::ReplaceFilesEntry;
+    use super::{plan_replacements, resolve_manifest, FileReference, ManifestEntry};
+
+    /// Tests that files referenced by ID, path and label are resolved and planned for replacement.
+    #[tokio::test]
+    async fn test_resolve_manifest() {
+        let server = MockServer::start();
+        let files = server.mock(|when, then| {
+            when.method(GET).path("/api/datasets/42/versions/:latest/files");
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": [
+                    {"label": "survey.csv", "directoryLabel": "raw", "dataFile": {"id": 1, "contentType": "text/csv"}},
+                    {"label": "survey.csv", "directoryLabel": "clean", "dataFile": {"id": 2, "contentType": "text/csv"}},
+                    {"label": "readme.txt", "dataFile": {"id": 3, "contentType": "text/plain"}}
+                ]
+            }));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let path = PathBuf::from("tests/fixtures/file.txt");
+        let entry = |file| ManifestEntry { file, path: path.clone() };
+        let entries = vec![
+            entry(FileReference::Id(1)),
+            entry(FileReference::Label("clean/survey.csv".to_string())),
+            entry(FileReference::Label("readme.txt".to_string())),
+        ];
+
+        let replacements = resolve_manifest(&client, Some(Identifier::Id(42)), &entries).await.unwrap();
+        let ids: Vec<i64> = replacements.iter().map(|replacement| replacement.file).collect();
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert_eq!(replacements[2].content_type.as_deref(), Some("text/plain"));
+        assert_eq!(plan_replacements(&replacements, true).to_string().lines().last(), Some("Plan: 3 replace"));
+
+        let ambiguous = vec![entry(FileReference::Label("survey.csv".to_string()))];
+        let result = resolve_manifest(&client, Some(Identifier::Id(42)), &ambiguous).await;
+        assert!(result.unwrap_err().contains("Several files"));
+
+        files.assert_hits(2);
+    }
+
+    /// Tests that all replacements are sent in a single request naming the files they replace.
+    #[cfg(feature = "directupload")]
+    #[tokio::test]
+    async fn test_replace_multiple() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(POST)
+                .path("/api/datasets/:persistentId/replaceFiles")
+                .query_param("persistentId", "doi:10.5072/FK2/ABC123")
+                .body_contains(r#""fileToReplaceId":17"#)
+                .body_contains(r#""fileToReplaceId":18"#)
+                .body_contains(r#""storageIdentifier":"s3://bucket:18f1""#);
+            then.status(200).json_body(json!({
+                "status": "OK",
+                "data": {"Files": [{}, {}], "Result": {"Total number of files": 2, "Number of files successfully replaced": 2}}
+            }));
+        });
+
+        let entries: Vec<ReplaceFilesEntry> = [(17, "s3://bucket:18f1"), (18, "s3://bucket:18f2")]
+            .into_iter()
+            .map(|(file_to_replace_id, storage_identifier)| ReplaceFilesEntry {
+                file_to_replace_id,
+                force_replace: false,
+                file: DirectUploadFile {
+                    storage_identifier: storage_identifier.to_string(),
+                    file_name: "wave1.csv".to_string(),
+                    mime_type: "text/csv".to_string(),
+                    checksum: DirectUploadChecksum {
+                        type_: "MD5".to_string(),
+                        value: "509ef88afa907eaf2c17c1c8d8fde77e".to_string(),
+                    },
+                    description: None,
+                    directory_label: None,
+                    categories: vec![],
+                    restrict: None,
+                    tab_ingest: None,
+                },
+            })
+            .collect();
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+        let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+        let response = file::replace_multiple(&client, id, &entries).await.unwrap();
+
+        assert_eq!(response.data.unwrap().result.unwrap().replaced, 2);
+
+        mock.assert();
+    }
+}
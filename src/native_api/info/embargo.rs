@@ -0,0 +1,75 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    request::RequestType,
+    response::Response,
+};
+
+/// The maximum duration of embargoes on files, as configured by the `:MaxEmbargoDurationInMonths` setting.
+///
+/// The duration is returned as a message, but numbers are accepted as well.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum MaxEmbargoDuration {
+    Months(i64),
+    Text(String),
+    Message { message: String },
+}
+
+impl MaxEmbargoDuration {
+    /// Returns the duration in months, where `-1` means that embargoes are unlimited and `0` that they are
+    /// disabled, or `None` if the duration could not be parsed.
+    pub fn months(&self) -> Option<i64> {
+        match self {
+            MaxEmbargoDuration::Months(months) => Some(*months),
+            MaxEmbargoDuration::Text(text) | MaxEmbargoDuration::Message { message: text } => {
+                text.trim().parse().ok()
+            }
+        }
+    }
+
+    /// Returns whether files can be embargoed at all.
+    pub fn allows_embargoes(&self) -> bool {
+        self.months().is_some_and(|months| months != 0)
+    }
+}
+
+/// Retrieves the maximum duration of embargoes on files of the instance.
+///
+/// Instances that have not configured the setting respond with an error, which means that embargoes are
+/// disabled.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MaxEmbargoDuration>`, which contains the HTTP response status and the
+/// duration, if the request is successful, or a `String` error message on failure.
+pub async fn get_max_embargo_duration(client: &BaseClient) -> Result<Response<MaxEmbargoDuration>, String> {
+    let context = RequestType::Plain;
+    let response = client
+        .get("api/info/settings/:MaxEmbargoDurationInMonths", None, &context)
+        .await;
+
+    evaluate_response::<MaxEmbargoDuration>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::MaxEmbargoDuration;
+
+    /// Tests that all representations of the duration are parsed into months.
+    #[test]
+    fn test_max_embargo_duration_months() {
+        let durations: Vec<MaxEmbargoDuration> =
+            serde_json::from_str(r#"[24, "-1", {"message": "0"}, "forever"]"#).unwrap();
+
+        assert_eq!(durations[0].months(), Some(24));
+        assert!(durations[1].allows_embargoes());
+        assert!(!durations[2].allows_embargoes());
+        assert_eq!(durations[3].months(), None);
+    }
+}
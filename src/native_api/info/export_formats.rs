@@ -0,0 +1,101 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    request::RequestType,
+    response::Response,
+};
+
+/// A metadata format the instance exports datasets in.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportFormat {
+    pub display_name: String,
+    pub media_type: String,
+    /// Whether the format is offered to harvesters via OAI-PMH.
+    #[serde(default)]
+    pub is_harvestable: bool,
+    /// Whether the format is offered in the export menu of the user interface.
+    #[serde(default)]
+    pub is_visible_in_user_interface: bool,
+    #[serde(rename = "XMLNameSpace", default, skip_serializing_if = "Option::is_none")]
+    pub xml_namespace: Option<String>,
+    #[serde(rename = "XMLSchemaLocation", default, skip_serializing_if = "Option::is_none")]
+    pub xml_schema_location: Option<String>,
+    #[serde(rename = "XMLSchemaVersion", default, skip_serializing_if = "Option::is_none")]
+    pub xml_schema_version: Option<String>,
+}
+
+/// Retrieves the metadata export formats of the Dataverse instance, including those of exporter plugins.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<HashMap<String, ExportFormat>>`, which contains the HTTP response status and
+/// the formats by the name used in export requests, e.g. `ddi` or `schema.org`, if the request is successful,
+/// or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+///
+/// # async fn run() -> Result<(), String> {
+/// let client = BaseClient::new("https://demo.dataverse.org", None)
+///     .expect("Failed to create client");
+///
+/// let response = info::get_export_formats(&client).await?;
+///
+/// for (name, format) in response.data.unwrap_or_default() {
+///     println!("{}: {} ({})", name, format.display_name, format.media_type);
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_export_formats(client: &BaseClient) -> Result<Response<HashMap<String, ExportFormat>>, String> {
+    let context = RequestType::Plain;
+    let response = client.get("api/info/exportFormats", None, &context).await;
+
+    evaluate_response::<HashMap<String, ExportFormat>>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::ExportFormat;
+
+    /// Tests parsing export formats with and without XML schema information.
+    #[test]
+    fn test_parse_export_formats() {
+        let formats: HashMap<String, ExportFormat> = serde_json::from_str(
+            r#"{
+                "ddi": {
+                    "displayName": "DDI Codebook v2",
+                    "mediaType": "application/xml",
+                    "isHarvestable": true,
+                    "isVisibleInUserInterface": true,
+                    "XMLNameSpace": "ddi:codebook:2_5",
+                    "XMLSchemaLocation": "https://ddialliance.org/Specification/DDI-Codebook/2.5/XMLSchema/codebook.xsd",
+                    "XMLSchemaVersion": "2.5"
+                },
+                "schema.org": {
+                    "displayName": "Schema.org JSON-LD",
+                    "mediaType": "application/json",
+                    "isHarvestable": false,
+                    "isVisibleInUserInterface": true
+                }
+            }"#,
+        )
+            .expect("Failed to parse export formats");
+
+        assert_eq!(formats["ddi"].xml_schema_version.as_deref(), Some("2.5"));
+        assert!(!formats["schema.org"].is_harvestable);
+        assert_eq!(formats["schema.org"].xml_namespace, None);
+    }
+}
@@ -0,0 +1,40 @@
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+/// Retrieves the host name of the Dataverse instance.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the host
+/// name as message, if the request is successful, or a `String` error message on failure.
+pub async fn get_server(client: &BaseClient) -> Result<Response<MessageResponse>, String> {
+    let context = RequestType::Plain;
+    let response = client.get("api/info/server", None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Retrieves the terms of use for the API of the Dataverse instance.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the terms
+/// as message, if the request is successful, or a `String` error message on failure.
+pub async fn get_api_terms_of_use(client: &BaseClient) -> Result<Response<MessageResponse>, String> {
+    let context = RequestType::Plain;
+    let response = client.get("api/info/apiTermsOfUse", None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
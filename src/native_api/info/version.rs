@@ -58,4 +58,26 @@ mod tests {
             *VersionResponseVersion(DV_VERSION.to_string())
         );
     }
+
+    #[tokio::test]
+    async fn test_get_version_offline() {
+        // Arrange
+        let transport = crate::transport::MockTransport::new().with_response(
+            reqwest::Method::GET,
+            "/api/info/version",
+            200,
+            serde_json::json!({"status": "OK", "data": {"version": "6.2", "build": "1"}}),
+        );
+        let client = BaseClient::new("http://localhost", None)
+            .unwrap()
+            .with_transport(transport);
+
+        // Act
+        let response = get_version(&client)
+            .await
+            .expect("Could not get version");
+
+        // Assert
+        assert_eq!(response.data.unwrap().version.to_string(), "6.2");
+    }
 }
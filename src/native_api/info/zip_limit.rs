@@ -0,0 +1,67 @@
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    request::RequestType,
+    response::Response,
+};
+
+/// The maximum total size of files the instance bundles into a single ZIP download.
+///
+/// Depending on the version of the instance, the limit is returned as a number, a string or
+/// wrapped into a message, hence all representations are accepted.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(untagged)]
+pub enum ZipDownloadLimit {
+    Bytes(u64),
+    Text(String),
+    Message { message: String },
+}
+
+impl ZipDownloadLimit {
+    /// Returns the limit in bytes, if it could be parsed.
+    pub fn bytes(&self) -> Option<u64> {
+        match self {
+            ZipDownloadLimit::Bytes(bytes) => Some(*bytes),
+            ZipDownloadLimit::Text(text) | ZipDownloadLimit::Message { message: text } => {
+                text.trim().parse().ok()
+            }
+        }
+    }
+}
+
+/// Retrieves the maximum total size of a ZIP download of the instance.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<ZipDownloadLimit>`, which contains the HTTP response status and the limit,
+/// if the request is successful, or a `String` error message on failure.
+pub async fn get_zip_download_limit(client: &BaseClient) -> Result<Response<ZipDownloadLimit>, String> {
+    let context = RequestType::Plain;
+    let response = client.get("api/info/zipDownloadLimit", None, &context).await;
+
+    evaluate_response::<ZipDownloadLimit>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ZipDownloadLimit;
+
+    /// Tests that all representations of the limit are parsed into bytes.
+    #[test]
+    fn test_zip_download_limit_bytes() {
+        let limits: Vec<ZipDownloadLimit> = serde_json::from_str(
+            r#"[104857600, "104857600", {"message": "104857600"}, "unlimited"]"#,
+        )
+            .unwrap();
+
+        assert_eq!(limits[0].bytes(), Some(104857600));
+        assert_eq!(limits[1].bytes(), Some(104857600));
+        assert_eq!(limits[2].bytes(), Some(104857600));
+        assert_eq!(limits[3].bytes(), None);
+    }
+}
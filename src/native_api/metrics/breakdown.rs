@@ -0,0 +1,84 @@
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::metrics::counts::{parent_parameters, CategoryCount, SubjectCount},
+    request::RequestType,
+    response::Response,
+};
+
+/// Retrieves the number of datasets per subject.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `parent_alias` - An optional string slice that holds the alias of the collection to scope the counts to.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<SubjectCount>>`, which contains the HTTP response status and the counts
+/// per subject, if the request is successful, or a `String` error message on failure.
+pub async fn get_datasets_by_subject(
+    client: &BaseClient,
+    parent_alias: Option<&str>,
+) -> Result<Response<Vec<SubjectCount>>, String> {
+    // Send request
+    let context = RequestType::Plain;
+    let response = client
+        .get("api/info/metrics/datasets/bySubject", parent_parameters(parent_alias), &context)
+        .await;
+
+    evaluate_response::<Vec<SubjectCount>>(response).await
+}
+
+/// Retrieves the number of collections per category.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `parent_alias` - An optional string slice that holds the alias of the collection to scope the counts to.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<CategoryCount>>`, which contains the HTTP response status and the counts
+/// per category, if the request is successful, or a `String` error message on failure.
+pub async fn get_dataverses_by_category(
+    client: &BaseClient,
+    parent_alias: Option<&str>,
+) -> Result<Response<Vec<CategoryCount>>, String> {
+    // Send request
+    let context = RequestType::Plain;
+    let response = client
+        .get("api/info/metrics/dataverses/byCategory", parent_parameters(parent_alias), &context)
+        .await;
+
+    evaluate_response::<Vec<CategoryCount>>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_utils::extract_test_env;
+
+    /// Tests retrieving the dataset counts per subject and the collection counts per category.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if any of the requests fail.
+    #[tokio::test]
+    async fn test_get_breakdowns() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        let response = metrics::get_datasets_by_subject(&client, None)
+            .await.expect("Failed to get datasets by subject");
+        assert!(response.status.is_ok());
+
+        let response = metrics::get_dataverses_by_category(&client, None)
+            .await.expect("Failed to get collections by category");
+        assert!(response.status.is_ok());
+    }
+}
@@ -0,0 +1,216 @@
+use std::collections::HashMap;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+use typify::import_types;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    request::RequestType,
+    response::Response,
+};
+
+import_types!(
+    schema = "models/info/metrics.json",
+    struct_builder = true,
+);
+
+/// Objects of an instance that metrics are collected for.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Metric {
+    Dataverses,
+    Datasets,
+    Files,
+    Downloads,
+}
+
+impl Metric {
+    pub fn as_str(&self) -> &str {
+        match self {
+            Metric::Dataverses => "dataverses",
+            Metric::Datasets => "datasets",
+            Metric::Files => "files",
+            Metric::Downloads => "downloads",
+        }
+    }
+}
+
+impl FromStr for Metric {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "dataverses" | "collections" => Ok(Metric::Dataverses),
+            "datasets" => Ok(Metric::Datasets),
+            "files" => Ok(Metric::Files),
+            "downloads" => Ok(Metric::Downloads),
+            _ => Err(format!("Invalid metric: {}", s)),
+        }
+    }
+}
+
+/// The period a count refers to.
+#[derive(Debug, Clone, PartialEq)]
+pub enum MetricPeriod {
+    /// All objects up to now.
+    Total,
+    /// All objects up to the end of the given month, formatted as `YYYY-MM`.
+    ToMonth(String),
+    /// All objects added within the given number of days.
+    PastDays(u32),
+}
+
+/// Retrieves the count of a metric for a given period.
+///
+/// This asynchronous function sends a GET request to the metrics API, optionally scoped to a collection and its
+/// sub-collections. Note that metrics are cached by the instance and may lag behind recent changes.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `metric` - The `Metric` to count.
+/// * `period` - A reference to the `MetricPeriod` the count refers to.
+/// * `parent_alias` - An optional string slice that holds the alias of the collection to scope the count to.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<CountResponse>`, which contains the HTTP response status and the count,
+/// if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::native_api::metrics::counts::{Metric, MetricPeriod};
+///
+/// # async fn run() -> Result<(), String> {
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, None)
+///     .expect("Failed to create client");
+///
+/// let period = MetricPeriod::ToMonth("2024-01".to_string());
+/// let response = metrics::get_metric(&client, Metric::Datasets, &period, None).await?;
+///
+/// println!("Datasets: {:?}", response.data.and_then(|d| d.count));
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_metric(
+    client: &BaseClient,
+    metric: Metric,
+    period: &MetricPeriod,
+    parent_alias: Option<&str>,
+) -> Result<Response<CountResponse>, String> {
+    // Endpoint metadata
+    let url = match period {
+        MetricPeriod::Total => format!("api/info/metrics/{}", metric.as_str()),
+        MetricPeriod::ToMonth(month) => format!("api/info/metrics/{}/toMonth/{}", metric.as_str(), month),
+        MetricPeriod::PastDays(days) => format!("api/info/metrics/{}/pastDays/{}", metric.as_str(), days),
+    };
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), parent_parameters(parent_alias), &context).await;
+
+    evaluate_response::<CountResponse>(response).await
+}
+
+/// Retrieves the cumulative count of a metric per month.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `metric` - The `Metric` to count.
+/// * `parent_alias` - An optional string slice that holds the alias of the collection to scope the counts to.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<Vec<MonthlyCount>>`, which contains the HTTP response status and the counts
+/// per month, if the request is successful, or a `String` error message on failure.
+pub async fn get_monthly_metric(
+    client: &BaseClient,
+    metric: Metric,
+    parent_alias: Option<&str>,
+) -> Result<Response<Vec<MonthlyCount>>, String> {
+    // Endpoint metadata
+    let url = format!("api/info/metrics/{}/monthly", metric.as_str());
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get(url.as_str(), parent_parameters(parent_alias), &context).await;
+
+    evaluate_response::<Vec<MonthlyCount>>(response).await
+}
+
+/// Serializes metric entries into CSV, one row per entry, for use in reporting dashboards.
+pub fn to_csv<T: Serialize>(rows: &[T]) -> Result<String, String> {
+    let mut writer = csv::Writer::from_writer(vec![]);
+    for row in rows {
+        writer.serialize(row).map_err(|e| e.to_string())?;
+    }
+
+    let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+    String::from_utf8(bytes).map_err(|e| e.to_string())
+}
+
+// Scopes metrics to a collection, if given
+pub(crate) fn parent_parameters(parent_alias: Option<&str>) -> Option<HashMap<String, String>> {
+    parent_alias.map(|alias| HashMap::from([("parentAlias".to_string(), alias.to_string())]))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_utils::extract_test_env;
+
+    use super::{Metric, MetricPeriod, MonthlyCount};
+
+    /// Tests retrieving the total number of datasets and their monthly counts.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if any of the requests fail.
+    #[tokio::test]
+    async fn test_get_dataset_metrics() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        let response = metrics::get_metric(&client, Metric::Datasets, &MetricPeriod::Total, None)
+            .await.expect("Failed to get metric");
+        assert!(response.status.is_ok());
+        assert!(response.data.unwrap().count.is_some());
+
+        let response = metrics::get_monthly_metric(&client, Metric::Datasets, None)
+            .await.expect("Failed to get monthly metric");
+        assert!(response.status.is_ok());
+    }
+
+    /// Tests the parsing of metrics from string literals.
+    #[test]
+    fn test_metric_from_str() {
+        for metric in [Metric::Dataverses, Metric::Datasets, Metric::Files, Metric::Downloads] {
+            assert_eq!(metric.as_str().parse::<Metric>(), Ok(metric));
+        }
+
+        assert_eq!("collections".parse::<Metric>(), Ok(Metric::Dataverses));
+        assert!("invalid".parse::<Metric>().is_err());
+    }
+
+    /// Tests the CSV serialization of monthly counts.
+    #[test]
+    fn test_monthly_to_csv() {
+        let rows: Vec<MonthlyCount> = serde_json::from_value(serde_json::json!([
+            {"date": "2024-01", "count": 10},
+            {"date": "2024-02", "count": 12}
+        ]))
+            .unwrap();
+
+        let csv = super::to_csv(&rows).expect("Failed to write CSV");
+
+        assert_eq!(csv, "count,date\n10,2024-01\n12,2024-02\n");
+    }
+}
@@ -0,0 +1,104 @@
+use serde::{Deserialize, Serialize};
+use typify::import_types;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    request::RequestType,
+    response::Response,
+};
+
+import_types!(
+    schema = "models/users/me.json",
+    struct_builder = true,
+);
+
+/// Retrieves the user the API token of the client belongs to.
+///
+/// This asynchronous function sends a GET request to the API endpoint designated for the current user. As
+/// the request fails for invalid or expired tokens, it can be used to verify the credentials before starting
+/// long running jobs.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<UserResponse>`, which contains the HTTP response status and the details
+/// of the user, if the request is successful, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let response = users::get_me(&client).await?;
+///
+/// if response.status.is_err() {
+///     panic!("The API token is not valid");
+/// }
+/// # Ok(())
+/// # }
+/// ```
+pub async fn get_me(client: &BaseClient) -> Result<Response<UserResponse>, String> {
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get("api/users/:me", None, &context).await;
+
+    evaluate_response::<UserResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_utils::extract_test_env;
+
+    /// Tests retrieving the user of the API token.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables
+    /// and retrieves the associated user. The test asserts that the request was successful and that
+    /// an identifier has been returned.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the request fails.
+    #[tokio::test]
+    async fn test_get_me() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        let response = users::get_me(&client).await.expect("Failed to get user");
+
+        assert!(response.status.is_ok());
+        assert!(response.data.unwrap().identifier.is_some());
+    }
+
+    /// Tests retrieving the user of an invalid API token.
+    ///
+    /// # Environment Variables
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the request unexpectedly succeeds.
+    #[tokio::test]
+    async fn test_get_me_invalid_token() {
+        // Set up the client
+        let (_, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&"invalid".to_string()))
+            .expect("Failed to create client");
+
+        let response = users::get_me(&client).await.expect("Failed to get user");
+
+        assert!(response.status.is_err());
+    }
+}
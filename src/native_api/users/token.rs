@@ -0,0 +1,109 @@
+use std::collections::HashMap;
+
+use crate::{
+    client::{BaseClient, evaluate_response},
+    native_api::dataset::link::MessageResponse,
+    request::RequestType,
+    response::Response,
+};
+
+/// Retrieves the expiration date of the API token of the client.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and a message
+/// stating the expiration date of the token, if the request is successful, or a `String` error message on failure.
+pub async fn get_token_expiration(client: &BaseClient) -> Result<Response<MessageResponse>, String> {
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.get("api/users/token", None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Replaces the API token of the client with a new one.
+///
+/// This asynchronous function sends a POST request to the token endpoint, which invalidates the current token
+/// and issues a new one. The new token is part of the returned message. Note that the client keeps using the
+/// invalidated token, hence a new client has to be created for subsequent requests.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `return_expiration` - A boolean flag indicating whether the message shall include the expiration date
+///   of the new token.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and a message
+/// containing the new token, if the request is successful, or a `String` error message on failure.
+pub async fn recreate_token(
+    client: &BaseClient,
+    return_expiration: bool,
+) -> Result<Response<MessageResponse>, String> {
+    // Build Parameters
+    let parameters = match return_expiration {
+        true => Some(HashMap::from([("returnExpiration".to_string(), "true".to_string())])),
+        false => None,
+    };
+
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.post("api/users/token/recreate", parameters, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+/// Revokes the API token of the client.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+///
+/// # Returns
+///
+/// A `Result` wrapping a `Response<MessageResponse>`, which contains the HTTP response status and the message
+/// of the server, if the request is successful, or a `String` error message on failure.
+pub async fn revoke_token(client: &BaseClient) -> Result<Response<MessageResponse>, String> {
+    // Send request
+    let context = RequestType::Plain;
+    let response = client.delete("api/users/token", None, &context).await;
+
+    evaluate_response::<MessageResponse>(response).await
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::prelude::*;
+    use crate::test_utils::extract_test_env;
+
+    /// Tests retrieving the expiration date of the API token.
+    ///
+    /// Recreating and revoking the token are not tested, as both would invalidate the token
+    /// used by the remaining tests.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the request fails.
+    #[tokio::test]
+    async fn test_get_token_expiration() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        let response = users::get_token_expiration(&client)
+            .await
+            .expect("Failed to get token expiration");
+
+        assert!(response.status.is_ok());
+        assert!(response.data.unwrap().message.is_some());
+    }
+}
@@ -0,0 +1,331 @@
+use std::fmt;
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::BaseClient,
+    identifier::Identifier,
+    native_api::dataset::create::{create_dataset, DatasetCreateBody},
+    native_api::dataset::upload::{upload_file_to_dataset, UploadBody},
+    native_api::file::delete::delete_file,
+    native_api::file::metadata::move_file,
+    native_api::file::replace::replace_file,
+    response::Response,
+};
+
+/// An action that a bulk operation intends to perform.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "action", rename_all = "lowercase")]
+pub enum Action {
+    /// Create a dataset within a collection.
+    Create {
+        collection: String,
+        body: Box<DatasetCreateBody>,
+    },
+    /// Upload a new file to a dataset.
+    Upload {
+        dataset: Identifier,
+        path: PathBuf,
+        body: Option<UploadBody>,
+    },
+    /// Replace an existing file with a local one.
+    Replace {
+        file: i64,
+        path: PathBuf,
+        body: Option<UploadBody>,
+    },
+    /// Move a file to another directory of its dataset, under the given name.
+    Move {
+        file: i64,
+        directory: String,
+        label: String,
+    },
+    /// Delete a file from the draft of its dataset.
+    Delete { file: Identifier },
+    /// Leave an item untouched.
+    Skip { item: String },
+}
+
+impl Action {
+    pub fn name(&self) -> &str {
+        match self {
+            Action::Create { .. } => "create",
+            Action::Upload { .. } => "upload",
+            Action::Replace { .. } => "replace",
+            Action::Move { .. } => "move",
+            Action::Delete { .. } => "delete",
+            Action::Skip { .. } => "skip",
+        }
+    }
+
+    // Executes the action and returns a short description of the outcome
+    async fn execute(&self, client: &BaseClient) -> Result<String, String> {
+        match self {
+            Action::Create { collection, body } => {
+                let response = create_dataset(client, collection, *body.clone()).await?;
+                let data = ok_data(response)?;
+                Ok(format!("Created dataset {}", data.persistent_id.unwrap_or_default()))
+            }
+            Action::Upload { dataset, path, body } => {
+                let response =
+                    upload_file_to_dataset(client, dataset.clone(), path.clone(), body.clone(), None).await?;
+                let ids = ok_data(response)?.file_ids();
+                Ok(format!("Uploaded {} as file {:?}", path.display(), ids))
+            }
+            Action::Replace { file, path, body } => {
+                let response = replace_file(client, &file.to_string(), path.clone(), body, None).await?;
+                let ids = ok_data(response)?.file_ids();
+                Ok(format!("Replaced file {} with {} as file {:?}", file, path.display(), ids))
+            }
+            Action::Move { file, directory, label } => {
+                ok_data(move_file(client, Identifier::Id(*file), directory, Some(label)).await?)?;
+                Ok(format!("Moved file {} to {}", file, target_path(directory, label)))
+            }
+            Action::Delete { file } => {
+                ok_data(delete_file(client, file.clone()).await?)?;
+                Ok(format!("Deleted file {}", identifier_label(file)))
+            }
+            Action::Skip { item } => Ok(format!("Skipped {}", item)),
+        }
+    }
+}
+
+impl fmt::Display for Action {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Action::Create { collection, .. } => write!(f, "dataset in collection '{}'", collection),
+            Action::Upload { dataset, path, .. } => {
+                write!(f, "{} to dataset {}", path.display(), identifier_label(dataset))
+            }
+            Action::Replace { file, path, .. } => write!(f, "file {} with {}", file, path.display()),
+            Action::Move { file, directory, label } => {
+                write!(f, "file {} to {}", file, target_path(directory, label))
+            }
+            Action::Delete { file } => write!(f, "file {}", identifier_label(file)),
+            Action::Skip { item } => write!(f, "{}", item),
+        }
+    }
+}
+
+/// An action of a `Plan` together with the reason it has been planned.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PlannedAction {
+    #[serde(flatten)]
+    pub action: Action,
+    pub reason: String,
+}
+
+/// The outcome of an executed action.
+#[derive(Debug)]
+pub struct ExecutedAction {
+    pub action: PlannedAction,
+    pub result: Result<String, String>,
+}
+
+/// A list of intended actions, which can be reviewed before it is executed.
+///
+/// Bulk commands build a plan instead of acting immediately. The plan can then be printed for a
+/// dry run, stored as JSON for review and executed later on, which allows review-before-apply
+/// workflows.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct Plan {
+    pub actions: Vec<PlannedAction>,
+}
+
+impl Plan {
+    pub fn new() -> Self {
+        Plan::default()
+    }
+
+    /// Adds an action along with the reason for it.
+    pub fn push(&mut self, action: Action, reason: &str) {
+        self.actions.push(PlannedAction {
+            action,
+            reason: reason.to_string(),
+        });
+    }
+
+    /// Returns whether the plan contains any action other than skips.
+    pub fn has_changes(&self) -> bool {
+        self.actions
+            .iter()
+            .any(|planned| !matches!(planned.action, Action::Skip { .. }))
+    }
+
+    /// Serializes the plan into pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    /// Parses a plan previously serialized with `to_json`.
+    pub fn from_json(content: &str) -> Result<Self, String> {
+        serde_json::from_str(content).map_err(|e| e.to_string())
+    }
+
+    /// Executes the actions in order.
+    ///
+    /// As later actions may depend on earlier ones, the execution stops at the first failing action. The
+    /// returned list hence contains the outcomes of all actions up to and including the failed one.
+    ///
+    /// # Arguments
+    ///
+    /// * `client` - A reference to the `BaseClient` instance used to send the requests.
+    ///
+    /// # Returns
+    ///
+    /// A `Vec<ExecutedAction>` holding the outcome of every executed action.
+    pub async fn execute(&self, client: &BaseClient) -> Vec<ExecutedAction> {
+        let mut executed = vec![];
+
+        for planned in &self.actions {
+            let result = planned.action.execute(client).await;
+            let failed = result.is_err();
+
+            executed.push(ExecutedAction {
+                action: planned.clone(),
+                result,
+            });
+
+            if failed {
+                break;
+            }
+        }
+
+        executed
+    }
+
+    // Counts the actions per kind, in the order of their first occurrence
+    fn counts(&self) -> Vec<(&str, usize)> {
+        let mut counts: Vec<(&str, usize)> = vec![];
+        for planned in &self.actions {
+            let name = planned.action.name();
+            match counts.iter_mut().find(|(n, _)| *n == name) {
+                Some((_, count)) => *count += 1,
+                None => counts.push((name, 1)),
+            }
+        }
+        counts
+    }
+}
+
+impl fmt::Display for Plan {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for planned in &self.actions {
+            writeln!(
+                f,
+                "{:<8} {} ({})",
+                planned.action.name(),
+                planned.action,
+                planned.reason
+            )?;
+        }
+
+        let counts = self
+            .counts()
+            .iter()
+            .map(|(name, count)| format!("{} {}", count, name))
+            .collect::<Vec<_>>();
+
+        match counts.is_empty() {
+            true => write!(f, "Plan: nothing to do"),
+            false => write!(f, "Plan: {}", counts.join(", ")),
+        }
+    }
+}
+
+// Extracts the data of a successful response or the error message otherwise
+fn ok_data<T>(response: Response<T>) -> Result<T, String> {
+    match (response.status.is_ok(), response.data) {
+        (true, Some(data)) => Ok(data),
+        (true, None) => Err("The response did not contain any data".to_string()),
+        (false, _) => Err(response.message.map(|m| m.to_string()).unwrap_or_default()),
+    }
+}
+
+fn target_path(directory: &str, label: &str) -> String {
+    match directory.is_empty() {
+        true => label.to_string(),
+        false => format!("{}/{}", directory, label),
+    }
+}
+
+fn identifier_label(id: &Identifier) -> String {
+    match id {
+        Identifier::PersistentId(pid) => pid.clone(),
+        Identifier::Id(id) => id.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::PathBuf;
+
+    use crate::identifier::Identifier;
+
+    use super::{Action, Plan};
+
+    fn example_plan() -> Plan {
+        let mut plan = Plan::new();
+        plan.push(
+            Action::Upload {
+                dataset: Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string()),
+                path: PathBuf::from("data/new.csv"),
+                body: None,
+            },
+            "not present in the dataset",
+        );
+        plan.push(
+            Action::Replace {
+                file: 42,
+                path: PathBuf::from("data/changed.csv"),
+                body: None,
+            },
+            "checksum differs",
+        );
+        plan.push(
+            Action::Skip {
+                item: "data/same.csv".to_string(),
+            },
+            "unchanged",
+        );
+        plan.push(
+            Action::Upload {
+                dataset: Identifier::Id(1),
+                path: PathBuf::from("data/other.csv"),
+                body: None,
+            },
+            "not present in the dataset",
+        );
+        plan
+    }
+
+    /// Tests the human-readable output of a plan.
+    #[test]
+    fn test_plan_display() {
+        let output = example_plan().to_string();
+        let lines: Vec<&str> = output.lines().collect();
+
+        assert_eq!(
+            lines[0],
+            "upload   data/new.csv to dataset doi:10.5072/FK2/ABC123 (not present in the dataset)"
+        );
+        assert_eq!(lines[1], "replace  file 42 with data/changed.csv (checksum differs)");
+        assert_eq!(lines[4], "Plan: 2 upload, 1 replace, 1 skip");
+        assert_eq!(Plan::new().to_string(), "Plan: nothing to do");
+    }
+
+    /// Tests that a plan survives a JSON round trip, such that reviewed plans can be executed.
+    #[test]
+    fn test_plan_json_round_trip() {
+        let plan = example_plan();
+        let json = plan.to_json().expect("Failed to serialize plan");
+
+        assert!(json.contains("\"action\": \"replace\""));
+
+        let parsed = Plan::from_json(&json).expect("Failed to parse plan");
+
+        assert_eq!(parsed.to_string(), plan.to_string());
+        assert!(parsed.has_changes());
+    }
+}
@@ -0,0 +1,69 @@
+use std::collections::HashMap;
+use std::sync::{Arc, Mutex};
+
+use tokio::sync::OwnedMutexGuard;
+
+use crate::identifier::Identifier;
+
+// Dataverse locks a dataset while it registers added files and rejects further
+// additions meanwhile. Requests adding files are therefore queued per dataset,
+// such that only one of them is in flight at a time, while transfers to the
+// storage, which do not touch the dataset, may still run in parallel.
+//
+// Datasets are keyed by the identifier they are addressed with, so a dataset
+// addressed by both its ID and its persistent identifier has two queues.
+
+/// Queues requests adding files to a dataset, such that they are sent one at a time.
+#[derive(Default)]
+pub struct DatasetQueues {
+    queues: Mutex<HashMap<String, Arc<tokio::sync::Mutex<()>>>>,
+}
+
+impl DatasetQueues {
+    /// Waits until no other request adding files to the dataset is in flight. The returned
+    /// guard holds the queue until it is dropped.
+    pub async fn acquire(&self, id: &Identifier) -> OwnedMutexGuard<()> {
+        let queue = self
+            .queues
+            .lock()
+            .unwrap()
+            .entry(key(id))
+            .or_default()
+            .clone();
+
+        queue.lock_owned().await
+    }
+}
+
+fn key(id: &Identifier) -> String {
+    match id {
+        Identifier::PersistentId(pid) => pid.clone(),
+        Identifier::Id(id) => id.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use crate::identifier::Identifier;
+
+    use super::DatasetQueues;
+
+    /// Tests that a dataset is only acquired once at a time, while other datasets remain available.
+    #[tokio::test]
+    async fn test_acquire() {
+        let queues = DatasetQueues::default();
+        let guard = queues.acquire(&Identifier::Id(1)).await;
+
+        let other = tokio::time::timeout(Duration::from_millis(50), queues.acquire(&Identifier::Id(2))).await;
+        assert!(other.is_ok());
+
+        let same = tokio::time::timeout(Duration::from_millis(50), queues.acquire(&Identifier::Id(1))).await;
+        assert!(same.is_err());
+
+        drop(guard);
+        let same = tokio::time::timeout(Duration::from_millis(50), queues.acquire(&Identifier::Id(1))).await;
+        assert!(same.is_ok());
+    }
+}
@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use reqwest::{Method, Url};
+use serde::{Deserialize, Serialize};
+
+use crate::request::RequestType;
+
+// This module is only available with the `record` feature and is used
+// to capture request/response pairs of a live instance as test fixtures.
+// Fixtures are written as JSON files, one per request, in the order the
+// requests were sent.
+
+/// A request/response pair as written to the fixtures directory.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct Fixture {
+    pub request: RecordedRequest,
+    pub response: RecordedResponse,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecordedRequest {
+    pub method: String,
+    pub path: String,
+    pub query: HashMap<String, String>,
+
+    // JSON bodies are stored as-is, multipart bodies as a map of their text
+    // parts and the names of the attached files
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<serde_json::Value>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct RecordedResponse {
+    pub status: u16,
+    pub body: serde_json::Value,
+}
+
+/// Writes every request sent by a `BaseClient` together with its response to a directory.
+pub struct Recorder {
+    dir: PathBuf,
+    counter: AtomicUsize,
+}
+
+impl Recorder {
+    pub fn new(dir: PathBuf) -> std::io::Result<Self> {
+        std::fs::create_dir_all(&dir)?;
+        Ok(Recorder {
+            dir,
+            counter: AtomicUsize::new(0),
+        })
+    }
+
+    // Reads the response, writes the fixture and returns an equivalent
+    // response, such that the caller can evaluate it as usual
+    pub(crate) async fn record(
+        &self,
+        method: &Method,
+        url: &Url,
+        parameters: &Option<HashMap<String, String>>,
+        context: &RequestType,
+        response: reqwest::Response,
+    ) -> Result<reqwest::Response, reqwest::Error> {
+        let status = response.status();
+        let headers = response.headers().clone();
+        let bytes = response.bytes().await?;
+
+        let fixture = Fixture {
+            request: RecordedRequest {
+                method: method.to_string(),
+                path: url.path().to_string(),
+                query: parameters.clone().unwrap_or_default(),
+                body: recorded_body(context),
+            },
+            response: RecordedResponse {
+                status: status.as_u16(),
+                body: serde_json::from_slice(&bytes)
+                    .unwrap_or_else(|_| String::from_utf8_lossy(&bytes).into()),
+            },
+        };
+
+        if let Err(e) = self.write(&fixture) {
+            eprintln!("Failed to write fixture: {}", e);
+        }
+
+        let mut builder = http::Response::builder().status(status);
+        if let Some(builder_headers) = builder.headers_mut() {
+            *builder_headers = headers;
+        }
+
+        Ok(builder
+            .body(bytes)
+            .expect("Failed to rebuild the recorded response")
+            .into())
+    }
+
+    fn write(&self, fixture: &Fixture) -> std::io::Result<()> {
+        let index = self.counter.fetch_add(1, Ordering::SeqCst);
+        let name = format!(
+            "{:03}_{}_{}.json",
+            index,
+            fixture.request.method.to_lowercase(),
+            sanitize(&fixture.request.path)
+        );
+
+        let content = serde_json::to_string_pretty(fixture)?;
+        std::fs::write(self.dir.join(name), content)
+    }
+}
+
+fn recorded_body(context: &RequestType) -> Option<serde_json::Value> {
+    match context {
+        RequestType::Plain => None,
+        RequestType::JSON { body } => {
+            Some(serde_json::from_str(body).unwrap_or_else(|_| body.clone().into()))
+        }
+        RequestType::Multipart { bodies, files, .. } => {
+            let mut parts = serde_json::Map::new();
+            for (key, value) in bodies.iter().flatten() {
+                let value = serde_json::from_str(value).unwrap_or_else(|_| value.clone().into());
+                parts.insert(key.clone(), value);
+            }
+            for (key, path) in files.iter().flatten() {
+                parts.insert(key.clone(), path.display().to_string().into());
+            }
+            Some(parts.into())
+        }
+        RequestType::Raw { body, .. } => match std::str::from_utf8(body) {
+            Ok(text) => Some(text.into()),
+            Err(_) => Some(format!("<{} bytes>", body.len()).into()),
+        },
+    }
+}
+
+// Turns an URL path into a file name friendly string
+fn sanitize(path: &str) -> String {
+    path.trim_matches('/')
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use crate::client::BaseClient;
+    use crate::request::RequestType;
+
+    use super::{Fixture, Recorder};
+
+    /// Tests that a recording client writes a fixture and still returns the full response.
+    #[tokio::test]
+    async fn test_record_request() {
+        let server = MockServer::start();
+        let mock = server.mock(|when, then| {
+            when.method(GET).path("/api/info/version");
+            then.status(200)
+                .json_body(serde_json::json!({"status": "OK", "data": {"version": "6.2"}}));
+        });
+
+        let dir = std::env::temp_dir().join(format!("dvcli_record_{}", rand::random::<u32>()));
+        let client = BaseClient::new(&server.base_url(), None)
+            .unwrap()
+            .with_recorder(Recorder::new(dir.clone()).unwrap());
+
+        let response = client
+            .get("api/info/version", None, &RequestType::Plain)
+            .await
+            .expect("Failed to send request");
+        let body: serde_json::Value = response.json().await.unwrap();
+
+        mock.assert();
+        assert_eq!(body["data"]["version"], "6.2");
+
+        let path = dir.join("000_get_api_info_version.json");
+        let fixture: Fixture =
+            serde_json::from_str(&std::fs::read_to_string(path).unwrap()).unwrap();
+
+        assert_eq!(fixture.request.method, "GET");
+        assert_eq!(fixture.response.status, 200);
+        assert_eq!(fixture.response.body, body);
+    }
+}
@@ -1,14 +1,17 @@
 use std::collections::HashMap;
+use std::io::Write;
 use std::path::PathBuf;
-use std::sync::Arc;
 
-use indicatif::MultiProgress;
+use flate2::write::GzEncoder;
+use flate2::Compression;
 use reqwest::{multipart, RequestBuilder};
 
 use crate::callback::CallbackFun;
+use crate::checksum::sha256_hex;
+#[cfg(feature = "fs")]
 use crate::filewrapper::create_multipart;
 
-// We distinguish between three types of requests: plain, JSON, and multipart
+// We distinguish between four types of requests: plain, JSON, multipart and raw
 pub enum RequestType {
     // A plain request with no body
     Plain,
@@ -25,6 +28,13 @@ pub enum RequestType {
         files: Option<HashMap<String, PathBuf>>,
         callbacks: Option<HashMap<String, CallbackFun>>,
     },
+
+    // A request with a body of any content type and further headers,
+    // such as the Atom documents and packages of the SWORD API
+    Raw {
+        body: Vec<u8>,
+        headers: Vec<(String, String)>,
+    },
 }
 
 impl RequestType {
@@ -40,9 +50,70 @@ impl RequestType {
             } => {
                 Self::build_form_request(bodies, files, request, callbacks.clone()).await
             }
+            RequestType::Raw { body, headers } => headers
+                .iter()
+                .fold(request, |request, (name, value)| request.header(name, value))
+                .body(body.clone()),
+        }
+    }
+
+    // Returns the size of bodies without files, which are held in memory
+    pub fn body_size(&self) -> Option<usize> {
+        match self {
+            RequestType::Plain => None,
+            RequestType::JSON { body } => Some(body.len()),
+            RequestType::Multipart { bodies, files: None, .. } => Some(
+                bodies
+                    .iter()
+                    .flatten()
+                    .map(|(key, value)| key.len() + value.len())
+                    .sum(),
+            ),
+            RequestType::Multipart { .. } => None,
+            RequestType::Raw { body, .. } => Some(body.len()),
         }
     }
 
+    // Compresses bodies without files of at least `threshold` bytes with gzip.
+    // Multipart bodies are encoded by hand, since reqwest streams its forms.
+    pub fn compress(&self, threshold: usize) -> Option<CompressedBody> {
+        if self.body_size()? < threshold {
+            return None;
+        }
+
+        let (content_type, content) = match self {
+            RequestType::JSON { body } => ("application/json".to_string(), body.clone()),
+            RequestType::Multipart { bodies, .. } => {
+                let bodies = bodies.clone().unwrap_or_default();
+                let mut keys: Vec<&String> = bodies.keys().collect();
+                keys.sort();
+
+                let boundary = format!("dvcli{}", &sha256_hex(format!("{:?}", bodies).as_bytes())[..32]);
+                let mut content = String::new();
+                for key in keys {
+                    content.push_str(&format!(
+                        "--{}\r\nContent-Disposition: form-data; name=\"{}\"\r\n\r\n{}\r\n",
+                        boundary, key, bodies[key]
+                    ));
+                }
+                content.push_str(&format!("--{}--\r\n", boundary));
+
+                (format!("multipart/form-data; boundary={}", boundary), content)
+            }
+            // Raw bodies keep their content type and are often compressed already
+            RequestType::Plain | RequestType::Raw { .. } => return None,
+        };
+
+        let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+        encoder.write_all(content.as_bytes()).ok()?;
+
+        Some(CompressedBody {
+            content_type,
+            original_size: content.len(),
+            body: encoder.finish().ok()?,
+        })
+    }
+
     fn build_json_request(body: &str, request: RequestBuilder) -> RequestBuilder {
         request
             .header("Content-Type", "application/json")
@@ -63,15 +134,19 @@ impl RequestType {
             }
         }
 
+        // Files are streamed from disk, which requires the `fs` feature
+        #[cfg(not(feature = "fs"))]
+        let _ = (files, callbacks);
+
+        #[cfg(feature = "fs")]
         if let Some(files) = files {
             for (key, value) in files {
-                let multi_pb = Arc::new(MultiProgress::new());
                 let local_callback = match &callbacks {
                     Some(callbacks) => callbacks.get(key).cloned(),
                     None => None,
                 };
 
-                let part = create_multipart(value, multi_pb, local_callback)
+                let part = create_multipart(value, local_callback)
                     .await
                     .expect("The progress bar could not be created. Please check the file path.");
 
@@ -83,6 +158,32 @@ impl RequestType {
     }
 }
 
+/// A request body compressed with gzip.
+pub struct CompressedBody {
+    content_type: String,
+    original_size: usize,
+    body: Vec<u8>,
+}
+
+impl CompressedBody {
+    /// Returns the size of the body before compression.
+    pub fn original_size(&self) -> usize {
+        self.original_size
+    }
+
+    /// Returns the size of the compressed body.
+    pub fn size(&self) -> usize {
+        self.body.len()
+    }
+
+    pub fn to_request(&self, request: RequestBuilder) -> RequestBuilder {
+        request
+            .header("Content-Type", self.content_type.as_str())
+            .header("Content-Encoding", "gzip")
+            .body(self.body.clone())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::collections::HashMap;
@@ -167,4 +268,46 @@ mod tests {
             request
         );
     }
+
+    #[tokio::test]
+    async fn test_request_type_compress() {
+        use std::io::Read;
+
+        use flate2::read::GzDecoder;
+
+        // Arrange
+        let body = format!("[{}]", vec!["{\"fileName\": \"data.csv\"}"; 100].join(","));
+        let json = RequestType::JSON { body: body.clone() };
+        let form = RequestType::Multipart {
+            bodies: Some(HashMap::from([("jsonData".to_string(), body.clone())])),
+            files: None,
+            callbacks: None,
+        };
+
+        // Act
+        let compressed = json.compress(1024).expect("Body was not compressed");
+        let request = compressed
+            .to_request(Client::new().request(reqwest::Method::POST, "http://localhost"))
+            .build()
+            .expect("Could not build request");
+
+        // Assert
+        assert!(json.compress(body.len() + 1).is_none());
+        assert!(compressed.size() < compressed.original_size());
+        assert_eq!(request.headers().get("Content-Encoding").unwrap(), "gzip");
+
+        let mut decoded = String::new();
+        GzDecoder::new(request.body().unwrap().as_bytes().unwrap())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, body);
+
+        let compressed = form.compress(1024).expect("Form was not compressed");
+        let request = compressed
+            .to_request(Client::new().request(reqwest::Method::POST, "http://localhost"))
+            .build()
+            .expect("Could not build request");
+        let content_type = request.headers().get("Content-Type").unwrap().to_str().unwrap();
+        assert!(content_type.starts_with("multipart/form-data; boundary=dvcli"));
+    }
 }
@@ -1,7 +1,15 @@
+#[cfg(feature = "cli")]
 use atty::Stream;
+#[cfg(feature = "cli")]
 use colored::Colorize;
+#[cfg(feature = "cli")]
 use colored_json::prelude::*;
 
+#[cfg(feature = "cli")]
+use crate::cli::i18n::tr;
+#[cfg(feature = "cli")]
+use crate::verbosity::{verbosity, Verbosity};
+
 // We distinguish success and error responses with this enum
 // Once the response is parsed, we can check if it's an error or not
 // and act accordingly
@@ -63,6 +71,7 @@ pub struct Response<T> {
     pub requestMethod: Option<String>,
 }
 
+#[cfg(feature = "cli")]
 impl<T> Response<T>
 where
     T: serde::Serialize,
@@ -73,15 +82,13 @@ where
                 let json = serde_json::to_string_pretty(&self.data.as_ref().unwrap()).unwrap();
 
                 self.redirect_stream(&json);
-                std::process::exit(exitcode::OK);
             }
             Status::ERROR => {
-                println!(
+                eprintln!(
                     "\n{} {}\n",
-                    "Error:".red().bold(),
+                    tr("error", &[]).red().bold(),
                     self.message.as_ref().unwrap()
                 );
-                std::process::exit(exitcode::DATAERR);
             }
         }
     }
@@ -89,22 +96,31 @@ where
     // This function is used to redirect the output to the appropriate stream
     // If users are redirecting the output to a file, we don't want to print
     // the success message but only the JSON response to ensure that the output
-    // is clean and can be used in other scripts
+    // is clean and can be used in other scripts. The success message is a
+    // diagnostic and hence written to stderr.
     fn redirect_stream(&self, json_str: &str) {
         if atty::is(Stream::Stdout) {
-            println!("{}", success_message());
-            println!("{}\n", json_str.to_colored_json_auto().unwrap());
+            if verbosity() > Verbosity::Quiet {
+                eprintln!("{}", success_message());
+            }
+
+            match colored::control::SHOULD_COLORIZE.should_colorize() {
+                true => println!("{}\n", json_str.to_colored_json_auto().unwrap()),
+                false => println!("{}\n", json_str),
+            }
         } else {
             println!("{}", json_str);
         }
     }
 }
 
+#[cfg(feature = "cli")]
 fn success_message() -> String {
     format!(
-        "{} {} - Received the following response: \n",
+        "{} {} - {} \n",
         "└── ".bold(),
-        "🎉 Success!".green().bold()
+        tr("success", &[]).green().bold(),
+        tr("received-response", &[])
     )
 }
 
@@ -185,6 +201,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "cli")]
     fn test_response_print_result() {
         let response = super::Response {
             status: super::Status::OK,
@@ -0,0 +1,200 @@
+use std::time::Duration;
+
+use reqwest::StatusCode;
+
+// Dataverse rejects changes to locked datasets, e.g. while files are ingested, with
+// one of these statuses and a message mentioning the lock. The status alone is not
+// sufficient, as it is also used for invalid requests.
+const LOCK_STATUSES: [StatusCode; 3] = [StatusCode::BAD_REQUEST, StatusCode::FORBIDDEN, StatusCode::CONFLICT];
+
+// Wording of the lock messages of Dataverse, e.g. "Dataset is locked. Reason: Ingest" or
+// "Dataset cannot be edited due to dataset lock.". They are matched as whole words, as
+// other errors mention e.g. metadata blocks.
+const LOCK_PHRASES: [&str; 3] = ["is locked", "dataset lock", "dataset locks"];
+
+/// Retries requests rejected because the dataset is locked, with exponentially growing delays.
+///
+/// Set it using `BaseClient::with_lock_retry` to keep uploads, edits and publications from
+/// failing while an ingest or another lock is in place. Requests are retried until the
+/// `timeout` has passed, after which the last error response is returned.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::time::Duration;
+/// use dataverse::prelude::*;
+/// use dataverse::retry::LockRetry;
+///
+/// let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+///     .expect("Failed to create client")
+///     .with_lock_retry(LockRetry::new(Duration::from_secs(300)));
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct LockRetry {
+    /// The time after which no further attempts are made.
+    pub timeout: Duration,
+    /// The delay before the first retry, which doubles with every further attempt.
+    pub initial_delay: Duration,
+    /// The upper bound of the delay between two attempts.
+    pub max_delay: Duration,
+}
+
+impl LockRetry {
+    /// Creates a retry giving up after `timeout`, with delays growing from one second to at most 30 seconds.
+    pub fn new(timeout: Duration) -> Self {
+        LockRetry {
+            timeout,
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+
+    /// Returns the delay before the given retry, starting at zero, or `None` if the timeout has passed.
+    pub fn delay(&self, attempt: u32, elapsed: Duration) -> Option<Duration> {
+        let remaining = self.timeout.checked_sub(elapsed).filter(|remaining| !remaining.is_zero())?;
+        let delay = self
+            .initial_delay
+            .checked_mul(2u32.saturating_pow(attempt))
+            .unwrap_or(self.max_delay)
+            .min(self.max_delay);
+
+        Some(delay.min(remaining))
+    }
+}
+
+// Reads the body of error responses to tell whether the request was rejected due to
+// a lock. The response is rebuilt from the body, such that it can be evaluated as usual.
+// Responses cannot be rebuilt on `wasm32`, where requests are not retried.
+#[cfg(not(target_arch = "wasm32"))]
+pub(crate) async fn check_lock(response: reqwest::Response) -> Result<(reqwest::Response, bool), reqwest::Error> {
+    let status = response.status();
+    if !LOCK_STATUSES.contains(&status) {
+        return Ok((response, false));
+    }
+
+    let headers = response.headers().clone();
+    let bytes = response.bytes().await?;
+    let locked = serde_json::from_slice::<serde_json::Value>(&bytes)
+        .ok()
+        .and_then(|body| body.get("message").and_then(|m| m.as_str()).map(|m| m.to_lowercase()))
+        .is_some_and(|message| is_lock_message(&message));
+
+    let mut builder = http::Response::builder().status(status);
+    if let Some(builder_headers) = builder.headers_mut() {
+        *builder_headers = headers;
+    }
+    let response = builder
+        .body(bytes)
+        .expect("Failed to rebuild the response")
+        .into();
+
+    Ok((response, locked))
+}
+
+// Tells whether a lowercase message contains one of the lock phrases as whole words
+fn is_lock_message(message: &str) -> bool {
+    LOCK_PHRASES.iter().any(|phrase| {
+        message.match_indices(phrase).any(|(start, _)| {
+            let before = message[..start].chars().next_back();
+            let after = message[start + phrase.len()..].chars().next();
+            !before.is_some_and(char::is_alphanumeric) && !after.is_some_and(char::is_alphanumeric)
+        })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use futures::future::BoxFuture;
+    use reqwest::Request;
+
+    use crate::native_api::dataset::link::MessageResponse;
+    use crate::prelude::*;
+    use crate::request::RequestType;
+    use crate::transport::Transport;
+
+    use super::{is_lock_message, LockRetry};
+
+    const LOCKED: &str = r#"{"status": "ERROR", "message": "Dataset is locked. Reason: Ingest"}"#;
+
+    // Rejects the first requests with the given error and accepts the following ones
+    struct LockedTransport {
+        locked: usize,
+        error: &'static str,
+        calls: AtomicUsize,
+    }
+
+    impl Transport for LockedTransport {
+        fn send(&self, _request: Request) -> BoxFuture<'_, Result<reqwest::Response, reqwest::Error>> {
+            let (status, body) = match self.calls.fetch_add(1, Ordering::SeqCst) < self.locked {
+                true => (409, self.error),
+                false => (200, r#"{"status": "OK", "data": {"message": "Dataset published"}}"#),
+            };
+            let response = http::Response::builder().status(status).body(body).unwrap();
+
+            Box::pin(async move { Ok(response.into()) })
+        }
+    }
+
+    /// Tests that locked requests are sent again until they succeed, and given up after the timeout.
+    #[tokio::test]
+    async fn test_lock_retry() {
+        let retry = LockRetry {
+            timeout: Duration::from_millis(200),
+            initial_delay: Duration::from_millis(5),
+            max_delay: Duration::from_millis(20),
+        };
+
+        let transport = Arc::new(LockedTransport { locked: 2, error: LOCKED, calls: AtomicUsize::new(0) });
+        let client = BaseClient::new("http://localhost", None)
+            .unwrap()
+            .with_transport(transport.clone())
+            .with_lock_retry(retry.clone());
+        let response = client.post("api/datasets/42/actions/:publish", None, &RequestType::Plain).await;
+        let response = crate::client::evaluate_response::<MessageResponse>(response).await.unwrap();
+
+        assert!(response.status.is_ok());
+        assert_eq!(transport.calls.load(Ordering::SeqCst), 3);
+
+        let transport = Arc::new(LockedTransport { locked: usize::MAX, error: LOCKED, calls: AtomicUsize::new(0) });
+        let client = BaseClient::new("http://localhost", None)
+            .unwrap()
+            .with_transport(transport.clone())
+            .with_lock_retry(retry);
+        let response = client.post("api/datasets/42/actions/:publish", None, &RequestType::Plain).await;
+        let response = crate::client::evaluate_response::<MessageResponse>(response).await.unwrap();
+
+        assert!(response.status.is_err());
+        assert!(transport.calls.load(Ordering::SeqCst) > 3);
+    }
+
+    /// Tests that other errors mentioning e.g. metadata blocks are returned without a retry.
+    #[tokio::test]
+    async fn test_lock_retry_other_error() {
+        let error = r#"{"status": "ERROR", "message": "Validation failed: the metadata block 'citation' is blocked"}"#;
+        let transport = Arc::new(LockedTransport { locked: usize::MAX, error, calls: AtomicUsize::new(0) });
+        let client = BaseClient::new("http://localhost", None)
+            .unwrap()
+            .with_transport(transport.clone())
+            .with_lock_retry(LockRetry::new(Duration::from_secs(60)));
+        let response = client.post("api/datasets/42/versions/:draft", None, &RequestType::Plain).await;
+        let response = crate::client::evaluate_response::<MessageResponse>(response).await.unwrap();
+
+        assert!(response.status.is_err());
+        assert_eq!(transport.calls.load(Ordering::SeqCst), 1);
+    }
+
+    /// Tests that only the lock wording of Dataverse is recognized.
+    #[test]
+    fn test_is_lock_message() {
+        assert!(is_lock_message("dataset is locked. reason: ingest"));
+        assert!(is_lock_message("dataset cannot be edited due to dataset lock."));
+        assert!(!is_lock_message("invalid metadatablock 'citation'"));
+        assert!(!is_lock_message("the metadata block 'geospatial' is not enabled"));
+        assert!(!is_lock_message("dataset was unlocked"));
+        assert!(!is_lock_message("this is lockedown"));
+    }
+}
@@ -0,0 +1,163 @@
+use std::fmt;
+use std::str::FromStr;
+
+use serde_json::{json, Value};
+
+// The request bodies of this crate are generated from the JSON Schemas in the
+// `models` directory. Bundling the schemas allows external tools, such as forms
+// or validators in other languages, to use exactly the same definitions.
+static COLLECTION_CREATE: &str = include_str!("../models/collection/create.json");
+static DATASET_CREATE: &str = include_str!("../models/dataset/create.json");
+static DATASET_EDIT: &str = include_str!("../models/dataset/edit.json");
+static DATASET_REVIEW: &str = include_str!("../models/dataset/review.json");
+static FILE_EMBARGO: &str = include_str!("../models/file/embargo.json");
+static FILE_META: &str = include_str!("../models/file/filemeta.json");
+static FILE_METADATA: &str = include_str!("../models/file/metadata.json");
+static BUILTIN_USERS: &str = include_str!("../models/admin/builtin_users.json");
+static HARVEST_CLIENTS: &str = include_str!("../models/admin/harvest_clients.json");
+
+/// Request bodies whose JSON Schema is bundled with the crate.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Schema {
+    CollectionCreate,
+    DatasetCreate,
+    DatasetEdit,
+    FileUpload,
+    FileMetadata,
+    Embargo,
+    Unembargo,
+    ReturnToAuthor,
+    BuiltinUser,
+    HarvestingClient,
+}
+
+impl Schema {
+    pub const ALL: [Schema; 10] = [
+        Schema::CollectionCreate,
+        Schema::DatasetCreate,
+        Schema::DatasetEdit,
+        Schema::FileUpload,
+        Schema::FileMetadata,
+        Schema::Embargo,
+        Schema::Unembargo,
+        Schema::ReturnToAuthor,
+        Schema::BuiltinUser,
+        Schema::HarvestingClient,
+    ];
+
+    pub fn name(&self) -> &str {
+        match self {
+            Schema::CollectionCreate => "collection-create",
+            Schema::DatasetCreate => "dataset-create",
+            Schema::DatasetEdit => "dataset-edit",
+            Schema::FileUpload => "file-upload",
+            Schema::FileMetadata => "file-metadata",
+            Schema::Embargo => "embargo",
+            Schema::Unembargo => "unembargo",
+            Schema::ReturnToAuthor => "return-to-author",
+            Schema::BuiltinUser => "builtin-user",
+            Schema::HarvestingClient => "harvesting-client",
+        }
+    }
+
+    // The model file and the name of the definition describing the body
+    fn source(&self) -> (&'static str, &'static str) {
+        match self {
+            Schema::CollectionCreate => (COLLECTION_CREATE, "CollectionCreateBody"),
+            Schema::DatasetCreate => (DATASET_CREATE, "DatasetCreateBody"),
+            Schema::DatasetEdit => (DATASET_EDIT, "EditMetadataBody"),
+            Schema::FileUpload => (FILE_META, "UploadBody"),
+            Schema::FileMetadata => (FILE_METADATA, "FileMetadataBody"),
+            Schema::Embargo => (FILE_EMBARGO, "EmbargoBody"),
+            Schema::Unembargo => (FILE_EMBARGO, "UnembargoBody"),
+            Schema::ReturnToAuthor => (DATASET_REVIEW, "ReturnToAuthorBody"),
+            Schema::BuiltinUser => (BUILTIN_USERS, "BuiltinUserBody"),
+            Schema::HarvestingClient => (HARVEST_CLIENTS, "HarvestingClientBody"),
+        }
+    }
+
+    /// Returns the JSON Schema of the body.
+    ///
+    /// The schema references the definition of the body at its root and carries all definitions of the model
+    /// file, such that it can be used as is by any draft-07 validator.
+    pub fn to_value(&self) -> Value {
+        let (source, definition) = self.source();
+        let model: Value = serde_json::from_str(source).expect("Bundled schemas are valid JSON");
+
+        json!({
+            "$schema": model["$schema"],
+            "title": definition,
+            "$ref": format!("#/definitions/{}", definition),
+            "definitions": model["definitions"],
+        })
+    }
+}
+
+impl fmt::Display for Schema {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Schema {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Schema::ALL
+            .into_iter()
+            .find(|schema| schema.name() == s)
+            .ok_or_else(|| format!("Unknown schema: {}", s))
+    }
+}
+
+/// Returns the JSON Schema of the body to create a collection.
+pub fn collection_create() -> Value {
+    Schema::CollectionCreate.to_value()
+}
+
+/// Returns the JSON Schema of the body to create a dataset.
+pub fn dataset_create() -> Value {
+    Schema::DatasetCreate.to_value()
+}
+
+/// Returns the JSON Schema of the body to edit the metadata of a dataset.
+pub fn dataset_edit() -> Value {
+    Schema::DatasetEdit.to_value()
+}
+
+/// Returns the JSON Schema of the metadata sent along with uploaded files.
+pub fn file_upload() -> Value {
+    Schema::FileUpload.to_value()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Schema;
+
+    /// Tests that every schema references an existing definition.
+    #[test]
+    fn test_schemas_resolve() {
+        for schema in Schema::ALL {
+            let value = schema.to_value();
+            let reference = value["$ref"].as_str().unwrap();
+            let definition = reference.trim_start_matches("#/definitions/");
+
+            assert!(
+                value["definitions"][definition].is_object(),
+                "{} does not resolve {}",
+                schema,
+                reference
+            );
+            assert_eq!(schema.name().parse::<Schema>(), Ok(schema));
+        }
+
+        assert!("unknown".parse::<Schema>().is_err());
+    }
+
+    /// Tests that the dataset schema describes the fields of the generated body.
+    #[test]
+    fn test_dataset_create_schema() {
+        let schema = super::dataset_create();
+        assert_eq!(schema["title"], "DatasetCreateBody");
+        assert!(schema["definitions"]["DatasetCreateBody"]["properties"]["datasetVersion"].is_object());
+    }
+}
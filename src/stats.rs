@@ -0,0 +1,725 @@
+use std::collections::BTreeMap;
+
+use chrono::NaiveDate;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    client::BaseClient,
+    identifier::Identifier,
+    native_api::collection::assignments::{list_assignments, RoleAssignment},
+    native_api::collection::tree::walk_tree,
+    native_api::dataset::assignments::list_dataset_assignments,
+    native_api::dataset::edit::{Dataset, GetDatasetResponse},
+    native_api::dataset::get::get_dataset_meta,
+};
+
+#[cfg(feature = "metrics")]
+use crate::{
+    native_api::collection::storage::{bytes_in_message, get_storage_size},
+    native_api::metrics::counts::{get_metric, Metric, MetricPeriod},
+};
+
+// Status used for datasets whose metadata could not be retrieved,
+// which usually happens due to missing permissions
+pub const UNAVAILABLE_STATUS: &str = "UNAVAILABLE";
+
+/// Aggregated statistics of a collection tree.
+///
+/// The report contains instance-wide totals as well as an optional breakdown per collection. The
+/// totals are taken from the metrics API and hence only count published collections, datasets and
+/// files, while the total bytes cover all files stored in the tree. Datasets of the breakdown are
+/// additionally counted by the state of their latest version (e.g. `DRAFT`, `RELEASED`).
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct InstanceReport {
+    pub root: String,
+    pub total_collections: u64,
+    pub total_datasets: u64,
+    pub total_files: u64,
+    pub total_bytes: u64,
+    pub by_status: BTreeMap<String, u64>,
+    pub collections: Vec<CollectionStats>,
+}
+
+/// Statistics of a single collection, not including its sub-collections.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct CollectionStats {
+    pub collection: String,
+    pub title: Option<String>,
+    pub parent: Option<String>,
+    pub depth: u64,
+    pub datasets: u64,
+    pub files: u64,
+    pub bytes: u64,
+    pub by_status: BTreeMap<String, u64>,
+}
+
+impl InstanceReport {
+    /// Serializes the report into pretty-printed JSON.
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+
+    /// Serializes the per-collection breakdown into CSV.
+    ///
+    /// Each collection is written as one row. Besides the dataset, file and byte counts, a column
+    /// is added for every publication status found in the report.
+    pub fn to_csv(&self) -> Result<String, String> {
+        let statuses: Vec<&String> = self.by_status.keys().collect();
+        let mut writer = csv::Writer::from_writer(vec![]);
+
+        let mut header = vec!["collection", "title", "parent", "depth", "datasets", "files", "bytes"];
+        header.extend(statuses.iter().map(|s| s.as_str()));
+        writer.write_record(&header).map_err(|e| e.to_string())?;
+
+        for stats in &self.collections {
+            let mut record = vec![
+                stats.collection.clone(),
+                stats.title.clone().unwrap_or_default(),
+                stats.parent.clone().unwrap_or_default(),
+                stats.depth.to_string(),
+                stats.datasets.to_string(),
+                stats.files.to_string(),
+                stats.bytes.to_string(),
+            ];
+            record.extend(
+                statuses
+                    .iter()
+                    .map(|s| stats.by_status.get(*s).copied().unwrap_or(0).to_string()),
+            );
+            writer.write_record(&record).map_err(|e| e.to_string())?;
+        }
+
+        let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+        String::from_utf8(bytes).map_err(|e| e.to_string())
+    }
+
+    // Adds the statistics of a collection to the breakdown of the report
+    #[cfg(feature = "metrics")]
+    fn add(&mut self, stats: CollectionStats) {
+        for (status, count) in &stats.by_status {
+            *self.by_status.entry(status.clone()).or_default() += count;
+        }
+
+        self.collections.push(stats);
+    }
+}
+
+/// Files whose embargo ends within a given number of days.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct EmbargoReport {
+    pub root: String,
+    pub days: i64,
+    pub entries: Vec<EmbargoEntry>,
+}
+
+/// A single embargoed file of an `EmbargoReport`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct EmbargoEntry {
+    pub dataset: String,
+    pub file: String,
+    pub file_id: Option<i64>,
+    pub embargo_end: String,
+    pub days_left: i64,
+    pub contact: Option<String>,
+}
+
+impl EmbargoReport {
+    /// Serializes the report into CSV, one row per embargoed file.
+    pub fn to_csv(&self) -> Result<String, String> {
+        let mut writer = csv::Writer::from_writer(vec![]);
+
+        writer
+            .write_record(["dataset", "file", "file_id", "embargo_end", "days_left", "contact"])
+            .map_err(|e| e.to_string())?;
+
+        for entry in &self.entries {
+            writer
+                .write_record([
+                    entry.dataset.clone(),
+                    entry.file.clone(),
+                    entry.file_id.map(|id| id.to_string()).unwrap_or_default(),
+                    entry.embargo_end.clone(),
+                    entry.days_left.to_string(),
+                    entry.contact.clone().unwrap_or_default(),
+                ])
+                .map_err(|e| e.to_string())?;
+        }
+
+        let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+        String::from_utf8(bytes).map_err(|e| e.to_string())
+    }
+}
+
+/// Role assignments of a collection tree, grouped by assignee.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct AssignmentReport {
+    pub root: String,
+    pub role: Option<String>,
+    pub entries: Vec<AssignmentEntry>,
+}
+
+/// A single role assignment of an `AssignmentReport`.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct AssignmentEntry {
+    pub assignee: String,
+    pub role: String,
+    pub object_type: String,
+    pub object: String,
+    pub assignment_id: Option<i64>,
+}
+
+impl AssignmentReport {
+    /// Serializes the report into CSV, one row per role assignment.
+    pub fn to_csv(&self) -> Result<String, String> {
+        let mut writer = csv::Writer::from_writer(vec![]);
+
+        writer
+            .write_record(["assignee", "role", "object_type", "object", "assignment_id"])
+            .map_err(|e| e.to_string())?;
+
+        for entry in &self.entries {
+            writer
+                .write_record([
+                    entry.assignee.clone(),
+                    entry.role.clone(),
+                    entry.object_type.clone(),
+                    entry.object.clone(),
+                    entry.assignment_id.map(|id| id.to_string()).unwrap_or_default(),
+                ])
+                .map_err(|e| e.to_string())?;
+        }
+
+        let bytes = writer.into_inner().map_err(|e| e.to_string())?;
+        String::from_utf8(bytes).map_err(|e| e.to_string())
+    }
+}
+
+/// Builds an `InstanceReport` for a collection and all of its sub-collections.
+///
+/// This asynchronous function retrieves the totals of the collection tree from the metrics API and
+/// the storage size of the collection, which takes a handful of requests regardless of the size of
+/// the instance. Note that metrics only count published objects and may lag behind recent changes.
+///
+/// The per-collection breakdown is not covered by the metrics API. If requested, the collection
+/// tree is traversed and the metadata of every dataset found is retrieved, which is considerably
+/// slower on large instances. File counts and sizes of the breakdown refer to the latest version of
+/// each dataset. Datasets whose metadata cannot be retrieved are counted as `UNAVAILABLE`.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `root` - A string slice that holds the alias of the collection to start from.
+/// * `breakdown` - Whether to traverse the collection tree for the per-collection breakdown.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `InstanceReport` on success, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let report = dataverse::stats::build_instance_report(&client, "root", true).await?;
+///
+/// println!("{}", report.to_csv()?);
+/// # Ok(())
+/// # }
+/// ```
+#[cfg(feature = "metrics")]
+pub async fn build_instance_report(
+    client: &BaseClient,
+    root: &str,
+    breakdown: bool,
+) -> Result<InstanceReport, String> {
+    let mut report = InstanceReport {
+        root: root.to_string(),
+        total_collections: count_metric(client, Metric::Dataverses, root).await?,
+        total_datasets: count_metric(client, Metric::Datasets, root).await?,
+        total_files: count_metric(client, Metric::Files, root).await?,
+        total_bytes: storage_size(client, root).await?,
+        ..Default::default()
+    };
+
+    if !breakdown {
+        return Ok(report);
+    }
+
+    for node in walk_tree(client, root, 1).await? {
+        let datasets = node.dataset_ids();
+        let mut stats = CollectionStats {
+            collection: node.collection,
+            title: node.title,
+            parent: node.parent,
+            depth: node.depth,
+            ..Default::default()
+        };
+
+        for id in datasets {
+            add_dataset(client, id, &mut stats).await?;
+        }
+
+        report.add(stats);
+    }
+
+    Ok(report)
+}
+
+/// Builds an `EmbargoReport` of all files whose embargo ends within the given number of days.
+///
+/// This asynchronous function traverses the collection tree starting at the given collection and
+/// inspects the file metadata of the latest version of every dataset found. Files with an embargo
+/// ending between today and `days` days from now are reported along with the dataset contacts,
+/// such that curators can prepare the checks due when the embargo is lifted.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `root` - A string slice that holds the alias or ID of the collection to start from.
+/// * `days` - The number of days to look ahead.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `EmbargoReport` on success, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let report = dataverse::stats::build_embargo_report(&client, "root", 30).await?;
+///
+/// println!("{}", report.to_csv()?);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn build_embargo_report(
+    client: &BaseClient,
+    root: &str,
+    days: i64,
+) -> Result<EmbargoReport, String> {
+    let today = chrono::Local::now().date_naive();
+    let mut report = EmbargoReport {
+        root: root.to_string(),
+        days,
+        ..Default::default()
+    };
+
+    for node in walk_tree(client, root, 1).await? {
+        for id in node.dataset_ids() {
+            let response = get_dataset_meta(client, Identifier::Id(id)).await?;
+            if let Some(dataset) = response.data {
+                report.entries.extend(expiring_embargoes(&dataset, today, days));
+            }
+        }
+    }
+
+    report.entries.sort_by(|a, b| a.embargo_end.cmp(&b.embargo_end));
+
+    Ok(report)
+}
+
+/// Builds an `AssignmentReport` of the role assignments within a collection tree.
+///
+/// This asynchronous function traverses the collection tree starting at the given collection and
+/// lists the role assignments defined on every collection and dataset found. The entries are grouped
+/// by assignee, such that access reviews can go through the permissions of one user or group at a
+/// time. Inherited assignments are reported only where they are defined.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the requests.
+/// * `root` - A string slice that holds the alias or ID of the collection to start from.
+/// * `role` - An optional role alias, such as `admin` or `curator`, to restrict the report to.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `AssignmentReport` on success, or a `String` error message on failure.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let report = dataverse::stats::build_assignment_report(&client, "root", Some("admin")).await?;
+///
+/// println!("{}", report.to_csv()?);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn build_assignment_report(
+    client: &BaseClient,
+    root: &str,
+    role: Option<&str>,
+) -> Result<AssignmentReport, String> {
+    let mut report = AssignmentReport {
+        root: root.to_string(),
+        role: role.map(str::to_string),
+        ..Default::default()
+    };
+
+    for node in walk_tree(client, root, 1).await? {
+        let response = list_assignments(client, &node.collection).await?;
+        let assignments = response.data.unwrap_or_default();
+        report.entries.extend(assignment_entries(&assignments, "collection", &node.collection, role));
+
+        for id in node.dataset_ids() {
+            let response = list_dataset_assignments(client, Identifier::Id(id)).await?;
+            let assignments = response.data.unwrap_or_default();
+            report.entries.extend(assignment_entries(&assignments, "dataset", &id.to_string(), role));
+        }
+    }
+
+    report.entries.sort_by(|a, b| {
+        (&a.assignee, &a.object_type, &a.object).cmp(&(&b.assignee, &b.object_type, &b.object))
+    });
+
+    Ok(report)
+}
+
+// Retrieves the total count of a metric within a collection tree
+#[cfg(feature = "metrics")]
+async fn count_metric(client: &BaseClient, metric: Metric, root: &str) -> Result<u64, String> {
+    let response = get_metric(client, metric, &MetricPeriod::Total, Some(root)).await?;
+    let count = response.data.and_then(|data| data.count).unwrap_or(0);
+
+    Ok(count.max(0) as u64)
+}
+
+// Retrieves the number of bytes stored within a collection tree
+#[cfg(feature = "metrics")]
+async fn storage_size(client: &BaseClient, root: &str) -> Result<u64, String> {
+    let response = get_storage_size(client, root, false).await?;
+    let message = response.data.and_then(|data| data.message).unwrap_or_default();
+
+    bytes_in_message(&message).ok_or_else(|| format!("Failed to read the storage size from: {}", message))
+}
+
+// Retrieves the latest version of a dataset and adds it to the collection statistics
+#[cfg(feature = "metrics")]
+async fn add_dataset(client: &BaseClient, id: i64, stats: &mut CollectionStats) -> Result<(), String> {
+    let response = get_dataset_meta(client, Identifier::Id(id)).await?;
+    let version = response.data.and_then(|dataset| dataset.latest_version);
+
+    let status = match &version {
+        Some(version) => version
+            .version_state
+            .clone()
+            .unwrap_or_else(|| UNAVAILABLE_STATUS.to_string()),
+        None => UNAVAILABLE_STATUS.to_string(),
+    };
+
+    stats.datasets += 1;
+    *stats.by_status.entry(status).or_default() += 1;
+
+    if let Some(version) = version {
+        stats.files += version.files.len() as u64;
+        stats.bytes += version
+            .files
+            .iter()
+            .filter_map(|file| file.data_file.as_ref()?.filesize)
+            .sum::<i64>() as u64;
+    }
+
+    Ok(())
+}
+
+// Extracts the files of the latest version whose embargo ends within the given number of days
+fn expiring_embargoes(dataset: &GetDatasetResponse, today: NaiveDate, days: i64) -> Vec<EmbargoEntry> {
+    let Some(version) = &dataset.latest_version else {
+        return vec![];
+    };
+
+    let pid = dataset
+        .persistent_url
+        .clone()
+        .or_else(|| version.dataset_persistent_id.clone())
+        .unwrap_or_else(|| dataset.id.map(|id| id.to_string()).unwrap_or_default());
+    let contact = dataset_contact(version);
+
+    version
+        .files
+        .iter()
+        .filter_map(|file| {
+            let data_file = file.data_file.as_ref()?;
+            let end = data_file.embargo.as_ref()?.date_available.as_ref()?;
+            let days_left = (NaiveDate::parse_from_str(end, "%Y-%m-%d").ok()? - today).num_days();
+
+            if !(0..=days).contains(&days_left) {
+                return None;
+            }
+
+            Some(EmbargoEntry {
+                dataset: pid.clone(),
+                file: file
+                    .label
+                    .clone()
+                    .or_else(|| data_file.filename.clone())
+                    .unwrap_or_default(),
+                file_id: data_file.id,
+                embargo_end: end.clone(),
+                days_left,
+                contact: contact.clone(),
+            })
+        })
+        .collect()
+}
+
+// Joins the e-mail addresses of the dataset contacts found in the citation block
+fn dataset_contact(version: &Dataset) -> Option<String> {
+    let blocks = serde_json::to_value(&version.metadata_blocks).ok()?;
+    let contacts = blocks
+        .pointer("/citation/fields")?
+        .as_array()?
+        .iter()
+        .find(|field| field["typeName"] == "datasetContact")?["value"]
+        .as_array()?
+        .iter()
+        .filter_map(|contact| contact["datasetContactEmail"]["value"].as_str())
+        .collect::<Vec<_>>();
+
+    (!contacts.is_empty()).then(|| contacts.join("; "))
+}
+
+// Turns the assignments of a collection or dataset into report entries, optionally filtered by role
+fn assignment_entries(
+    assignments: &[RoleAssignment],
+    object_type: &str,
+    object: &str,
+    role: Option<&str>,
+) -> Vec<AssignmentEntry> {
+    assignments
+        .iter()
+        .filter(|assignment| role.is_none() || assignment.role_alias.as_deref() == role)
+        .map(|assignment| AssignmentEntry {
+            assignee: assignment.assignee.clone().unwrap_or_default(),
+            role: assignment.role_alias.clone().unwrap_or_default(),
+            object_type: object_type.to_string(),
+            object: object.to_string(),
+            assignment_id: assignment.id,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+
+    #[cfg(feature = "metrics")]
+    use httpmock::prelude::*;
+
+    use crate::prelude::BaseClient;
+    use crate::test_utils::{create_test_collection, create_test_dataset, extract_test_env};
+
+    use super::{assignment_entries, expiring_embargoes, AssignmentReport, CollectionStats, InstanceReport};
+
+    /// Tests the CSV serialization of an instance report.
+    ///
+    /// This test builds a report from two collections with differing publication states and
+    /// verifies that the statuses are aggregated and that every status gets its own CSV column.
+    #[cfg(feature = "metrics")]
+    #[test]
+    fn test_report_to_csv() {
+        let mut report = InstanceReport {
+            root: "root".to_string(),
+            ..Default::default()
+        };
+
+        report.add(CollectionStats {
+            collection: "root".to_string(),
+            datasets: 2,
+            files: 3,
+            bytes: 100,
+            by_status: BTreeMap::from([("DRAFT".to_string(), 1), ("RELEASED".to_string(), 1)]),
+            ..Default::default()
+        });
+
+        report.add(CollectionStats {
+            collection: "12".to_string(),
+            title: Some("Child, with comma".to_string()),
+            parent: Some("root".to_string()),
+            depth: 1,
+            datasets: 1,
+            files: 1,
+            bytes: 50,
+            by_status: BTreeMap::from([("DRAFT".to_string(), 1)]),
+        });
+
+        assert_eq!(report.collections.len(), 2);
+        assert_eq!(report.by_status["DRAFT"], 2);
+
+        let csv = report.to_csv().expect("Failed to write CSV");
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], "collection,title,parent,depth,datasets,files,bytes,DRAFT,RELEASED");
+        assert_eq!(lines[1], "root,,,0,2,3,100,1,1");
+        assert_eq!(lines[2], "12,\"Child, with comma\",root,1,1,1,50,1,0");
+    }
+
+    /// Tests that the totals are taken from the metrics API and the storage size of the collection,
+    /// without traversing the collection tree.
+    #[cfg(feature = "metrics")]
+    #[tokio::test]
+    async fn test_build_instance_totals() {
+        let server = MockServer::start();
+        let metrics = [("dataverses", 3), ("datasets", 12), ("files", 40)].map(|(metric, count)| {
+            server.mock(|when, then| {
+                when.method(GET)
+                    .path(format!("/api/info/metrics/{}", metric))
+                    .query_param("parentAlias", "root");
+                then.status(200).json_body(serde_json::json!({"status": "OK", "data": {"count": count}}));
+            })
+        });
+        let storage = server.mock(|when, then| {
+            when.method(GET).path("/api/dataverses/root/storagesize");
+            then.status(200).json_body(serde_json::json!({
+                "status": "OK",
+                "data": {"message": "Total size of the files stored in this dataverse: 1,024 bytes"}
+            }));
+        });
+        let contents = server.mock(|when, then| {
+            when.method(GET).path("/api/dataverses/root/contents");
+            then.status(200).json_body(serde_json::json!({"status": "OK", "data": []}));
+        });
+
+        let client = BaseClient::new(&server.base_url(), None).unwrap();
+        let report = super::build_instance_report(&client, "root", false)
+            .await
+            .expect("Failed to build report");
+
+        assert_eq!(report.total_collections, 3);
+        assert_eq!(report.total_datasets, 12);
+        assert_eq!(report.total_files, 40);
+        assert_eq!(report.total_bytes, 1024);
+        assert!(report.collections.is_empty());
+
+        metrics.iter().for_each(|mock| mock.assert());
+        storage.assert();
+        contents.assert_hits(0);
+    }
+
+    /// Tests the extraction of expiring embargoes from the metadata of a dataset.
+    ///
+    /// This test deserializes a dataset with three embargoed files, of which one ends within the
+    /// look-ahead window, one ends later and one has already ended. Only the first one is reported
+    /// together with the contact of the dataset.
+    #[test]
+    fn test_expiring_embargoes() {
+        let dataset = serde_json::from_value(serde_json::json!({
+            "id": 1,
+            "persistentUrl": "https://doi.org/10.5072/FK2/ABC123",
+            "latestVersion": {
+                "metadataBlocks": {
+                    "citation": {
+                        "fields": [{
+                            "typeName": "datasetContact",
+                            "multiple": true,
+                            "typeClass": "compound",
+                            "value": [{
+                                "datasetContactEmail": {
+                                    "typeName": "datasetContactEmail",
+                                    "multiple": false,
+                                    "typeClass": "primitive",
+                                    "value": "curator@example.com"
+                                }
+                            }]
+                        }]
+                    }
+                },
+                "files": [
+                    {"label": "soon.csv", "dataFile": {"id": 10, "embargo": {"dateAvailable": "2024-06-10"}}},
+                    {"label": "later.csv", "dataFile": {"id": 11, "embargo": {"dateAvailable": "2024-12-01"}}},
+                    {"label": "past.csv", "dataFile": {"id": 12, "embargo": {"dateAvailable": "2024-05-01"}}},
+                    {"label": "open.csv", "dataFile": {"id": 13}}
+                ]
+            }
+        }))
+        .expect("Failed to deserialize dataset");
+
+        let today = chrono::NaiveDate::from_ymd_opt(2024, 6, 1).unwrap();
+        let entries = expiring_embargoes(&dataset, today, 30);
+
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].file, "soon.csv");
+        assert_eq!(entries[0].file_id, Some(10));
+        assert_eq!(entries[0].days_left, 9);
+        assert_eq!(entries[0].contact.as_deref(), Some("curator@example.com"));
+    }
+
+    /// Tests the filtering of role assignments and the CSV serialization of the report.
+    #[test]
+    fn test_assignment_report_to_csv() {
+        let assignments = serde_json::from_value::<Vec<_>>(serde_json::json!([
+            {"id": 1, "assignee": "@dataverseAdmin", "roleId": 1, "_roleAlias": "admin", "definitionPointId": 2},
+            {"id": 2, "assignee": "&explicit/1-reviewers", "roleId": 7, "_roleAlias": "curator", "definitionPointId": 2}
+        ]))
+        .expect("Failed to deserialize assignments");
+
+        let admins = assignment_entries(&assignments, "collection", "root", Some("admin"));
+        assert_eq!(admins.len(), 1);
+        assert_eq!(admins[0].assignee, "@dataverseAdmin");
+
+        let report = AssignmentReport {
+            root: "root".to_string(),
+            role: None,
+            entries: assignment_entries(&assignments, "dataset", "42", None),
+        };
+
+        let csv = report.to_csv().expect("Failed to write CSV");
+        let lines: Vec<&str> = csv.lines().collect();
+
+        assert_eq!(lines[0], "assignee,role,object_type,object,assignment_id");
+        assert_eq!(lines[1], "@dataverseAdmin,admin,dataset,42,1");
+        assert_eq!(lines[2], "&explicit/1-reviewers,curator,dataset,42,2");
+    }
+
+    /// Tests building a report for a freshly created collection.
+    ///
+    /// This test sets up a client using API token and base URL obtained from environment variables,
+    /// creates a collection containing a sub-collection and a dataset, and verifies that the breakdown
+    /// covers both collections and counts the draft dataset.
+    ///
+    /// # Environment Variables
+    /// - `API_TOKEN`: The API token used for authentication with the API.
+    /// - `BASE_URL`: The base URL of the instance.
+    ///
+    /// # Panics
+    /// This test will panic if the client fails to be created or if the report is incomplete.
+    #[cfg(feature = "metrics")]
+    #[tokio::test]
+    async fn test_build_instance_report() {
+        // Set up the client
+        let (api_token, base_url, _) = extract_test_env();
+        let client = BaseClient::new(&base_url, Some(&api_token))
+            .expect("Failed to create client");
+
+        // Create a collection with a sub-collection and a dataset
+        let alias = create_test_collection(&client, "Root").await;
+        create_test_collection(&client, &alias).await;
+        create_test_dataset(&client, &alias).await;
+
+        // Build the report
+        let report = super::build_instance_report(&client, &alias, true)
+            .await
+            .expect("Failed to build report");
+
+        assert_eq!(report.collections.len(), 2);
+        assert_eq!(report.collections.iter().map(|stats| stats.datasets).sum::<u64>(), 1);
+        assert_eq!(report.by_status.get("DRAFT"), Some(&1));
+    }
+}
@@ -0,0 +1,518 @@
+//! Deposits using the SWORD v2 API of Dataverse, as an alternative to the native API.
+//!
+//! SWORD describes datasets as Atom entries with Dublin Core terms and adds files as ZIP packages. Some
+//! institutional workflows are built around SWORD, which these functions allow to drive from Rust. The
+//! native API should be preferred otherwise, as SWORD only covers part of the citation metadata.
+//!
+//! Dataverse authenticates SWORD requests by HTTP Basic authentication, using the API token as the user
+//! name. Clients created with an API token are therefore supported, whereas bearer tokens are not.
+//!
+//! ```no_run
+//! use dataverse::prelude::*;
+//! use dataverse::sword::{self, AtomEntry};
+//!
+//! # async fn run() -> Result<(), String> {
+//! let client = BaseClient::new("https://demo.dataverse.org", Some(&"api_token".to_string()))
+//!     .expect("Failed to create client");
+//!
+//! let entry = AtomEntry::new("Soil moisture")
+//!     .add_creator("Doe, Jane", Some("University of Example"))
+//!     .description("Hourly measurements at ten sites.")
+//!     .add_subject("Earth and Environmental Sciences")
+//!     .add_contact("jane.doe@example.edu");
+//!
+//! let receipt = sword::create_dataset(&client, "root", &entry).await?;
+//! let pid = receipt.persistent_id().expect("No persistent identifier");
+//!
+//! let package = std::fs::read("data.zip").map_err(|e| e.to_string())?;
+//! sword::add_files(&client, &pid, "data.zip", package).await?;
+//! # Ok(())
+//! # }
+//! ```
+
+use base64::{engine::general_purpose::STANDARD, Engine};
+use quick_xml::escape::escape;
+use serde::Deserialize;
+
+use crate::auth::Auth;
+use crate::client::BaseClient;
+use crate::request::RequestType;
+
+const SWORD_PATH: &str = "dvn/api/data-deposit/v1.1/swordv2";
+const SIMPLE_ZIP: &str = "http://purl.org/net/sword/package/SimpleZip";
+
+/// The metadata of a dataset to deposit, as an Atom entry with Dublin Core terms.
+///
+/// Dataverse requires a title, a creator, a description, a subject and a contact email.
+#[derive(Debug, Clone, Default)]
+pub struct AtomEntry {
+    title: String,
+    creators: Vec<(String, Option<String>)>,
+    descriptions: Vec<String>,
+    subjects: Vec<String>,
+    keywords: Vec<String>,
+    contacts: Vec<String>,
+    license: Option<String>,
+}
+
+impl AtomEntry {
+    pub fn new(title: &str) -> Self {
+        AtomEntry {
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    pub fn add_creator(mut self, name: &str, affiliation: Option<&str>) -> Self {
+        self.creators.push((name.to_string(), affiliation.map(String::from)));
+        self
+    }
+
+    pub fn description(mut self, description: &str) -> Self {
+        self.descriptions.push(description.to_string());
+        self
+    }
+
+    pub fn add_subject(mut self, subject: &str) -> Self {
+        self.subjects.push(subject.to_string());
+        self
+    }
+
+    pub fn add_keyword(mut self, keyword: &str) -> Self {
+        self.keywords.push(keyword.to_string());
+        self
+    }
+
+    /// Adds the email of a contact, to whom requests about the dataset are sent.
+    pub fn add_contact(mut self, email: &str) -> Self {
+        self.contacts.push(email.to_string());
+        self
+    }
+
+    /// Sets the license by its name, which has to match a license configured for the instance.
+    pub fn license(mut self, name: &str) -> Self {
+        self.license = Some(name.to_string());
+        self
+    }
+
+    /// Renders the entry as the XML document sent to the instance.
+    pub fn to_xml(&self) -> String {
+        let mut elements = vec![format!("<dcterms:title>{}</dcterms:title>", escape(&self.title))];
+        for (name, affiliation) in &self.creators {
+            elements.push(match affiliation {
+                Some(affiliation) => format!(
+                    "<dcterms:creator affiliation=\"{}\">{}</dcterms:creator>",
+                    escape(affiliation),
+                    escape(name)
+                ),
+                None => format!("<dcterms:creator>{}</dcterms:creator>", escape(name)),
+            });
+        }
+        for description in &self.descriptions {
+            elements.push(format!("<dcterms:description>{}</dcterms:description>", escape(description)));
+        }
+        // Dublin Core has a single term for both subjects of the vocabulary and keywords
+        for subject in self.subjects.iter().chain(&self.keywords) {
+            elements.push(format!("<dcterms:subject>{}</dcterms:subject>", escape(subject)));
+        }
+        for contact in &self.contacts {
+            elements.push(format!(
+                "<dcterms:contributor type=\"Contact\">{}</dcterms:contributor>",
+                escape(contact)
+            ));
+        }
+        if let Some(license) = &self.license {
+            elements.push(format!("<dcterms:license>{}</dcterms:license>", escape(license)));
+        }
+
+        let mut xml = String::from(concat!(
+            "<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n",
+            "<entry xmlns=\"http://www.w3.org/2005/Atom\" xmlns:dcterms=\"http://purl.org/dc/terms/\">\n",
+        ));
+        for element in elements {
+            xml.push_str(&format!("  {}\n", element));
+        }
+        xml.push_str("</entry>\n");
+
+        xml
+    }
+}
+
+/// The receipt of a deposit, linking to the URIs to continue working with the dataset.
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct DepositReceipt {
+    #[serde(default)]
+    pub id: String,
+    #[serde(rename = "link", default)]
+    pub links: Vec<Link>,
+}
+
+impl DepositReceipt {
+    /// Returns the URI of the link with the given relation, e.g. `edit-media`.
+    pub fn link(&self, rel: &str) -> Option<&str> {
+        self.links.iter().find(|link| link.rel == rel).map(|link| link.href.as_str())
+    }
+
+    /// Returns the persistent identifier of the dataset, taken from its edit URI.
+    pub fn persistent_id(&self) -> Option<String> {
+        let uri = self.link("edit").unwrap_or(&self.id);
+        uri.split_once("/edit/study/").map(|(_, pid)| pid.to_string())
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct Link {
+    #[serde(rename = "@href", default)]
+    pub href: String,
+    #[serde(rename = "@rel", default)]
+    pub rel: String,
+}
+
+/// A dataset listed in a collection.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ListedDataset {
+    pub title: String,
+    pub persistent_id: String,
+}
+
+/// A file of a dataset, as listed in its statement.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StatementFile {
+    pub id: Option<i64>,
+    pub name: String,
+    pub content_type: Option<String>,
+    /// The URI of the file within the SWORD API.
+    pub uri: String,
+}
+
+/// Creates a dataset in a collection from an Atom entry.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - The alias of the collection to create the dataset in.
+/// * `entry` - The `AtomEntry` holding the metadata of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `DepositReceipt` of the new dataset, or a `String` error message on failure.
+pub async fn create_dataset(client: &BaseClient, alias: &str, entry: &AtomEntry) -> Result<DepositReceipt, String> {
+    let url = format!("{}/collection/dataverse/{}", SWORD_PATH, alias);
+    let context = RequestType::Raw {
+        body: entry.to_xml().into_bytes(),
+        headers: with_credentials(client, vec![("Content-Type", "application/atom+xml")]),
+    };
+
+    let xml = read_response(client.post(&url, None, &context).await).await?;
+    quick_xml::de::from_str(&xml).map_err(|e| e.to_string())
+}
+
+/// Adds the files of a ZIP package to the draft of a dataset.
+///
+/// The package is unpacked by the instance, adding each contained file to the dataset.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `pid` - The persistent identifier of the dataset.
+/// * `filename` - The name of the package, e.g. `data.zip`.
+/// * `package` - The content of the ZIP package.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `DepositReceipt` of the dataset, or a `String` error message on failure.
+pub async fn add_files(
+    client: &BaseClient,
+    pid: &str,
+    filename: &str,
+    package: Vec<u8>,
+) -> Result<DepositReceipt, String> {
+    let url = format!("{}/edit-media/study/{}", SWORD_PATH, pid);
+    let disposition = format!("filename={}", filename);
+    let context = RequestType::Raw {
+        body: package,
+        headers: with_credentials(
+            client,
+            vec![
+                ("Content-Type", "application/zip"),
+                ("Content-Disposition", &disposition),
+                ("Packaging", SIMPLE_ZIP),
+            ],
+        ),
+    };
+
+    let xml = read_response(client.post(&url, None, &context).await).await?;
+    quick_xml::de::from_str(&xml).map_err(|e| e.to_string())
+}
+
+/// Lists the datasets of a collection.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `alias` - The alias of the collection.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `ListedDataset`s of the collection, or a `String` error message on failure.
+pub async fn list_datasets(client: &BaseClient, alias: &str) -> Result<Vec<ListedDataset>, String> {
+    #[derive(Deserialize)]
+    struct Feed {
+        #[serde(default)]
+        entry: Vec<Entry>,
+    }
+
+    #[derive(Deserialize)]
+    struct Entry {
+        #[serde(default)]
+        id: String,
+        #[serde(default)]
+        title: String,
+    }
+
+    let url = format!("{}/collection/dataverse/{}", SWORD_PATH, alias);
+    let context = RequestType::Raw { body: Vec::new(), headers: with_credentials(client, vec![]) };
+
+    let xml = read_response(client.get(&url, None, &context).await).await?;
+    let feed: Feed = quick_xml::de::from_str(&xml).map_err(|e| e.to_string())?;
+
+    Ok(feed
+        .entry
+        .into_iter()
+        .filter_map(|entry| {
+            let (_, pid) = entry.id.split_once("/edit/study/")?;
+            Some(ListedDataset { title: entry.title, persistent_id: pid.to_string() })
+        })
+        .collect())
+}
+
+/// Lists the files of the latest version of a dataset, using the statement of the dataset.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `pid` - The persistent identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `StatementFile`s of the dataset, or a `String` error message on failure.
+pub async fn list_files(client: &BaseClient, pid: &str) -> Result<Vec<StatementFile>, String> {
+    #[derive(Deserialize)]
+    struct Feed {
+        #[serde(default)]
+        entry: Vec<Entry>,
+    }
+
+    #[derive(Deserialize)]
+    struct Entry {
+        content: Content,
+    }
+
+    #[derive(Deserialize)]
+    struct Content {
+        #[serde(rename = "@src")]
+        src: String,
+        #[serde(rename = "@type")]
+        content_type: Option<String>,
+    }
+
+    let url = format!("{}/statement/study/{}", SWORD_PATH, pid);
+    let context = RequestType::Raw { body: Vec::new(), headers: with_credentials(client, vec![]) };
+
+    let xml = read_response(client.get(&url, None, &context).await).await?;
+    let feed: Feed = quick_xml::de::from_str(&xml).map_err(|e| e.to_string())?;
+
+    // The sources have the form `.../edit-media/file/{id}/{name}`
+    Ok(feed
+        .entry
+        .into_iter()
+        .map(|entry| {
+            let segments: Vec<&str> = entry.content.src.rsplitn(3, '/').collect();
+            StatementFile {
+                id: segments.get(1).and_then(|id| id.parse().ok()),
+                name: segments.first().unwrap_or(&"").to_string(),
+                content_type: entry.content.content_type,
+                uri: entry.content.src.clone(),
+            }
+        })
+        .collect())
+}
+
+/// Publishes the draft of a dataset, once its collection is published.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `pid` - The persistent identifier of the dataset.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `DepositReceipt` of the dataset, or a `String` error message on failure.
+pub async fn publish_dataset(client: &BaseClient, pid: &str) -> Result<DepositReceipt, String> {
+    let url = format!("{}/edit/study/{}", SWORD_PATH, pid);
+    let context = RequestType::Raw {
+        body: Vec::new(),
+        headers: with_credentials(client, vec![("In-Progress", "false")]),
+    };
+
+    let xml = read_response(client.post(&url, None, &context).await).await?;
+    quick_xml::de::from_str(&xml).map_err(|e| e.to_string())
+}
+
+// Adds the API token of the client as the user name of HTTP Basic authentication
+fn with_credentials(client: &BaseClient, headers: Vec<(&str, &str)>) -> Vec<(String, String)> {
+    let mut headers: Vec<(String, String)> = headers
+        .into_iter()
+        .map(|(name, value)| (name.to_string(), value.to_string()))
+        .collect();
+
+    if let Auth::ApiToken(token) = client.auth() {
+        let credentials = STANDARD.encode(format!("{}:", token));
+        headers.push(("Authorization".to_string(), format!("Basic {}", credentials)));
+    }
+
+    headers
+}
+
+// Returns the body of successful responses and the summary of SWORD error documents otherwise
+async fn read_response(response: Result<reqwest::Response, reqwest::Error>) -> Result<String, String> {
+    #[derive(Deserialize)]
+    struct ErrorDocument {
+        summary: Option<String>,
+    }
+
+    let response = response.map_err(|e| e.to_string())?;
+    let status = response.status();
+    let body = response.text().await.map_err(|e| e.to_string())?;
+
+    if status.is_success() {
+        return Ok(body);
+    }
+
+    let summary = quick_xml::de::from_str::<ErrorDocument>(&body)
+        .ok()
+        .and_then(|error| error.summary);
+    Err(summary.unwrap_or_else(|| format!("The SWORD API responded with status {}", status)))
+}
+
+#[cfg(test)]
+mod tests {
+    use httpmock::prelude::*;
+
+    use crate::prelude::*;
+
+    use super::{AtomEntry, StatementFile};
+
+    const RECEIPT: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<entry xmlns="http://www.w3.org/2005/Atom">
+  <id>http://localhost/dvn/api/data-deposit/v1.1/swordv2/edit/study/doi:10.5072/FK2/ABC</id>
+  <link href="http://localhost/dvn/api/data-deposit/v1.1/swordv2/edit/study/doi:10.5072/FK2/ABC" rel="edit"/>
+  <link href="http://localhost/dvn/api/data-deposit/v1.1/swordv2/edit-media/study/doi:10.5072/FK2/ABC" rel="edit-media"/>
+  <treatment>no treatment information available</treatment>
+</entry>"#;
+
+    /// Tests the deposit of an entry and a package using the API token as Basic credentials.
+    #[tokio::test]
+    async fn test_deposit() {
+        let server = MockServer::start();
+        let create = server.mock(|when, then| {
+            when.method(POST)
+                .path("/dvn/api/data-deposit/v1.1/swordv2/collection/dataverse/root")
+                .header("Authorization", "Basic dG9rZW46")
+                .header("Content-Type", "application/atom+xml")
+                .body_contains("<dcterms:title>Soil &amp; water</dcterms:title>")
+                .body_contains("<dcterms:creator affiliation=\"University of Example\">Doe, Jane</dcterms:creator>")
+                .body_contains("<dcterms:contributor type=\"Contact\">jane.doe@example.edu</dcterms:contributor>");
+            then.status(201).body(RECEIPT);
+        });
+        let package = server.mock(|when, then| {
+            when.method(POST)
+                .path("/dvn/api/data-deposit/v1.1/swordv2/edit-media/study/doi:10.5072/FK2/ABC")
+                .header("Content-Type", "application/zip")
+                .header("Content-Disposition", "filename=data.zip")
+                .header("Packaging", "http://purl.org/net/sword/package/SimpleZip")
+                .body("PK");
+            then.status(201).body(RECEIPT);
+        });
+        let rejected = server.mock(|when, then| {
+            when.method(POST).path("/dvn/api/data-deposit/v1.1/swordv2/collection/dataverse/closed");
+            then.status(400).body(concat!(
+                r#"<sword:error xmlns:sword="http://purl.org/net/sword/" xmlns:atom="http://www.w3.org/2005/Atom">"#,
+                "<atom:summary>user is not authorized to create a dataset in this dataverse</atom:summary>",
+                "</sword:error>"
+            ));
+        });
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+        let entry = AtomEntry::new("Soil & water")
+            .add_creator("Doe, Jane", Some("University of Example"))
+            .description("Hourly measurements.")
+            .add_subject("Earth and Environmental Sciences")
+            .add_contact("jane.doe@example.edu");
+
+        let receipt = super::create_dataset(&client, "root", &entry).await.unwrap();
+        let pid = receipt.persistent_id().unwrap();
+        assert_eq!(pid, "doi:10.5072/FK2/ABC");
+        assert!(receipt.link("edit-media").unwrap().ends_with("/edit-media/study/doi:10.5072/FK2/ABC"));
+
+        super::add_files(&client, &pid, "data.zip", b"PK".to_vec()).await.unwrap();
+
+        let error = super::create_dataset(&client, "closed", &entry).await.unwrap_err();
+        assert_eq!(error, "user is not authorized to create a dataset in this dataverse");
+
+        create.assert();
+        package.assert();
+        rejected.assert();
+    }
+
+    /// Tests the listing of the datasets of a collection and the files of a dataset.
+    #[tokio::test]
+    async fn test_listing() {
+        let server = MockServer::start();
+        server.mock(|when, then| {
+            when.method(GET).path("/dvn/api/data-deposit/v1.1/swordv2/collection/dataverse/root");
+            then.status(200).body(r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <id>http://localhost/dvn/api/data-deposit/v1.1/swordv2/collection/dataverse/root</id>
+  <title type="text">Dataverse: root</title>
+  <dataverseHasBeenReleased xmlns="http://purl.org/net/sword/terms/state">true</dataverseHasBeenReleased>
+  <entry>
+    <id>http://localhost/dvn/api/data-deposit/v1.1/swordv2/edit/study/doi:10.5072/FK2/ABC</id>
+    <link href="http://localhost/dvn/api/data-deposit/v1.1/swordv2/edit/study/doi:10.5072/FK2/ABC" rel="edit"/>
+    <title type="text">Soil moisture</title>
+  </entry>
+</feed>"#);
+        });
+        server.mock(|when, then| {
+            when.method(GET)
+                .path("/dvn/api/data-deposit/v1.1/swordv2/statement/study/doi:10.5072/FK2/ABC");
+            then.status(200).body(r#"<feed xmlns="http://www.w3.org/2005/Atom">
+  <id>http://localhost/dvn/api/data-deposit/v1.1/swordv2/statement/study/doi:10.5072/FK2/ABC</id>
+  <title type="text">Soil moisture</title>
+  <entry>
+    <content type="text/csv" src="http://localhost/dvn/api/data-deposit/v1.1/swordv2/edit-media/file/42/data.csv"/>
+    <id>http://localhost/dvn/api/data-deposit/v1.1/swordv2/edit-media/file/42/data.csv</id>
+    <summary type="text">Resource Part</summary>
+  </entry>
+  <category term="latestVersionState" scheme="http://purl.org/net/sword/terms/state" label="State">DRAFT</category>
+</feed>"#);
+        });
+
+        let client = BaseClient::new(&server.base_url(), Some(&"token".to_string())).unwrap();
+
+        let datasets = super::list_datasets(&client, "root").await.unwrap();
+        assert_eq!(datasets.len(), 1);
+        assert_eq!(datasets[0].title, "Soil moisture");
+        assert_eq!(datasets[0].persistent_id, "doi:10.5072/FK2/ABC");
+
+        let files = super::list_files(&client, "doi:10.5072/FK2/ABC").await.unwrap();
+        assert_eq!(
+            files,
+            vec![StatementFile {
+                id: Some(42),
+                name: "data.csv".to_string(),
+                content_type: Some("text/csv".to_string()),
+                uri: "http://localhost/dvn/api/data-deposit/v1.1/swordv2/edit-media/file/42/data.csv".to_string(),
+            }]
+        );
+    }
+}
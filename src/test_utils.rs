@@ -5,6 +5,7 @@ use crate::native_api::collection::create::{CollectionCreateBody, CollectionCrea
 use crate::native_api::dataset::edit::EditMetadataBody;
 use crate::prelude::{BaseClient, dataset};
 use crate::prelude::dataset::create::DatasetCreateBody;
+#[cfg(feature = "fs")]
 use crate::prelude::dataset::upload::UploadBody;
 
 /// Extracts environment variables required for test configurations.
@@ -36,6 +37,7 @@ pub fn prepare_dataset_body(path: PathBuf) -> DatasetCreateBody {
         .expect("Failed to read dataset create body")
 }
 
+#[cfg(feature = "fs")]
 pub fn prepare_upload_body() -> UploadBody {
     let rdr = std::fs::File::open("tests/fixtures/upload_datafile_body.json")
         .expect("Failed to open upload body");
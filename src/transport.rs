@@ -0,0 +1,193 @@
+#[cfg(not(target_arch = "wasm32"))]
+use std::collections::HashMap;
+use std::sync::Arc;
+#[cfg(not(target_arch = "wasm32"))]
+use std::sync::Mutex;
+
+#[cfg(not(target_arch = "wasm32"))]
+use futures::future::BoxFuture;
+#[cfg(target_arch = "wasm32")]
+use futures::future::LocalBoxFuture as BoxFuture;
+use reqwest::{Client, Request};
+#[cfg(not(target_arch = "wasm32"))]
+use reqwest::Method;
+#[cfg(not(target_arch = "wasm32"))]
+use serde::Serialize;
+
+#[cfg(not(target_arch = "wasm32"))]
+use crate::response::Response;
+
+/// Sends the requests of a `BaseClient`.
+///
+/// The default transport sends requests using `reqwest`. Other transports can be set using
+/// `BaseClient::with_transport`, e.g. a `MockTransport` to test code using the client without
+/// a live instance.
+///
+/// On `wasm32`, the returned futures are not required to be `Send`, as the requests of the
+/// browser cannot be moved between threads.
+pub trait Transport: Send + Sync {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<reqwest::Response, reqwest::Error>>;
+}
+
+// Allows callers to keep a handle on the transport, e.g. to inspect a mock
+impl<T: Transport> Transport for Arc<T> {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<reqwest::Response, reqwest::Error>> {
+        self.as_ref().send(request)
+    }
+}
+
+/// Sends requests over the network using a `reqwest` client.
+pub struct ReqwestTransport {
+    client: Client,
+}
+
+impl ReqwestTransport {
+    pub fn new(client: Client) -> Self {
+        ReqwestTransport { client }
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<reqwest::Response, reqwest::Error>> {
+        Box::pin(self.client.execute(request))
+    }
+}
+
+/// Answers requests with canned responses instead of sending them.
+///
+/// Responses are registered per method and path, regardless of the query. Requests without a
+/// registered response are answered with a `404` error response in the format of Dataverse.
+///
+/// Not available on `wasm32`, as the `wasm` backend of `reqwest` cannot build responses
+/// from local bodies.
+///
+/// # Examples
+///
+/// ```no_run
+/// use dataverse::prelude::*;
+/// use dataverse::response::{Response, Status};
+/// use dataverse::transport::MockTransport;
+/// use reqwest::Method;
+///
+/// # async fn run() {
+/// let transport = MockTransport::new().with_response(
+///     Method::GET,
+///     "/api/info/version",
+///     200,
+///     serde_json::json!({"status": "OK", "data": {"version": "6.2"}}),
+/// );
+///
+/// let client = BaseClient::new("http://localhost", None)
+///     .unwrap()
+///     .with_transport(transport);
+///
+/// let response = info::get_version(&client).await.unwrap();
+/// assert_eq!(response.status, Status::OK);
+/// # }
+/// ```
+#[cfg(not(target_arch = "wasm32"))]
+#[derive(Default)]
+pub struct MockTransport {
+    responses: HashMap<(Method, String), (u16, Vec<u8>)>,
+    requests: Mutex<Vec<(Method, String)>>,
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl MockTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registers the JSON body and status code to answer requests to `path` with.
+    pub fn with_response(mut self, method: Method, path: &str, status: u16, body: serde_json::Value) -> Self {
+        let body = serde_json::to_vec(&body).expect("Failed to serialize the mock response");
+        self.responses.insert((method, path.to_string()), (status, body));
+        self
+    }
+
+    /// Registers a response of the API to answer requests to `path` with. Error responses are
+    /// answered with status `400`.
+    pub fn with_fixture<T: Serialize>(self, method: Method, path: &str, response: &Response<T>) -> Self {
+        let status = match response.status.is_ok() {
+            true => 200,
+            false => 400,
+        };
+        let body = serde_json::to_value(response).expect("Failed to serialize the fixture");
+        self.with_response(method, path, status, body)
+    }
+
+    /// Returns the method and path of all requests received so far, in order. Pass the
+    /// transport to the client as an `Arc` to keep access to it.
+    pub fn requests(&self) -> Vec<(Method, String)> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Transport for MockTransport {
+    fn send(&self, request: Request) -> BoxFuture<'_, Result<reqwest::Response, reqwest::Error>> {
+        let key = (request.method().clone(), request.url().path().to_string());
+        self.requests.lock().unwrap().push(key.clone());
+
+        let (status, body) = match self.responses.get(&key) {
+            Some((status, body)) => (*status, body.clone()),
+            None => {
+                let message = format!("No mock response for {} {}", key.0, key.1);
+                let body = serde_json::json!({"status": "ERROR", "message": message});
+                (404, serde_json::to_vec(&body).unwrap())
+            }
+        };
+
+        let response = http::Response::builder()
+            .status(status)
+            .header("content-type", "application/json")
+            .body(body)
+            .expect("Failed to build the mock response");
+
+        Box::pin(async move { Ok(response.into()) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use reqwest::Method;
+    use serde_json::json;
+
+    use crate::native_api::dataset::link::MessageResponse;
+    use crate::prelude::*;
+    use crate::response::{Response, Status};
+
+    use super::MockTransport;
+
+    /// Tests that registered fixtures are returned and other requests fail without a network.
+    #[tokio::test]
+    async fn test_mock_transport() {
+        let fixture: Response<MessageResponse> = serde_json::from_value(json!({
+            "status": "OK",
+            "data": {"message": "File deleted"}
+        }))
+        .unwrap();
+
+        let transport = Arc::new(MockTransport::new().with_fixture(Method::DELETE, "/api/files/5", &fixture));
+        let client = BaseClient::new("http://localhost:1", None)
+            .unwrap()
+            .with_transport(transport.clone());
+
+        let response = file::delete_file(&client, Identifier::Id(5)).await.unwrap();
+        assert_eq!(response.status, Status::OK);
+
+        let response = file::delete_file(&client, Identifier::Id(6)).await.unwrap();
+        assert_eq!(response.status, Status::ERROR);
+        assert!(response.message.unwrap().to_string().contains("DELETE /api/files/6"));
+
+        assert_eq!(
+            transport.requests(),
+            vec![
+                (Method::DELETE, "/api/files/5".to_string()),
+                (Method::DELETE, "/api/files/6".to_string())
+            ]
+        );
+    }
+}
@@ -0,0 +1,169 @@
+use reqwest::Url;
+
+use crate::{client::BaseClient, identifier::Identifier};
+
+// Builders for links to pages of the web interface and to API endpoints. Query
+// parameters are encoded by `Url`, so identifiers containing reserved characters
+// (e.g. handles or DOIs with `?` and `&`) result in valid links.
+
+/// Returns the URL of the landing page of a dataset, optionally of a specific version.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` whose base URL is used.
+/// * `id` - A reference to the `Identifier` of the dataset.
+/// * `version` - An optional string slice that holds the version, e.g. `1.0` or `DRAFT`.
+///
+/// # Examples
+///
+/// ```
+/// use dataverse::prelude::*;
+/// use dataverse::urls::dataset_page_url;
+///
+/// let client = BaseClient::new("https://demo.dataverse.org", None).unwrap();
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+///
+/// assert_eq!(
+///     dataset_page_url(&client, &id, Some("1.0")).as_str(),
+///     "https://demo.dataverse.org/dataset.xhtml?persistentId=doi%3A10.5072%2FFK2%2FABC123&version=1.0"
+/// );
+/// ```
+pub fn dataset_page_url(client: &BaseClient, id: &Identifier, version: Option<&str>) -> Url {
+    page_url(client, "dataset.xhtml", id, "id", version)
+}
+
+/// Returns the URL of the page of a file, optionally within a specific dataset version.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` whose base URL is used.
+/// * `id` - A reference to the `Identifier` of the file.
+/// * `version` - An optional string slice that holds the version of the dataset, e.g. `1.0` or `DRAFT`.
+pub fn file_page_url(client: &BaseClient, id: &Identifier, version: Option<&str>) -> Url {
+    page_url(client, "file.xhtml", id, "fileId", version)
+}
+
+/// Returns the URL of the page of a collection.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` whose base URL is used.
+/// * `alias` - A string slice that holds the alias of the collection.
+pub fn collection_page_url(client: &BaseClient, alias: &str) -> Url {
+    let mut url = join(client, "dataverse/");
+    url.path_segments_mut()
+        .expect("The base URL cannot be a base")
+        .pop_if_empty()
+        .push(alias);
+    url
+}
+
+/// Returns the native API URL of a dataset.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` whose base URL is used.
+/// * `id` - A reference to the `Identifier` of the dataset.
+pub fn dataset_api_url(client: &BaseClient, id: &Identifier) -> Url {
+    api_url(client, "api/datasets/", id)
+}
+
+/// Returns the native API URL of a file.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` whose base URL is used.
+/// * `id` - A reference to the `Identifier` of the file.
+pub fn file_api_url(client: &BaseClient, id: &Identifier) -> Url {
+    api_url(client, "api/files/", id)
+}
+
+/// Returns the data access API URL to download a file.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` whose base URL is used.
+/// * `id` - A reference to the `Identifier` of the file.
+pub fn file_download_url(client: &BaseClient, id: &Identifier) -> Url {
+    api_url(client, "api/access/datafile/", id)
+}
+
+fn page_url(client: &BaseClient, page: &str, id: &Identifier, id_key: &str, version: Option<&str>) -> Url {
+    let mut url = join(client, page);
+    {
+        let mut query = url.query_pairs_mut();
+        match id {
+            Identifier::PersistentId(pid) => query.append_pair("persistentId", pid),
+            Identifier::Id(id) => query.append_pair(id_key, &id.to_string()),
+        };
+        if let Some(version) = version {
+            query.append_pair("version", version);
+        }
+    }
+    url
+}
+
+fn api_url(client: &BaseClient, endpoint: &str, id: &Identifier) -> Url {
+    match id {
+        Identifier::PersistentId(pid) => {
+            let mut url = join(client, &format!("{}:persistentId", endpoint));
+            url.query_pairs_mut().append_pair("persistentId", pid);
+            url
+        }
+        Identifier::Id(id) => join(client, &format!("{}{}", endpoint, id)),
+    }
+}
+
+fn join(client: &BaseClient, path: &str) -> Url {
+    client
+        .base_url()
+        .join(path)
+        .expect("Failed to join the path to the base URL")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Tests the links to pages of the web interface.
+    #[test]
+    fn test_page_urls() {
+        let client = BaseClient::new("https://demo.dataverse.org", None).unwrap();
+
+        assert_eq!(
+            dataset_page_url(&client, &Identifier::Id(42), None).as_str(),
+            "https://demo.dataverse.org/dataset.xhtml?id=42"
+        );
+        assert_eq!(
+            file_page_url(&client, &Identifier::Id(43), Some("DRAFT")).as_str(),
+            "https://demo.dataverse.org/file.xhtml?fileId=43&version=DRAFT"
+        );
+        assert_eq!(
+            file_page_url(&client, &Identifier::PersistentId("hdl:1902.1/111012?x&y".into()), None).as_str(),
+            "https://demo.dataverse.org/file.xhtml?persistentId=hdl%3A1902.1%2F111012%3Fx%26y"
+        );
+        assert_eq!(
+            collection_page_url(&client, "my collection").as_str(),
+            "https://demo.dataverse.org/dataverse/my%20collection"
+        );
+    }
+
+    /// Tests the links to API endpoints.
+    #[test]
+    fn test_api_urls() {
+        let client = BaseClient::new("https://demo.dataverse.org/", None).unwrap();
+
+        assert_eq!(
+            dataset_api_url(&client, &Identifier::PersistentId("doi:10.5072/FK2/ABC123".into())).as_str(),
+            "https://demo.dataverse.org/api/datasets/:persistentId?persistentId=doi%3A10.5072%2FFK2%2FABC123"
+        );
+        assert_eq!(
+            file_api_url(&client, &Identifier::Id(43)).as_str(),
+            "https://demo.dataverse.org/api/files/43"
+        );
+        assert_eq!(
+            file_download_url(&client, &Identifier::Id(43)).as_str(),
+            "https://demo.dataverse.org/api/access/datafile/43"
+        );
+    }
+}
@@ -0,0 +1,55 @@
+use std::sync::OnceLock;
+
+// Diagnostics of the client, such as the URLs of calls and progress bars, are
+// written to stderr, such that stdout only holds data. How much of them is
+// shown is controlled by a process-wide verbosity, which the command line
+// interface sets via -q and -v and services embedding the client may lower.
+
+static VERBOSITY: OnceLock<Verbosity> = OnceLock::new();
+
+/// How much diagnostic output to write to stderr.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verbosity {
+    /// Errors only, without progress bars.
+    Quiet,
+    /// Calls, progress bars and notices, as long as stderr is a terminal.
+    #[default]
+    Normal,
+    /// Calls including their method, regardless of whether stderr is a terminal.
+    Verbose,
+}
+
+impl Verbosity {
+    /// Determines the verbosity from the `-q` and `-v` flags of the command line, where quiet wins.
+    pub fn from_flags(quiet: bool, verbose: bool) -> Self {
+        match (quiet, verbose) {
+            (true, _) => Verbosity::Quiet,
+            (false, true) => Verbosity::Verbose,
+            (false, false) => Verbosity::Normal,
+        }
+    }
+}
+
+/// Sets the verbosity for the rest of the process. Only the first call has an effect.
+pub fn set_verbosity(verbosity: Verbosity) {
+    let _ = VERBOSITY.set(verbosity);
+}
+
+/// Returns the verbosity, which is `Normal` unless set otherwise.
+pub fn verbosity() -> Verbosity {
+    VERBOSITY.get().copied().unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Verbosity;
+
+    /// Tests that quiet takes precedence over verbose and that levels are ordered.
+    #[test]
+    fn test_verbosity_from_flags() {
+        assert_eq!(Verbosity::from_flags(true, true), Verbosity::Quiet);
+        assert_eq!(Verbosity::from_flags(false, true), Verbosity::Verbose);
+        assert_eq!(Verbosity::from_flags(false, false), Verbosity::Normal);
+        assert!(Verbosity::Quiet < Verbosity::Normal);
+    }
+}
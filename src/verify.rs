@@ -0,0 +1,448 @@
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    checksum::{file_checksums, hashing_threads, sha256_hex},
+    client::BaseClient,
+    identifier::Identifier,
+    native_api::dataset::edit::File,
+    native_api::dataset::get::get_dataset_meta,
+};
+
+// After a deposit, the files of the dataset are compared against the local
+// files. Each entry of the resulting report is chained to its predecessor by a
+// SHA-256 hash, such that altering, removing or reordering entries of a stored
+// report is detectable by recomputing the chain.
+
+/// Outcome of the verification of a single local file.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VerificationStatus {
+    /// The file is present in the dataset with matching size and checksum.
+    Verified,
+    /// No file with the same name exists in the directory of the dataset.
+    Missing,
+    /// The file exists, but its size differs from the local file.
+    SizeMismatch,
+    /// The file exists, but its checksum differs from the local file.
+    ChecksumMismatch,
+}
+
+/// Comparison of a local file with its counterpart in the dataset.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct VerificationEntry {
+    pub path: PathBuf,
+    #[serde(default)]
+    pub directory_label: Option<String>,
+    pub file_id: Option<i64>,
+    pub local_size: u64,
+    pub remote_size: Option<i64>,
+    pub algorithm: Option<String>,
+    pub local_checksum: Option<String>,
+    pub remote_checksum: Option<String>,
+    pub status: VerificationStatus,
+    #[serde(default)]
+    pub previous_hash: String,
+    #[serde(default)]
+    pub hash: String,
+}
+
+/// A hash-chained report of the verification of local files against a dataset.
+///
+/// The chain starts with the hash of the dataset and the creation time. Every entry stores the hash of its
+/// predecessor and its own hash, which covers its content and the previous hash. The `final_hash` thus
+/// attests the complete report and can be recorded separately for compliance purposes.
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq)]
+pub struct VerificationReport {
+    pub dataset: String,
+    pub created: String,
+    pub entries: Vec<VerificationEntry>,
+    pub final_hash: String,
+}
+
+impl VerificationReport {
+    pub fn new(dataset: &str) -> Self {
+        let created = chrono::Utc::now().to_rfc3339();
+        let final_hash = header_hash(dataset, &created);
+
+        VerificationReport {
+            dataset: dataset.to_string(),
+            created,
+            entries: vec![],
+            final_hash,
+        }
+    }
+
+    /// Appends an entry and links it to the end of the chain.
+    pub fn push(&mut self, mut entry: VerificationEntry) {
+        entry.previous_hash = self.final_hash.clone();
+        entry.hash = entry_hash(&entry);
+
+        self.final_hash = entry.hash.clone();
+        self.entries.push(entry);
+    }
+
+    /// Returns `true` if every local file has been verified.
+    pub fn is_verified(&self) -> bool {
+        self.entries
+            .iter()
+            .all(|entry| entry.status == VerificationStatus::Verified)
+    }
+
+    /// Returns the number of files that could not be verified.
+    pub fn failures(&self) -> usize {
+        self.entries
+            .iter()
+            .filter(|entry| entry.status != VerificationStatus::Verified)
+            .count()
+    }
+
+    /// Recomputes the hash chain and returns `true` if the report has not been altered.
+    pub fn is_intact(&self) -> bool {
+        let mut previous = header_hash(&self.dataset, &self.created);
+        for entry in &self.entries {
+            if entry.previous_hash != previous || entry.hash != entry_hash(entry) {
+                return false;
+            }
+            previous = entry.hash.clone();
+        }
+
+        previous == self.final_hash
+    }
+
+    pub fn to_json(&self) -> Result<String, String> {
+        serde_json::to_string_pretty(self).map_err(|e| e.to_string())
+    }
+}
+
+/// Verifies that the given local files are present in the latest version of a dataset.
+///
+/// Files are matched by the directory label they have been uploaded with and by their name against the
+/// labels of the dataset files, such that files of the same name in different folders are told apart. The
+/// checksum of each local file is computed with the algorithm reported by the instance for its counterpart
+/// and compared along with the size. Files that have been ingested as tabular data are matched by their
+/// `.tab` label, and their size is not compared, since ingest replaces the stored file.
+///
+/// # Arguments
+///
+/// * `client` - A reference to the `BaseClient` instance used to send the request.
+/// * `id` - An `Identifier` enum instance, which can be either a `PersistentId(String)` or an `Id(i64)`,
+///   representing the dataset the files have been uploaded to.
+/// * `files` - The paths of the local files to verify, each with the directory label it has been uploaded with.
+///
+/// # Returns
+///
+/// A `Result` wrapping the `VerificationReport`, or a `String` error message if the dataset could not be
+/// retrieved or a local file could not be read.
+///
+/// # Examples
+///
+/// ```no_run
+/// use std::path::PathBuf;
+///
+/// use dataverse::prelude::*;
+/// use dataverse::verify::verify_uploads;
+///
+/// # async fn run() -> Result<(), String> {
+/// let api_token = "api_token".to_string();
+/// let base_url = "https://demo.dataverse.com".to_string();
+/// let client = BaseClient::new(&base_url, Some(&api_token))
+///     .expect("Failed to create client");
+///
+/// let id = Identifier::PersistentId("doi:10.5072/FK2/ABC123".to_string());
+/// let files = [(PathBuf::from("results/data.csv"), Some("results".to_string()))];
+/// let report = verify_uploads(&client, id, &files).await?;
+///
+/// println!("Verified: {}, final hash: {}", report.is_verified(), report.final_hash);
+/// # Ok(())
+/// # }
+/// ```
+pub async fn verify_uploads(
+    client: &BaseClient,
+    id: Identifier,
+    files: &[(PathBuf, Option<String>)],
+) -> Result<VerificationReport, String> {
+    let label = match &id {
+        Identifier::PersistentId(pid) => pid.clone(),
+        Identifier::Id(id) => id.to_string(),
+    };
+
+    let response = get_dataset_meta(client, id).await?;
+    if !response.status.is_ok() {
+        return Err(response.message.map(|m| m.to_string()).unwrap_or_default());
+    }
+
+    let remote = response
+        .data
+        .and_then(|dataset| dataset.latest_version)
+        .map(|version| version.files)
+        .unwrap_or_default();
+
+    let checksums = local_checksums(files, &remote).await?;
+    let mut report = VerificationReport::new(&label);
+    for (path, directory) in files {
+        report.push(verify_file(path, directory.as_deref(), &remote, &checksums)?);
+    }
+
+    Ok(report)
+}
+
+// Hashes each local file with every algorithm used by the dataset files of the
+// same path. All files are hashed up front, such that large trees use all cores.
+async fn local_checksums(
+    local: &[(PathBuf, Option<String>)],
+    files: &[File],
+) -> Result<HashMap<(PathBuf, String), String>, String> {
+    let mut jobs = Vec::new();
+    let mut seen = HashSet::new();
+    for (path, directory) in local {
+        let algorithms = files
+            .iter()
+            .filter(|file| matches_file(path, directory.as_deref(), file))
+            .filter_map(|file| file.data_file.as_ref()?.checksum.as_ref()?.type_.clone());
+
+        for algorithm in algorithms {
+            let job = (path.clone(), algorithm);
+            if seen.insert(job.clone()) {
+                jobs.push(job);
+            }
+        }
+    }
+
+    let results = file_checksums(jobs.clone(), hashing_threads()).await;
+    jobs.into_iter()
+        .zip(results)
+        .map(|(job, result)| Ok((job, result?)))
+        .collect()
+}
+
+// Compares a local file with the dataset files of the same path, preferring
+// a file with a matching checksum if the path is ambiguous
+fn verify_file(
+    path: &Path,
+    directory: Option<&str>,
+    files: &[File],
+    checksums: &HashMap<(PathBuf, String), String>,
+) -> Result<VerificationEntry, String> {
+    let local_size = std::fs::metadata(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?
+        .len();
+
+    let mut entry = VerificationEntry {
+        path: path.to_path_buf(),
+        directory_label: directory.map(str::to_string),
+        file_id: None,
+        local_size,
+        remote_size: None,
+        algorithm: None,
+        local_checksum: None,
+        remote_checksum: None,
+        status: VerificationStatus::Missing,
+        previous_hash: String::new(),
+        hash: String::new(),
+    };
+
+    let candidates: Vec<&File> = files.iter().filter(|file| matches_file(path, directory, file)).collect();
+    let mut best: Option<VerificationEntry> = None;
+
+    for file in candidates {
+        let Some(data_file) = &file.data_file else {
+            continue;
+        };
+        let checksum = data_file.checksum.as_ref();
+        let algorithm = checksum.and_then(|c| c.type_.clone());
+        let remote_checksum = checksum.and_then(|c| c.value.clone());
+
+        let local_checksum = algorithm
+            .as_ref()
+            .and_then(|algorithm| checksums.get(&(path.to_path_buf(), algorithm.clone())))
+            .cloned();
+
+        let tabular = data_file.tabular_data.unwrap_or(false);
+        let status = if local_checksum.is_none() || local_checksum != remote_checksum {
+            VerificationStatus::ChecksumMismatch
+        } else if !tabular && data_file.filesize != Some(local_size as i64) {
+            VerificationStatus::SizeMismatch
+        } else {
+            VerificationStatus::Verified
+        };
+
+        let candidate = VerificationEntry {
+            file_id: data_file.id,
+            remote_size: data_file.filesize,
+            algorithm,
+            local_checksum,
+            remote_checksum,
+            status,
+            ..entry.clone()
+        };
+
+        if candidate.status == VerificationStatus::Verified {
+            return Ok(candidate);
+        }
+        best.get_or_insert(candidate);
+    }
+
+    if let Some(best) = best {
+        entry = best;
+    }
+
+    Ok(entry)
+}
+
+// Files match if they are in the same directory and share the name. Tabular
+// files are stored with a `.tab` extension once ingested.
+fn matches_file(path: &Path, directory: Option<&str>, file: &File) -> bool {
+    let Some(label) = &file.label else {
+        return false;
+    };
+
+    // Leading and trailing slashes are dropped by the instance
+    let normalize = |directory: Option<&str>| directory.unwrap_or_default().trim_matches('/').to_string();
+    if normalize(directory) != normalize(file.directory_label.as_deref()) {
+        return false;
+    }
+
+    let name = path.file_name().map(|name| name.to_string_lossy());
+    let stem = path.file_stem().map(|stem| stem.to_string_lossy());
+
+    match (name, stem) {
+        (Some(name), _) if *label == name => true,
+        (_, Some(stem)) => {
+            let tabular = file
+                .data_file
+                .as_ref()
+                .and_then(|data_file| data_file.tabular_data)
+                .unwrap_or(false);
+            tabular && *label == format!("{}.tab", stem)
+        }
+        _ => false,
+    }
+}
+
+fn header_hash(dataset: &str, created: &str) -> String {
+    sha256_hex(format!("{}\n{}", dataset, created).as_bytes())
+}
+
+// Hashes the entry without its own hash, such that the result covers
+// the content and the link to the previous entry
+fn entry_hash(entry: &VerificationEntry) -> String {
+    let content = VerificationEntry {
+        hash: String::new(),
+        ..entry.clone()
+    };
+    let json = serde_json::to_string(&content).expect("Failed to serialize verification entry");
+
+    sha256_hex(json.as_bytes())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::{Path, PathBuf};
+
+    use crate::native_api::dataset::edit::File;
+
+    use super::{local_checksums, verify_file, VerificationEntry, VerificationReport, VerificationStatus};
+
+    fn dataset_file(label: &str, size: i64, algorithm: &str, checksum: &str) -> File {
+        let (directory, label) = match label.rsplit_once('/') {
+            Some((directory, label)) => (Some(directory), label),
+            None => (None, label),
+        };
+
+        serde_json::from_value(serde_json::json!({
+            "label": label,
+            "directoryLabel": directory,
+            "dataFile": {
+                "id": 7,
+                "filesize": size,
+                "checksum": {"type": algorithm, "value": checksum}
+            }
+        }))
+            .expect("Failed to parse file")
+    }
+
+    async fn verify_in(path: &Path, directory: Option<&str>, files: &[File]) -> VerificationEntry {
+        let local = [(path.to_path_buf(), directory.map(str::to_string))];
+        let checksums = local_checksums(&local, files).await.unwrap();
+        verify_file(path, directory, files, &checksums).unwrap()
+    }
+
+    async fn verify(path: &Path, files: &[File]) -> VerificationEntry {
+        verify_in(path, None, files).await
+    }
+
+    /// Tests the comparison of the test fixture against dataset files.
+    #[tokio::test]
+    async fn test_verify_file() {
+        let path = Path::new("tests/fixtures/file.txt");
+        let md5 = "a28bca1b906f539ba70ca3a0b1f2e773";
+
+        let entry = verify(path, &[dataset_file("file.txt", 16, "MD5", md5)]).await;
+        assert_eq!(entry.status, VerificationStatus::Verified);
+        assert_eq!(entry.file_id, Some(7));
+
+        let entry = verify(path, &[dataset_file("file.txt", 15, "MD5", md5)]).await;
+        assert_eq!(entry.status, VerificationStatus::SizeMismatch);
+
+        let entry = verify(path, &[dataset_file("file.txt", 16, "MD5", "0")]).await;
+        assert_eq!(entry.status, VerificationStatus::ChecksumMismatch);
+
+        let entry = verify(path, &[dataset_file("other.txt", 16, "MD5", md5)]).await;
+        assert_eq!(entry.status, VerificationStatus::Missing);
+
+        // A duplicate name with a matching checksum takes precedence
+        let files = [
+            dataset_file("file.txt", 16, "MD5", "0"),
+            dataset_file("file.txt", 16, "MD5", md5),
+        ];
+        let entry = verify(path, &files).await;
+        assert_eq!(entry.status, VerificationStatus::Verified);
+
+        // Files of the same name in other folders are told apart
+        let files = [
+            dataset_file("raw/file.txt", 16, "MD5", md5),
+            dataset_file("clean/file.txt", 16, "MD5", "0"),
+        ];
+        assert_eq!(verify(path, &files).await.status, VerificationStatus::Missing);
+        assert_eq!(verify_in(path, Some("raw"), &files).await.status, VerificationStatus::Verified);
+        assert_eq!(verify_in(path, Some("/clean/"), &files).await.status, VerificationStatus::ChecksumMismatch);
+    }
+
+    /// Tests that the hash chain detects altered, removed and reordered entries.
+    #[tokio::test]
+    async fn test_report_chain() {
+        let path = Path::new("tests/fixtures/file.txt");
+        let files = [dataset_file("file.txt", 16, "MD5", "a28bca1b906f539ba70ca3a0b1f2e773")];
+
+        let mut report = VerificationReport::new("doi:10.5072/FK2/ABC123");
+        report.push(verify(path, &files).await);
+        report.push(verify(&PathBuf::from("tests/fixtures/file.txt"), &[]).await);
+
+        assert!(report.is_intact());
+        assert!(!report.is_verified());
+        assert_eq!(report.failures(), 1);
+        assert_eq!(report.final_hash, report.entries[1].hash);
+
+        let parsed: VerificationReport = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+        assert!(parsed.is_intact());
+
+        let mut altered = report.clone();
+        altered.entries[1].status = VerificationStatus::Verified;
+        assert!(!altered.is_intact());
+
+        let mut removed = report.clone();
+        removed.entries.remove(0);
+        assert!(!removed.is_intact());
+
+        let mut reordered = report.clone();
+        reordered.entries.swap(0, 1);
+        assert!(!reordered.is_intact());
+
+        let mut redated = report.clone();
+        redated.created = "2000-01-01T00:00:00+00:00".to_string();
+        assert!(!redated.is_intact());
+    }
+}
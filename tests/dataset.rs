@@ -1,4 +1,4 @@
-#[cfg(test)]
+#[cfg(all(test, feature = "fs"))]
 mod tests {
     use std::fs;
 
@@ -29,7 +29,7 @@ mod tests {
         let body = serde_json::from_str::<DatasetCreateBody>(&body);
 
         // Act
-        let response = dataset::create::create_dataset(&client, &"Root".to_string(), body.unwrap())
+        let response = dataset::create::create_dataset(&client, "Root", body.unwrap())
             .await
             .expect("Could not create dataset");
 
@@ -59,13 +59,13 @@ mod tests {
     async fn test_dataset_publish() {
         // Part 1: Create a dataset
         // Arrange
-        let client = BaseClient::new(&BASE_URL.to_string(), Some(&API_TOKEN.to_string())).unwrap();
+        let client = BaseClient::new(&BASE_URL.to_string(), Some(&API_TOKEN)).unwrap();
         let body = fs::read_to_string("tests/fixtures/create_dataset_body.json")
             .expect("Could not read body");
         let body = serde_json::from_str::<DatasetCreateBody>(&body);
 
         // Act
-        let response = dataset::create::create_dataset(&client, &"Root".to_string(), body.unwrap())
+        let response = dataset::create::create_dataset(&client, "Root", body.unwrap())
             .await
             .expect("Could not create dataset");
 
@@ -109,7 +109,7 @@ mod tests {
 
         let body = serde_json::from_str::<DatasetCreateBody>(&body);
         let response =
-            dataset::create::create_dataset(&client, &"Root".to_string(), body.unwrap())
+            dataset::create::create_dataset(&client, "Root", body.unwrap())
                 .await
                 .expect("Could not create dataset");
 